@@ -0,0 +1,148 @@
+//! Generating colors that look good together.
+//!
+//! The colors are computed in the [Oklab](https://bottosson.github.io/posts/oklab/) color space,
+//! so that colors of the same lightness and chroma look equally bright and saturated,
+//! no matter their hue.
+
+use crate::{Color32, Rgba};
+
+/// The hue step between consecutive categorical colors, as a fraction of a full turn.
+///
+/// Stepping by the golden ratio never repeats, and keeps neighboring indices far apart.
+const GOLDEN_RATIO_CONJUGATE: f32 = 0.618_034;
+
+/// The `index`:th color in an endless cycle of distinct colors, e.g. for the series of a chart.
+///
+/// All colors have the same perceived lightness and saturation, and consecutive colors have very different hues.
+/// The lightness is chosen to stand out against a dark or light background.
+///
+/// ```
+/// use ecolor::color_palette::categorical;
+/// assert_ne!(categorical(0, true), categorical(1, true));
+/// assert_ne!(categorical(0, true), categorical(0, false));
+/// ```
+pub fn categorical(index: usize, dark_mode: bool) -> Color32 {
+    let (lightness, chroma) = if dark_mode {
+        (0.75, 0.14)
+    } else {
+        (0.55, 0.16)
+    };
+    let hue = (index as f32 * GOLDEN_RATIO_CONJUGATE).fract();
+    from_oklch(lightness, chroma, hue)
+}
+
+/// The first `n` colors of [`categorical`].
+pub fn categorical_palette(n: usize, dark_mode: bool) -> Vec<Color32> {
+    (0..n).map(|i| categorical(i, dark_mode)).collect()
+}
+
+/// Mix the color with black, keeping its hue.
+///
+/// `amount` goes from 0 (the color itself) to 1 (black).
+pub fn shade(color: Color32, amount: f32) -> Color32 {
+    mix_oklab(color, Color32::BLACK, amount)
+}
+
+/// Mix the color with white, keeping its hue.
+///
+/// `amount` goes from 0 (the color itself) to 1 (white).
+pub fn tint(color: Color32, amount: f32) -> Color32 {
+    mix_oklab(color, Color32::WHITE, amount)
+}
+
+/// `n` perceptually evenly spaced shades of the seed color, from the seed color itself towards black.
+///
+/// The darkest shade is not quite black, so that all shades are distinguishable from each other.
+pub fn shades(seed: Color32, n: usize) -> Vec<Color32> {
+    (0..n)
+        .map(|i| shade(seed, 0.8 * i as f32 / n as f32))
+        .collect()
+}
+
+/// `n` perceptually evenly spaced tints of the seed color, from the seed color itself towards white.
+///
+/// The lightest tint is not quite white, so that all tints are distinguishable from each other.
+pub fn tints(seed: Color32, n: usize) -> Vec<Color32> {
+    (0..n)
+        .map(|i| tint(seed, 0.8 * i as f32 / n as f32))
+        .collect()
+}
+
+/// Linear interpolation in Oklab, keeping the alpha of `from`.
+fn mix_oklab(from: Color32, to: Color32, t: f32) -> Color32 {
+    let t = t.clamp(0.0, 1.0);
+    let [r, g, b, a] = from.to_srgba_unmultiplied();
+    let from = oklab_from_color(Color32::from_rgb(r, g, b));
+    let to = oklab_from_color(to);
+    let lab = [0, 1, 2].map(|i| from[i] + t * (to[i] - from[i]));
+    let [r, g, b, _] = color_from_oklab(lab).to_array();
+    Color32::from_rgba_unmultiplied(r, g, b, a)
+}
+
+/// Lightness in 0-1, chroma (roughly 0-0.3), and hue in 0-1 turns.
+fn from_oklch(lightness: f32, chroma: f32, hue: f32) -> Color32 {
+    let angle = std::f32::consts::TAU * hue;
+    color_from_oklab([lightness, chroma * angle.cos(), chroma * angle.sin()])
+}
+
+fn oklab_from_color(color: Color32) -> [f32; 3] {
+    let rgba = Rgba::from(color);
+    let [r, g, b] = [rgba.r(), rgba.g(), rgba.b()];
+
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+    [
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    ]
+}
+
+/// Colors outside of the sRGB gamut are clamped.
+fn color_from_oklab([lightness, a, b]: [f32; 3]) -> Color32 {
+    let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+    let r = 4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s;
+    let g = -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s;
+    let b = -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s;
+
+    Rgba::from_rgb(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oklab_round_trip() {
+        for color in [
+            Color32::RED,
+            Color32::GREEN,
+            Color32::BLUE,
+            Color32::WHITE,
+            Color32::BLACK,
+            Color32::from_rgb(12, 200, 140),
+        ] {
+            assert_eq!(color_from_oklab(oklab_from_color(color)), color);
+        }
+    }
+
+    #[test]
+    fn shades_and_tints() {
+        let seed = Color32::from_rgb(40, 120, 220);
+        assert_eq!(shade(seed, 0.0), seed);
+        assert_eq!(shade(seed, 1.0), Color32::BLACK);
+        assert_eq!(tint(seed, 1.0), Color32::WHITE);
+
+        let shades = shades(seed, 4);
+        assert_eq!(shades.len(), 4);
+        assert_eq!(shades[0], seed);
+        assert!(shades
+            .windows(2)
+            .all(|w| w[0].r() >= w[1].r() && w[0].g() >= w[1].g() && w[0].b() >= w[1].b()));
+    }
+}
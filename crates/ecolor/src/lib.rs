@@ -31,6 +31,8 @@ pub use rgba::*;
 mod hex_color_runtime;
 pub use hex_color_runtime::*;
 
+pub mod color_palette;
+
 // ----------------------------------------------------------------------------
 // Color conversion:
 
@@ -285,7 +285,7 @@ impl EpiIntegration {
             if let Some(viewport_ui_cb) = viewport_ui_cb {
                 // Child viewport
                 crate::profile_scope!("viewport_callback");
-                viewport_ui_cb(egui_ctx);
+                viewport_ui_cb(egui_ctx, egui_ctx.viewport_ids());
             } else {
                 crate::profile_scope!("App::update");
                 app.update(egui_ctx, &mut self.frame);
@@ -399,6 +399,21 @@ pub fn load_egui_memory(_storage: Option<&dyn epi::Storage>) -> Option<egui::Mem
     None
 }
 
+/// Move what [`egui_winit::State`] learned from window events about its viewport (e.g. occlusion)
+/// into the [`egui::ViewportInfo`] we keep for it, before we overwrite [`egui::RawInput::viewports`].
+pub fn merge_egui_winit_viewport_info(
+    raw_input: &mut egui::RawInput,
+    viewport_id: ViewportId,
+    info: &mut egui::ViewportInfo,
+) {
+    if let Some(egui_winit_info) = raw_input.viewports.remove(&viewport_id) {
+        if egui_winit_info.occluded.is_some() {
+            info.occluded = egui_winit_info.occluded;
+        }
+        info.events.extend(egui_winit_info.events);
+    }
+}
+
 pub(crate) fn theme_from_winit_theme(theme: winit::window::Theme) -> Theme {
     match theme {
         winit::window::Theme::Dark => Theme::Dark,
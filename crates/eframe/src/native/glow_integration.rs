@@ -554,12 +554,23 @@ impl GlowWinitRunning {
             let Some(window) = viewport.window.as_ref() else {
                 return EventResult::Wait;
             };
-            egui_winit::update_viewport_info(&mut viewport.info, &egui_ctx, window, false);
+            egui_winit::update_viewport_info(
+                &mut viewport.info,
+                &egui_ctx,
+                viewport_id,
+                window,
+                false,
+            );
 
             let Some(egui_winit) = viewport.egui_winit.as_mut() else {
                 return EventResult::Wait;
             };
             let mut raw_input = egui_winit.take_egui_input(window);
+            epi_integration::merge_egui_winit_viewport_info(
+                &mut raw_input,
+                viewport_id,
+                &mut viewport.info,
+            );
             let viewport_ui_cb = viewport.viewport_ui_cb.clone();
 
             self.integration.pre_update();
@@ -638,6 +649,7 @@ impl GlowWinitRunning {
             shapes,
             pixels_per_point,
             viewport_output,
+            stats: _,
         } = full_output;
 
         glutin.remove_viewports_not_in(&viewport_output);
@@ -1008,7 +1020,7 @@ impl GlutinWindowContext {
         if let Some(window) = &window {
             viewport_from_window.insert(window.id(), ViewportId::ROOT);
             window_from_viewport.insert(ViewportId::ROOT, window.id());
-            egui_winit::update_viewport_info(&mut info, egui_ctx, window, true);
+            egui_winit::update_viewport_info(&mut info, egui_ctx, ViewportId::ROOT, window, true);
         }
 
         let mut viewports = ViewportIdMap::default();
@@ -1101,7 +1113,13 @@ impl GlutinWindowContext {
                 &viewport.builder,
             );
 
-            egui_winit::update_viewport_info(&mut viewport.info, &self.egui_ctx, &window, true);
+            egui_winit::update_viewport_info(
+                &mut viewport.info,
+                &self.egui_ctx,
+                viewport_id,
+                &window,
+                true,
+            );
             viewport.window.insert(Arc::new(window))
         };
 
@@ -1252,6 +1270,7 @@ impl GlutinWindowContext {
                 viewport_ui_cb,
                 mut commands,
                 repaint_delay: _, // ignored - we listened to the repaint callback instead
+                snapped: _,
             },
         ) in viewport_output.clone()
         {
@@ -1273,6 +1292,7 @@ impl GlutinWindowContext {
 
                 egui_winit::process_viewport_commands(
                     egui_ctx,
+                    viewport_id,
                     &mut viewport.info,
                     std::mem::take(&mut viewport.deferred_commands),
                     window,
@@ -1406,9 +1426,14 @@ fn render_immediate_viewport(
         let (Some(egui_winit), Some(window)) = (&mut viewport.egui_winit, &viewport.window) else {
             return;
         };
-        egui_winit::update_viewport_info(&mut viewport.info, egui_ctx, window, false);
+        egui_winit::update_viewport_info(&mut viewport.info, egui_ctx, viewport_id, window, false);
 
         let mut raw_input = egui_winit.take_egui_input(window);
+        epi_integration::merge_egui_winit_viewport_info(
+            &mut raw_input,
+            viewport_id,
+            &mut viewport.info,
+        );
         raw_input.viewports = glutin
             .viewports
             .iter()
@@ -1428,6 +1453,7 @@ fn render_immediate_viewport(
         shapes,
         pixels_per_point,
         viewport_output,
+        stats: _,
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
     });
@@ -278,7 +278,7 @@ impl WgpuWinitApp {
         viewport_from_window.insert(window.id(), ViewportId::ROOT);
 
         let mut info = ViewportInfo::default();
-        egui_winit::update_viewport_info(&mut info, &egui_ctx, &window, true);
+        egui_winit::update_viewport_info(&mut info, &egui_ctx, ViewportId::ROOT, &window, true);
 
         let mut viewports = Viewports::default();
         viewports.insert(
@@ -602,7 +602,13 @@ impl WgpuWinitRunning {
             let Some(window) = window else {
                 return EventResult::Wait;
             };
-            egui_winit::update_viewport_info(info, &integration.egui_ctx, window, false);
+            egui_winit::update_viewport_info(
+                info,
+                &integration.egui_ctx,
+                viewport_id,
+                window,
+                false,
+            );
 
             {
                 crate::profile_scope!("set_window");
@@ -617,6 +623,7 @@ impl WgpuWinitRunning {
                 return EventResult::Wait;
             };
             let mut raw_input = egui_winit.take_egui_input(window);
+            epi_integration::merge_egui_winit_viewport_info(&mut raw_input, viewport_id, info);
 
             integration.pre_update();
 
@@ -653,6 +660,7 @@ impl WgpuWinitRunning {
             shapes,
             pixels_per_point,
             viewport_output,
+            stats: _,
         } = full_output;
 
         remove_viewports_not_in(viewports, painter, viewport_from_window, &viewport_output);
@@ -879,7 +887,13 @@ impl Viewport {
                     painter.max_texture_side(),
                 ));
 
-                egui_winit::update_viewport_info(&mut self.info, egui_ctx, &window, true);
+                egui_winit::update_viewport_info(
+                    &mut self.info,
+                    egui_ctx,
+                    viewport_id,
+                    &window,
+                    true,
+                );
                 self.window = Some(window);
             }
             Err(err) => {
@@ -943,9 +957,10 @@ fn render_immediate_viewport(
         let (Some(window), Some(egui_winit)) = (&viewport.window, &mut viewport.egui_winit) else {
             return;
         };
-        egui_winit::update_viewport_info(&mut viewport.info, egui_ctx, window, false);
+        egui_winit::update_viewport_info(&mut viewport.info, egui_ctx, ids.this, window, false);
 
         let mut input = egui_winit.take_egui_input(window);
+        epi_integration::merge_egui_winit_viewport_info(&mut input, ids.this, &mut viewport.info);
         input.viewports = viewports
             .iter()
             .map(|(id, viewport)| (*id, viewport.info.clone()))
@@ -966,6 +981,7 @@ fn render_immediate_viewport(
         shapes,
         pixels_per_point,
         viewport_output,
+        stats: _,
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
     });
@@ -1053,6 +1069,7 @@ fn handle_viewport_output(
             viewport_ui_cb,
             mut commands,
             repaint_delay: _, // ignored - we listened to the repaint callback instead
+            snapped: _,
         },
     ) in viewport_output.clone()
     {
@@ -1069,6 +1086,7 @@ fn handle_viewport_output(
 
             egui_winit::process_viewport_commands(
                 egui_ctx,
+                viewport_id,
                 &mut viewport.info,
                 std::mem::take(&mut viewport.deferred_commands),
                 window,
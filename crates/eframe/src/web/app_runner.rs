@@ -197,6 +197,7 @@ impl AppRunner {
             shapes,
             pixels_per_point,
             viewport_output,
+            stats: _,
         } = full_output;
 
         if viewport_output.len() > 1 {
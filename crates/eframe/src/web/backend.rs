@@ -26,6 +26,7 @@ impl WebInput {
         let mut raw_input = egui::RawInput {
             screen_rect: Some(egui::Rect::from_min_size(Default::default(), canvas_size)),
             time: Some(super::now_sec()),
+            system_preferences: super::system_preferences(),
             ..self.raw.take()
         };
         raw_input
@@ -88,6 +88,19 @@ pub fn system_theme() -> Option<Theme> {
     Some(theme_from_dark_mode(dark_mode))
 }
 
+/// Ask the browser about the accessibility preferences of the user.
+///
+/// `None` means unknown.
+pub fn system_preferences() -> Option<egui::SystemPreferences> {
+    let window = web_sys::window()?;
+    let matches = |query: &str| Some(window.match_media(query).ok()??.matches());
+    Some(egui::SystemPreferences {
+        reduce_motion: matches("(prefers-reduced-motion: reduce)"),
+        high_contrast: matches("(prefers-contrast: more)"),
+        ..Default::default()
+    })
+}
+
 fn prefers_color_scheme_dark(window: &web_sys::Window) -> Result<Option<MediaQueryList>, JsValue> {
     window.match_media("(prefers-color-scheme: dark)")
 }
@@ -14,9 +14,15 @@ pub type CallbackResources = type_map::concurrent::TypeMap;
 /// Implement [`CallbackTrait`] and call [`Callback::new_paint_callback`].
 ///
 /// This can be turned into a [`epaint::PaintCallback`] and [`epaint::Shape`].
+/// To support other backends too, see [`epaint::PaintCallback::with_backend`].
 pub struct Callback(Box<dyn CallbackTrait>);
 
 impl Callback {
+    /// Wrap a callback trait instance, e.g. to pass to [`epaint::PaintCallback::with_backend`].
+    pub fn new(callback: impl CallbackTrait + 'static) -> Self {
+        Self(Box::new(callback))
+    }
+
     /// Creates a new [`epaint::PaintCallback`] from a callback trait instance.
     pub fn new_paint_callback(
         rect: epaint::emath::Rect,
@@ -32,10 +38,11 @@ impl Callback {
 /// A callback trait that can be used to compose an [`epaint::PaintCallback`] via [`Callback`]
 /// for custom WGPU rendering.
 ///
-/// Callbacks in [`Renderer`] are done in three steps:
+/// Callbacks in [`Renderer`] are done in four steps:
 /// * [`CallbackTrait::prepare`]: called for all registered callbacks before the main egui render pass.
 /// * [`CallbackTrait::finish_prepare`]: called for all registered callbacks after all callbacks finished calling prepare.
 /// * [`CallbackTrait::paint`]: called for all registered callbacks during the main egui render pass.
+/// * [`CallbackTrait::cleanup`]: called for all registered callbacks once the frame has been rendered.
 ///
 /// Each callback has access to an instance of [`CallbackResources`] that is stored in the [`Renderer`].
 /// This can be used to store wgpu resources that need to be accessed during the [`CallbackTrait::paint`] step.
@@ -106,6 +113,12 @@ pub trait CallbackTrait: Send + Sync {
         render_pass: &mut wgpu::RenderPass<'a>,
         callback_resources: &'a CallbackResources,
     );
+
+    /// Called after the frame this callback was painted in has been rendered,
+    /// at the start of the next [`Renderer::update_buffers`].
+    ///
+    /// Use this to free per-frame resources.
+    fn cleanup(&self, _callback_resources: &mut CallbackResources) {}
 }
 
 /// Information about the screen used for rendering.
@@ -172,6 +185,9 @@ pub struct Renderer {
     ///
     /// See also [`CallbackTrait`].
     pub callback_resources: CallbackResources,
+
+    /// The callbacks of the last frame, to call [`CallbackTrait::cleanup`] on.
+    previous_callbacks: Vec<epaint::PaintCallback>,
 }
 
 impl Renderer {
@@ -369,6 +385,7 @@ impl Renderer {
             next_user_texture_id: 0,
             samplers: HashMap::default(),
             callback_resources: CallbackResources::default(),
+            previous_callbacks: Vec::new(),
         }
     }
 
@@ -451,7 +468,7 @@ impl Renderer {
                     }
                 }
                 Primitive::Callback(callback) => {
-                    let Some(cbfn) = callback.callback.downcast_ref::<Callback>() else {
+                    let Some(cbfn) = callback.downcast_ref::<Callback>() else {
                         // We already warned in the `prepare` callback
                         continue;
                     };
@@ -776,6 +793,15 @@ impl Renderer {
     ) -> Vec<wgpu::CommandBuffer> {
         crate::profile_function!();
 
+        {
+            crate::profile_scope!("cleanup callbacks");
+            for callback in std::mem::take(&mut self.previous_callbacks) {
+                if let Some(c) = callback.downcast_ref::<Callback>() {
+                    c.0.cleanup(&mut self.callback_resources);
+                }
+            }
+        }
+
         let screen_size_in_points = screen_descriptor.screen_size_in_points();
 
         let uniform_buffer_content = UniformBuffer {
@@ -802,8 +828,9 @@ impl Renderer {
                         (acc.0 + mesh.vertices.len(), acc.1 + mesh.indices.len())
                     }
                     Primitive::Callback(callback) => {
-                        if let Some(c) = callback.callback.downcast_ref::<Callback>() {
+                        if let Some(c) = callback.downcast_ref::<Callback>() {
                             callbacks.push(c.0.as_ref());
+                            self.previous_callbacks.push(callback.clone());
                         } else {
                             log::warn!("Unknown paint callback: expected `egui_wgpu::Callback`");
                         };
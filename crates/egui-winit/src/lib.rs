@@ -45,6 +45,18 @@ pub fn pixels_per_point(egui_ctx: &egui::Context, window: &Window) -> f32 {
     egui_zoom_factor * native_pixels_per_point
 }
 
+/// Calculate the `pixels_per_point` for the window of a viewport,
+/// given the zoom factor of that viewport (see [`egui::Context::viewport_zoom_factor`]).
+pub fn viewport_pixels_per_point(
+    egui_ctx: &egui::Context,
+    viewport_id: ViewportId,
+    window: &Window,
+) -> f32 {
+    let native_pixels_per_point = window.scale_factor() as f32;
+    let egui_zoom_factor = egui_ctx.viewport_zoom_factor(viewport_id);
+    egui_zoom_factor * native_pixels_per_point
+}
+
 // ----------------------------------------------------------------------------
 
 #[must_use]
@@ -100,6 +112,11 @@ pub struct State {
 
     allow_ime: bool,
     ime_rect_px: Option<egui::Rect>,
+
+    /// The `pixels_per_point` that [`egui::RawInput::monitors`] was last queried with.
+    ///
+    /// `None` when the monitors may have changed, and need to be queried again.
+    monitors_pixels_per_point: Option<f32>,
 }
 
 impl State {
@@ -141,6 +158,8 @@ impl State {
 
             allow_ime: false,
             ime_rect_px: None,
+
+            monitors_pixels_per_point: None,
         };
 
         slf.egui_input
@@ -230,9 +249,9 @@ impl State {
         // On Windows, a minimized window will have 0 width and height.
         // See: https://github.com/rust-windowing/winit/issues/208
         // This solves an issue where egui window positions would be changed when minimizing on Windows.
+        let pixels_per_point = viewport_pixels_per_point(&self.egui_ctx, self.viewport_id, window);
         let screen_size_in_pixels = screen_size_in_pixels(window);
-        let screen_size_in_points =
-            screen_size_in_pixels / pixels_per_point(&self.egui_ctx, window);
+        let screen_size_in_points = screen_size_in_pixels / pixels_per_point;
 
         self.egui_input.screen_rect = (screen_size_in_points.x > 0.0
             && screen_size_in_points.y > 0.0)
@@ -247,7 +266,18 @@ impl State {
             .or_default()
             .native_pixels_per_point = Some(window.scale_factor() as f32);
 
-        self.egui_input.take()
+        if self.monitors_pixels_per_point != Some(pixels_per_point) {
+            // Querying the monitors can be slow, so we only do it when something may have changed:
+            self.egui_input.monitors = monitors_in_points(window, pixels_per_point);
+            self.monitors_pixels_per_point = Some(pixels_per_point);
+        }
+
+        let raw_input = self.egui_input.take();
+        if let Some(info) = self.egui_input.viewports.get_mut(&self.viewport_id) {
+            // Only report each viewport event once:
+            info.events.clear();
+        }
+        raw_input
     }
 
     /// Call this when there is a new event.
@@ -266,6 +296,17 @@ impl State {
         }
 
         use winit::event::WindowEvent;
+
+        if matches!(
+            event,
+            WindowEvent::ScaleFactorChanged { .. }
+                | WindowEvent::Moved(_)
+                | WindowEvent::Resized(_)
+        ) {
+            // The monitors may have been reconfigured:
+            self.monitors_pixels_per_point = None;
+        }
+
         match event {
             WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
                 let native_pixels_per_point = *scale_factor as f32;
@@ -281,6 +322,20 @@ impl State {
                     consumed: false,
                 }
             }
+            WindowEvent::Occluded(occluded) => {
+                let info = self
+                    .egui_input
+                    .viewports
+                    .entry(self.viewport_id)
+                    .or_default();
+                info.occluded = Some(*occluded);
+                info.events.push(egui::ViewportEvent::Occluded(*occluded));
+
+                EventResponse {
+                    repaint: true,
+                    consumed: false,
+                }
+            }
             WindowEvent::MouseInput { state, button, .. } => {
                 self.on_mouse_button_input(*state, *button);
                 EventResponse {
@@ -389,6 +444,7 @@ impl State {
             WindowEvent::HoveredFile(path) => {
                 self.egui_input.hovered_files.push(egui::HoveredFile {
                     path: Some(path.clone()),
+                    viewport_id: Some(self.viewport_id),
                     ..Default::default()
                 });
                 EventResponse {
@@ -407,6 +463,7 @@ impl State {
                 self.egui_input.hovered_files.clear();
                 self.egui_input.dropped_files.push(egui::DroppedFile {
                     path: Some(path.clone()),
+                    viewport_id: Some(self.viewport_id),
                     ..Default::default()
                 });
                 EventResponse {
@@ -442,7 +499,6 @@ impl State {
             WindowEvent::RedrawRequested
             | WindowEvent::CursorEntered { .. }
             | WindowEvent::Destroyed
-            | WindowEvent::Occluded(_)
             | WindowEvent::Resized(_)
             | WindowEvent::Moved(_)
             | WindowEvent::ThemeChanged(_)
@@ -541,7 +597,7 @@ impl State {
         window: &Window,
         pos_in_pixels: winit::dpi::PhysicalPosition<f64>,
     ) {
-        let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+        let pixels_per_point = viewport_pixels_per_point(&self.egui_ctx, self.viewport_id, window);
 
         let pos_in_points = egui::pos2(
             pos_in_pixels.x as f32 / pixels_per_point,
@@ -571,7 +627,7 @@ impl State {
     }
 
     fn on_touch(&mut self, window: &Window, touch: &winit::event::Touch) {
-        let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+        let pixels_per_point = viewport_pixels_per_point(&self.egui_ctx, self.viewport_id, window);
 
         // Emit touch event
         self.egui_input.events.push(egui::Event::Touch {
@@ -635,7 +691,7 @@ impl State {
     }
 
     fn on_mouse_wheel(&mut self, window: &Window, delta: winit::event::MouseScrollDelta) {
-        let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+        let pixels_per_point = viewport_pixels_per_point(&self.egui_ctx, self.viewport_id, window);
 
         {
             let (unit, delta) = match delta {
@@ -819,7 +875,8 @@ impl State {
         }
 
         if let Some(ime) = ime {
-            let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+            let pixels_per_point =
+                viewport_pixels_per_point(&self.egui_ctx, self.viewport_id, window);
             let ime_rect_px = pixels_per_point * ime.rect;
             if self.ime_rect_px != Some(ime_rect_px)
                 || self.egui_ctx.input(|i| !i.events.is_empty())
@@ -898,20 +955,48 @@ pub fn outer_rect_in_points(window: &Window, pixels_per_point: f32) -> Option<Re
     Some(outer_rect_px / pixels_per_point)
 }
 
+/// All monitors of the computer, in the same space as [`outer_rect_in_points`].
+pub fn monitors_in_points(window: &Window, pixels_per_point: f32) -> Vec<egui::MonitorInfo> {
+    crate::profile_function!();
+
+    let primary_monitor = window.primary_monitor();
+    window
+        .available_monitors()
+        .map(|monitor| {
+            let pos_px = monitor.position();
+            let pos_px = egui::pos2(pos_px.x as f32, pos_px.y as f32);
+            let size_px = monitor.size();
+            let size_px = egui::vec2(size_px.width as f32, size_px.height as f32);
+
+            egui::MonitorInfo {
+                name: monitor.name(),
+                rect: egui::Rect::from_min_size(pos_px, size_px) / pixels_per_point,
+                native_pixels_per_point: monitor.scale_factor() as f32,
+                is_primary: primary_monitor.as_ref().map(|primary| primary == &monitor),
+            }
+        })
+        .collect()
+}
+
 /// Update the given viewport info with the current state of the window.
 ///
+/// Unless `is_init`, this also adds a [`egui::ViewportEvent`] for each change since the last call,
+/// e.g. [`egui::ViewportEvent::Moved`].
+///
 /// Call before [`State::take_egui_input`].
 ///
 /// If this is called right after window creation, `is_init` should be `true`, otherwise `false`.
 pub fn update_viewport_info(
     viewport_info: &mut ViewportInfo,
     egui_ctx: &egui::Context,
+    viewport_id: ViewportId,
     window: &Window,
     is_init: bool,
 ) {
     crate::profile_function!();
 
-    let pixels_per_point = pixels_per_point(egui_ctx, window);
+    let previous = viewport_info.clone();
+    let pixels_per_point = viewport_pixels_per_point(egui_ctx, viewport_id, window);
 
     let has_a_position = match window.is_minimized() {
         Some(true) => false,
@@ -957,6 +1042,50 @@ pub fn update_viewport_info(
 
     viewport_info.fullscreen = Some(window.fullscreen().is_some());
     viewport_info.focused = Some(window.has_focus());
+
+    if !is_init {
+        push_viewport_events(&previous, viewport_info);
+    }
+}
+
+/// Add events for the changes between `previous` and `current`.
+fn push_viewport_events(previous: &ViewportInfo, current: &mut ViewportInfo) {
+    use egui::ViewportEvent;
+
+    if let Some(focused) = current.focused {
+        if previous.focused != Some(focused) {
+            current.events.push(ViewportEvent::Focused(focused));
+        }
+    }
+
+    if let (Some(previous_rect), Some(rect)) = (previous.outer_rect, current.outer_rect) {
+        if previous_rect.min != rect.min {
+            current.events.push(ViewportEvent::Moved);
+        }
+    }
+
+    match (previous.minimized, current.minimized) {
+        (Some(false), Some(true)) => current.events.push(ViewportEvent::Minimized),
+        (Some(true), Some(false)) => current.events.push(ViewportEvent::Restored),
+        _ => {}
+    }
+
+    if let (Some(previous_ppp), Some(ppp)) = (
+        previous.native_pixels_per_point,
+        current.native_pixels_per_point,
+    ) {
+        if previous_ppp != ppp {
+            current.events.push(ViewportEvent::ScaleFactorChanged);
+        }
+    }
+
+    if let (Some(previous_orientation), Some(orientation)) =
+        (previous.orientation, current.orientation)
+    {
+        if previous_orientation != orientation {
+            current.events.push(ViewportEvent::OrientationChanged);
+        }
+    }
 }
 
 fn open_url_in_browser(_url: &str) {
@@ -1257,6 +1386,7 @@ fn translate_cursor(cursor_icon: egui::CursorIcon) -> Option<winit::window::Curs
 
 pub fn process_viewport_commands(
     egui_ctx: &egui::Context,
+    viewport_id: ViewportId,
     info: &mut ViewportInfo,
     commands: impl IntoIterator<Item = ViewportCommand>,
     window: &Window,
@@ -1266,6 +1396,7 @@ pub fn process_viewport_commands(
     for command in commands {
         process_viewport_command(
             egui_ctx,
+            viewport_id,
             window,
             command,
             info,
@@ -1277,6 +1408,7 @@ pub fn process_viewport_commands(
 
 fn process_viewport_command(
     egui_ctx: &egui::Context,
+    viewport_id: ViewportId,
     window: &Window,
     command: ViewportCommand,
     info: &mut ViewportInfo,
@@ -1289,7 +1421,7 @@ fn process_viewport_command(
 
     log::trace!("Processing ViewportCommand::{command:?}");
 
-    let pixels_per_point = pixels_per_point(egui_ctx, window);
+    let pixels_per_point = viewport_pixels_per_point(egui_ctx, viewport_id, window);
 
     match command {
         ViewportCommand::Close => {
@@ -1361,6 +1493,17 @@ fn process_viewport_command(
                 pixels_per_point * pos.y,
             ));
         }
+        ViewportCommand::OuterPositionOnMonitor { monitor, position } => {
+            if let Some(monitor) = window.available_monitors().nth(monitor) {
+                let monitor_pos = monitor.position();
+                window.set_outer_position(PhysicalPosition::new(
+                    monitor_pos.x as f32 + pixels_per_point * position.x,
+                    monitor_pos.y as f32 + pixels_per_point * position.y,
+                ));
+            } else {
+                log::warn!("{command:?}: no such monitor");
+            }
+        }
         ViewportCommand::MinInnerSize(s) => {
             window.set_min_inner_size((s.is_finite() && s != Vec2::ZERO).then_some(
                 PhysicalSize::new(pixels_per_point * s.x, pixels_per_point * s.y),
@@ -1480,6 +1623,9 @@ fn process_viewport_command(
         ViewportCommand::Screenshot => {
             *screenshot_requested = true;
         }
+        ViewportCommand::ZoomFactor(_) => {
+            // Handled by egui
+        }
     }
 }
 
@@ -1503,6 +1649,19 @@ pub fn create_window<T>(
     Ok(window)
 }
 
+/// Windows groups (and pins) taskbar buttons by the `AppUserModelID` of the process.
+#[cfg(windows)]
+fn set_app_user_model_id(app_id: &str) {
+    let wide: Vec<u16> = app_id.encode_utf16().chain(std::iter::once(0)).collect();
+    // SAFETY: `wide` is a nul-terminated UTF-16 string, which outlives the call.
+    let result = unsafe {
+        windows_sys::Win32::UI::Shell::SetCurrentProcessExplicitAppUserModelID(wide.as_ptr())
+    };
+    if result != 0 {
+        log::warn!("Failed to set the AppUserModelID to {app_id:?}: HRESULT {result:#x}");
+    }
+}
+
 pub fn create_winit_window_builder<T>(
     egui_ctx: &egui::Context,
     event_loop: &EventLoopWindowTarget<T>,
@@ -1545,6 +1704,9 @@ pub fn create_winit_window_builder<T>(
         minimize_button,
         maximize_button,
         window_level,
+        modal,
+        persist: _,       // handled by egui
+        snap_distance: _, // handled by egui
 
         // macOS:
         fullsize_content_view: _fullsize_content_view,
@@ -1565,6 +1727,13 @@ pub fn create_winit_window_builder<T>(
         mouse_passthrough: _, // handled in `apply_viewport_builder_to_window`
     } = viewport_builder;
 
+    let window_level = window_level.unwrap_or(if modal.is_some() {
+        // Keep modal dialogs in front of their parent:
+        egui::viewport::WindowLevel::AlwaysOnTop
+    } else {
+        egui::viewport::WindowLevel::Normal
+    });
+
     let mut window_builder = winit::window::WindowBuilder::new()
         .with_title(title.unwrap_or_else(|| "egui window".to_owned()))
         .with_transparent(transparent.unwrap_or(false))
@@ -1572,7 +1741,7 @@ pub fn create_winit_window_builder<T>(
         .with_resizable(resizable.unwrap_or(true))
         .with_visible(visible.unwrap_or(true))
         .with_maximized(maximized.unwrap_or(false))
-        .with_window_level(match window_level.unwrap_or_default() {
+        .with_window_level(match window_level {
             egui::viewport::WindowLevel::AlwaysOnBottom => WindowLevel::AlwaysOnBottom,
             egui::viewport::WindowLevel::AlwaysOnTop => WindowLevel::AlwaysOnTop,
             egui::viewport::WindowLevel::Normal => WindowLevel::Normal,
@@ -1629,11 +1798,23 @@ pub fn create_winit_window_builder<T>(
     }
 
     #[cfg(all(feature = "wayland", target_os = "linux"))]
-    if let Some(app_id) = _app_id {
+    if let Some(app_id) = &_app_id {
         use winit::platform::wayland::WindowBuilderExtWayland as _;
         window_builder = window_builder.with_name(app_id, "");
     }
 
+    #[cfg(all(feature = "x11", target_os = "linux"))]
+    if let Some(app_id) = &_app_id {
+        // Used as both the class and instance of `WM_CLASS`:
+        window_builder =
+            winit::platform::x11::WindowBuilderExtX11::with_name(window_builder, app_id, app_id);
+    }
+
+    #[cfg(windows)]
+    if let Some(app_id) = &_app_id {
+        set_app_user_model_id(app_id);
+    }
+
     #[cfg(all(feature = "x11", target_os = "linux"))]
     {
         if let Some(window_type) = _window_type {
@@ -0,0 +1,101 @@
+use crate::*;
+
+/// A widget that has been measured, but not yet added.
+struct Item<'a> {
+    size: Vec2,
+    baseline: Option<f32>,
+    add: Box<dyn 'a + FnOnce(&mut Ui) -> Response>,
+}
+
+/// The widgets of a row, see [`Ui::aligned_row`].
+pub struct AlignedRow<'u, 'a> {
+    ui: &'u Ui,
+    items: Vec<Item<'a>>,
+    justified: bool,
+}
+
+impl<'u, 'a> AlignedRow<'u, 'a> {
+    /// Add a widget to the row.
+    ///
+    /// It is measured with [`Widget::preferred_size`] and [`Widget::baseline`] right away,
+    /// but only added to the [`Ui`] once the whole row is known.
+    pub fn add(&mut self, widget: impl Widget + 'a) {
+        self.items.push(Item {
+            size: widget.preferred_size(self.ui),
+            baseline: widget.baseline(self.ui),
+            add: Box::new(move |ui| widget.ui(ui)),
+        });
+    }
+
+    /// Shortcut for `add(Label::new(text))`.
+    pub fn label(&mut self, text: impl Into<WidgetText>) {
+        self.add(Label::new(text));
+    }
+
+    /// If `true`, the remaining width of the [`Ui`] is shared out equally between the widgets.
+    ///
+    /// Default: `false`.
+    pub fn justified(&mut self, justified: bool) {
+        self.justified = justified;
+    }
+}
+
+pub(crate) fn show<'a>(
+    ui: &mut Ui,
+    add_contents: impl FnOnce(&mut AlignedRow<'_, 'a>),
+) -> InnerResponse<Vec<Response>> {
+    let mut row = AlignedRow {
+        ui: &*ui,
+        items: vec![],
+        justified: false,
+    };
+    add_contents(&mut row);
+    let AlignedRow {
+        items, justified, ..
+    } = row;
+
+    let spacing = ui.spacing().item_spacing.x;
+    let n = items.len();
+    let natural_width =
+        items.iter().map(|item| item.size.x).sum::<f32>() + spacing * n.saturating_sub(1) as f32;
+    let extra_width = if justified && 0 < n {
+        ((ui.available_width() - natural_width) / n as f32).at_least(0.0)
+    } else {
+        0.0
+    };
+
+    // Line up the baselines of the widgets that have one, and center the rest:
+    let above_baseline = items
+        .iter()
+        .filter_map(|item| item.baseline)
+        .fold(0.0, f32::max);
+    let below_baseline = items
+        .iter()
+        .filter_map(|item| Some(item.size.y - item.baseline?))
+        .fold(0.0, f32::max);
+    let height = items
+        .iter()
+        .map(|item| item.size.y)
+        .fold(above_baseline + below_baseline, f32::max);
+    let baseline = 0.5 * (height - above_baseline - below_baseline) + above_baseline;
+
+    let width = natural_width + extra_width * n as f32;
+    let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+
+    let mut x = rect.left();
+    let responses = items
+        .into_iter()
+        .map(|item| {
+            let top = match item.baseline {
+                Some(item_baseline) => rect.top() + baseline - item_baseline,
+                None => rect.center().y - 0.5 * item.size.y,
+            };
+            let item_rect =
+                Rect::from_min_size(pos2(x, top), vec2(item.size.x + extra_width, item.size.y));
+            x = item_rect.right() + spacing;
+            ui.put(item_rect, item.add)
+        })
+        .collect();
+
+    InnerResponse::new(responses, response)
+}
@@ -0,0 +1,201 @@
+//! A debug tool that records what the app copies to the clipboard.
+//!
+//! This helps diagnosing "copy didn't work" reports in complex UIs:
+//! open [`window`] and see if (and what) the app actually copied.
+//!
+//! Requires the `clipboard_history` feature.
+//! By default it only records in debug builds; call [`set_enabled`] to change that.
+//!
+//! ```
+//! # egui::__run_test_ctx(|ctx| {
+//! # let mut show_clipboard_history = true;
+//! egui::clipboard_history::window(ctx, &mut show_clipboard_history);
+//! # });
+//! ```
+
+use std::collections::VecDeque;
+
+use crate::*;
+
+/// How many copy events to keep.
+const MAX_EVENTS: usize = 100;
+
+/// Records whatever was copied to the clipboard this frame.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_end_frame("clipboard_history", std::sync::Arc::new(State::end_frame));
+}
+
+/// The app copied some text to the clipboard.
+#[derive(Clone, Debug)]
+pub struct CopyEvent {
+    /// What was copied.
+    pub text: String,
+
+    /// The frame it was copied in, see [`Context::frame_nr`].
+    pub frame_nr: u64,
+
+    /// The time it was copied, see [`InputState::time`].
+    pub time: f64,
+
+    /// The viewport that copied it.
+    pub viewport_id: ViewportId,
+
+    /// The widget that had keyboard focus when the text was copied, if any.
+    ///
+    /// This is usually the widget that did the copying, e.g. a [`TextEdit`].
+    pub source: Option<Id>,
+}
+
+/// Start or stop recording.
+///
+/// Default: `true` in debug builds, `false` in release builds.
+pub fn set_enabled(ctx: &Context, enabled: bool) {
+    ctx.data_mut(|data| data.get_temp_mut_or_default::<State>(Id::NULL).enabled = Some(enabled));
+}
+
+/// Are copy events being recorded?
+pub fn is_enabled(ctx: &Context) -> bool {
+    ctx.data_mut(|data| data.get_temp_mut_or_default::<State>(Id::NULL).is_enabled())
+}
+
+/// The recorded copy events, oldest first.
+pub fn events(ctx: &Context) -> Vec<CopyEvent> {
+    ctx.data_mut(|data| {
+        let state = data.get_temp_mut_or_default::<State>(Id::NULL);
+        state.events.iter().cloned().collect()
+    })
+}
+
+/// Forget all recorded copy events.
+pub fn clear(ctx: &Context) {
+    ctx.data_mut(|data| {
+        data.get_temp_mut_or_default::<State>(Id::NULL)
+            .events
+            .clear()
+    });
+}
+
+/// Show a window listing the recorded copy events, newest first.
+pub fn window(ctx: &Context, open: &mut bool) {
+    Window::new("📋 Clipboard history")
+        .open(open)
+        .default_width(400.0)
+        .show(ctx, |ui| {
+            ui_impl(ctx, ui);
+        });
+}
+
+/// Show the recorded copy events, newest first.
+pub fn ui(ui: &mut Ui) {
+    let ctx = ui.ctx().clone();
+    ui_impl(&ctx, ui);
+}
+
+fn ui_impl(ctx: &Context, ui: &mut Ui) {
+    ui.horizontal(|ui| {
+        let mut enabled = is_enabled(ctx);
+        if ui.checkbox(&mut enabled, "Record").changed() {
+            set_enabled(ctx, enabled);
+        }
+        if ui.button("Clear").clicked() {
+            clear(ctx);
+        }
+    });
+
+    let events = events(ctx);
+    if events.is_empty() {
+        ui.weak("Nothing has been copied yet.");
+        return;
+    }
+
+    ui.separator();
+
+    ScrollArea::vertical()
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            Grid::new("clipboard_history")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong("Frame");
+                    ui.strong("Size");
+                    ui.strong("Source");
+                    ui.strong("Text");
+                    ui.end_row();
+
+                    for event in events.iter().rev() {
+                        ui.label(event.frame_nr.to_string())
+                            .on_hover_text(format!("{:.3} s", event.time));
+                        ui.label(format!(
+                            "{} B, {} chars",
+                            event.text.len(),
+                            event.text.chars().count()
+                        ));
+                        match event.source {
+                            Some(source) => {
+                                ui.monospace(source.short_debug_format());
+                            }
+                            None => {
+                                ui.weak("–");
+                            }
+                        }
+                        let first_line = event.text.lines().next().unwrap_or_default();
+                        let preview = if first_line.chars().count() > 40 {
+                            format!("{}…", first_line.chars().take(40).collect::<String>())
+                        } else if first_line.len() < event.text.len() {
+                            format!("{first_line}…")
+                        } else {
+                            first_line.to_owned()
+                        };
+                        ui.monospace(preview).on_hover_text(&event.text);
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+/// A plugin for recording what is copied to the clipboard.
+///
+/// This is a built-in plugin in egui, if the `clipboard_history` feature is enabled.
+#[derive(Clone, Default)]
+struct State {
+    /// `None` means the default.
+    enabled: Option<bool>,
+
+    /// Oldest first.
+    events: VecDeque<CopyEvent>,
+}
+
+impl State {
+    fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or(cfg!(debug_assertions))
+    }
+
+    fn end_frame(ctx: &Context) {
+        let copied_text = ctx.output(|o| o.copied_text.clone());
+        if copied_text.is_empty() {
+            return;
+        }
+
+        let event = CopyEvent {
+            text: copied_text,
+            frame_nr: ctx.frame_nr(),
+            time: ctx.input(|i| i.time),
+            viewport_id: ctx.viewport_id(),
+            source: ctx.memory(|m| m.focused()),
+        };
+
+        ctx.data_mut(|data| {
+            // We use `Id::NULL` as the id, since we only have one instance of this plugin.
+            // We use the `temp` version instead of `persisted` since we don't want to
+            // persist what was copied on disk when the egui app is closed.
+            let state = data.get_temp_mut_or_default::<Self>(Id::NULL);
+            if state.is_enabled() {
+                state.events.push_back(event);
+                while MAX_EVENTS < state.events.len() {
+                    state.events.pop_front();
+                }
+            }
+        });
+    }
+}
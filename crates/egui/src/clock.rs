@@ -0,0 +1,85 @@
+//! Custom time sources, see [`crate::Context::set_clock`].
+
+/// Decides the time egui sees, instead of the time given by the integration.
+///
+/// All animations, cursor blinking, tooltip delays etc. read [`crate::InputState::time`]
+/// and [`crate::InputState::stable_dt`], which come from the clock.
+///
+/// Any `FnMut(Option<f64>, f64) -> f64` closure is a [`Clock`] too.
+pub trait Clock: Send + Sync {
+    /// The time of the new frame, in seconds.
+    ///
+    /// `real_time` is [`crate::RawInput::time`] as given by the integration, if any,
+    /// and `last_time` is the time of the previous frame of the same viewport.
+    ///
+    /// Should never be less than `last_time`.
+    fn frame_time(&mut self, real_time: Option<f64>, last_time: f64) -> f64;
+}
+
+impl<F> Clock for F
+where
+    F: FnMut(Option<f64>, f64) -> f64 + Send + Sync,
+{
+    fn frame_time(&mut self, real_time: Option<f64>, last_time: f64) -> f64 {
+        self(real_time, last_time)
+    }
+}
+
+/// Advances time by the same amount every frame, no matter how long the frame really took.
+///
+/// Useful for tests and for recording videos, where the result should not depend on how fast the computer is.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FixedStepClock {
+    /// Seconds per frame.
+    pub step: f64,
+}
+
+impl FixedStepClock {
+    /// `step` is the number of seconds per frame, e.g. `1.0 / 60.0`.
+    pub fn new(step: f64) -> Self {
+        Self { step }
+    }
+}
+
+impl Clock for FixedStepClock {
+    fn frame_time(&mut self, _real_time: Option<f64>, last_time: f64) -> f64 {
+        last_time + self.step.max(0.0)
+    }
+}
+
+/// Lets time pass faster or slower than real time, e.g. for slow-motion debugging of animations.
+///
+/// Time continues from where it was when the clock was set, so it doesn't jump.
+/// If the integration doesn't provide [`crate::RawInput::time`], time doesn't advance.
+///
+/// See also [`crate::Context::set_debug_time_scale`], which is easier to change at runtime.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScaledClock {
+    /// `0.5` means half speed.
+    pub scale: f64,
+
+    /// The real time and the egui time the first time the clock was asked.
+    anchor: Option<(f64, f64)>,
+}
+
+impl ScaledClock {
+    /// `scale` is how fast time passes, e.g. `0.1` for ten times slower than normal.
+    pub fn new(scale: f64) -> Self {
+        Self {
+            scale,
+            anchor: None,
+        }
+    }
+}
+
+impl Clock for ScaledClock {
+    fn frame_time(&mut self, real_time: Option<f64>, last_time: f64) -> f64 {
+        let Some(real_time) = real_time else {
+            return last_time;
+        };
+        let (anchor_real_time, anchor_time) = *self.anchor.get_or_insert((real_time, last_time));
+        let time = anchor_time + (real_time - anchor_real_time) * self.scale.max(0.0);
+        // Each viewport has its own time, which may be ahead of the anchor:
+        time.max(last_time)
+    }
+}
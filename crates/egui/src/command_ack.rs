@@ -0,0 +1,114 @@
+//! Acknowledgements of viewport commands, see [`Context::viewport_command_with_ack`].
+
+use std::sync::Arc;
+
+use crate::{
+    mutex::Mutex, Context, Id, Promise, ViewportCommand, ViewportCommandAck, ViewportCommandStatus,
+    ViewportId, ViewportInfo,
+};
+
+type Fulfill = Box<dyn FnOnce(ViewportCommandAck) + Send>;
+
+struct Pending {
+    viewport_id: ViewportId,
+    command: ViewportCommand,
+
+    /// [`Context::frame_nr_for`] the viewport when the command was sent.
+    frame_nr: u64,
+
+    fulfill: Fulfill,
+}
+
+/// The commands we are waiting to acknowledge.
+#[derive(Clone, Default)]
+struct State {
+    pending: Arc<Mutex<Vec<Pending>>>,
+}
+
+pub(crate) fn send(
+    ctx: &Context,
+    viewport_id: ViewportId,
+    command: ViewportCommand,
+) -> Promise<ViewportCommandAck> {
+    let (promise, fulfill) = Promise::new_pending(ctx);
+
+    let pending = ctx.data_mut(|d| d.get_temp_mut_or_default::<State>(Id::NULL).pending.clone());
+    pending.lock().push(Pending {
+        viewport_id,
+        command: command.clone(),
+        frame_nr: ctx.frame_nr_for(viewport_id),
+        fulfill: Box::new(fulfill),
+    });
+
+    ctx.send_viewport_cmd_to(viewport_id, command);
+    promise
+}
+
+/// Resolves the acknowledgements of the viewport commands the integration has handled.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_end_frame("command_ack", Arc::new(end_frame));
+}
+
+fn end_frame(ctx: &Context) {
+    let Some(State { pending }) = ctx.data(|d| d.get_temp::<State>(Id::NULL)) else {
+        return;
+    };
+    if pending.lock().is_empty() {
+        return;
+    }
+
+    // The input of this frame shows what the integration did with the commands of the previous frame:
+    let viewport_id = ctx.viewport_id();
+    let frame_nr = ctx.frame_nr();
+    let resolved: Vec<Pending> = {
+        let mut pending = pending.lock();
+        let (resolved, waiting): (Vec<Pending>, Vec<Pending>) = std::mem::take(&mut *pending)
+            .into_iter()
+            .partition(|p| p.viewport_id == viewport_id && p.frame_nr < frame_nr);
+        *pending = waiting;
+        resolved
+    };
+    if resolved.is_empty() {
+        return;
+    }
+
+    let info = ctx.input(|i| i.viewport().clone());
+    for Pending {
+        command, fulfill, ..
+    } in resolved
+    {
+        let status = match is_applied(&command, &info) {
+            Some(true) => ViewportCommandStatus::Applied,
+            Some(false) => ViewportCommandStatus::Rejected,
+            None => ViewportCommandStatus::Unknown,
+        };
+        fulfill(ViewportCommandAck {
+            command,
+            status,
+            info: info.clone(),
+        });
+    }
+}
+
+/// Is the viewport in the state requested by the command?
+///
+/// `None` if we can't tell, either because the command doesn't affect [`ViewportInfo`],
+/// or because the integration doesn't report that part of it.
+fn is_applied(command: &ViewportCommand, info: &ViewportInfo) -> Option<bool> {
+    const TOLERANCE: f32 = 1.0; // points
+
+    match command {
+        ViewportCommand::Title(title) => Some(info.title.as_ref()? == title),
+        ViewportCommand::InnerSize(size) => {
+            Some((info.inner_rect?.size() - *size).length() < TOLERANCE)
+        }
+        ViewportCommand::OuterPosition(pos) => {
+            Some(info.outer_rect?.min.distance(*pos) < TOLERANCE)
+        }
+        ViewportCommand::Minimized(minimized) => Some(info.minimized? == *minimized),
+        ViewportCommand::Maximized(maximized) => Some(info.maximized? == *maximized),
+        ViewportCommand::Fullscreen(fullscreen) => Some(info.fullscreen? == *fullscreen),
+        ViewportCommand::Focus => info.focused,
+        _ => None,
+    }
+}
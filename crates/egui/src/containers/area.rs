@@ -42,6 +42,44 @@ impl State {
     }
 }
 
+/// How an [`Area`] behaves when dragged against the edges of the rectangle it is constrained to.
+///
+/// See [`Area::edge_behavior`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum EdgeBehavior {
+    /// The area can't be moved past the edges.
+    #[default]
+    Clamp,
+
+    /// The area resists being dragged past the edges,
+    /// but once dragged more than `distance` points beyond them it breaks free
+    /// and can be placed anywhere.
+    ///
+    /// Dragging it back inside re-engages the constraint.
+    Resist { distance: f32 },
+
+    /// The area can be dragged past the edges (with some resistance),
+    /// but snaps back inside when released.
+    SnapBack,
+}
+
+/// How much of the drag past an edge actually moves the area,
+/// for [`EdgeBehavior::Resist`] and [`EdgeBehavior::SnapBack`].
+const EDGE_RESISTANCE: f32 = 0.25;
+
+/// How quickly [`EdgeBehavior::SnapBack`] moves the area back inside, in 1/seconds.
+const SNAP_BACK_SPEED: f32 = 15.0;
+
+/// Temporary state for [`EdgeBehavior::Resist`] and [`EdgeBehavior::SnapBack`].
+#[derive(Clone, Copy, Debug, Default)]
+struct EdgeState {
+    /// Where the area would be if it wasn't constrained, while being dragged.
+    unconstrained_pos: Option<Pos2>,
+
+    /// Has the area broken free of [`EdgeBehavior::Resist`]?
+    escaped: bool,
+}
+
 /// An area on the screen that can be moved by dragging.
 ///
 /// This forms the base of the [`Window`] container.
@@ -67,6 +105,7 @@ pub struct Area {
     enabled: bool,
     constrain: bool,
     constrain_rect: Option<Rect>,
+    edge_behavior: EdgeBehavior,
     order: Order,
     default_pos: Option<Pos2>,
     pivot: Align2,
@@ -84,6 +123,7 @@ impl Area {
             interactable: true,
             constrain: false,
             constrain_rect: None,
+            edge_behavior: EdgeBehavior::Clamp,
             enabled: true,
             order: Order::Middle,
             default_pos: None,
@@ -188,6 +228,28 @@ impl Area {
         self
     }
 
+    /// What happens when the area is dragged against the edges of the constraint rectangle.
+    ///
+    /// Only has an effect if the area is constrained, see [`Self::constrain`] and [`Self::constrain_to`].
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// egui::Area::new(egui::Id::new("tool"))
+    ///     .constrain_to(ctx.available_rect())
+    ///     .edge_behavior(egui::EdgeBehavior::Resist { distance: 64.0 })
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Drag me");
+    ///     });
+    /// # });
+    /// ```
+    ///
+    /// Default: [`EdgeBehavior::Clamp`].
+    #[inline]
+    pub fn edge_behavior(mut self, edge_behavior: EdgeBehavior) -> Self {
+        self.edge_behavior = edge_behavior;
+        self
+    }
+
     /// Where the "root" of the area is.
     ///
     /// For instance, if you set this to [`Align2::RIGHT_TOP`]
@@ -241,6 +303,7 @@ pub(crate) struct Prepared {
     enabled: bool,
     constrain: bool,
     constrain_rect: Option<Rect>,
+    edge_behavior: EdgeBehavior,
 
     /// We always make windows invisible the first frame to hide "first-frame-jitters".
     ///
@@ -277,6 +340,7 @@ impl Area {
             anchor,
             constrain,
             constrain_rect,
+            edge_behavior,
         } = self;
 
         let layer_id = LayerId::new(order, id);
@@ -332,6 +396,17 @@ impl Area {
 
             if movable && move_response.dragged() {
                 state.pivot_pos += move_response.drag_delta();
+
+                #[cfg(debug_assertions)]
+                if ctx.style().debug.show_alignment_guides {
+                    state.set_left_top_pos(crate::debug_guides::snap_dragged_area(
+                        ctx,
+                        layer_id,
+                        state.rect(),
+                        move_response.drag_delta(),
+                        move_response.drag_started(),
+                    ));
+                }
             }
 
             if (move_response.dragged() || move_response.clicked())
@@ -346,10 +421,15 @@ impl Area {
         };
 
         if constrain {
-            state.set_left_top_pos(
-                ctx.constrain_window_rect_to_area(state.rect(), constrain_rect)
-                    .min,
-            );
+            let is_dragged = movable && move_response.dragged();
+            state.set_left_top_pos(constrained_pos(
+                ctx,
+                id,
+                edge_behavior,
+                state.rect(),
+                constrain_rect,
+                is_dragged.then(|| move_response.drag_delta()),
+            ));
         }
 
         state.set_left_top_pos(ctx.round_pos_to_pixels(state.left_top_pos()));
@@ -365,6 +445,7 @@ impl Area {
             enabled,
             constrain,
             constrain_rect,
+            edge_behavior,
             temporarily_invisible: is_new,
         }
     }
@@ -438,7 +519,11 @@ impl Prepared {
                 .at_least(self.state.left_top_pos() + Vec2::splat(32.0)),
         );
 
-        let clip_rect = constrain_rect; // Don't paint outside our bounds
+        let clip_rect = if self.edge_behavior == EdgeBehavior::Clamp {
+            constrain_rect // Don't paint outside our bounds
+        } else {
+            screen_rect // We may be dragged outside our bounds
+        };
 
         let mut ui = Ui::new(
             ctx.clone(),
@@ -461,6 +546,7 @@ impl Prepared {
             enabled: _,
             constrain: _,
             constrain_rect: _,
+            edge_behavior: _,
             temporarily_invisible: _,
         } = self;
 
@@ -472,6 +558,78 @@ impl Prepared {
     }
 }
 
+/// Where to put the left-top corner of a constrained area.
+///
+/// `drag_delta` is `Some` while the area is being dragged.
+fn constrained_pos(
+    ctx: &Context,
+    id: Id,
+    edge_behavior: EdgeBehavior,
+    rect: Rect,
+    constrain_rect: Option<Rect>,
+    drag_delta: Option<Vec2>,
+) -> Pos2 {
+    let constrain = |pos: Pos2| {
+        ctx.constrain_window_rect_to_area(Rect::from_min_size(pos, rect.size()), constrain_rect)
+            .min
+    };
+
+    if edge_behavior == EdgeBehavior::Clamp {
+        return constrain(rect.min);
+    }
+
+    let edge_id = id.with("edge_behavior");
+    let mut edge_state: EdgeState = ctx.data_mut(|d| d.get_temp(edge_id)).unwrap_or_default();
+
+    let pos = if let Some(drag_delta) = drag_delta {
+        let unconstrained = edge_state
+            .unconstrained_pos
+            .map_or(rect.min, |pos| pos + drag_delta);
+        edge_state.unconstrained_pos = Some(unconstrained);
+
+        let inside = constrain(unconstrained);
+        let overshoot = unconstrained - inside;
+
+        if let EdgeBehavior::Resist { distance } = edge_behavior {
+            if edge_state.escaped && overshoot == Vec2::ZERO {
+                edge_state.escaped = false; // back inside
+            } else if distance < overshoot.length() {
+                edge_state.escaped = true;
+            }
+        }
+
+        if edge_state.escaped {
+            unconstrained
+        } else {
+            inside + EDGE_RESISTANCE * overshoot
+        }
+    } else {
+        edge_state.unconstrained_pos = None;
+
+        if edge_state.escaped {
+            rect.min
+        } else {
+            let target = constrain(rect.min);
+            if edge_behavior == EdgeBehavior::SnapBack && target != rect.min && !ctx.reduce_motion()
+            {
+                let dt = ctx.input(|i| i.stable_dt).at_most(0.1);
+                let pos = rect.min.lerp(target, 1.0 - (-SNAP_BACK_SPEED * dt).exp());
+                if pos.distance(target) < 2.0 {
+                    target
+                } else {
+                    ctx.request_repaint();
+                    pos
+                }
+            } else {
+                target
+            }
+        }
+    };
+
+    ctx.data_mut(|d| d.insert_temp(edge_id, edge_state));
+    pos
+}
+
 fn pointer_pressed_on_area(ctx: &Context, layer_id: LayerId) -> bool {
     if let Some(pointer_pos) = ctx.pointer_interact_pos() {
         let any_pressed = ctx.input(|i| i.pointer.any_pressed());
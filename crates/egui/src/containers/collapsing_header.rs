@@ -334,7 +334,13 @@ pub fn paint_default_icon(ui: &mut Ui, openness: f32, response: &Response) {
     let rect = rect.expand(visuals.expansion);
     let mut points = vec![rect.left_top(), rect.right_top(), rect.center_bottom()];
     use std::f32::consts::TAU;
-    let rotation = emath::Rot2::from_angle(remap(openness, 0.0..=1.0, -TAU / 4.0..=0.0));
+    // When closed, the arrow points towards the text:
+    let closed_angle = if ui.layout_direction().is_right_to_left() {
+        TAU / 4.0
+    } else {
+        -TAU / 4.0
+    };
+    let rotation = emath::Rot2::from_angle(remap(openness, 0.0..=1.0, closed_angle..=0.0));
     for p in &mut points {
         *p = rect.center() + rotation * (*p - rect.center());
     }
@@ -520,8 +526,14 @@ impl CollapsingHeader {
         let (_, rect) = ui.allocate_space(desired_size);
 
         let mut header_response = ui.interact(rect, id, Sense::click());
+        let right_to_left = ui.layout_direction().is_right_to_left();
+        let text_x = if right_to_left {
+            header_response.rect.right() - ui.spacing().indent - galley.size().x
+        } else {
+            text_pos.x
+        };
         let text_pos = pos2(
-            text_pos.x,
+            text_x,
             header_response.rect.center().y - galley.size().y / 2.0,
         );
 
@@ -563,10 +575,12 @@ impl CollapsingHeader {
 
             {
                 let (mut icon_rect, _) = ui.spacing().icon_rectangles(header_response.rect);
-                icon_rect.set_center(pos2(
-                    header_response.rect.left() + ui.spacing().indent / 2.0,
-                    header_response.rect.center().y,
-                ));
+                let icon_x = if right_to_left {
+                    header_response.rect.right() - ui.spacing().indent / 2.0
+                } else {
+                    header_response.rect.left() + ui.spacing().indent / 2.0
+                };
+                icon_rect.set_center(pos2(icon_x, header_response.rect.center().y));
                 let icon_response = header_response.clone().with_new_rect(icon_rect);
                 if let Some(icon) = icon {
                     icon(ui, openness, &icon_response);
@@ -8,18 +8,22 @@ mod combo_box;
 pub(crate) mod frame;
 pub mod panel;
 pub mod popup;
+pub(crate) mod progress_dialog;
 pub(crate) mod resize;
 pub mod scroll_area;
+pub(crate) mod toolbar;
 pub(crate) mod window;
 
 pub use {
-    area::Area,
+    area::{Area, EdgeBehavior},
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
     frame::Frame,
     panel::{CentralPanel, SidePanel, TopBottomPanel},
     popup::*,
+    progress_dialog::{CancellationToken, ProgressDialog},
     resize::Resize,
     scroll_area::ScrollArea,
+    toolbar::{Toolbar, ToolbarUi},
     window::Window,
 };
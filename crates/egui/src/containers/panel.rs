@@ -34,8 +34,9 @@ impl PanelState {
         self.rect.size()
     }
 
-    fn store(self, ctx: &Context, bar_id: Id) {
+    pub(crate) fn store(self, ctx: &Context, bar_id: Id) {
         ctx.data_mut(|d| d.insert_persisted(bar_id, self));
+        ctx.memory_mut(|mem| mem.panel_ids.insert(bar_id));
     }
 }
 
@@ -56,6 +57,15 @@ impl Side {
         }
     }
 
+    /// Panels swap sides in right-to-left layouts.
+    fn for_direction(self, direction: LayoutDirection) -> Self {
+        if direction.is_right_to_left() {
+            self.opposite()
+        } else {
+            self
+        }
+    }
+
     fn set_rect_width(self, rect: &mut Rect, width: f32) {
         match self {
             Self::Left => rect.max.x = rect.min.x + width,
@@ -98,15 +108,20 @@ pub struct SidePanel {
     show_separator_line: bool,
     default_width: f32,
     width_range: Rangef,
+    respect_safe_area: bool,
 }
 
 impl SidePanel {
     /// The id should be globally unique, e.g. `Id::new("my_left_panel")`.
+    ///
+    /// With [`LayoutDirection::RightToLeft`] the panel is shown on the right side instead.
     pub fn left(id: impl Into<Id>) -> Self {
         Self::new(Side::Left, id)
     }
 
     /// The id should be globally unique, e.g. `Id::new("my_right_panel")`.
+    ///
+    /// With [`LayoutDirection::RightToLeft`] the panel is shown on the left side instead.
     pub fn right(id: impl Into<Id>) -> Self {
         Self::new(Side::Right, id)
     }
@@ -121,6 +136,7 @@ impl SidePanel {
             show_separator_line: true,
             default_width: 200.0,
             width_range: Rangef::new(96.0, f32::INFINITY),
+            respect_safe_area: false,
         }
     }
 
@@ -198,6 +214,18 @@ impl SidePanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Keep the contents clear of display cutouts, OS bars etc.,
+    /// as given by [`crate::RawInput::safe_area_insets`].
+    ///
+    /// The background of the panel still covers them.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn respect_safe_area(mut self, respect_safe_area: bool) -> Self {
+        self.respect_safe_area = respect_safe_area;
+        self
+    }
 }
 
 impl SidePanel {
@@ -224,7 +252,9 @@ impl SidePanel {
             show_separator_line,
             default_width,
             width_range,
+            respect_safe_area,
         } = self;
+        let side = side.for_direction(ui.layout_direction());
 
         let available_rect = ui.available_rect_before_wrap();
         let mut panel_rect = available_rect;
@@ -257,9 +287,13 @@ impl SidePanel {
             }
         }
 
-        let mut panel_ui = ui.child_ui_with_id_source(panel_rect, Layout::top_down(Align::Min), id);
+        let layout = Layout::top_down(Align::Min).for_direction(ui.layout_direction());
+        let mut panel_ui = ui.child_ui_with_id_source(panel_rect, layout, id);
         panel_ui.expand_to_include_rect(panel_rect);
-        let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+        let mut frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+        if respect_safe_area {
+            frame.inner_margin = frame.inner_margin + safe_area_margin(ui.ctx(), panel_rect);
+        }
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_height(ui.max_rect().height()); // Make sure the frame fills the full height
             ui.set_min_width(width_range.min);
@@ -345,7 +379,7 @@ impl SidePanel {
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
         let layer_id = LayerId::background();
-        let side = self.side;
+        let side = self.side.for_direction(ctx.style().layout_direction);
         let available_rect = ctx.available_rect();
         let clip_rect = ctx.screen_rect();
         let mut panel_ui = Ui::new(ctx.clone(), layer_id, self.id, available_rect, clip_rect);
@@ -557,6 +591,7 @@ pub struct TopBottomPanel {
     show_separator_line: bool,
     default_height: Option<f32>,
     height_range: Rangef,
+    respect_safe_area: bool,
 }
 
 impl TopBottomPanel {
@@ -580,6 +615,7 @@ impl TopBottomPanel {
             show_separator_line: true,
             default_height: None,
             height_range: Rangef::new(20.0, f32::INFINITY),
+            respect_safe_area: false,
         }
     }
 
@@ -660,6 +696,18 @@ impl TopBottomPanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Keep the contents clear of display cutouts, OS bars etc.,
+    /// as given by [`crate::RawInput::safe_area_insets`].
+    ///
+    /// The background of the panel still covers them.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn respect_safe_area(mut self, respect_safe_area: bool) -> Self {
+        self.respect_safe_area = respect_safe_area;
+        self
+    }
 }
 
 impl TopBottomPanel {
@@ -686,6 +734,7 @@ impl TopBottomPanel {
             show_separator_line,
             default_height,
             height_range,
+            respect_safe_area,
         } = self;
 
         let available_rect = ui.available_rect_before_wrap();
@@ -723,9 +772,13 @@ impl TopBottomPanel {
             }
         }
 
-        let mut panel_ui = ui.child_ui_with_id_source(panel_rect, Layout::top_down(Align::Min), id);
+        let layout = Layout::top_down(Align::Min).for_direction(ui.layout_direction());
+        let mut panel_ui = ui.child_ui_with_id_source(panel_rect, layout, id);
         panel_ui.expand_to_include_rect(panel_rect);
-        let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+        let mut frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+        if respect_safe_area {
+            frame.inner_margin = frame.inner_margin + safe_area_margin(ui.ctx(), panel_rect);
+        }
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_width(ui.max_rect().width()); // Make the frame fill full width
             ui.set_min_height(height_range.min);
@@ -1015,6 +1068,7 @@ impl TopBottomPanel {
 #[derive(Default)]
 pub struct CentralPanel {
     frame: Option<Frame>,
+    respect_safe_area: bool,
 }
 
 impl CentralPanel {
@@ -1024,6 +1078,18 @@ impl CentralPanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Keep the contents clear of display cutouts, OS bars etc.,
+    /// as given by [`crate::RawInput::safe_area_insets`].
+    ///
+    /// The background of the panel still covers them.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn respect_safe_area(mut self, respect_safe_area: bool) -> Self {
+        self.respect_safe_area = respect_safe_area;
+        self
+    }
 }
 
 impl CentralPanel {
@@ -1042,12 +1108,19 @@ impl CentralPanel {
         ui: &mut Ui,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
-        let Self { frame } = self;
+        let Self {
+            frame,
+            respect_safe_area,
+        } = self;
 
         let panel_rect = ui.available_rect_before_wrap();
-        let mut panel_ui = ui.child_ui(panel_rect, Layout::top_down(Align::Min));
+        let layout = Layout::top_down(Align::Min).for_direction(ui.layout_direction());
+        let mut panel_ui = ui.child_ui(panel_rect, layout);
 
-        let frame = frame.unwrap_or_else(|| Frame::central_panel(ui.style()));
+        let mut frame = frame.unwrap_or_else(|| Frame::central_panel(ui.style()));
+        if respect_safe_area {
+            frame.inner_margin = frame.inner_margin + safe_area_margin(ui.ctx(), panel_rect);
+        }
         frame.show(&mut panel_ui, |ui| {
             ui.expand_to_include_rect(ui.max_rect()); // Expand frame to include it all
             add_contents(ui)
@@ -1085,6 +1158,18 @@ impl CentralPanel {
     }
 }
 
+/// How far the unsafe areas of the screen (see [`crate::RawInput::safe_area_insets`])
+/// reach into each side of `rect`.
+fn safe_area_margin(ctx: &Context, rect: Rect) -> Margin {
+    let safe_rect = ctx.input(|i| i.safe_area_rect());
+    Margin {
+        left: (safe_rect.left() - rect.left()).at_least(0.0),
+        right: (rect.right() - safe_rect.right()).at_least(0.0),
+        top: (safe_rect.top() - rect.top()).at_least(0.0),
+        bottom: (rect.bottom() - safe_rect.bottom()).at_least(0.0),
+    }
+}
+
 fn clamp_to_range(x: f32, range: Rangef) -> f32 {
     let range = range.as_positive();
     x.clamp(range.min, range.max)
@@ -0,0 +1,208 @@
+//! A modal dialog showing the progress of a long operation, see [`ProgressDialog`].
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::*;
+
+/// A flag for asking a background task to stop, shared between the ui and the task.
+///
+/// Clones share the same flag, so give one to the task and one to the [`ProgressDialog`].
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask the task to stop.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Has [`Self::cancel`] been called?
+    ///
+    /// The task should poll this regularly, and stop when it returns `true`.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct ProgressDialogState {
+    /// When the dialog was first shown, in [`InputState::time`].
+    start_time: f64,
+
+    /// The last frame the dialog was shown.
+    last_frame_nr: u64,
+}
+
+/// A modal dialog with a progress bar, for long operations running in the background.
+///
+/// Show it every frame while the operation is running.
+/// It shows the elapsed time and an estimate of the remaining time,
+/// and blocks the pointer from reaching the rest of the ui.
+///
+/// With a [`CancellationToken`] it also has a Cancel button (and cancels on Escape),
+/// which the background task can poll.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// # let progress = 0.5;
+/// # let exporting = true;
+/// use egui::{CancellationToken, ProgressDialog};
+///
+/// # let token = CancellationToken::new();
+/// // Give a clone of the same token to the background task when starting it.
+/// if exporting {
+///     ProgressDialog::new("export_progress", "Exporting…", progress)
+///         .text("frame_0042.png")
+///         .cancellation_token(token.clone())
+///         .show(ctx);
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct ProgressDialog {
+    id: Id,
+    title: WidgetText,
+    progress: f32,
+    text: Option<WidgetText>,
+    cancellation_token: Option<CancellationToken>,
+    width: f32,
+}
+
+impl ProgressDialog {
+    /// Progress in the `[0, 1]` range, where `1` means "completed".
+    ///
+    /// The `id_source` must be unique; the timing is stored under it.
+    pub fn new(
+        id_source: impl std::hash::Hash,
+        title: impl Into<WidgetText>,
+        progress: f32,
+    ) -> Self {
+        Self {
+            id: Id::new(id_source),
+            title: title.into(),
+            progress: progress.clamp(0.0, 1.0),
+            text: None,
+            cancellation_token: None,
+            width: 300.0,
+        }
+    }
+
+    /// What is happening right now, e.g. the name of the file being processed.
+    #[inline]
+    pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Show a Cancel button, which cancels this token.
+    #[inline]
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// The width of the dialog contents. Default: `300.0`.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Show the dialog, centered on the screen, on top of everything else.
+    pub fn show(self, ctx: &Context) -> Response {
+        let Self {
+            id,
+            title,
+            progress,
+            text,
+            cancellation_token,
+            width,
+        } = self;
+
+        // Start timing anew if the dialog wasn't shown the previous frame:
+        let now = ctx.input(|i| i.time);
+        let frame_nr = ctx.frame_nr();
+        let start_time = ctx.data_mut(|data| {
+            let state = data.get_temp_mut_or_insert_with(id, || ProgressDialogState {
+                start_time: now,
+                last_frame_nr: frame_nr,
+            });
+            if state.last_frame_nr + 1 < frame_nr {
+                state.start_time = now;
+            }
+            state.last_frame_nr = frame_nr;
+            state.start_time
+        });
+        let elapsed = now - start_time;
+
+        // Dim and block everything below the dialog:
+        let screen_rect = ctx.screen_rect();
+        let backdrop = Area::new(id.with("backdrop"))
+            .order(Order::Foreground)
+            .fixed_pos(screen_rect.min)
+            .show(ctx, |ui| {
+                ui.painter()
+                    .rect_filled(screen_rect, 0.0, Color32::from_black_alpha(100));
+                ui.allocate_response(screen_rect.size(), Sense::click_and_drag());
+            });
+        ctx.move_to_top(backdrop.response.layer_id);
+
+        let dialog = Area::new(id)
+            .order(Order::Foreground)
+            .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+            .show(ctx, |ui| {
+                Frame::window(ui.style()).show(ui, |ui| {
+                    ui.set_width(width);
+                    ui.label(title.strong());
+                    if let Some(text) = text {
+                        ui.label(text);
+                    }
+                    ui.add(ProgressBar::new(progress).show_percentage().animate(true));
+
+                    ui.horizontal(|ui| {
+                        let mut times = format!("Elapsed: {}", format_duration(elapsed));
+                        if 0.0 < progress && progress < 1.0 {
+                            let remaining = elapsed * (1.0 - progress as f64) / progress as f64;
+                            times += &format!("   Remaining: ~{}", format_duration(remaining));
+                        }
+                        ui.weak(times);
+
+                        if let Some(token) = &cancellation_token {
+                            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                if token.is_cancelled() {
+                                    ui.add_enabled(false, Button::new("Cancelling…"));
+                                } else if ui.button("Cancel").clicked()
+                                    || ui.input(|i| i.key_pressed(Key::Escape))
+                                {
+                                    token.cancel();
+                                }
+                            });
+                        }
+                    });
+                });
+            });
+        ctx.move_to_top(dialog.response.layer_id);
+
+        dialog.response
+    }
+}
+
+/// E.g. `42 s`, `3:05` or `1:02:03`.
+fn format_duration(seconds: f64) -> String {
+    let seconds = seconds.max(0.0).round() as u64;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else if minutes > 0 {
+        format!("{minutes}:{seconds:02}")
+    } else {
+        format!("{seconds} s")
+    }
+}
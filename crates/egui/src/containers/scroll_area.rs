@@ -1,5 +1,7 @@
 #![allow(clippy::needless_range_loop)]
 
+use epaint::util::FloatOrd;
+
 use crate::*;
 
 #[derive(Clone, Copy, Debug)]
@@ -9,6 +11,15 @@ struct ScrollTarget {
     target_offset: f32,
 }
 
+/// The widget that [`ScrollArea::scroll_anchoring`] keeps in place.
+#[derive(Clone, Copy, Debug)]
+struct ScrollAnchor {
+    id: Id,
+
+    /// Where the widget was, relative to the top left of the content.
+    pos: Vec2,
+}
+
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
@@ -39,6 +50,15 @@ pub struct State {
     /// and remains that way until the user moves the scroll_handle. Once unstuck (false)
     /// it remains false until the scroll touches the end position, which reenables stickiness.
     scroll_stuck_to_end: Vec2b,
+
+    /// The size of the content last frame.
+    ///
+    /// Used to keep the view anchored when content is prepended.
+    content_size: Vec2,
+
+    /// The first visible widget last frame, see [`ScrollArea::scroll_anchoring`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scroll_anchor: Option<ScrollAnchor>,
 }
 
 impl Default for State {
@@ -52,6 +72,8 @@ impl Default for State {
             vel: Vec2::ZERO,
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
+            content_size: Vec2::ZERO,
+            scroll_anchor: None,
         }
     }
 }
@@ -87,6 +109,81 @@ pub struct ScrollAreaOutput<R> {
 
     /// Where on the screen the content is (excludes scroll bars).
     pub inner_rect: Rect,
+
+    /// Does this [`ScrollArea`] have horizontal/vertical scrolling enabled?
+    scroll_enabled: Vec2b,
+}
+
+impl<R> ScrollAreaOutput<R> {
+    /// Is the view within `threshold` points of the start (top/left) of the content?
+    ///
+    /// Only the axes with scrolling enabled are considered.
+    pub fn is_near_start(&self, threshold: f32) -> bool {
+        (0..2).any(|d| self.scroll_enabled[d] && self.state.offset[d] <= threshold)
+    }
+
+    /// Is the view within `threshold` points of the end (bottom/right) of the content?
+    ///
+    /// Only the axes with scrolling enabled are considered.
+    /// This is also true if all the content fits in the view.
+    pub fn is_near_end(&self, threshold: f32) -> bool {
+        (0..2).any(|d| {
+            let remaining =
+                self.content_size[d] - (self.state.offset[d] + self.inner_rect.size()[d]);
+            self.scroll_enabled[d] && remaining <= threshold
+        })
+    }
+
+    /// Call `load_more` if the view is within `threshold` points of the start (top/left) of the content.
+    ///
+    /// This is useful for loading older items in a feed.
+    /// Use [`ScrollArea::anchor_to_end`] to keep the view still when prepending the new items,
+    /// and [`ScrollArea::show_loading_at_start`] to show a spinner while loading.
+    ///
+    /// NOTE: `load_more` is called each frame as long as the view is close to the start,
+    /// so you should keep track of whether or not you are already loading.
+    #[inline]
+    pub fn on_reach_start(self, threshold: f32, load_more: impl FnOnce()) -> Self {
+        if self.is_near_start(threshold) {
+            load_more();
+        }
+        self
+    }
+
+    /// Call `load_more` if the view is within `threshold` points of the end (bottom/right) of the content.
+    ///
+    /// This is useful for infinite scrolling and lazy pagination.
+    /// Use [`ScrollArea::show_loading_at_end`] to show a spinner while loading.
+    ///
+    /// NOTE: `load_more` is called each frame as long as the view is close to the end,
+    /// so you should keep track of whether or not you are already loading.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let items: Vec<String> = vec![];
+    /// # let mut is_loading = false;
+    /// egui::ScrollArea::vertical()
+    ///     .show_loading_at_end(is_loading)
+    ///     .show(ui, |ui| {
+    ///         for item in &items {
+    ///             ui.label(item);
+    ///         }
+    ///     })
+    ///     .on_reach_end(100.0, || {
+    ///         if !is_loading {
+    ///             is_loading = true;
+    ///             // Start loading the next page…
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn on_reach_end(self, threshold: f32, load_more: impl FnOnce()) -> Self {
+        if self.is_near_end(threshold) {
+            load_more();
+        }
+        self
+    }
 }
 
 /// Indicate whether the horizontal and vertical scroll bars must be always visible, hidden or visible when needed.
@@ -179,6 +276,18 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    /// Show a spinner before the content.
+    loading_at_start: bool,
+
+    /// Show a spinner after the content.
+    loading_at_end: bool,
+
+    /// Keep the offset from the end of the content constant when the content grows.
+    anchor_to_end: bool,
+
+    /// Keep the first visible widget in place when the content before it changes size.
+    scroll_anchoring: bool,
 }
 
 impl ScrollArea {
@@ -223,6 +332,10 @@ impl ScrollArea {
             drag_to_scroll: true,
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            loading_at_start: false,
+            loading_at_end: false,
+            anchor_to_end: false,
+            scroll_anchoring: false,
         }
     }
 
@@ -434,6 +547,77 @@ impl ScrollArea {
         self.stick_to_end[1] = stick;
         self
     }
+
+    /// Show a loading spinner before the content (at the top/left).
+    ///
+    /// Useful together with [`ScrollAreaOutput::on_reach_start`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn show_loading_at_start(mut self, loading: bool) -> Self {
+        self.loading_at_start = loading;
+        self
+    }
+
+    /// Show a loading spinner after the content (at the bottom/right).
+    ///
+    /// Useful together with [`ScrollAreaOutput::on_reach_end`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn show_loading_at_end(mut self, loading: bool) -> Self {
+        self.loading_at_end = loading;
+        self
+    }
+
+    /// Keep the view anchored to the end of the content when the content grows.
+    ///
+    /// When items are prepended to the content (e.g. older messages in a chat),
+    /// the scroll offset is increased by the same amount as the content grew,
+    /// so that the items the user is looking at stay in place.
+    ///
+    /// Don't use this if content is appended at the end, since that would scroll along with it.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn anchor_to_end(mut self, anchor: bool) -> Self {
+        self.anchor_to_end = anchor;
+        self
+    }
+
+    /// Keep what the user is looking at in place when the content before it changes size,
+    /// like browsers do.
+    ///
+    /// The first widget in view is remembered each frame. If it has moved within the content
+    /// the next frame (e.g. because an image above it finished loading, or a section above it was expanded),
+    /// the scroll offset is adjusted by the same amount, so that the widget stays where it was on screen.
+    ///
+    /// The widget is found again by its [`Id`], so the items in the scroll area need ids that
+    /// don't change when other items are added or removed, e.g. by wrapping each item in [`Ui::push_id`]
+    /// with a stable key. Automatic ids (e.g. of labels) change when widgets are inserted before them.
+    ///
+    /// This takes care of content prepended before the view too, so [`Self::anchor_to_end`]
+    /// is ignored when this is on.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let messages: Vec<(u64, String)> = vec![];
+    /// egui::ScrollArea::vertical()
+    ///     .scroll_anchoring(true)
+    ///     .show(ui, |ui| {
+    ///         for (message_id, text) in &messages {
+    ///             ui.push_id(message_id, |ui| ui.label(text));
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn scroll_anchoring(mut self, scroll_anchoring: bool) -> Self {
+        self.scroll_anchoring = scroll_anchoring;
+        self
+    }
 }
 
 struct Prepared {
@@ -473,6 +657,10 @@ struct Prepared {
     scrolling_enabled: bool,
     stick_to_end: Vec2b,
     animated: bool,
+    loading_at_start: bool,
+    loading_at_end: bool,
+    anchor_to_end: bool,
+    scroll_anchoring: bool,
 }
 
 impl ScrollArea {
@@ -490,6 +678,10 @@ impl ScrollArea {
             drag_to_scroll,
             stick_to_end,
             animated,
+            loading_at_start,
+            loading_at_end,
+            anchor_to_end,
+            scroll_anchoring,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -666,6 +858,10 @@ impl ScrollArea {
             scrolling_enabled,
             stick_to_end,
             animated,
+            loading_at_start,
+            loading_at_end,
+            anchor_to_end,
+            scroll_anchoring,
         }
     }
 
@@ -749,7 +945,14 @@ impl ScrollArea {
         let mut prepared = self.begin(ui);
         let id = prepared.id;
         let inner_rect = prepared.inner_rect;
+        let scroll_enabled = prepared.scroll_enabled;
+        if prepared.loading_at_start {
+            add_loading_spinner(&mut prepared.content_ui, scroll_enabled);
+        }
         let inner = add_contents(&mut prepared.content_ui, prepared.viewport);
+        if prepared.loading_at_end {
+            add_loading_spinner(&mut prepared.content_ui, scroll_enabled);
+        }
         let (content_size, state) = prepared.end(ui);
         ScrollAreaOutput {
             inner,
@@ -757,10 +960,43 @@ impl ScrollArea {
             state,
             content_size,
             inner_rect,
+            scroll_enabled,
         }
     }
 }
 
+/// The widget in the content closest to the top left of the view, preferring widgets
+/// that start inside the view over ones that start before it.
+fn first_visible_widget(content_ui: &Ui, view: Rect) -> Option<WidgetRect> {
+    let content_rect = content_ui.min_rect();
+    content_ui.ctx().widgets_this_frame(|widgets| {
+        widgets
+            .get_layer(content_ui.layer_id())
+            .filter(|w| {
+                content_rect.contains_rect(w.rect)
+                    && w.interact_rect.is_positive()
+                    && w.interact_rect.intersects(view)
+            })
+            .min_by_key(|w| {
+                let starts_before_view = w.rect.top() < view.top() || w.rect.left() < view.left();
+                (
+                    starts_before_view,
+                    (w.rect.top() - view.top()).abs().ord(),
+                    (w.rect.left() - view.left()).abs().ord(),
+                )
+            })
+            .copied()
+    })
+}
+
+fn add_loading_spinner(ui: &mut Ui, scroll_enabled: Vec2b) {
+    if scroll_enabled[1] {
+        ui.vertical_centered(|ui| ui.spinner());
+    } else {
+        ui.horizontal_centered(|ui| ui.spinner());
+    }
+}
+
 impl Prepared {
     /// Returns content size and state
     fn end(self, ui: &mut Ui) -> (Vec2, State) {
@@ -774,14 +1010,57 @@ impl Prepared {
             current_bar_use,
             scroll_bar_visibility,
             content_ui,
-            viewport: _,
+            viewport,
             scrolling_enabled,
             stick_to_end,
             animated,
+            loading_at_start: _,
+            loading_at_end: _,
+            anchor_to_end,
+            scroll_anchoring,
         } = self;
 
         let content_size = content_ui.min_size();
 
+        if scroll_anchoring {
+            // Where the content was laid out this frame:
+            let content_origin = inner_rect.min - viewport.min.to_vec2();
+
+            if let Some(anchor) = state.scroll_anchor {
+                let moved = ui
+                    .ctx()
+                    .widgets_this_frame(|widgets| widgets.get(anchor.id).map(|w| w.rect))
+                    .map(|rect| rect.min - content_origin - anchor.pos);
+                if let Some(moved) = moved {
+                    for d in 0..2 {
+                        if scroll_enabled[d] && moved[d] != 0.0 {
+                            // The content was already painted at the old offset, so repaint right away.
+                            state.offset[d] += moved[d];
+                            ui.ctx().request_repaint();
+                        }
+                    }
+                }
+            }
+
+            state.scroll_anchor =
+                first_visible_widget(&content_ui, inner_rect).map(|widget| ScrollAnchor {
+                    id: widget.id,
+                    pos: widget.rect.min - content_origin,
+                });
+        }
+
+        if anchor_to_end && !scroll_anchoring && state.content_size != Vec2::ZERO {
+            for d in 0..2 {
+                let growth = content_size[d] - state.content_size[d];
+                if scroll_enabled[d] && growth > 0.0 {
+                    // Content was added before the view; keep what the user sees in place.
+                    // The content was already painted at the old offset, so repaint right away.
+                    state.offset[d] += growth;
+                    ui.ctx().request_repaint();
+                }
+            }
+        }
+
         for d in 0..2 {
             // We always take both scroll targets regardless of which scroll axes are enabled. This
             // is to avoid them leaking to other scroll areas.
@@ -821,7 +1100,7 @@ impl Prepared {
                     if delta != 0.0 {
                         let target_offset = state.offset[d] + delta;
 
-                        if !animated {
+                        if !animated || ui.ctx().reduce_motion() {
                             state.offset[d] = target_offset;
                         } else if let Some(animation) = &mut state.offset_target[d] {
                             // For instance: the user is continuously calling `ui.scroll_to_cursor`,
@@ -1186,6 +1465,7 @@ impl Prepared {
 
         state.show_scroll = show_scroll_this_frame;
         state.content_is_too_large = content_is_too_large;
+        state.content_size = content_size;
 
         state.store(ui.ctx(), id);
 
@@ -0,0 +1,253 @@
+//! A row of widgets that moves what doesn't fit into an overflow menu, see [`Toolbar`].
+
+use crate::*;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct ToolbarState {
+    /// The width of each item, the last time it was shown in the bar.
+    item_widths: Vec<f32>,
+
+    /// The width of the button that opens the overflow menu.
+    overflow_button_width: f32,
+}
+
+impl ToolbarState {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_temp(id))
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+
+    /// How many of the items fit in the bar.
+    ///
+    /// If not all of them fit, this leaves room for the overflow button.
+    fn num_fitting(&self, available_width: f32, item_spacing: f32) -> usize {
+        let num_items = self.item_widths.len();
+        let total_width = self.item_widths.iter().sum::<f32>()
+            + item_spacing * num_items.saturating_sub(1) as f32;
+        if total_width <= available_width {
+            // Also any new items, which we haven't measured yet.
+            return usize::MAX;
+        }
+
+        let available_width = available_width - self.overflow_button_width;
+        let mut used_width = 0.0;
+        for (i, width) in self.item_widths.iter().enumerate() {
+            used_width += width + item_spacing;
+            if available_width < used_width {
+                return i;
+            }
+        }
+        num_items
+    }
+}
+
+/// A horizontal bar of buttons and other widgets,
+/// that moves the items that don't fit into a trailing "»" overflow menu.
+///
+/// The items are measured as they are shown,
+/// and as the bar shrinks the last items are moved into the menu, keeping their order.
+///
+/// Because an item can be shown either in the bar or in the menu,
+/// the contents closure is called once for the bar, and again for the menu when it is open.
+/// Each item is only shown in one of them.
+///
+/// Keyboard shortcuts added with [`ToolbarUi::shortcut_button`]
+/// keep working when their button is in the closed overflow menu.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{Key, KeyboardShortcut, Modifiers, Toolbar};
+///
+/// const SAVE: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+///
+/// let mut bold = false;
+/// Toolbar::new("editor_toolbar").show(ui, |toolbar| {
+///     if toolbar.button("New") {
+///         // …
+///     }
+///     if toolbar.shortcut_button("Save", SAVE) {
+///         // …
+///     }
+///     toolbar.add(|ui| ui.toggle_value(&mut bold, "Bold"));
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Toolbar {
+    id_source: Id,
+    overflow_button: WidgetText,
+}
+
+impl Toolbar {
+    /// The `id_source` must be unique within the parent [`Ui`].
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            overflow_button: "»".into(),
+        }
+    }
+
+    /// The text of the button that opens the overflow menu. Default: `"»"`.
+    #[inline]
+    pub fn overflow_button(mut self, text: impl Into<WidgetText>) -> Self {
+        self.overflow_button = text.into();
+        self
+    }
+
+    /// Show the toolbar, filling the rest of the current row.
+    ///
+    /// `add_contents` is called once for the bar, and once more for the overflow menu when it is open.
+    pub fn show(self, ui: &mut Ui, mut add_contents: impl FnMut(&mut ToolbarUi<'_>)) -> Response {
+        let Self {
+            id_source,
+            overflow_button,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let prev_state = ToolbarState::load(ui.ctx(), id).unwrap_or_default();
+
+        ui.horizontal(|ui| {
+            let num_fitting =
+                prev_state.num_fitting(ui.available_width(), ui.spacing().item_spacing.x);
+
+            let mut bar = ToolbarUi {
+                ui,
+                in_overflow_menu: false,
+                num_fitting,
+                next_index: 0,
+                item_widths: prev_state.item_widths.clone(),
+            };
+            add_contents(&mut bar);
+
+            let num_items = bar.next_index;
+            let mut state = ToolbarState {
+                item_widths: bar.item_widths,
+                overflow_button_width: prev_state.overflow_button_width,
+            };
+            state.item_widths.truncate(num_items);
+
+            if num_fitting < num_items {
+                let response = ui
+                    .menu_button(overflow_button, |ui| {
+                        add_contents(&mut ToolbarUi {
+                            ui,
+                            in_overflow_menu: true,
+                            num_fitting,
+                            next_index: 0,
+                            item_widths: vec![],
+                        });
+                    })
+                    .response;
+                state.overflow_button_width = response.rect.width();
+            }
+
+            if state != prev_state {
+                state.store(ui.ctx(), id);
+                ui.ctx().request_repaint();
+            }
+        })
+        .response
+    }
+}
+
+/// Adds items to a [`Toolbar`], see [`Toolbar::show`].
+pub struct ToolbarUi<'a> {
+    ui: &'a mut Ui,
+
+    /// Are we adding to the overflow menu, or to the bar?
+    in_overflow_menu: bool,
+
+    /// Items before this index are in the bar, the rest in the overflow menu.
+    num_fitting: usize,
+
+    next_index: usize,
+
+    /// Measured widths of the items, only updated for the bar.
+    item_widths: Vec<f32>,
+}
+
+impl<'a> ToolbarUi<'a> {
+    /// Are the items being added to the overflow menu (or to the bar)?
+    ///
+    /// Useful if you want an item to look different in the menu.
+    #[inline]
+    pub fn in_overflow_menu(&self) -> bool {
+        self.in_overflow_menu
+    }
+
+    /// Add an item, consisting of one or more widgets.
+    ///
+    /// Returns `None` if the item is shown elsewhere, i.e. in the overflow menu when adding to the bar, or vice versa.
+    pub fn add<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> Option<R> {
+        let index = self.next_index;
+        self.next_index += 1;
+
+        let in_bar = index < self.num_fitting;
+        if in_bar == self.in_overflow_menu {
+            return None;
+        }
+
+        if self.in_overflow_menu {
+            Some(add_contents(self.ui))
+        } else {
+            let InnerResponse { inner, response } = self.ui.scope(add_contents);
+            if self.item_widths.len() <= index {
+                self.item_widths.resize(index + 1, 0.0);
+            }
+            self.item_widths[index] = response.rect.width();
+            Some(inner)
+        }
+    }
+
+    /// Add a button. Clicking it in the overflow menu closes the menu.
+    ///
+    /// Returns `true` if the button was clicked.
+    pub fn button(&mut self, text: impl Into<WidgetText>) -> bool {
+        let in_overflow_menu = self.in_overflow_menu;
+        let clicked = self.add(|ui| {
+            let clicked = ui.button(text).clicked();
+            if in_overflow_menu && clicked {
+                ui.close_menu();
+            }
+            clicked
+        });
+        clicked == Some(true)
+    }
+
+    /// Add a button that can also be triggered with a keyboard shortcut.
+    ///
+    /// The shortcut works even when the button is in the overflow menu, and the menu is closed.
+    /// In the bar the shortcut is shown on hover, and in the overflow menu next to the button text.
+    ///
+    /// Returns `true` if the button was clicked, or the shortcut was pressed.
+    pub fn shortcut_button(
+        &mut self,
+        text: impl Into<WidgetText>,
+        shortcut: KeyboardShortcut,
+    ) -> bool {
+        // The contents are added twice when the menu is open, but the shortcut should only trigger once:
+        let pressed =
+            !self.in_overflow_menu && self.ui.input_mut(|i| i.consume_shortcut(&shortcut));
+
+        let shortcut_text = self.ui.ctx().format_shortcut(&shortcut);
+        let in_overflow_menu = self.in_overflow_menu;
+        let clicked = self.add(|ui| {
+            if in_overflow_menu {
+                let clicked = ui
+                    .add(Button::new(text).shortcut_text(shortcut_text))
+                    .clicked();
+                if clicked {
+                    ui.close_menu();
+                }
+                clicked
+            } else {
+                ui.button(text).on_hover_text(shortcut_text).clicked()
+            }
+        });
+
+        pressed || clicked == Some(true)
+    }
+}
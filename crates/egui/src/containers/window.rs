@@ -218,6 +218,18 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// What happens when the window is dragged against the edges of the constraint rectangle.
+    ///
+    /// For instance, use [`EdgeBehavior::SnapBack`] together with [`Self::constrain_to`]
+    /// to keep a tool window inside the central panel.
+    ///
+    /// Default: [`EdgeBehavior::Clamp`].
+    #[inline]
+    pub fn edge_behavior(mut self, edge_behavior: EdgeBehavior) -> Self {
+        self.area = self.area.edge_behavior(edge_behavior);
+        self
+    }
+
     /// Where the "root" of the window is.
     ///
     /// For instance, if you set this to [`Align2::RIGHT_TOP`]
@@ -394,7 +406,7 @@ impl<'open> Window<'open> {
     ) -> Option<InnerResponse<Option<R>>> {
         let Window {
             title,
-            open,
+            mut open,
             area,
             frame,
             resize,
@@ -413,6 +425,14 @@ impl<'open> Window<'open> {
         // Add border padding to the inner margin to prevent it from covering the contents
         window_frame.inner_margin += border_padding;
 
+        if let Some(open) = &mut open {
+            // Apply the open-state of a layout loaded with `Context::load_layout`:
+            if let Some(restored) = ctx.memory_mut(|mem| mem.restored_window_open.remove(&area.id))
+            {
+                **open = restored;
+            }
+        }
+
         let is_explicitly_closed = matches!(open, Some(false));
         let is_open = !is_explicitly_closed || ctx.memory(|mem| mem.everything_is_visible());
         area.show_open_close_animation(ctx, &window_frame, is_open);
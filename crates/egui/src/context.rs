@@ -10,6 +10,7 @@ use crate::{
     animation_manager::AnimationManager,
     data::output::PlatformOutput,
     frame_state::FrameState,
+    frame_stats::TessellationStats,
     input_state::*,
     layers::GraphicLayers,
     load::{Bytes, Loaders, SizedTexture},
@@ -75,6 +76,10 @@ pub type ContextCallback = Arc<dyn Fn(&Context) + Send + Sync>;
 #[derive(Clone)]
 struct NamedContextCallback {
     debug_name: &'static str,
+
+    /// Only call for this viewport, or for all viewports if `None`.
+    viewport_id: Option<ViewportId>,
+
     callback: ContextCallback,
 }
 
@@ -86,24 +91,34 @@ struct Plugins {
 }
 
 impl Plugins {
-    fn call(ctx: &Context, _cb_name: &str, callbacks: &[NamedContextCallback]) {
+    fn call(
+        ctx: &Context,
+        current_viewport: ViewportId,
+        _cb_name: &str,
+        callbacks: &[NamedContextCallback],
+    ) {
         crate::profile_scope!("plugins", _cb_name);
         for NamedContextCallback {
             debug_name: _name,
+            viewport_id,
             callback,
         } in callbacks
         {
+            if viewport_id.is_some_and(|id| id != current_viewport) {
+                continue;
+            }
             crate::profile_scope!("plugin", _name);
             (callback)(ctx);
         }
     }
 
-    fn on_begin_frame(&self, ctx: &Context) {
-        Self::call(ctx, "on_begin_frame", &self.on_begin_frame);
+    /// Called before the frame has begun, so the viewport is passed in explicitly.
+    fn on_begin_frame(&self, ctx: &Context, viewport_id: ViewportId) {
+        Self::call(ctx, viewport_id, "on_begin_frame", &self.on_begin_frame);
     }
 
     fn on_end_frame(&self, ctx: &Context) {
-        Self::call(ctx, "on_end_frame", &self.on_end_frame);
+        Self::call(ctx, ctx.viewport_id(), "on_end_frame", &self.on_end_frame);
     }
 }
 
@@ -149,6 +164,11 @@ impl ContextImpl {
         viewport_id: ViewportId,
         cause: RepaintCause,
     ) {
+        let delay = if delay == Duration::ZERO {
+            delay
+        } else {
+            delay.max(self.power_hint().min_repaint_interval())
+        };
         let viewport = self.viewports.entry(viewport_id).or_default();
 
         if delay == Duration::ZERO {
@@ -228,6 +248,17 @@ struct ViewportState {
     /// Read
     widgets_prev_frame: WidgetRects,
 
+    /// Custom occluders registered during the frame, see [`Context::register_occluder`].
+    occluders_this_frame: Vec<(LayerId, Rect)>,
+
+    /// Occluders registered last frame, used by [`Context::layer_id_at`].
+    occluders_prev_frame: Vec<(LayerId, Rect)>,
+
+    /// Data attached to widgets with [`Context::set_widget_user_data`].
+    ///
+    /// Removed at the end of each frame for widgets that were not shown that frame.
+    widget_user_data: IdMap<ahash::HashMap<std::any::TypeId, Box<dyn std::any::Any + Send + Sync>>>,
+
     /// State related to repaint scheduling.
     repaint: ViewportRepaintInfo,
 
@@ -249,6 +280,28 @@ struct ViewportState {
     // Most of the things in `PlatformOutput` are not actually viewport dependent.
     output: PlatformOutput,
     commands: Vec<ViewportCommand>,
+
+    /// Set by [`ViewportCommand::ZoomFactor`], overriding [`Options::zoom_factor`].
+    zoom_factor: Option<f32>,
+
+    /// Set by [`ViewportCommand::ZoomFactor`], applied at the start of the next frame.
+    new_zoom_factor: Option<f32>,
+
+    /// The persisted state this viewport was created with, see [`ViewportBuilder::with_persist`].
+    restored: Option<crate::viewport::PersistedViewport>,
+
+    /// What this viewport is snapped to, see [`ViewportBuilder::with_snap_distance`].
+    snapped: Vec<ViewportSnap>,
+
+    /// The outer position of this viewport at the start of the last frame,
+    /// used to tell when it is moved.
+    last_outer_pos: Option<Pos2>,
+
+    /// Statistics of the last ended frame, see [`Context::frame_stats`].
+    frame_stats: FrameStats,
+
+    /// From the last call to [`Context::tessellate`] for this viewport.
+    tessellation_stats: TessellationStats,
 }
 
 /// What called [`Context::request_repaint`]?
@@ -343,6 +396,9 @@ impl ViewportRepaintInfo {
 
 // ----------------------------------------------------------------------------
 
+/// See [`Context::on_viewport_close`].
+type ViewportCloseHandler = Arc<dyn Fn(&Context) -> bool + Send + Sync>;
+
 #[derive(Default)]
 struct ContextImpl {
     /// Since we could have multiple viewports across multiple monitors with
@@ -353,6 +409,15 @@ struct ContextImpl {
     fonts: std::collections::BTreeMap<OrderedFloat<f32>, Fonts>,
     font_definitions: FontDefinitions,
 
+    /// When each of the [`Self::fonts`] was last used, in [`InputState::time`].
+    ///
+    /// We keep unused fonts around for a while, so that moving a window back and forth
+    /// between monitors with different scale factors doesn't recreate them each time.
+    fonts_last_used: std::collections::BTreeMap<OrderedFloat<f32>, f64>,
+
+    /// Which of the [`Self::fonts`] the font texture was last fully uploaded for.
+    font_atlas_pixels_per_point: Option<OrderedFloat<f32>>,
+
     memory: Memory,
     animation_manager: AnimationManager,
 
@@ -379,11 +444,33 @@ struct ContextImpl {
 
     paint_stats: PaintStats,
 
+    /// See [`Context::set_clock`].
+    clock: Option<Box<dyn crate::Clock>>,
+
+    /// See [`Context::debug_pause_frames`].
+    debug_time: crate::debug_time::DebugTime,
+
+    /// The latest [`RawInput::system_preferences`].
+    system_preferences: SystemPreferences,
+
+    /// The effective preferences that have been applied to the style.
+    applied_system_preferences: SystemPreferences,
+
+    /// The layers of the last ended frame, so that [`Context::tessellate`] can collect per-layer statistics.
+    last_frame_layers: Option<(ViewportId, Vec<(LayerId, std::ops::Range<usize>)>)>,
+
     request_repaint_callback: Option<Box<dyn Fn(RequestRepaintInfo) + Send + Sync>>,
 
     viewport_parents: ViewportIdMap<ViewportId>,
     viewports: ViewportIdMap<ViewportState>,
 
+    /// See [`Context::on_viewport_close`].
+    viewport_close_handlers: ViewportIdMap<ViewportCloseHandler>,
+
+    /// Files dropped on a viewport that were given to us in the input of another viewport,
+    /// see [`DroppedFile::viewport_id`].
+    pending_dropped_files: ViewportIdMap<Vec<DroppedFile>>,
+
     embed_viewports: bool,
 
     #[cfg(feature = "accesskit")]
@@ -404,6 +491,47 @@ impl ContextImpl {
             .unwrap_or_default();
         let ids = ViewportIdPair::from_self_and_parent(viewport_id, parent_id);
 
+        if let Some(clock) = &mut self.clock {
+            let last_time = self
+                .viewports
+                .get(&viewport_id)
+                .map_or(0.0, |viewport| viewport.input.time);
+            let time = clock.frame_time(new_raw_input.time, last_time);
+            new_raw_input.time = Some(time);
+            new_raw_input.predicted_dt = (time - last_time).max(0.0) as f32;
+        }
+        self.debug_time.begin_frame(&mut new_raw_input);
+
+        self.route_dropped_files(&mut new_raw_input);
+
+        if let Some(modal_id) = self.modal_child_of(viewport_id) {
+            if block_input_for_modal(&mut new_raw_input) {
+                self.viewport_for(modal_id)
+                    .commands
+                    .push(ViewportCommand::Focus);
+            }
+        }
+
+        if let Some(timings) = &new_raw_input.system_timings {
+            if self.memory.options.input_options.follow_system_timings {
+                self.memory.options.apply_system_timings(timings);
+            }
+        }
+
+        self.persist_viewports(&new_raw_input);
+        self.snap_viewport(viewport_id, &new_raw_input);
+
+        if let Some(preferences) = new_raw_input.system_preferences {
+            self.system_preferences = preferences;
+        }
+        let preferences = self.effective_system_preferences();
+        if preferences != self.applied_system_preferences {
+            self.memory
+                .options
+                .apply_system_preferences(&self.applied_system_preferences, &preferences);
+            self.applied_system_preferences = preferences;
+        }
+
         let is_outermost_viewport = self.viewport_stack.is_empty(); // not necessarily root, just outermost immediate viewport
         self.viewport_stack.push(ids);
 
@@ -411,37 +539,61 @@ impl ContextImpl {
 
         let viewport = self.viewports.entry(viewport_id).or_default();
 
+        // How much the zoom factor changed, if it did:
+        let mut zoom_ratio = None;
         if is_outermost_viewport {
             if let Some(new_zoom_factor) = self.new_zoom_factor.take() {
-                let ratio = self.memory.options.zoom_factor / new_zoom_factor;
+                if viewport.zoom_factor.is_none() {
+                    zoom_ratio = Some(self.memory.options.zoom_factor / new_zoom_factor);
+                }
                 self.memory.options.zoom_factor = new_zoom_factor;
-
-                let input = &viewport.input;
-                // This is a bit hacky, but is required to avoid jitter:
-                let mut rect = input.screen_rect;
-                rect.min = (ratio * rect.min.to_vec2()).to_pos2();
-                rect.max = (ratio * rect.max.to_vec2()).to_pos2();
-                new_raw_input.screen_rect = Some(rect);
-                // We should really scale everything else in the input too,
-                // but the `screen_rect` is the most important part.
             }
         }
+        if let Some(new_zoom_factor) = viewport.new_zoom_factor.take() {
+            let old_zoom_factor = viewport
+                .zoom_factor
+                .unwrap_or(self.memory.options.zoom_factor);
+            zoom_ratio = Some(old_zoom_factor / new_zoom_factor);
+            viewport.zoom_factor = Some(new_zoom_factor);
+        }
+        if let Some(ratio) = zoom_ratio {
+            let input = &viewport.input;
+            // This is a bit hacky, but is required to avoid jitter:
+            let mut rect = input.screen_rect;
+            rect.min = (ratio * rect.min.to_vec2()).to_pos2();
+            rect.max = (ratio * rect.max.to_vec2()).to_pos2();
+            new_raw_input.screen_rect = Some(rect);
+            // We should really scale everything else in the input too,
+            // but the `screen_rect` is the most important part.
+        }
+        let zoom_factor = viewport
+            .zoom_factor
+            .unwrap_or(self.memory.options.zoom_factor);
+
         let native_pixels_per_point = new_raw_input
             .viewport()
             .native_pixels_per_point
             .unwrap_or(1.0);
-        let pixels_per_point = self.memory.options.zoom_factor * native_pixels_per_point;
+        let pixels_per_point = zoom_factor * native_pixels_per_point;
 
         let all_viewport_ids: ViewportIdSet = self.all_viewport_ids();
 
         let viewport = self.viewports.entry(self.viewport_id()).or_default();
 
+        if viewport.repaint.frame_nr != 0 && viewport.input.pixels_per_point != pixels_per_point {
+            new_raw_input.events.push(Event::ScaleFactorChanged {
+                viewport_id: new_raw_input.viewport_id,
+                pixels_per_point,
+            });
+        }
+
         self.memory.begin_frame(&new_raw_input, &all_viewport_ids);
 
         viewport.input = std::mem::take(&mut viewport.input).begin_frame(
             new_raw_input,
             viewport.repaint.requested_immediate_repaint_prev_frame(),
             pixels_per_point,
+            self.memory.options.input_options,
         );
 
         let screen_rect = viewport.input.screen_rect;
@@ -527,6 +679,7 @@ impl ContextImpl {
         if let Some(font_definitions) = self.memory.new_font_definitions.take() {
             // New font definition loaded, so we need to reload all fonts.
             self.fonts.clear();
+            self.font_atlas_pixels_per_point = None;
             self.font_definitions = font_definitions;
             #[cfg(feature = "log")]
             log::debug!("Loading new font definitions");
@@ -621,6 +774,218 @@ impl ContextImpl {
     fn viewport_for(&mut self, viewport_id: ViewportId) -> &mut ViewportState {
         self.viewports.entry(viewport_id).or_default()
     }
+
+    /// Only keep the files hovering or dropped on the viewport of the input,
+    /// and hand those dropped on other viewports over to them.
+    ///
+    /// See [`DroppedFile::viewport_id`].
+    fn route_dropped_files(&mut self, new_raw_input: &mut RawInput) {
+        let viewport_id = new_raw_input.viewport_id;
+        let is_ours = |file_viewport_id: Option<ViewportId>| {
+            file_viewport_id.map_or(true, |id| id == viewport_id)
+        };
+
+        new_raw_input
+            .hovered_files
+            .retain(|file| is_ours(file.viewport_id));
+
+        let (ours, others): (Vec<DroppedFile>, Vec<DroppedFile>) =
+            std::mem::take(&mut new_raw_input.dropped_files)
+                .into_iter()
+                .partition(|file| is_ours(file.viewport_id));
+
+        new_raw_input.dropped_files = self
+            .pending_dropped_files
+            .remove(&viewport_id)
+            .unwrap_or_default();
+        new_raw_input.dropped_files.extend(ours);
+
+        for file in others {
+            let target = file.viewport_id.unwrap_or_default();
+            self.pending_dropped_files
+                .entry(target)
+                .or_default()
+                .push(file);
+            self.request_repaint(target, RepaintCause::new());
+        }
+    }
+
+    /// Remember the state of the viewports that use [`ViewportBuilder::with_persist`].
+    fn persist_viewports(&mut self, new_raw_input: &RawInput) {
+        use crate::viewport::PersistedViewport;
+
+        for (id, info) in &new_raw_input.viewports {
+            let persist = self
+                .viewports
+                .get(id)
+                .map_or(false, |viewport| viewport.builder.persist == Some(true));
+            if !persist {
+                continue;
+            }
+
+            let key = PersistedViewport::id(*id);
+            let data = &mut self.memory.data;
+            let previous = data.get_persisted::<PersistedViewport>(key);
+            let mut persisted = previous.unwrap_or_default();
+            persisted.update(info);
+            if Some(persisted) != previous {
+                data.insert_persisted(key, persisted);
+            }
+        }
+    }
+
+    /// Snap the viewport to its parent and siblings if it was moved,
+    /// see [`ViewportBuilder::with_snap_distance`].
+    fn snap_viewport(&mut self, viewport_id: ViewportId, new_raw_input: &RawInput) {
+        let Some(distance) = self
+            .viewports
+            .get(&viewport_id)
+            .and_then(|viewport| viewport.builder.snap_distance)
+        else {
+            return;
+        };
+        let Some(info) = new_raw_input.viewports.get(&viewport_id) else {
+            return;
+        };
+        if distance.max_elem() <= 0.0
+            || info.minimized == Some(true)
+            || info.maximized == Some(true)
+            || info.fullscreen == Some(true)
+        {
+            self.viewport_for(viewport_id).snapped.clear();
+            return;
+        }
+        let Some(rect) = info.outer_rect else {
+            return;
+        };
+
+        let last_outer_pos = self
+            .viewports
+            .get(&viewport_id)
+            .and_then(|viewport| viewport.last_outer_pos);
+        if last_outer_pos == Some(rect.min) {
+            // Not moved, so we are still snapped to the same viewports,
+            // even if those have moved away:
+            return;
+        }
+
+        let parent = *self.viewport_parents.entry(viewport_id).or_default();
+        let others: Vec<(ViewportId, Rect)> = new_raw_input
+            .viewports
+            .iter()
+            .filter(|(&id, info)| {
+                let is_relative = id == parent
+                    || (id != viewport_id && self.viewport_parents.get(&id) == Some(&parent));
+                is_relative && info.minimized != Some(true)
+            })
+            .filter_map(|(&id, info)| Some((id, info.outer_rect?)))
+            .collect();
+
+        let (delta, snapped) = crate::viewport::snap_to_viewports(rect, &others, distance);
+
+        let viewport = self.viewport_for(viewport_id);
+        if last_outer_pos.is_some() && delta != Vec2::ZERO {
+            viewport
+                .commands
+                .push(ViewportCommand::OuterPosition(rect.min + delta));
+        }
+        // Our own snapping move should not count as being moved:
+        viewport.last_outer_pos = Some(rect.min + delta);
+        viewport.snapped = snapped;
+    }
+
+    /// Apply the remembered state of a viewport that uses [`ViewportBuilder::with_persist`].
+    fn restore_viewport_builder(
+        &mut self,
+        id: ViewportId,
+        builder: ViewportBuilder,
+    ) -> ViewportBuilder {
+        use crate::viewport::PersistedViewport;
+
+        if builder.persist != Some(true) {
+            return builder;
+        }
+
+        if !self.viewports.contains_key(&id) {
+            // A new viewport: restore what we remember, and keep applying that
+            // so that the integration doesn't resize the window back to `builder`.
+            let restored = self
+                .memory
+                .data
+                .get_persisted::<PersistedViewport>(PersistedViewport::id(id));
+            self.viewport_for(id).restored = restored;
+        }
+
+        let restored = self
+            .viewports
+            .get(&id)
+            .and_then(|viewport| viewport.restored);
+        match restored {
+            Some(restored) => restored.apply(builder),
+            None => builder,
+        }
+    }
+
+    /// The system preferences, with the overrides of the app applied.
+    fn effective_system_preferences(&self) -> SystemPreferences {
+        let options = &self.memory.options;
+        let preferences = if options.follow_system_preferences {
+            options
+                .system_preference_overrides
+                .or(self.system_preferences)
+        } else {
+            options.system_preference_overrides
+        };
+        SystemPreferences {
+            power_hint: options.performance_mode.or(preferences.power_hint),
+            ..preferences
+        }
+    }
+
+    fn power_hint(&self) -> PowerHint {
+        self.effective_system_preferences()
+            .power_hint
+            .unwrap_or_default()
+    }
+
+    /// A viewport that is shown as a modal dialog of the given one, if any.
+    ///
+    /// See [`ViewportBuilder::with_modal`].
+    fn modal_child_of(&self, viewport_id: ViewportId) -> Option<ViewportId> {
+        self.viewports
+            .iter()
+            .find(|(&id, viewport)| {
+                id != viewport_id && viewport.builder.modal == Some(viewport_id)
+            })
+            .map(|(&id, _)| id)
+    }
+}
+
+/// Remove all keyboard and pointer input, because a modal viewport is blocking it.
+///
+/// Returns `true` if a pointer button was pressed.
+fn block_input_for_modal(raw_input: &mut RawInput) -> bool {
+    let mut pressed = false;
+    raw_input.events.retain(|event| match event {
+        Event::PointerButton { pressed: true, .. }
+        | Event::Touch {
+            phase: TouchPhase::Start,
+            ..
+        } => {
+            pressed = true;
+            false
+        }
+        Event::WindowFocused(_)
+        | Event::Screenshot { .. }
+        | Event::ScaleFactorChanged { .. }
+        | Event::Controller { .. } => true,
+        _ => false,
+    });
+    raw_input.events.push(Event::PointerGone);
+    raw_input.modifiers = Default::default();
+    raw_input.hovered_files.clear();
+    raw_input.dropped_files.clear();
+    pressed
 }
 
 // ----------------------------------------------------------------------------
@@ -698,10 +1063,19 @@ impl Default for Context {
         };
         let ctx = Self(Arc::new(RwLock::new(ctx_impl)));
 
-        // Register built-in plugins:
+        // Register built-in plugins.
+        // Their `register` functions are `pub(crate)`, since they are only ever called from here:
         crate::debug_text::register(&ctx);
         crate::text_selection::LabelSelectionState::register(&ctx);
         crate::DragAndDrop::register(&ctx);
+        crate::promise::register(&ctx);
+        crate::screenshot::register(&ctx);
+        crate::command_ack::register(&ctx);
+        crate::shortcut_registry::register(&ctx);
+        crate::control_learn::register(&ctx);
+        // After `LabelSelectionState`, since that copies text at the end of the frame:
+        #[cfg(feature = "clipboard_history")]
+        crate::clipboard_history::register(&ctx);
 
         ctx
     }
@@ -768,8 +1142,33 @@ impl Context {
     /// ```
     pub fn begin_frame(&self, new_input: RawInput) {
         crate::profile_function!();
-        self.read(|ctx| ctx.plugins.clone()).on_begin_frame(self);
+        self.read(|ctx| ctx.plugins.clone())
+            .on_begin_frame(self, new_input.viewport_id);
         self.write(|ctx| ctx.begin_frame_mut(new_input));
+        self.handle_close_request();
+    }
+
+    /// Let the [`Self::on_viewport_close`] handler veto a close request for the current viewport.
+    fn handle_close_request(&self) {
+        let viewport_id = self.viewport_id();
+        let Some(handler) = self.read(|ctx| ctx.viewport_close_handlers.get(&viewport_id).cloned())
+        else {
+            return;
+        };
+        if !self.input(|i| i.viewport().close_requested()) {
+            return;
+        }
+
+        if !handler(self) {
+            self.send_viewport_cmd(ViewportCommand::CancelClose);
+
+            // So that code checking `close_requested` doesn't close the viewport anyway:
+            self.input_mut(|i| {
+                if let Some(info) = i.raw.viewports.get_mut(&viewport_id) {
+                    info.events.retain(|&event| event != ViewportEvent::Close);
+                }
+            });
+        }
     }
 }
 
@@ -1054,6 +1453,39 @@ impl Context {
         res
     }
 
+    /// Attach some data of type `T` to a widget, to be read in later frames with [`Self::widget_user_data`].
+    ///
+    /// This is useful for widgets that need to remember layout results for the hover logic of the next frame.
+    ///
+    /// Each widget can store one value per type.
+    /// The data is removed automatically at the end of the first frame in which the widget is not shown,
+    /// so call this during the frame, after creating the widget.
+    ///
+    /// See also [`Response::set_user_data`].
+    pub fn set_widget_user_data<T: 'static + Send + Sync>(&self, id: Id, value: T) {
+        self.write(|ctx| {
+            ctx.viewport()
+                .widget_user_data
+                .entry(id)
+                .or_default()
+                .insert(std::any::TypeId::of::<T>(), Box::new(value));
+        });
+    }
+
+    /// Read the data attached to a widget with [`Self::set_widget_user_data`].
+    ///
+    /// Returns `None` if the widget has no data of type `T`, or if the widget has disappeared.
+    pub fn widget_user_data<T: 'static + Clone>(&self, id: Id) -> Option<T> {
+        self.write(|ctx| {
+            ctx.viewport()
+                .widget_user_data
+                .get(&id)?
+                .get(&std::any::TypeId::of::<T>())?
+                .downcast_ref::<T>()
+                .cloned()
+        })
+    }
+
     /// Read the response of some widget, which may be called _before_ creating the widget (!).
     ///
     /// This is because widget interaction happens at the start of the frame, using the previous frame's widgets.
@@ -1071,6 +1503,144 @@ impl Context {
         .map(|widget_rect| self.get_response(widget_rect))
     }
 
+    /// Register a region of a custom layer that blocks interaction with whatever is beneath it.
+    ///
+    /// Use this for overlays that you paint yourself (e.g. with [`Context::layer_painter`])
+    /// instead of with an [`Area`] or [`Window`].
+    /// Widgets in lower layers will not be hovered or clicked where the occluder covers them,
+    /// and [`Context::layer_id_at`] and [`Context::rect_contains_pointer`] will report the occluding layer.
+    ///
+    /// Occluders are ordered by the [`Order`] of their layer, below any [`Area`] of the same [`Order`].
+    ///
+    /// The `rect` is in the coordinates of the layer, just like widget rectangles.
+    /// Call this every frame the overlay is shown. Like all interaction, it takes effect from the next frame.
+    ///
+    /// The returned [`Response`] is that of a hover-only widget covering the occluder.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let layer_id = egui::LayerId::new(egui::Order::Foreground, egui::Id::new("my_overlay"));
+    /// let rect = egui::Rect::from_min_size(egui::pos2(100.0, 100.0), egui::vec2(200.0, 50.0));
+    /// ctx.layer_painter(layer_id)
+    ///     .rect_filled(rect, 4.0, egui::Color32::from_black_alpha(200));
+    /// ctx.register_occluder(layer_id, rect);
+    /// # });
+    /// ```
+    pub fn register_occluder(&self, layer_id: LayerId, rect: Rect) -> Response {
+        let index = self.write(|ctx| {
+            let occluders = &mut ctx.viewport().occluders_this_frame;
+            occluders.push((layer_id, rect));
+            occluders.len()
+        });
+
+        self.create_widget(WidgetRect {
+            id: Id::new("egui_occluder").with(layer_id).with(index),
+            layer_id,
+            rect,
+            interact_rect: rect,
+            sense: Sense::hover(),
+            enabled: true,
+        })
+    }
+
+    /// Read the widgets added so far this frame, in the current viewport.
+    pub(crate) fn widgets_this_frame<R>(&self, reader: impl FnOnce(&WidgetRects) -> R) -> R {
+        self.write(|ctx| reader(&ctx.viewport().widgets_this_frame))
+    }
+
+    /// All widgets whose interact rectangle intersects the given screen-space rectangle,
+    /// front-to-back (the top-most widget first).
+    ///
+    /// This looks at the widgets added so far this frame, so call it after laying out your ui.
+    /// Between frames (e.g. after [`Self::run`]) it looks at the widgets of the last frame.
+    ///
+    /// This includes non-interactive widgets, such as labels and the backgrounds of windows.
+    /// The returned rectangles are in the coordinates of their layer, see [`Self::set_transform_layer`].
+    ///
+    /// Use [`Self::widget_info`] to find out what kind of widget each one is.
+    ///
+    /// For a marquee selection that only includes widgets completely inside the rectangle,
+    /// filter the result on [`Rect::contains_rect`].
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// egui::CentralPanel::default().show(ctx, |ui| {
+    ///     ui.button("Click me");
+    /// });
+    /// let marquee = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 100.0));
+    /// for widget in ctx.widgets_under_rect(marquee) {
+    ///     let selected = marquee.contains_rect(widget.interact_rect);
+    ///     // …
+    /// }
+    /// # });
+    /// ```
+    pub fn widgets_under_rect(&self, rect: Rect) -> Vec<WidgetRect> {
+        self.widgets_front_to_back(|interact_rect| interact_rect.intersects(rect))
+    }
+
+    /// The top-most widget whose interact rectangle contains the given screen-space position.
+    ///
+    /// See [`Self::widgets_under_rect`] for details.
+    pub fn widget_at(&self, pos: Pos2) -> Option<WidgetRect> {
+        self.widgets_front_to_back(|interact_rect| interact_rect.contains(pos))
+            .into_iter()
+            .next()
+    }
+
+    /// The [`crate::WidgetInfo`] of the given widget, if it was recorded.
+    ///
+    /// This is only recorded if [`crate::Options::record_widget_info`] is set,
+    /// and only for widgets that report it (see [`Response::widget_info`]).
+    pub fn widget_info(&self, id: Id) -> Option<crate::WidgetInfo> {
+        self.write(|ctx| {
+            let viewport = ctx.viewport();
+            viewport
+                .widgets_this_frame
+                .info(id)
+                .or_else(|| viewport.widgets_prev_frame.info(id))
+                .cloned()
+        })
+    }
+
+    /// The widgets of the current frame (or the last one, between frames),
+    /// whose screen-space interact rectangle passes the filter, front-to-back.
+    fn widgets_front_to_back(&self, filter: impl Fn(Rect) -> bool) -> Vec<WidgetRect> {
+        self.write(|ctx| {
+            let area_order = ctx.memory.areas().order_map();
+            let layer_transforms = &ctx.memory.layer_transforms;
+            let viewport = ctx.viewports.entry(ctx.viewport_id()).or_default();
+
+            let widgets_this_frame = &viewport.widgets_this_frame;
+            let widgets = if widgets_this_frame.layers().any(|(_, w)| !w.is_empty()) {
+                widgets_this_frame
+            } else {
+                &viewport.widgets_prev_frame
+            };
+
+            let mut layers: Vec<LayerId> = widgets.layer_ids().collect();
+            layers.sort_by(|a, b| {
+                if a.order == b.order {
+                    area_order.get(a).cmp(&area_order.get(b))
+                } else {
+                    a.order.cmp(&b.order)
+                }
+            });
+
+            let mut result = Vec::new();
+            for layer_id in layers.iter().rev() {
+                let transform = layer_transforms.get(layer_id);
+                for widget in widgets.get_layer(*layer_id).rev() {
+                    let interact_rect =
+                        transform.map_or(widget.interact_rect, |t| *t * widget.interact_rect);
+                    if filter(interact_rect) {
+                        result.push(*widget);
+                    }
+                }
+            }
+            result
+        })
+    }
+
     /// Returns `true` if the widget with the given `Id` contains the pointer.
     #[deprecated = "Use Response.contains_pointer or Context::read_response instead"]
     pub fn widget_contains_pointer(&self, id: Id) -> bool {
@@ -1204,19 +1774,18 @@ impl Context {
 
     /// This is called by [`Response::widget_info`], but can also be called directly.
     ///
-    /// With some debug flags it will store the widget info in [`WidgetRects`] for later display.
+    /// With some debug flags, or with [`crate::Options::record_widget_info`],
+    /// it will store the widget info in [`WidgetRects`] for later display or [`Self::widget_info`].
     #[inline]
     pub fn register_widget_info(&self, id: Id, make_info: impl Fn() -> crate::WidgetInfo) {
-        #[cfg(debug_assertions)]
-        self.write(|ctx| {
-            if ctx.memory.options.style.debug.show_interactive_widgets {
-                ctx.viewport().widgets_this_frame.set_info(id, make_info());
-            }
+        // Only take the write lock if we are recording, since this is called for every widget:
+        let record = self.options(|options| {
+            options.record_widget_info
+                || (cfg!(debug_assertions) && options.style.debug.show_interactive_widgets)
         });
-
-        #[cfg(not(debug_assertions))]
-        {
-            _ = (self, id, make_info);
+        if record {
+            let info = make_info();
+            self.write(|ctx| ctx.viewport().widgets_this_frame.set_info(id, info));
         }
     }
 
@@ -1231,6 +1800,20 @@ impl Context {
         Self::layer_painter(self, LayerId::debug())
     }
 
+    /// Show the horizontal and vertical space between two rectangles on top of everything else,
+    /// e.g. to check the spacing between two widgets.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let a = ui.button("A");
+    /// let b = ui.button("B");
+    /// ui.ctx().debug_show_spacing_between(a.rect, b.rect);
+    /// # });
+    /// ```
+    pub fn debug_show_spacing_between(&self, a: Rect, b: Rect) {
+        crate::debug_guides::paint_spacing_between(&self.debug_painter(), a, b);
+    }
+
     /// Print this text next to the cursor at the end of the frame.
     ///
     /// If you call this multiple times, the text will be appended.
@@ -1249,6 +1832,61 @@ impl Context {
         crate::debug_text::print(self, text);
     }
 
+    /// Declare a setting, so that it shows up in [`Ui::settings_editor`].
+    ///
+    /// Registering a setting with the same key again replaces the old declaration,
+    /// so it is fine to call this every frame.
+    ///
+    /// See [`crate::settings`] for an example.
+    pub fn register_setting(&self, setting: crate::settings::Setting) {
+        crate::settings::register(self, setting);
+    }
+
+    /// The current value of a setting, or its default if the user hasn't changed it.
+    ///
+    /// Returns `None` if there is no such setting, or if it is of another type.
+    pub fn setting<T: crate::settings::SettingType>(&self, key: &str) -> Option<T> {
+        crate::settings::get(self, key)
+    }
+
+    /// Change the value of a setting.
+    pub fn set_setting<T: crate::settings::SettingType>(&self, key: &str, value: T) {
+        crate::settings::set(self, key, value);
+    }
+
+    /// Run a future, and get a [`crate::Promise`] for its output.
+    ///
+    /// When the future completes, a repaint is requested.
+    /// See [`crate::promise`] for which executor the future is run on.
+    pub fn spawn_async<T: Send + Sync + 'static>(
+        &self,
+        future: impl std::future::Future<Output = T> + Send + 'static,
+    ) -> crate::Promise<T> {
+        crate::promise::spawn_async(self, future)
+    }
+
+    /// Run a blocking function on a background thread, and get a [`crate::Promise`] for its output.
+    ///
+    /// When the function returns, a repaint is requested.
+    /// Dropping the promise can't stop the function, but its result is then thrown away.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_worker<T: Send + Sync + 'static>(
+        &self,
+        work: impl FnOnce() -> T + Send + 'static,
+    ) -> crate::Promise<T> {
+        crate::promise::spawn_worker(self, work)
+    }
+
+    /// Use this to spawn the futures passed to [`Self::spawn_async`], e.g. `|future| { tokio::spawn(future); }`.
+    ///
+    /// By default egui drives the futures itself, see [`crate::promise`].
+    pub fn set_async_spawner(
+        &self,
+        spawner: impl Fn(crate::promise::BoxedFuture) + Send + Sync + 'static,
+    ) {
+        crate::promise::set_spawner(self, Some(Arc::new(spawner)));
+    }
+
     /// What operating system are we running on?
     ///
     /// When compiling natively, this is
@@ -1304,16 +1942,123 @@ impl Context {
         self.output_mut(|o| o.copied_text = text);
     }
 
-    /// Format the given shortcut in a human-readable way (e.g. `Ctrl+Shift+X`).
+    /// Check if a keyboard shortcut was pressed, and register it so that it shows up in
+    /// [`Self::shortcut_help_window`].
     ///
-    /// Can be used to get the text for [`Button::shortcut_text`].
-    pub fn format_shortcut(&self, shortcut: &KeyboardShortcut) -> String {
-        let os = self.os();
+    /// Call this every frame, instead of [`crate::InputState::consume_shortcut`].
+    /// `category` groups the shortcuts in the help window, e.g. `"File"`,
+    /// and `name` says what the shortcut does, e.g. `"Save"`.
+    ///
+    /// Returns `true` if the shortcut was pressed (and then consumes it),
+    /// or if the user clicked the command in the help window.
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    ///
+    /// const SAVE: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+    /// # egui::__run_test_ctx(|ctx| {
+    /// if ctx.register_shortcut("File", "Save", SAVE) {
+    ///     // save…
+    /// }
+    /// # });
+    /// ```
+    pub fn register_shortcut(
+        &self,
+        category: impl Into<String>,
+        name: impl Into<String>,
+        shortcut: KeyboardShortcut,
+    ) -> bool {
+        crate::shortcut_registry::register_shortcut(self, category.into(), name.into(), shortcut)
+    }
 
-        let is_mac = matches!(os, OperatingSystem::Mac | OperatingSystem::IOS);
+    /// All shortcuts registered with [`Self::register_shortcut`] this frame or the previous one,
+    /// in the order they were registered.
+    pub fn registered_shortcuts(&self) -> Vec<crate::RegisteredShortcut> {
+        crate::shortcut_registry::registered_shortcuts(self)
+    }
 
-        let can_show_symbols = || {
-            let ModifierNames {
+    /// Show a window listing all shortcuts registered with [`Self::register_shortcut`], grouped by category.
+    ///
+    /// The list can be searched, and clicking a command runs it.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut show_shortcuts = true;
+    /// ctx.shortcut_help_window(&mut show_shortcuts);
+    /// # });
+    /// ```
+    pub fn shortcut_help_window(&self, open: &mut bool) {
+        crate::shortcut_registry::help_window(self, open);
+    }
+
+    /// Bind the next control that is moved on an external controller to the parameter `param`
+    /// ("MIDI learn").
+    ///
+    /// The parameter is the id given to e.g. [`crate::Slider::control_param`],
+    /// and the control is the one of the next [`Event::Controller`].
+    /// Any previous binding of the parameter or the control is replaced.
+    ///
+    /// The bindings are stored in [`crate::Memory`], and persisted with the `persistence` feature.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut volume = 0.5;
+    /// let volume_id = egui::Id::new("volume");
+    /// let response = ui.add(egui::Slider::new(&mut volume, 0.0..=1.0).control_param(volume_id));
+    /// response.context_menu(|ui| {
+    ///     if ui.button("Learn controller").clicked() {
+    ///         ui.ctx().learn_control(volume_id);
+    ///         ui.close_menu();
+    ///     }
+    ///     if ui.button("Forget controller").clicked() {
+    ///         ui.ctx().unbind_control(volume_id);
+    ///         ui.close_menu();
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn learn_control(&self, param: Id) {
+        crate::control_learn::learn(self, param);
+    }
+
+    /// Stop waiting for a control to bind, see [`Self::learn_control`].
+    pub fn cancel_learn_control(&self) {
+        crate::control_learn::cancel_learning(self);
+    }
+
+    /// The parameter waiting for a control to be moved, see [`Self::learn_control`].
+    pub fn learning_control(&self) -> Option<Id> {
+        crate::control_learn::learning(self)
+    }
+
+    /// Remove the binding of the given parameter, if any.
+    pub fn unbind_control(&self, param: Id) {
+        crate::control_learn::unbind(self, param);
+    }
+
+    /// All controls and the parameters they are bound to, see [`Self::learn_control`].
+    pub fn control_bindings(&self) -> Vec<(crate::ControlId, Id)> {
+        crate::control_learn::bindings(self)
+    }
+
+    /// The position (`0.0..=1.0`) of the control bound to `param`, if it was moved this frame.
+    ///
+    /// This is what [`crate::Slider`] and [`crate::DragValue`] use to follow their controls,
+    /// and can be used to make custom widgets bindable too.
+    pub fn control_value(&self, param: Id) -> Option<f32> {
+        crate::control_learn::value(self, param)
+    }
+
+    /// Format the given shortcut in a human-readable way (e.g. `Ctrl+Shift+X`).
+    ///
+    /// Can be used to get the text for [`Button::shortcut_text`].
+    pub fn format_shortcut(&self, shortcut: &KeyboardShortcut) -> String {
+        let os = self.os();
+
+        let is_mac = matches!(os, OperatingSystem::Mac | OperatingSystem::IOS);
+
+        let can_show_symbols = || {
+            let ModifierNames {
                 alt,
                 ctrl,
                 shift,
@@ -1516,9 +2261,12 @@ impl Context {
     ///
     /// This can be used for egui _plugins_.
     /// See [`crate::debug_text`] for an example.
+    ///
+    /// To only run for one viewport, use [`Self::on_begin_viewport_frame`].
     pub fn on_begin_frame(&self, debug_name: &'static str, cb: ContextCallback) {
         let named_cb = NamedContextCallback {
             debug_name,
+            viewport_id: None,
             callback: cb,
         };
         self.write(|ctx| ctx.plugins.on_begin_frame.push(named_cb));
@@ -1529,9 +2277,60 @@ impl Context {
     ///
     /// This can be used for egui _plugins_.
     /// See [`crate::debug_text`] for an example.
+    ///
+    /// To only run for one viewport, use [`Self::on_end_viewport_frame`].
     pub fn on_end_frame(&self, debug_name: &'static str, cb: ContextCallback) {
         let named_cb = NamedContextCallback {
             debug_name,
+            viewport_id: None,
+            callback: cb,
+        };
+        self.write(|ctx| ctx.plugins.on_end_frame.push(named_cb));
+    }
+
+    /// Call the given callback at the start of each frame of the given viewport.
+    ///
+    /// Like [`Self::on_begin_frame`], this is called before the input of the frame is processed.
+    pub fn on_begin_viewport_frame(
+        &self,
+        viewport_id: ViewportId,
+        debug_name: &'static str,
+        cb: ContextCallback,
+    ) {
+        let named_cb = NamedContextCallback {
+            debug_name,
+            viewport_id: Some(viewport_id),
+            callback: cb,
+        };
+        self.write(|ctx| ctx.plugins.on_begin_frame.push(named_cb));
+    }
+
+    /// Call the given callback at the end of each frame of the given viewport,
+    /// before the output of that viewport is collected.
+    ///
+    /// This is a good place to paint overlays on top of the ui of the viewport.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// ctx.on_end_viewport_frame(
+    ///     egui::ViewportId::ROOT,
+    ///     "toasts",
+    ///     std::sync::Arc::new(|ctx| {
+    ///         egui::Area::new(egui::Id::new("toasts")).show(ctx, |ui| {
+    ///             ui.label("Saved!");
+    ///         });
+    ///     }),
+    /// );
+    /// ```
+    pub fn on_end_viewport_frame(
+        &self,
+        viewport_id: ViewportId,
+        debug_name: &'static str,
+        cb: ContextCallback,
+    ) {
+        let named_cb = NamedContextCallback {
+            debug_name,
+            viewport_id: Some(viewport_id),
             callback: cb,
         };
         self.write(|ctx| ctx.plugins.on_end_frame.push(named_cb));
@@ -1645,6 +2444,21 @@ impl Context {
         self.options(|o| o.zoom_factor)
     }
 
+    /// The zoom factor of the given viewport.
+    ///
+    /// This is the same as [`Self::zoom_factor`],
+    /// unless the viewport has been sent a [`ViewportCommand::ZoomFactor`].
+    ///
+    /// Integrations should use this when converting between points and pixels for a viewport.
+    pub fn viewport_zoom_factor(&self, viewport_id: ViewportId) -> f32 {
+        self.read(|ctx| {
+            ctx.viewports
+                .get(&viewport_id)
+                .and_then(|viewport| viewport.zoom_factor)
+                .unwrap_or(ctx.memory.options.zoom_factor)
+        })
+    }
+
     /// Sets zoom factor of the UI.
     /// Will become active at the start of the next frame.
     ///
@@ -1819,6 +2633,11 @@ impl Context {
 
         self.read(|ctx| ctx.plugins.clone()).on_end_frame(self);
 
+        #[cfg(debug_assertions)]
+        if self.style().debug.show_rulers {
+            crate::debug_guides::rulers_ui(self);
+        }
+
         #[cfg(debug_assertions)]
         self.debug_painting();
 
@@ -1955,27 +2774,27 @@ impl ContextImpl {
 
         if let Some(fonts) = self.fonts.get(&pixels_per_point.into()) {
             let tex_mngr = &mut self.tex_manager.0.write();
-            if let Some(font_image_delta) = fonts.font_image_delta() {
-                // A partial font atlas update, e.g. a new glyph has been entered.
-                tex_mngr.set(TextureId::default(), font_image_delta);
-            }
+            let font_image_delta = fonts.font_image_delta();
 
-            if 1 < self.fonts.len() {
-                // We have multiple different `pixels_per_point`,
+            if self.font_atlas_pixels_per_point == Some(pixels_per_point.into()) {
+                if let Some(font_image_delta) = font_image_delta {
+                    // A partial font atlas update, e.g. a new glyph has been entered.
+                    tex_mngr.set(TextureId::default(), font_image_delta);
+                }
+            } else {
+                // The font texture holds the atlas of another `pixels_per_point`,
                 // e.g. because we have many viewports spread across
                 // monitors with different DPI scaling.
                 // All viewports share the same texture namespace and renderer,
-                // so the all use `TextureId::default()` for the font texture.
-                // This is a problem.
-                // We solve this with a hack: we always upload the full font atlas
-                // every frame, for all viewports.
-                // This ensures it is up-to-date, solving
-                // https://github.com/emilk/egui/issues/3664
-                // at the cost of a lot of performance.
-                // (This will override any smaller delta that was uploaded above.)
+                // so they all use `TextureId::default()` for the font texture.
+                // We solve this by uploading the full font atlas whenever
+                // we switch to a viewport with a different `pixels_per_point`,
+                // see https://github.com/emilk/egui/issues/3664.
+                // Viewports with the same `pixels_per_point` only upload the smaller deltas.
                 crate::profile_scope!("full_font_atlas_update");
                 let full_delta = ImageDelta::full(fonts.image(), TextureAtlas::texture_options());
                 tex_mngr.set(TextureId::default(), full_delta);
+                self.font_atlas_pixels_per_point = Some(pixels_per_point.into());
             }
         }
 
@@ -2015,13 +2834,74 @@ impl ContextImpl {
             }
         }
 
-        let shapes = viewport
+        let (shapes, layers) = viewport
             .graphics
-            .drain(self.memory.areas().order(), &self.memory.layer_transforms);
+            .drain_layers(self.memory.areas().order(), &self.memory.layer_transforms);
+
+        let stats = if self.memory.options.record_frame_stats {
+            crate::profile_scope!("frame_stats");
+            let tessellation = &viewport.tessellation_stats;
+            let (num_textures, texture_bytes) = {
+                let tex_mngr = self.tex_manager.0.read();
+                let texture_bytes = tex_mngr
+                    .allocated()
+                    .map(|(_, meta)| meta.bytes_used())
+                    .sum();
+                (tex_mngr.num_allocated(), texture_bytes)
+            };
+            FrameStats {
+                layers: layers
+                    .iter()
+                    .map(|(layer_id, range)| {
+                        let (num_vertices, num_indices) = tessellation
+                            .layers
+                            .get(layer_id)
+                            .copied()
+                            .unwrap_or_default();
+                        crate::frame_stats::LayerStats {
+                            layer_id: *layer_id,
+                            num_shapes: range.len(),
+                            num_vertices,
+                            num_indices,
+                        }
+                    })
+                    .collect(),
+                num_shapes: shapes.len(),
+                num_vertices: tessellation.num_vertices,
+                num_indices: tessellation.num_indices,
+                num_draw_calls: tessellation.num_draw_calls,
+                num_textures,
+                texture_bytes,
+                num_galleys_in_cache: self
+                    .fonts
+                    .get(&pixels_per_point.into())
+                    .map_or(0, |fonts| fonts.num_galleys_in_cache()),
+                num_widgets: viewport
+                    .widgets_this_frame
+                    .layers()
+                    .map(|(_, widgets)| widgets.len())
+                    .sum(),
+            }
+        } else {
+            FrameStats::default()
+        };
+        self.last_frame_layers = self
+            .memory
+            .options
+            .record_frame_stats
+            .then_some((ended_viewport_id, layers));
+        viewport.frame_stats = stats.clone();
 
         let mut repaint_needed = false;
 
         {
+            {
+                let widgets_this_frame = &viewport.widgets_this_frame;
+                viewport
+                    .widget_user_data
+                    .retain(|id, _| widgets_this_frame.contains(*id));
+            }
+
             if self.memory.options.repaint_on_widget_change {
                 crate::profile_function!("compare-widget-rects");
                 if viewport.widgets_prev_frame != viewport.widgets_this_frame {
@@ -2034,6 +2914,12 @@ impl ContextImpl {
                 &mut viewport.widgets_this_frame,
             );
             viewport.widgets_this_frame.clear();
+
+            std::mem::swap(
+                &mut viewport.occluders_prev_frame,
+                &mut viewport.occluders_this_frame,
+            );
+            viewport.occluders_this_frame.clear();
         }
 
         if repaint_needed || viewport.input.wants_repaint() {
@@ -2109,6 +2995,7 @@ impl ContextImpl {
                         viewport_ui_cb: viewport.viewport_ui_cb.clone(),
                         commands,
                         repaint_delay: viewport.repaint.repaint_delay,
+                        snapped: viewport.snapped.clone(),
                     },
                 )
             })
@@ -2119,27 +3006,38 @@ impl ContextImpl {
             self.viewports.retain(|id, _| all_viewport_ids.contains(id));
             self.viewport_parents
                 .retain(|id, _| all_viewport_ids.contains(id));
+            self.viewport_close_handlers
+                .retain(|id, _| all_viewport_ids.contains(id));
+            self.pending_dropped_files
+                .retain(|id, _| all_viewport_ids.contains(id));
         } else {
             let viewport_id = self.viewport_id();
             self.memory.set_viewport_id(viewport_id);
         }
 
-        let active_pixels_per_point: std::collections::BTreeSet<OrderedFloat<f32>> = self
+        /// How long to keep fonts that no viewport uses anymore, in seconds.
+        const UNUSED_FONTS_KEEP_ALIVE: f64 = 10.0;
+
+        let now = self
             .viewports
-            .values()
-            .map(|v| v.input.pixels_per_point.into())
-            .collect();
+            .get(&ended_viewport_id)
+            .map_or(0.0, |viewport| viewport.input.time);
+        for viewport in self.viewports.values() {
+            self.fonts_last_used
+                .insert(viewport.input.pixels_per_point.into(), now);
+        }
+        let fonts_last_used = &mut self.fonts_last_used;
+        fonts_last_used.retain(|_, last_used| now - *last_used < UNUSED_FONTS_KEEP_ALIVE);
         self.fonts.retain(|pixels_per_point, _| {
-            if active_pixels_per_point.contains(pixels_per_point) {
-                true
-            } else {
-                #[cfg(feature = "log")]
+            let keep = fonts_last_used.contains_key(pixels_per_point);
+            #[cfg(feature = "log")]
+            if !keep {
                 log::trace!(
                     "Freeing Fonts with pixels_per_point={} because it is no longer needed",
                     pixels_per_point.into_inner()
                 );
-                false
             }
+            keep
         });
 
         FullOutput {
@@ -2148,6 +3046,7 @@ impl ContextImpl {
             shapes,
             pixels_per_point,
             viewport_output,
+            stats,
         }
     }
 }
@@ -2183,16 +3082,52 @@ impl Context {
             };
 
             let paint_stats = PaintStats::from_shapes(&shapes);
+            let mut tessellator = tessellator::Tessellator::new(
+                pixels_per_point,
+                tessellation_options,
+                font_tex_size,
+                prepared_discs,
+            );
+
+            // If these are the shapes of the last frame, tessellate them one layer at a time
+            // so we can tell how many vertices each layer has.
+            // The integration may have given us other shapes, e.g. from an earlier frame,
+            // in which case the layer ranges recorded by `end_frame` don't match:
+            let last_frame_layers = ctx.last_frame_layers.take().filter(|(_, layers)| {
+                layers.last().map(|(_, range)| range.end) == Some(shapes.len())
+            });
+
+            let mut tessellation_stats = TessellationStats::default();
             let clipped_primitives = {
                 crate::profile_scope!("tessellator::tessellate_shapes");
-                tessellator::Tessellator::new(
-                    pixels_per_point,
-                    tessellation_options,
-                    font_tex_size,
-                    prepared_discs,
-                )
-                .tessellate_shapes(shapes)
+                if let Some((_, layers)) = &last_frame_layers {
+                    let mut shapes = shapes.into_iter();
+                    let mut clipped_primitives = vec![];
+                    for (layer_id, range) in layers {
+                        // The ranges are in order and without gaps:
+                        let layer_shapes = shapes.by_ref().take(range.len()).collect();
+                        let layer_primitives = tessellator.tessellate_shapes(layer_shapes);
+                        tessellation_stats
+                            .layers
+                            .insert(*layer_id, count_vertices(&layer_primitives));
+                        clipped_primitives.extend(layer_primitives);
+                    }
+                    clipped_primitives
+                } else {
+                    tessellator.tessellate_shapes(shapes)
+                }
             };
+
+            if let Some((viewport_id, _)) = last_frame_layers {
+                let (num_vertices, num_indices) = count_vertices(&clipped_primitives);
+                tessellation_stats.num_vertices = num_vertices;
+                tessellation_stats.num_indices = num_indices;
+                tessellation_stats.num_draw_calls = clipped_primitives.len();
+                if let Some(viewport) = ctx.viewports.get_mut(&viewport_id) {
+                    viewport.tessellation_stats = tessellation_stats;
+                }
+            }
+
             ctx.paint_stats = paint_stats.with_clipped_primitives(&clipped_primitives);
             clipped_primitives
         })
@@ -2373,8 +3308,32 @@ impl Context {
     }
 
     /// Top-most layer at the given position.
+    ///
+    /// This includes layers registered with [`Self::register_occluder`].
     pub fn layer_id_at(&self, pos: Pos2) -> Option<LayerId> {
-        self.memory(|mem| mem.layer_id_at(pos))
+        let area_layer = self.memory(|mem| mem.layer_id_at(pos));
+
+        let occluder_layer = self.write(|ctx| {
+            let layer_transforms = &ctx.memory.layer_transforms;
+            let viewport = ctx.viewports.entry(ctx.viewport_id()).or_default();
+            viewport
+                .occluders_prev_frame
+                .iter()
+                .filter(|(layer_id, rect)| {
+                    let rect = layer_transforms
+                        .get(layer_id)
+                        .map_or(*rect, |transform| *transform * *rect);
+                    rect.contains(pos)
+                })
+                .map(|(layer_id, _)| *layer_id)
+                .max_by_key(|layer_id| layer_id.order)
+        });
+
+        match (area_layer, occluder_layer) {
+            (Some(area), Some(occluder)) if area.order < occluder.order => Some(occluder),
+            (None, Some(occluder)) => Some(occluder),
+            (area, _) => area,
+        }
     }
 
     /// Moves the given area to the top in its [`Order`].
@@ -2449,6 +3408,8 @@ impl Context {
     /// The function will call [`Self::request_repaint()`] when appropriate.
     ///
     /// The animation time is taken from [`Style::animation_time`].
+    ///
+    /// If [`Self::reduce_motion`] is set, the value jumps straight to the target.
     #[track_caller] // To track repaint cause
     pub fn animate_bool(&self, id: Id, value: bool) -> f32 {
         let animation_time = self.style().animation_time;
@@ -2458,6 +3419,7 @@ impl Context {
     /// Like [`Self::animate_bool`] but allows you to control the animation time.
     #[track_caller] // To track repaint cause
     pub fn animate_bool_with_time(&self, id: Id, target_value: bool, animation_time: f32) -> f32 {
+        let animation_time = self.animation_time_or_zero(animation_time);
         let animated_value = self.write(|ctx| {
             ctx.animation_manager.animate_bool(
                 &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
@@ -2468,7 +3430,7 @@ impl Context {
         });
         let animation_in_progress = 0.0 < animated_value && animated_value < 1.0;
         if animation_in_progress {
-            self.request_repaint();
+            self.request_animation_repaint();
         }
         animated_value
     }
@@ -2479,6 +3441,7 @@ impl Context {
     /// When it is called with a new value, it linearly interpolates to it in the given time.
     #[track_caller] // To track repaint cause
     pub fn animate_value_with_time(&self, id: Id, target_value: f32, animation_time: f32) -> f32 {
+        let animation_time = self.animation_time_or_zero(animation_time);
         let animated_value = self.write(|ctx| {
             ctx.animation_manager.animate_value(
                 &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
@@ -2489,16 +3452,167 @@ impl Context {
         });
         let animation_in_progress = animated_value != target_value;
         if animation_in_progress {
-            self.request_repaint();
+            self.request_animation_repaint();
         }
 
         animated_value
     }
 
+    /// Should animations be skipped, because the user prefers reduced motion?
+    ///
+    /// This is true if [`Options::reduce_motion`] is set,
+    /// or if [`SystemPreferences::reduce_motion`] in [`RawInput::system_preferences`] is set
+    /// (unless overridden with [`Options::system_preference_overrides`]).
+    ///
+    /// When set, [`Self::animate_bool`] and friends jump straight to the target value,
+    /// [`crate::ScrollArea`] scrolls without animating, and [`crate::Spinner`] stands still.
+    /// Custom animated widgets should check this too.
+    pub fn reduce_motion(&self) -> bool {
+        self.read(|ctx| {
+            ctx.memory.options.reduce_motion
+                || ctx.effective_system_preferences().reduce_motion == Some(true)
+        })
+    }
+
+    /// The accessibility and appearance preferences of the user, with the overrides of the app applied.
+    ///
+    /// See [`RawInput::system_preferences`] and [`Options::system_preference_overrides`].
+    pub fn system_preferences(&self) -> SystemPreferences {
+        self.read(|ctx| ctx.effective_system_preferences())
+    }
+
+    /// Should egui save power or go for the smoothest experience?
+    ///
+    /// This is [`Options::performance_mode`] if set, otherwise [`SystemPreferences::power_hint`]
+    /// as given by the integration, defaulting to [`PowerHint::HighPerformance`].
+    pub fn power_hint(&self) -> PowerHint {
+        self.read(|ctx| ctx.power_hint())
+    }
+
+    /// Request a repaint to show the next frame of an animation.
+    ///
+    /// This is the same as [`Self::request_repaint`], except with [`PowerHint::LowPower`],
+    /// where the frame rate of animations is limited to save power.
+    /// Custom animated widgets should call this instead of [`Self::request_repaint`].
+    #[track_caller]
+    pub fn request_animation_repaint(&self) {
+        let cause = RepaintCause::new();
+        let viewport_id = self.viewport_id();
+        self.write(|ctx| {
+            let delay = ctx.power_hint().min_repaint_interval();
+            ctx.request_repaint_after(delay, viewport_id, cause);
+        });
+    }
+
+    fn animation_time_or_zero(&self, animation_time: f32) -> f32 {
+        if self.reduce_motion() {
+            0.0
+        } else {
+            animation_time
+        }
+    }
+
     /// Clear memory of any animations.
     pub fn clear_animations(&self) {
         self.write(|ctx| ctx.animation_manager = Default::default());
     }
+
+    /// Replace the time given by the integration with the time of the given [`crate::Clock`].
+    ///
+    /// Everything that depends on time (animations, cursor blinking, tooltip delays, …)
+    /// reads [`InputState::time`], which then comes from the clock.
+    /// Use [`crate::FixedStepClock`] for deterministic tests and recordings,
+    /// or [`crate::ScaledClock`] for slow motion.
+    ///
+    /// [`Self::debug_pause_frames`] and [`Self::set_debug_time_scale`] are applied on top of the clock.
+    ///
+    /// ```
+    /// let ctx = egui::Context::default();
+    /// ctx.set_clock(egui::FixedStepClock::new(0.25));
+    ///
+    /// let mut time = 0.0;
+    /// for _ in 0..4 {
+    ///     let _ = ctx.run(Default::default(), |ctx| time = ctx.input(|i| i.time));
+    /// }
+    /// assert_eq!(time, 1.0);
+    /// ```
+    pub fn set_clock(&self, clock: impl crate::Clock + 'static) {
+        self.write(|ctx| ctx.clock = Some(Box::new(clock)));
+    }
+
+    /// Go back to using the time given by the integration, see [`Self::set_clock`].
+    pub fn reset_clock(&self) {
+        self.write(|ctx| ctx.clock = None);
+    }
+
+    /// Freeze time, for debugging.
+    ///
+    /// While paused, [`InputState::time`] stops advancing, so animations, tooltips and other timed things stop,
+    /// while input is still processed (so you can keep using the inspector).
+    /// Use [`Self::step_one_frame`] to advance time by one frame.
+    ///
+    /// See also [`Self::debug_time_ui`].
+    pub fn debug_pause_frames(&self, paused: bool) {
+        self.write(|ctx| ctx.debug_time.paused = paused);
+        self.request_repaint();
+    }
+
+    /// Is time frozen by [`Self::debug_pause_frames`]?
+    pub fn is_debug_paused(&self) -> bool {
+        self.read(|ctx| ctx.debug_time.paused)
+    }
+
+    /// While paused with [`Self::debug_pause_frames`], advance time by one frame.
+    pub fn step_one_frame(&self) {
+        self.write(|ctx| ctx.debug_time.steps += 1);
+        self.request_repaint();
+    }
+
+    /// Slow down (or speed up) time, for debugging animations.
+    ///
+    /// `0.1` means time passes ten times slower than normal.
+    ///
+    /// Default: `1.0`.
+    pub fn set_debug_time_scale(&self, time_scale: f32) {
+        if time_scale.is_finite() && 0.0 <= time_scale {
+            self.write(|ctx| ctx.debug_time.time_scale = time_scale);
+        }
+    }
+
+    /// See [`Self::set_debug_time_scale`].
+    pub fn debug_time_scale(&self) -> f32 {
+        self.read(|ctx| ctx.debug_time.time_scale)
+    }
+
+    /// Show controls for pausing, stepping and slowing down time.
+    pub fn debug_time_ui(&self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            let mut paused = self.is_debug_paused();
+            let label = if paused { "▶ Resume" } else { "⏸ Pause" };
+            if ui.toggle_value(&mut paused, label).changed() {
+                self.debug_pause_frames(paused);
+            }
+            if ui
+                .add_enabled(paused, crate::Button::new("⏭ Step"))
+                .on_hover_text("Advance time by one frame")
+                .clicked()
+            {
+                self.step_one_frame();
+            }
+        });
+
+        let mut time_scale = self.debug_time_scale();
+        if ui
+            .add(
+                crate::Slider::new(&mut time_scale, 0.01..=2.0)
+                    .logarithmic(true)
+                    .text("Time scale"),
+            )
+            .changed()
+        {
+            self.set_debug_time_scale(time_scale);
+        }
+    }
 }
 
 impl Context {
@@ -2584,6 +3698,18 @@ impl Context {
                 paint_stats.ui(ui);
             });
 
+        CollapsingHeader::new("⏱ Time")
+            .default_open(false)
+            .show(ui, |ui| {
+                self.debug_time_ui(ui);
+            });
+
+        CollapsingHeader::new("📊 Frame stats")
+            .default_open(false)
+            .show(ui, |ui| {
+                self.frame_stats_ui(ui);
+            });
+
         CollapsingHeader::new("🖼 Textures")
             .default_open(false)
             .show(ui, |ui| {
@@ -2614,6 +3740,37 @@ impl Context {
             });
     }
 
+    /// Statistics about the last frame of the current viewport, e.g. how many shapes and vertices were painted.
+    ///
+    /// This is the same as [`FullOutput::stats`].
+    pub fn frame_stats(&self) -> FrameStats {
+        self.write(|ctx| ctx.viewport().frame_stats.clone())
+    }
+
+    /// Show [`Self::frame_stats`].
+    ///
+    /// This turns on [`crate::Options::record_frame_stats`].
+    pub fn frame_stats_ui(&self, ui: &mut Ui) {
+        let was_recording =
+            self.options_mut(|options| std::mem::replace(&mut options.record_frame_stats, true));
+        if !was_recording {
+            self.request_repaint();
+        }
+        self.frame_stats().ui(ui);
+    }
+
+    /// Show [`Self::frame_stats`] in a window.
+    ///
+    /// Useful to keep an eye on the rendering cost of your app while developing it.
+    pub fn frame_stats_window(&self, open: &mut bool) {
+        crate::Window::new("📊 Frame stats")
+            .open(open)
+            .vscroll(true)
+            .show(self, |ui| {
+                self.frame_stats_ui(ui);
+            });
+    }
+
     /// Show stats about the allocated textures.
     pub fn texture_ui(&self, ui: &mut crate::Ui) {
         let tex_mngr = self.tex_manager();
@@ -2781,6 +3938,192 @@ impl Context {
     }
 }
 
+/// ## Saved layouts
+impl Context {
+    /// Save where all windows and panels are, under the given name.
+    ///
+    /// This snapshots the position, size, collapsed- and open-state of every [`Window`],
+    /// and the size of every panel, in all viewports.
+    /// Restore it later with [`Self::load_layout`], e.g. to let users switch between workspace presets.
+    ///
+    /// The layouts are stored in [`Memory`], and persisted with the `persistence` feature.
+    /// Saving with an existing name overwrites it.
+    ///
+    /// The open-state is only restored for windows shown with [`Window::open`].
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// if ctx.input(|i| i.key_pressed(egui::Key::F1)) {
+    ///     ctx.save_layout("compare-mode");
+    /// }
+    /// if ctx.input(|i| i.key_pressed(egui::Key::F2)) {
+    ///     ctx.load_layout("compare-mode");
+    /// }
+    /// # });
+    /// ```
+    pub fn save_layout(&self, name: impl Into<String>) {
+        self.save_layout_impl(name.into(), false);
+    }
+
+    /// Like [`Self::save_layout`], but also save the position, size,
+    /// maximized- and fullscreen-state of the native windows (viewports).
+    pub fn save_layout_with_viewports(&self, name: impl Into<String>) {
+        self.save_layout_impl(name.into(), true);
+    }
+
+    fn save_layout_impl(&self, name: String, include_viewports: bool) {
+        use crate::{
+            collapsing_header::CollapsingState,
+            containers::{panel::PanelState, resize},
+            memory::{SavedLayout, SavedWindow},
+            viewport::PersistedViewport,
+        };
+
+        let (windows, panel_ids) =
+            self.memory(|mem| (mem.windows_of_all_viewports(), mem.panel_ids.clone()));
+
+        let mut layout = SavedLayout::default();
+        for (viewport_id, windows) in windows {
+            let windows = windows
+                .into_iter()
+                .map(|(layer_id, state, open)| SavedWindow {
+                    layer_id,
+                    state,
+                    open,
+                    size: resize::State::load(self, layer_id.id.with("resize"))
+                        .map(|resize| resize.desired_size),
+                    expanded: CollapsingState::load(self, layer_id.id.with("collapsing"))
+                        .map(|collapsing| collapsing.is_open()),
+                })
+                .collect();
+            layout.windows.insert(viewport_id, windows);
+        }
+
+        for id in panel_ids {
+            if let Some(state) = PanelState::load(self, id) {
+                layout.panels.insert(id, state);
+            }
+        }
+
+        if include_viewports {
+            for (viewport_id, info) in self.input(|i| i.raw.viewports.clone()) {
+                let mut persisted = PersistedViewport::default();
+                persisted.update(&info);
+                layout.viewports.insert(viewport_id, persisted);
+            }
+        }
+
+        self.memory_mut(|mem| mem.saved_layouts.insert(name, layout));
+    }
+
+    /// Restore a layout saved with [`Self::save_layout`].
+    ///
+    /// Windows and panels that aren't part of the layout are left as they are.
+    ///
+    /// Returns `false` if there is no layout with that name.
+    pub fn load_layout(&self, name: &str) -> bool {
+        use crate::{collapsing_header::CollapsingState, containers::resize};
+
+        let Some(layout) = self.memory(|mem| mem.saved_layouts.get(name).cloned()) else {
+            return false;
+        };
+
+        for window in layout.windows.values().flatten() {
+            let id = window.layer_id.id;
+            if let Some(size) = window.size {
+                let resize_id = id.with("resize");
+                if let Some(mut state) = resize::State::load(self, resize_id) {
+                    state.requested_size = Some(size);
+                    state.store(self, resize_id);
+                }
+            }
+            if let Some(expanded) = window.expanded {
+                let mut collapsing =
+                    CollapsingState::load_with_default_open(self, id.with("collapsing"), expanded);
+                collapsing.set_open(expanded);
+                collapsing.store(self);
+            }
+        }
+
+        for (id, state) in &layout.panels {
+            state.store(self, *id);
+        }
+
+        for (viewport_id, persisted) in &layout.viewports {
+            let maximized = persisted.maximized == Some(true);
+            let fullscreen = persisted.fullscreen == Some(true);
+            self.send_viewport_cmd_to(*viewport_id, ViewportCommand::Fullscreen(fullscreen));
+            self.send_viewport_cmd_to(*viewport_id, ViewportCommand::Maximized(maximized));
+            if !maximized && !fullscreen {
+                if let Some(position) = persisted.position {
+                    self.send_viewport_cmd_to(
+                        *viewport_id,
+                        ViewportCommand::OuterPosition(position),
+                    );
+                }
+                if let Some(size) = persisted.inner_size {
+                    self.send_viewport_cmd_to(*viewport_id, ViewportCommand::InnerSize(size));
+                }
+            }
+        }
+
+        self.memory_mut(|mem| {
+            mem.restored_window_open.clear();
+            for (viewport_id, windows) in &layout.windows {
+                for window in windows {
+                    mem.areas_of_mut(*viewport_id)
+                        .restore_window(window.layer_id, window.state);
+                    mem.restored_window_open
+                        .insert(window.layer_id.id, window.open);
+                }
+            }
+        });
+
+        self.request_repaint();
+        true
+    }
+
+    /// The names of all saved layouts, sorted.
+    ///
+    /// See [`Self::save_layout`].
+    pub fn saved_layout_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.memory(|mem| mem.saved_layouts.keys().cloned().collect());
+        names.sort();
+        names
+    }
+
+    /// Forget a layout saved with [`Self::save_layout`].
+    pub fn remove_layout(&self, name: &str) {
+        self.memory_mut(|mem| mem.saved_layouts.remove(name));
+    }
+}
+
+/// ## Hints
+impl Context {
+    /// Has the user closed the hint with this id, see [`Ui::hint_once`]?
+    pub fn is_hint_dismissed(&self, id: impl Into<Id>) -> bool {
+        let id = id.into();
+        self.memory(|mem| mem.dismissed_hints.contains(&id))
+    }
+
+    /// Stop showing the hint with this id, as if the user closed it.
+    pub fn dismiss_hint(&self, id: impl Into<Id>) {
+        let id = id.into();
+        self.memory_mut(|mem| mem.dismissed_hints.insert(id));
+    }
+
+    /// Show the hint with this id again.
+    pub fn reset_hint(&self, id: impl Into<Id>) {
+        let id = id.into();
+        self.memory_mut(|mem| mem.dismissed_hints.remove(&id));
+    }
+
+    /// Show all hints again, e.g. from a "Reset hints" menu item.
+    pub fn reset_all_hints(&self) {
+        self.memory_mut(|mem| mem.dismissed_hints.clear());
+    }
+}
+
 /// ## Accessibility
 impl Context {
     /// Call the provided function with the given ID pushed on the stack of
@@ -3105,6 +4448,88 @@ impl Context {
         self.read(|ctx| ctx.parent_viewport_id())
     }
 
+    /// Is the viewport still open?
+    pub(crate) fn viewport_exists(&self, viewport_id: ViewportId) -> bool {
+        self.read(|ctx| viewport_id == ViewportId::ROOT || ctx.viewports.contains_key(&viewport_id))
+    }
+
+    /// The ids of the current viewport and its parent.
+    ///
+    /// Use this in the callback of [`Self::show_viewport_deferred`] or [`Self::show_viewport_immediate`]
+    /// to know which viewport you are drawing.
+    ///
+    /// Don't use this outside of `Self::run`, or after `Self::end_frame`.
+    pub fn viewport_ids(&self) -> ViewportIdPair {
+        self.read(|ctx| {
+            ViewportIdPair::from_self_and_parent(ctx.viewport_id(), ctx.parent_viewport_id())
+        })
+    }
+
+    /// What the backend reported about the given viewport at the start of this frame,
+    /// e.g. the actual size and position of its window.
+    ///
+    /// Returns `None` if the viewport is unknown to the backend, e.g. because it hasn't been created yet.
+    ///
+    /// [`ViewportBuilder`] and [`ViewportCommand`] only _request_ a size or position;
+    /// use this to read back what the OS actually gave you.
+    pub fn viewport_info(&self, viewport_id: ViewportId) -> Option<ViewportInfo> {
+        self.input(|i| i.raw.viewports.get(&viewport_id).cloned())
+    }
+
+    /// What the given viewport is snapped to, if it uses [`ViewportBuilder::with_snap_distance`].
+    ///
+    /// The snapping is only updated when the viewport itself moves,
+    /// so this also tells you where to move it when the viewport it is snapped to moves.
+    /// Use this to move tool palettes along with the viewport they are snapped to:
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let palette_id = egui::ViewportId::from_hash_of("palette");
+    /// for snap in ctx.viewport_snapped(palette_id) {
+    ///     if let Some(to) = ctx.viewport_outer_rect(snap.to) {
+    ///         let pos = to.min + snap.offset;
+    ///         if ctx.viewport_outer_rect(palette_id).map(|r| r.min) != Some(pos) {
+    ///             ctx.send_viewport_cmd_to(palette_id, egui::ViewportCommand::OuterPosition(pos));
+    ///         }
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub fn viewport_snapped(&self, viewport_id: ViewportId) -> Vec<ViewportSnap> {
+        self.read(|ctx| {
+            ctx.viewports
+                .get(&viewport_id)
+                .map(|viewport| viewport.snapped.clone())
+                .unwrap_or_default()
+        })
+    }
+
+    /// The inner rectangle (content area) of the window of the given viewport,
+    /// in monitor space and ui points, as reported by the backend.
+    ///
+    /// `None` if unknown, e.g. when the window is minimized.
+    /// See also [`Self::viewport_info`].
+    pub fn viewport_inner_rect(&self, viewport_id: ViewportId) -> Option<Rect> {
+        self.input(|i| i.raw.viewports.get(&viewport_id)?.inner_rect)
+    }
+
+    /// The outer rectangle (including decorations) of the window of the given viewport,
+    /// in monitor space and ui points, as reported by the backend.
+    ///
+    /// `None` if unknown, e.g. when the window is minimized.
+    /// See also [`Self::viewport_info`].
+    pub fn viewport_outer_rect(&self, viewport_id: ViewportId) -> Option<Rect> {
+        self.input(|i| i.raw.viewports.get(&viewport_id)?.outer_rect)
+    }
+
+    /// The position of the top left corner of the window of the given viewport (including decorations),
+    /// in monitor space and ui points, as reported by the backend.
+    ///
+    /// This is what [`ViewportBuilder::with_position`] and [`ViewportCommand::OuterPosition`] set.
+    pub fn viewport_outer_position(&self, viewport_id: ViewportId) -> Option<Pos2> {
+        self.viewport_outer_rect(viewport_id).map(|rect| rect.min)
+    }
+
     /// For integrations: Set this to render a sync viewport.
     ///
     /// This will only set the callback for the current thread,
@@ -3156,6 +4581,17 @@ impl Context {
     pub fn send_viewport_cmd_to(&self, id: ViewportId, command: ViewportCommand) {
         self.request_repaint_of(id);
 
+        if let ViewportCommand::ZoomFactor(zoom_factor) = command {
+            // Handled by egui, not the integration:
+            self.write(|ctx| {
+                let viewport = ctx.viewport_for(id);
+                if viewport.zoom_factor != Some(zoom_factor) {
+                    viewport.new_zoom_factor = Some(zoom_factor);
+                }
+            });
+            return;
+        }
+
         if command.requires_parent_repaint() {
             self.request_repaint_of(self.parent_viewport_id());
         }
@@ -3163,6 +4599,153 @@ impl Context {
         self.write(|ctx| ctx.viewport_for(id).commands.push(command));
     }
 
+    /// The rectangles of the enabled, interactive widgets of the current viewport,
+    /// as of the previous frame.
+    ///
+    /// Use these to make a transparent overlay viewport click-through everywhere except on its widgets,
+    /// by toggling [`ViewportCommand::MousePassthrough`] depending on where the cursor is.
+    ///
+    /// A pass-through window gets no pointer events, so the cursor position has to come from elsewhere,
+    /// e.g. from a global cursor query of the platform:
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let global_cursor_pos: Option<egui::Pos2> = None;
+    /// // `global_cursor_pos` is in ui points, relative to the viewport.
+    /// let over_widget = global_cursor_pos.map_or(false, |pos| {
+    ///     ctx.hit_test_regions().iter().any(|rect| rect.contains(pos))
+    /// });
+    /// let was_over_widget = ctx.data_mut(|d| {
+    ///     let previous = d.get_temp_mut_or_default::<bool>(egui::Id::new("over_widget"));
+    ///     std::mem::replace(previous, over_widget)
+    /// });
+    /// if over_widget != was_over_widget {
+    ///     ctx.send_viewport_cmd(egui::ViewportCommand::MousePassthrough(!over_widget));
+    /// }
+    /// # });
+    /// ```
+    pub fn hit_test_regions(&self) -> Vec<Rect> {
+        self.write(|ctx| {
+            ctx.viewport()
+                .widgets_prev_frame
+                .layers()
+                .flat_map(|(_, widgets)| widgets)
+                .filter(|widget| widget.enabled && widget.sense.interactive())
+                .map(|widget| widget.interact_rect)
+                .filter(|rect| rect.is_positive())
+                .collect()
+        })
+    }
+
+    /// Send a command to the given viewport, and find out whether it had the desired effect.
+    ///
+    /// Commands like [`ViewportCommand::InnerSize`] or [`ViewportCommand::Fullscreen`]
+    /// can be refused or altered by the OS.
+    /// The returned promise is fulfilled at the end of the next frame of the viewport,
+    /// with the state of the viewport after the integration applied the command.
+    ///
+    /// The promise is never fulfilled if the viewport is closed first.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut resize: Option<egui::Promise<egui::ViewportCommandAck>> = None;
+    /// use egui::{ViewportCommand, ViewportCommandStatus, ViewportId};
+    ///
+    /// if ui.button("Make it big").clicked() {
+    ///     let command = ViewportCommand::InnerSize(egui::vec2(4000.0, 3000.0));
+    ///     resize = Some(ui.ctx().viewport_command_with_ack(ViewportId::ROOT, command));
+    /// }
+    /// if let Some(ack) = resize.as_ref().and_then(|promise| promise.ready()) {
+    ///     if ack.status == ViewportCommandStatus::Rejected {
+    ///         ui.label(format!("Got {:?} instead", ack.info.inner_rect));
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub fn viewport_command_with_ack(
+        &self,
+        id: ViewportId,
+        command: ViewportCommand,
+    ) -> crate::Promise<ViewportCommandAck> {
+        crate::command_ack::send(self, id, command)
+    }
+
+    /// Take a screenshot of the given viewport.
+    ///
+    /// The screenshot is taken when the viewport is next painted,
+    /// and the promise is fulfilled during the frame after that.
+    /// This sends a [`ViewportCommand::Screenshot`] to the viewport,
+    /// unless there is already a screenshot pending for it.
+    ///
+    /// The promise is never fulfilled if the backend doesn't support screenshots,
+    /// or if the viewport is closed first.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut thumbnail: Option<egui::Promise<std::sync::Arc<egui::ColorImage>>> = None;
+    /// if ui.button("Capture").clicked() {
+    ///     thumbnail = Some(ui.ctx().viewport_screenshot(egui::ViewportId::ROOT));
+    /// }
+    /// if let Some(image) = thumbnail.as_ref().and_then(|promise| promise.ready()) {
+    ///     ui.label(format!("Captured {}x{} pixels", image.width(), image.height()));
+    /// }
+    /// # });
+    /// ```
+    pub fn viewport_screenshot(&self, id: ViewportId) -> crate::Promise<Arc<ColorImage>> {
+        crate::screenshot::request(self, id)
+    }
+
+    /// Call the given handler when the user tries to close the given viewport,
+    /// e.g. by clicking the close button of its window.
+    ///
+    /// The handler is called at the start of the frame of that viewport,
+    /// and returns `true` to let the viewport close, or `false` to cancel the close.
+    /// When cancelled, a [`ViewportCommand::CancelClose`] is sent,
+    /// and [`crate::ViewportInfo::close_requested`] will be `false` for that frame.
+    ///
+    /// The handler is also called for [`ViewportCommand::Close`],
+    /// so after asking the user, it needs to return `true` to actually close.
+    ///
+    /// Registering a handler for a viewport again replaces the old one.
+    /// The handler is removed when the viewport is.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// use std::sync::{atomic::{AtomicBool, Ordering}, Arc};
+    ///
+    /// let has_unsaved_changes = Arc::new(AtomicBool::new(true));
+    /// let show_save_dialog = egui::Id::new("show_save_dialog");
+    ///
+    /// ctx.on_viewport_close(egui::ViewportId::ROOT, {
+    ///     let has_unsaved_changes = has_unsaved_changes.clone();
+    ///     move |ctx| {
+    ///         if has_unsaved_changes.load(Ordering::Relaxed) {
+    ///             ctx.data_mut(|d| d.insert_temp(show_save_dialog, true));
+    ///             false
+    ///         } else {
+    ///             true
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    pub fn on_viewport_close(
+        &self,
+        viewport_id: ViewportId,
+        handler: impl Fn(&Self) -> bool + Send + Sync + 'static,
+    ) {
+        self.write(|ctx| {
+            ctx.viewport_close_handlers
+                .insert(viewport_id, Arc::new(handler));
+        });
+    }
+
+    /// Remove the handler registered with [`Self::on_viewport_close`], if any.
+    pub fn remove_viewport_close_handler(&self, viewport_id: ViewportId) {
+        self.write(|ctx| {
+            ctx.viewport_close_handlers.remove(&viewport_id);
+        });
+    }
+
     /// Show a deferred viewport, creating a new native window, if possible.
     ///
     /// The given id must be unique for each viewport.
@@ -3180,6 +4763,13 @@ impl Context {
     /// You will need to wrap your viewport state in an `Arc<RwLock<T>>` or `Arc<Mutex<T>>`.
     /// When this is called again with the same id in `ViewportBuilder` the render function for that viewport will be updated.
     ///
+    /// ### Threading
+    /// The callback is stored by egui and called later by the integration,
+    /// outside of the call to this function. That is why it must be `Send + Sync + 'static`.
+    /// `eframe` calls it on the main thread, but other integrations are free to call it from any thread.
+    /// The integration passes it the [`ViewportIdPair`] of the viewport it is drawing, see [`DeferredViewportUiCallback`].
+    /// Inside the callback, [`Self::viewport_id`] and [`Self::viewport_ids`] tell you which viewport is being drawn.
+    ///
     /// You can also use [`Self::show_viewport_immediate`], which uses a simpler `FnOnce`
     /// with no need for `Send` or `Sync`. The downside is that it will require
     /// the parent viewport (the caller) to repaint anytime the child is repainted,
@@ -3207,11 +4797,14 @@ impl Context {
                 ctx.viewport_parents
                     .insert(new_viewport_id, ctx.viewport_id());
 
+                let viewport_builder =
+                    ctx.restore_viewport_builder(new_viewport_id, viewport_builder);
                 let viewport = ctx.viewports.entry(new_viewport_id).or_default();
                 viewport.class = ViewportClass::Deferred;
                 viewport.builder = viewport_builder;
                 viewport.used = true;
-                viewport.viewport_ui_cb = Some(Arc::new(move |ctx| {
+                viewport.viewport_ui_cb = Some(Arc::new(move |ctx, ids| {
+                    debug_assert_eq!(ids.this, ctx.viewport_id(), "Wrong viewport");
                     (viewport_ui_cb)(ctx, ViewportClass::Deferred);
                 }));
             });
@@ -3232,10 +4825,14 @@ impl Context {
     /// You can check if the user wants to close the viewport by checking the
     /// [`crate::ViewportInfo::close_requested`] flags found in [`crate::InputState::viewport`].
     ///
-    /// The given ui function will be called immediately.
+    /// ### Threading
+    /// The given ui function will be called immediately, on the calling thread, before this function returns.
     /// This may only be called on the main thread.
     /// This call will pause the current viewport and render the child viewport in its own window.
-    /// This means that the child viewport will not be repainted when the parent viewport is repainted, and vice versa.
+    /// This means that the child viewport will be repainted whenever the parent viewport is repainted, and vice versa.
+    ///
+    /// The integration renders the child with the callback set by [`Self::set_immediate_viewport_renderer`],
+    /// which gets the [`ViewportIdPair`] of the child in [`ImmediateViewport::ids`].
     ///
     /// If [`Context::embed_viewports`] is `true` (e.g. if the current egui
     /// backend does not support multiple viewports), the given callback
@@ -3263,18 +4860,22 @@ impl Context {
                 return viewport_ui_cb(self, ViewportClass::Embedded);
             };
 
-            let ids = self.write(|ctx| {
+            let (ids, builder) = self.write(|ctx| {
                 let parent_viewport_id = ctx.viewport_id();
 
                 ctx.viewport_parents
                     .insert(new_viewport_id, parent_viewport_id);
 
+                let builder = ctx.restore_viewport_builder(new_viewport_id, builder);
                 let viewport = ctx.viewports.entry(new_viewport_id).or_default();
                 viewport.builder = builder.clone();
                 viewport.used = true;
                 viewport.viewport_ui_cb = None; // it is immediate
 
-                ViewportIdPair::from_self_and_parent(new_viewport_id, parent_viewport_id)
+                (
+                    ViewportIdPair::from_self_and_parent(new_viewport_id, parent_viewport_id),
+                    builder,
+                )
             });
 
             let mut out = None;
@@ -3378,6 +4979,20 @@ impl Context {
     }
 }
 
+/// Total number of vertices and indices in these primitives.
+fn count_vertices(clipped_primitives: &[ClippedPrimitive]) -> (usize, usize) {
+    clipped_primitives
+        .iter()
+        .fold((0, 0), |(vertices, indices), clipped| {
+            match &clipped.primitive {
+                epaint::Primitive::Mesh(mesh) => {
+                    (vertices + mesh.vertices.len(), indices + mesh.indices.len())
+                }
+                epaint::Primitive::Callback(_) => (vertices, indices),
+            }
+        })
+}
+
 #[test]
 fn context_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
@@ -0,0 +1,88 @@
+//! Binding controls on external controllers (e.g. MIDI knobs and faders) to value widgets,
+//! see [`Context::learn_control`].
+
+use std::sync::Arc;
+
+use crate::{Context, ControlId, Event, Id};
+
+/// Which control moves which parameter.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct Bindings(Vec<(ControlId, Id)>);
+
+/// The parameter waiting for a control to be moved, see [`Context::learn_control`].
+#[derive(Clone, Copy, Debug)]
+struct Learning(Id);
+
+fn bindings_id() -> Id {
+    Id::new("egui_control_bindings")
+}
+
+/// Binds the control that moved this frame to the widget that is learning.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_end_frame("control_learn", Arc::new(end_frame));
+}
+
+/// Bind the first control that moved this frame to the parameter being learned, if any.
+///
+/// Done at the end of the frame, so the widget doesn't jump to the position of the control
+/// until the control is moved again.
+fn end_frame(ctx: &Context) {
+    let Some(Learning(param)) = ctx.data(|d| d.get_temp::<Learning>(Id::NULL)) else {
+        return;
+    };
+    let moved = ctx.input(|i| {
+        i.events.iter().find_map(|event| match event {
+            Event::Controller { control, .. } => Some(*control),
+            _ => None,
+        })
+    });
+    if let Some(control) = moved {
+        ctx.data_mut(|d| {
+            d.remove::<Learning>(Id::NULL);
+            let bindings = d.get_persisted_mut_or_default::<Bindings>(bindings_id());
+            bindings.0.retain(|&(c, p)| c != control && p != param);
+            bindings.0.push((control, param));
+        });
+    }
+}
+
+pub(crate) fn learn(ctx: &Context, param: Id) {
+    ctx.data_mut(|d| d.insert_temp(Id::NULL, Learning(param)));
+}
+
+pub(crate) fn cancel_learning(ctx: &Context) {
+    ctx.data_mut(|d| d.remove::<Learning>(Id::NULL));
+}
+
+pub(crate) fn learning(ctx: &Context) -> Option<Id> {
+    ctx.data(|d| d.get_temp::<Learning>(Id::NULL))
+        .map(|Learning(param)| param)
+}
+
+pub(crate) fn unbind(ctx: &Context, param: Id) {
+    ctx.data_mut(|d| {
+        d.get_persisted_mut_or_default::<Bindings>(bindings_id())
+            .0
+            .retain(|&(_, p)| p != param);
+    });
+}
+
+pub(crate) fn bindings(ctx: &Context) -> Vec<(ControlId, Id)> {
+    ctx.data_mut(|d| d.get_persisted::<Bindings>(bindings_id()))
+        .unwrap_or_default()
+        .0
+}
+
+pub(crate) fn value(ctx: &Context, param: Id) -> Option<f32> {
+    let control = bindings(ctx)
+        .into_iter()
+        .find(|&(_, p)| p == param)
+        .map(|(control, _)| control)?;
+    ctx.input(|i| {
+        i.events.iter().rev().find_map(|event| match event {
+            Event::Controller { control: c, value } if *c == control => Some(value.clamp(0.0, 1.0)),
+            _ => None,
+        })
+    })
+}
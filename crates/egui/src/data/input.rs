@@ -1,6 +1,6 @@
 //! The input needed by egui.
 
-use epaint::ColorImage;
+use epaint::{Color32, ColorImage, Margin};
 
 use crate::{emath::*, Key, ViewportId, ViewportIdMap};
 
@@ -24,6 +24,22 @@ pub struct RawInput {
     /// Information about all egui viewports.
     pub viewports: ViewportIdMap<ViewportInfo>,
 
+    /// The monitors (screens) of the computer, if known.
+    ///
+    /// Empty if the integration doesn't support this, e.g. on web.
+    pub monitors: Vec<MonitorInfo>,
+
+    /// Interaction timings of the operating system (double-click delay etc), if known.
+    ///
+    /// Used to seed [`crate::InputOptions`] and the [`crate::Style`],
+    /// unless [`crate::InputOptions::follow_system_timings`] is turned off.
+    pub system_timings: Option<SystemTimings>,
+
+    /// Accessibility and appearance preferences of the user, set in the operating system (or browser).
+    ///
+    /// These are honored by egui unless turned off with [`crate::Options::follow_system_preferences`].
+    pub system_preferences: Option<SystemPreferences>,
+
     /// Position and size of the area that egui should use, in points.
     /// Usually you would set this to
     ///
@@ -34,6 +50,15 @@ pub struct RawInput {
     /// `None` will be treated as "same as last frame", with the default being a very big area.
     pub screen_rect: Option<Rect>,
 
+    /// The parts of [`Self::screen_rect`] that may be covered by things outside of egui's control,
+    /// e.g. a display notch or cutout, rounded screen corners, or the status and navigation bars
+    /// of a mobile OS. In points, measured inwards from each edge of [`Self::screen_rect`].
+    ///
+    /// Panels can keep their contents clear of these with e.g. [`crate::CentralPanel::respect_safe_area`].
+    ///
+    /// `None` will be treated as "same as last frame", with the default being no insets.
+    pub safe_area_insets: Option<Margin>,
+
     /// Maximum size of one side of the font texture.
     ///
     /// Ask your graphics drivers about this. This corresponds to `GL_MAX_TEXTURE_SIZE`.
@@ -80,7 +105,11 @@ impl Default for RawInput {
         Self {
             viewport_id: ViewportId::ROOT,
             viewports: std::iter::once((ViewportId::ROOT, Default::default())).collect(),
+            monitors: vec![],
+            system_timings: None,
+            system_preferences: None,
             screen_rect: None,
+            safe_area_insets: None,
             max_texture_side: None,
             time: None,
             predicted_dt: 1.0 / 60.0,
@@ -108,7 +137,11 @@ impl RawInput {
         Self {
             viewport_id: self.viewport_id,
             viewports: self.viewports.clone(),
+            monitors: self.monitors.clone(),
+            system_timings: self.system_timings,
+            system_preferences: self.system_preferences,
             screen_rect: self.screen_rect.take(),
+            safe_area_insets: self.safe_area_insets.take(),
             max_texture_side: self.max_texture_side.take(),
             time: self.time.take(),
             predicted_dt: self.predicted_dt,
@@ -125,7 +158,11 @@ impl RawInput {
         let Self {
             viewport_id: viewport_ids,
             viewports,
+            monitors,
+            system_timings,
+            system_preferences,
             screen_rect,
+            safe_area_insets,
             max_texture_side,
             time,
             predicted_dt,
@@ -138,7 +175,11 @@ impl RawInput {
 
         self.viewport_id = viewport_ids;
         self.viewports = viewports;
+        self.monitors = monitors;
+        self.system_timings = system_timings.or(self.system_timings);
+        self.system_preferences = system_preferences.or(self.system_preferences);
         self.screen_rect = screen_rect.or(self.screen_rect);
+        self.safe_area_insets = safe_area_insets.or(self.safe_area_insets);
         self.max_texture_side = max_texture_side.or(self.max_texture_side);
         self.time = time; // use latest time
         self.predicted_dt = predicted_dt; // use latest dt
@@ -151,6 +192,11 @@ impl RawInput {
 }
 
 /// An input event from the backend into egui, about a specific [viewport](crate::viewport).
+///
+/// The events of this frame are in [`ViewportInfo::events`],
+/// e.g. `ctx.input(|i| i.viewport().events.clone())`.
+/// Use them to react to changes, and the other fields of [`ViewportInfo`] for the current state,
+/// e.g. to pause expensive rendering while [`ViewportInfo::is_hidden`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ViewportEvent {
@@ -165,6 +211,72 @@ pub enum ViewportEvent {
     ///
     /// This even will wake up both the child and parent viewport.
     Close,
+
+    /// The window gained (`true`) or lost (`false`) focus.
+    ///
+    /// See [`ViewportInfo::focused`].
+    Focused(bool),
+
+    /// The window was moved.
+    ///
+    /// See [`ViewportInfo::outer_rect`].
+    Moved,
+
+    /// The window was minimized.
+    ///
+    /// See [`ViewportInfo::minimized`].
+    Minimized,
+
+    /// The window was restored after having been minimized.
+    Restored,
+
+    /// The window became fully hidden by other windows (`true`), or visible again (`false`).
+    ///
+    /// Not supported on all platforms. See [`ViewportInfo::occluded`].
+    Occluded(bool),
+
+    /// The OS scale factor changed, e.g. because the window moved to another monitor.
+    ///
+    /// See [`ViewportInfo::native_pixels_per_point`].
+    ScaleFactorChanged,
+
+    /// The device was rotated.
+    ///
+    /// See [`ViewportInfo::orientation`].
+    OrientationChanged,
+}
+
+/// Which way a device (e.g. a phone or tablet) is being held.
+///
+/// See [`ViewportInfo::orientation`] and [`crate::InputState::orientation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DeviceOrientation {
+    /// Taller than wide, held the normal way.
+    Portrait,
+
+    /// Taller than wide, held upside down.
+    PortraitFlipped,
+
+    /// Wider than tall, rotated a quarter turn counter-clockwise from [`Self::Portrait`].
+    Landscape,
+
+    /// Wider than tall, rotated a quarter turn clockwise from [`Self::Portrait`].
+    LandscapeFlipped,
+}
+
+impl DeviceOrientation {
+    /// Is this [`Self::Portrait`] or [`Self::PortraitFlipped`]?
+    #[inline]
+    pub fn is_portrait(self) -> bool {
+        matches!(self, Self::Portrait | Self::PortraitFlipped)
+    }
+
+    /// Is this [`Self::Landscape`] or [`Self::LandscapeFlipped`]?
+    #[inline]
+    pub fn is_landscape(self) -> bool {
+        !self.is_portrait()
+    }
 }
 
 /// Information about the current viewport, given as input each frame.
@@ -218,6 +330,23 @@ pub struct ViewportInfo {
     ///
     /// This should be the same as [`RawInput::focused`].
     pub focused: Option<bool>,
+
+    /// Is the window fully hidden by other windows?
+    ///
+    /// Not supported on all platforms.
+    pub occluded: Option<bool>,
+
+    /// Which way the device is being held, if known.
+    ///
+    /// Usually only known on phones and tablets.
+    pub orientation: Option<DeviceOrientation>,
+
+    /// The hinge or fold of a foldable or dual-screen device, if the viewport spans across it.
+    ///
+    /// In the same coordinates as [`RawInput::screen_rect`], i.e. ui points relative to the viewport.
+    /// Content placed here may be hidden or distorted; see [`crate::Ui::avoid_hinge`].
+    /// A fold with no physical gap is a zero-width (or zero-height) rectangle.
+    pub hinge_rect: Option<Rect>,
 }
 
 impl ViewportInfo {
@@ -235,6 +364,13 @@ impl ViewportInfo {
             .any(|&event| event == ViewportEvent::Close)
     }
 
+    /// Is the window minimized or fully hidden by other windows?
+    ///
+    /// If so, there is no point in doing expensive rendering for it.
+    pub fn is_hidden(&self) -> bool {
+        self.minimized == Some(true) || self.occluded == Some(true)
+    }
+
     pub fn ui(&self, ui: &mut crate::Ui) {
         let Self {
             parent,
@@ -248,6 +384,9 @@ impl ViewportInfo {
             maximized,
             fullscreen,
             focused,
+            occluded,
+            orientation,
+            hinge_rect,
         } = self;
 
         crate::Grid::new("viewport_info").show(ui, |ui| {
@@ -295,6 +434,18 @@ impl ViewportInfo {
             ui.label(opt_as_str(focused));
             ui.end_row();
 
+            ui.label("Occluded:");
+            ui.label(opt_as_str(occluded));
+            ui.end_row();
+
+            ui.label("Orientation:");
+            ui.label(opt_as_str(orientation));
+            ui.end_row();
+
+            ui.label("Hinge rect:");
+            ui.label(opt_rect_as_string(hinge_rect));
+            ui.end_row();
+
             fn opt_rect_as_string(v: &Option<Rect>) -> String {
                 v.as_ref().map_or(String::new(), |r| {
                     format!("Pos: {:?}, size: {:?}", r.min, r.size())
@@ -308,6 +459,128 @@ impl ViewportInfo {
     }
 }
 
+/// Information about a monitor (screen), given as input each frame.
+///
+/// See [`RawInput::monitors`] and [`crate::ViewportCommand::OuterPositionOnMonitor`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MonitorInfo {
+    /// Name of the monitor, if known.
+    pub name: Option<String>,
+
+    /// Position and size of the monitor, in monitor space and ui points scale,
+    /// i.e. the same space as [`ViewportInfo::outer_rect`].
+    pub rect: Rect,
+
+    /// The OS native pixels-per-point of this monitor.
+    pub native_pixels_per_point: f32,
+
+    /// Is this the primary monitor, if known?
+    pub is_primary: Option<bool>,
+}
+
+/// Interaction timings of the operating system, given as input by the integration.
+///
+/// All fields are optional, since not all platforms (or integrations) can provide them.
+///
+/// See [`RawInput::system_timings`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SystemTimings {
+    /// The longest time between two clicks for them to count as a double-click, in seconds.
+    pub double_click_delay: Option<f64>,
+
+    /// How long a key or button must be held before it starts repeating, in seconds.
+    pub key_repeat_delay: Option<f64>,
+
+    /// How many times per second a held key or button repeats.
+    pub key_repeat_rate: Option<f64>,
+
+    /// How long the text cursor stays visible (and then invisible) when blinking, in seconds.
+    ///
+    /// `Some(0.0)` means the cursor should not blink.
+    pub cursor_blink_interval: Option<f32>,
+
+    /// How long the pointer must rest on a widget before its tooltip is shown, in seconds.
+    pub tooltip_delay: Option<f32>,
+}
+
+/// Accessibility and appearance preferences of the user.
+///
+/// `None` means unknown (or, for overrides, "use the system setting").
+///
+/// See [`RawInput::system_preferences`] and [`crate::Options::system_preference_overrides`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SystemPreferences {
+    /// The user prefers less motion, so animations should be skipped.
+    ///
+    /// See [`crate::Context::reduce_motion`].
+    pub reduce_motion: Option<bool>,
+
+    /// Scale all text by this much, e.g. `1.25` for 25% larger text.
+    ///
+    /// This only affects text, unlike [`crate::Context::set_zoom_factor`].
+    pub text_scale: Option<f32>,
+
+    /// The accent color chosen by the user, used for selections and hyperlinks.
+    pub accent_color: Option<Color32>,
+
+    /// The user prefers high contrast, see [`crate::Visuals::increase_contrast`].
+    pub high_contrast: Option<bool>,
+
+    /// Should egui save power, e.g. because the device is running on battery?
+    ///
+    /// Integrations can set this from the battery status or power saving mode of the device.
+    /// See [`crate::Options::performance_mode`] and [`crate::Context::power_hint`].
+    pub power_hint: Option<PowerHint>,
+}
+
+impl SystemPreferences {
+    /// Use the values of `self` where set, and of `fallback` otherwise.
+    #[must_use]
+    pub fn or(self, fallback: Self) -> Self {
+        Self {
+            reduce_motion: self.reduce_motion.or(fallback.reduce_motion),
+            text_scale: self.text_scale.or(fallback.text_scale),
+            accent_color: self.accent_color.or(fallback.accent_color),
+            high_contrast: self.high_contrast.or(fallback.high_contrast),
+            power_hint: self.power_hint.or(fallback.power_hint),
+        }
+    }
+}
+
+/// Whether egui should save power or go for the smoothest experience.
+///
+/// See [`SystemPreferences::power_hint`] and [`crate::Options::performance_mode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PowerHint {
+    /// Save power, e.g. on a laptop or phone running on battery.
+    ///
+    /// Animations run at a lower frame rate, windows and popups have no shadows,
+    /// and delayed repaints (see [`crate::Context::request_repaint_after`]) are not scheduled
+    /// sooner than [`Self::min_repaint_interval`].
+    LowPower,
+
+    /// Repaint as often as needed, and paint everything.
+    #[default]
+    HighPerformance,
+}
+
+impl PowerHint {
+    /// The shortest time between repaints requested by animations and with a delay.
+    ///
+    /// Repaints caused by input are never delayed.
+    pub fn min_repaint_interval(self) -> std::time::Duration {
+        match self {
+            Self::LowPower => std::time::Duration::from_secs_f32(1.0 / 30.0),
+            Self::HighPerformance => std::time::Duration::ZERO,
+        }
+    }
+}
+
 /// A file about to be dropped into egui.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -315,6 +588,12 @@ pub struct HoveredFile {
     /// Set by the `egui-winit` backend.
     pub path: Option<std::path::PathBuf>,
 
+    /// The viewport the file is hovering.
+    ///
+    /// `None` means the viewport of the [`RawInput`] this is part of.
+    /// Files hovering other viewports are ignored.
+    pub viewport_id: Option<ViewportId>,
+
     /// With the `eframe` web backend, this is set to the mime-type of the file (if available).
     pub mime: String,
 }
@@ -326,6 +605,13 @@ pub struct DroppedFile {
     /// Set by the `egui-winit` backend.
     pub path: Option<std::path::PathBuf>,
 
+    /// The viewport the file was dropped on.
+    ///
+    /// `None` means the viewport of the [`RawInput`] this is part of.
+    /// Files dropped on another viewport are held back by egui
+    /// and handed to that viewport the next time it runs.
+    pub viewport_id: Option<ViewportId>,
+
     /// Name of the file. Set by the `eframe` web backend.
     pub name: String,
 
@@ -359,6 +645,21 @@ pub enum Event {
     /// When the user presses enter/return, do not send a [`Text`](Event::Text) (just [`Key::Enter`]).
     Text(String),
 
+    /// Replace a range of the text in the focused [`crate::TextEdit`],
+    /// e.g. for speech dictation or remote input.
+    ///
+    /// Unlike a series of [`Key`](Event::Key) and [`Text`](Event::Text) events,
+    /// this is applied at once, and can be undone in one step.
+    TextRangeReplace {
+        /// The range of characters (not bytes) to replace.
+        ///
+        /// Use an empty range to insert text. The range is clamped to the length of the text.
+        range: std::ops::Range<usize>,
+
+        /// What to put there instead.
+        text: String,
+    },
+
     /// A key was pressed or released.
     Key {
         /// The logical key, heeding the users keymap.
@@ -505,6 +806,31 @@ pub enum Event {
         viewport_id: crate::ViewportId,
         image: std::sync::Arc<ColorImage>,
     },
+
+    /// The `pixels_per_point` of a viewport changed since its last frame,
+    /// e.g. because its window was moved to a monitor with a different scale factor,
+    /// or because of [`crate::Context::set_zoom_factor`].
+    ///
+    /// This is generated by egui itself, so integrations don't need to send it.
+    /// Each `pixels_per_point` has its own fonts and text layout cache,
+    /// so text laid out for other viewports is not affected.
+    ScaleFactorChanged {
+        viewport_id: crate::ViewportId,
+
+        /// The new [`crate::Context::pixels_per_point`] of the viewport.
+        pixels_per_point: f32,
+    },
+
+    /// A control on an external controller was moved, e.g. a knob or fader on a MIDI controller.
+    ///
+    /// Sliders and drag values bound to the control follow it,
+    /// see [`crate::Context::learn_control`].
+    Controller {
+        control: ControlId,
+
+        /// The new position of the control, normalized to `0.0..=1.0`.
+        value: f32,
+    },
 }
 
 /// Mouse button (or similar for touch input)
@@ -989,7 +1315,11 @@ impl RawInput {
         let Self {
             viewport_id,
             viewports,
+            monitors,
+            system_timings,
+            system_preferences,
             screen_rect,
+            safe_area_insets,
             max_texture_side,
             time,
             predicted_dt,
@@ -1009,7 +1339,13 @@ impl RawInput {
                 });
             });
         }
+        for (i, monitor) in monitors.iter().enumerate() {
+            ui.label(format!("Monitor {i}: {monitor:?}"));
+        }
+        ui.label(format!("system_timings: {system_timings:?}"));
+        ui.label(format!("system_preferences: {system_preferences:?}"));
         ui.label(format!("screen_rect: {screen_rect:?} points"));
+        ui.label(format!("safe_area_insets: {safe_area_insets:?} points"));
 
         ui.label(format!("max_texture_side: {max_texture_side:?}"));
         if let Some(time) = time {
@@ -1035,6 +1371,21 @@ impl RawInput {
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct TouchDeviceId(pub u64);
 
+/// Identifies a control on an external controller, e.g. a knob or fader, see [`Event::Controller`].
+///
+/// How controls are numbered is up to the integration,
+/// as long as the same control always gets the same id.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ControlId(pub u64);
+
+impl ControlId {
+    /// A MIDI control change (CC) message on the given channel (`0..16`) with the given controller number (`0..128`).
+    pub const fn midi_cc(channel: u8, controller: u8) -> Self {
+        Self(((channel as u64) << 8) | controller as u64)
+    }
+}
+
 /// Unique identification of a touch occurrence (finger or pen or …).
 /// A Touch ID is valid until the finger is lifted.
 /// A new ID is used for the next touch.
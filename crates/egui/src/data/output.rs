@@ -33,6 +33,11 @@ pub struct FullOutput {
     /// It is up to the integration to spawn a native window for each viewport,
     /// and to close any window that no longer has a viewport in this map.
     pub viewport_output: ViewportIdMap<ViewportOutput>,
+
+    /// Statistics about the frame, e.g. how many shapes were painted.
+    ///
+    /// See also [`crate::Context::frame_stats_window`].
+    pub stats: crate::FrameStats,
 }
 
 impl FullOutput {
@@ -44,12 +49,14 @@ impl FullOutput {
             shapes,
             pixels_per_point,
             viewport_output: viewports,
+            stats,
         } = newer;
 
         self.platform_output.append(platform_output);
         self.textures_delta.append(textures_delta);
         self.shapes = shapes; // Only paint the latest
         self.pixels_per_point = pixels_per_point; // Use latest
+        self.stats = stats; // Use latest
 
         for (id, new_viewport) in viewports {
             match self.viewport_output.entry(id) {
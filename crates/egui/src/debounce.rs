@@ -0,0 +1,270 @@
+//! Debouncing and throttling of values that change often, e.g. a search query or a slider value.
+//!
+//! See [`crate::Ui::debounce`], [`crate::Ui::throttle`] and [`crate::Response::changed_debounced`].
+
+use std::time::Duration;
+
+use crate::{Context, Id, Response};
+
+#[derive(Clone)]
+struct Debounced<T> {
+    /// The value we last returned (or started with).
+    emitted: T,
+
+    /// A new value, and when we first saw it.
+    pending: Option<(T, f64)>,
+}
+
+#[derive(Clone)]
+struct Throttled<T> {
+    /// The value we last returned (or started with).
+    emitted: T,
+
+    /// When we last returned a value.
+    emitted_time: f64,
+}
+
+fn repaint_in(ctx: &Context, secs: f64) {
+    ctx.request_repaint_after(Duration::from_secs_f64(secs.max(0.0)));
+}
+
+pub(crate) fn debounce<T>(ctx: &Context, id: Id, value: T, secs: f32) -> Option<T>
+where
+    T: 'static + Clone + PartialEq + Send + Sync,
+{
+    let now = ctx.input(|i| i.time);
+    let secs = secs as f64;
+
+    let mut state = ctx.data(|d| d.get_temp::<Debounced<T>>(id));
+    let result = match &mut state {
+        None => {
+            state = Some(Debounced {
+                emitted: value,
+                pending: None,
+            });
+            None
+        }
+        Some(state) if state.emitted == value => {
+            // Changed back before it settled:
+            state.pending = None;
+            None
+        }
+        Some(state) => {
+            let since = match &state.pending {
+                Some((pending, since)) if pending == &value => *since,
+                _ => now,
+            };
+            if since + secs <= now {
+                state.emitted = value.clone();
+                state.pending = None;
+                Some(value)
+            } else {
+                state.pending = Some((value, since));
+                repaint_in(ctx, since + secs - now);
+                None
+            }
+        }
+    };
+
+    if let Some(state) = state {
+        ctx.data_mut(|d| d.insert_temp(id, state));
+    }
+    result
+}
+
+pub(crate) fn throttle<T>(ctx: &Context, id: Id, value: T, secs: f32) -> Option<T>
+where
+    T: 'static + Clone + PartialEq + Send + Sync,
+{
+    let now = ctx.input(|i| i.time);
+    let secs = secs as f64;
+
+    let mut state = ctx.data(|d| d.get_temp::<Throttled<T>>(id));
+    let result = match &mut state {
+        None => {
+            state = Some(Throttled {
+                emitted: value,
+                emitted_time: f64::NEG_INFINITY,
+            });
+            None
+        }
+        Some(state) if state.emitted == value => None,
+        Some(state) => {
+            if state.emitted_time + secs <= now {
+                state.emitted = value.clone();
+                state.emitted_time = now;
+                Some(value)
+            } else {
+                // Make sure the latest value gets through, even if it stops changing:
+                repaint_in(ctx, state.emitted_time + secs - now);
+                None
+            }
+        }
+    };
+
+    if let Some(state) = state {
+        ctx.data_mut(|d| d.insert_temp(id, state));
+    }
+    result
+}
+
+pub(crate) fn changed_debounced(response: &Response, secs: f32) -> bool {
+    let ctx = &response.ctx;
+    let id = response.id.with("__changed_debounced");
+    let now = ctx.input(|i| i.time);
+    let secs = secs as f64;
+
+    if response.changed() {
+        ctx.data_mut(|d| d.insert_temp(id, now));
+        repaint_in(ctx, secs);
+        return false;
+    }
+
+    let Some(last_change) = ctx.data(|d| d.get_temp::<f64>(id)) else {
+        return false;
+    };
+    if last_change + secs <= now {
+        ctx.data_mut(|d| d.remove::<f64>(id));
+        true
+    } else {
+        repaint_in(ctx, last_change + secs - now);
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RawInput;
+
+    /// Runs one frame at the given time, and returns what `f` returned.
+    fn at<R>(ctx: &Context, time: f64, f: impl FnOnce(&Context) -> R) -> R {
+        let mut result = None;
+        let _ = ctx.run(
+            RawInput {
+                time: Some(time),
+                ..Default::default()
+            },
+            |ctx| result = Some(f(ctx)),
+        );
+        result.expect("the frame should run once")
+    }
+
+    fn debounce_at(ctx: &Context, time: f64, value: i32) -> Option<i32> {
+        at(ctx, time, |ctx| {
+            debounce(ctx, Id::new("debounce"), value, 1.0)
+        })
+    }
+
+    fn throttle_at(ctx: &Context, time: f64, value: i32) -> Option<i32> {
+        at(ctx, time, |ctx| {
+            throttle(ctx, Id::new("throttle"), value, 1.0)
+        })
+    }
+
+    #[test]
+    fn debounce_fires_after_the_delay() {
+        let ctx = Context::default();
+        assert_eq!(
+            debounce_at(&ctx, 0.0, 0),
+            None,
+            "the initial value isn't a change"
+        );
+        assert_eq!(debounce_at(&ctx, 1.0, 1), None);
+        assert_eq!(debounce_at(&ctx, 1.5, 1), None, "still settling");
+        assert_eq!(debounce_at(&ctx, 2.0, 1), Some(1));
+        assert_eq!(debounce_at(&ctx, 3.0, 1), None, "only fires once");
+    }
+
+    #[test]
+    fn debounce_resets_on_new_input() {
+        let ctx = Context::default();
+        debounce_at(&ctx, 0.0, 0);
+        assert_eq!(debounce_at(&ctx, 1.0, 1), None);
+        assert_eq!(debounce_at(&ctx, 1.8, 2), None);
+        assert_eq!(
+            debounce_at(&ctx, 2.5, 2),
+            None,
+            "the delay restarted at 1.8"
+        );
+        assert_eq!(debounce_at(&ctx, 2.8, 2), Some(2));
+    }
+
+    #[test]
+    fn debounce_forgets_a_change_that_is_undone() {
+        let ctx = Context::default();
+        debounce_at(&ctx, 0.0, 0);
+        debounce_at(&ctx, 1.0, 1);
+        assert_eq!(debounce_at(&ctx, 1.5, 0), None);
+        assert_eq!(debounce_at(&ctx, 3.0, 0), None);
+    }
+
+    #[test]
+    fn debounce_requests_a_repaint_for_the_trailing_value() {
+        let ctx = Context::default();
+        debounce_at(&ctx, 0.0, 0);
+        let delays = std::sync::Arc::new(crate::mutex::Mutex::new(vec![]));
+        ctx.set_request_repaint_callback({
+            let delays = delays.clone();
+            move |info| delays.lock().push(info.delay)
+        });
+        debounce_at(&ctx, 1.0, 1);
+        assert!(
+            delays.lock().contains(&Duration::from_secs(1)),
+            "got {:?}",
+            delays.lock()
+        );
+    }
+
+    #[test]
+    fn throttle_passes_at_most_one_value_per_interval() {
+        let ctx = Context::default();
+        assert_eq!(throttle_at(&ctx, 0.0, 0), None);
+        assert_eq!(
+            throttle_at(&ctx, 0.1, 1),
+            Some(1),
+            "the first change is let through"
+        );
+        assert_eq!(throttle_at(&ctx, 0.5, 2), None);
+        assert_eq!(
+            throttle_at(&ctx, 1.1, 2),
+            Some(2),
+            "the latest value once the interval is over"
+        );
+    }
+
+    #[test]
+    fn changed_debounced_fires_once_after_the_last_change() {
+        let ctx = Context::default();
+        let mut text = String::new();
+        let mut edit = |time: f64, typed: Option<&str>| {
+            let mut events = vec![];
+            if let Some(typed) = typed {
+                events.push(crate::Event::Text(typed.to_owned()));
+            }
+            let mut fired = false;
+            let _ = ctx.run(
+                RawInput {
+                    time: Some(time),
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    crate::CentralPanel::default().show(ctx, |ui| {
+                        let response = ui.text_edit_singleline(&mut text);
+                        response.request_focus();
+                        fired = changed_debounced(&response, 1.0);
+                    });
+                },
+            );
+            fired
+        };
+
+        assert!(!edit(0.0, None));
+        assert!(!edit(0.1, Some("a")));
+        assert!(!edit(0.6, Some("b")));
+        assert!(!edit(1.4, None), "the delay restarted at 0.6");
+        assert!(edit(1.7, None));
+        assert!(!edit(3.0, None), "only fires once");
+    }
+}
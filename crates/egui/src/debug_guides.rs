@@ -0,0 +1,294 @@
+//! Rulers, guides and measurements for building pixel-perfect UIs.
+//!
+//! * [`Context::debug_show_spacing_between`] measures the space between two rectangles.
+//! * [`crate::style::DebugOptions::show_rulers`] shows rulers along the edges of the screen.
+//!   Drag from them to add guide lines, and drag guide lines back onto them to remove them.
+//! * [`crate::style::DebugOptions::show_alignment_guides`] snaps dragged windows to the edges
+//!   of other windows and to the guide lines, showing where they align.
+
+use crate::*;
+
+/// The width of the rulers.
+#[cfg(debug_assertions)]
+const RULER_THICKNESS: f32 = 16.0;
+
+/// How close a dragged edge needs to be to another edge to snap to it.
+#[cfg(debug_assertions)]
+const SNAP_DISTANCE: f32 = 6.0;
+
+fn measure_color() -> Color32 {
+    Color32::from_rgb(255, 0, 200)
+}
+
+#[cfg(debug_assertions)]
+fn guide_color() -> Color32 {
+    Color32::from_rgb(0, 200, 255)
+}
+
+/// The guide lines dragged out of the rulers.
+#[cfg(debug_assertions)]
+#[derive(Clone, Debug, Default)]
+struct Guides {
+    /// The x coordinates of the vertical guides, and the y coordinates of the horizontal ones.
+    positions: [Vec<f32>; 2],
+}
+
+#[cfg(debug_assertions)]
+impl Guides {
+    fn id() -> Id {
+        Id::new("egui_debug_guides")
+    }
+
+    fn load(ctx: &Context) -> Self {
+        ctx.data(|d| d.get_temp(Self::id())).unwrap_or_default()
+    }
+
+    fn store(self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_temp(Self::id(), self));
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Paint the horizontal and vertical gaps between two rectangles, with their lengths.
+pub(crate) fn paint_spacing_between(painter: &Painter, a: Rect, b: Rect) {
+    let stroke = Stroke::new(1.0, measure_color());
+    painter.rect_stroke(a, 0.0, stroke);
+    painter.rect_stroke(b, 0.0, stroke);
+
+    for d in 0..2 {
+        let (first, second) = if a.center()[d] <= b.center()[d] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        if first.max[d] < second.min[d] {
+            // Measure across the part where they overlap, if any:
+            let other = 1 - d;
+            let overlap_min = first.min[other].max(second.min[other]);
+            let overlap_max = first.max[other].min(second.max[other]);
+            let across = if overlap_min <= overlap_max {
+                0.5 * (overlap_min + overlap_max)
+            } else {
+                0.5 * (first.center()[other] + second.center()[other])
+            };
+
+            let mut from = Pos2::ZERO;
+            from[d] = first.max[d];
+            from[other] = across;
+            let mut to = from;
+            to[d] = second.min[d];
+            paint_measurement(painter, from, to, stroke);
+        }
+    }
+}
+
+/// A line with end-caps, labeled with its length.
+fn paint_measurement(painter: &Painter, from: Pos2, to: Pos2, stroke: Stroke) {
+    let dir = (to - from).normalized();
+    let cap = 4.0 * dir.rot90();
+    painter.line_segment([from, to], stroke);
+    painter.line_segment([from - cap, from + cap], stroke);
+    painter.line_segment([to - cap, to + cap], stroke);
+
+    let anchor = if dir.x.abs() > dir.y.abs() {
+        Align2::CENTER_BOTTOM
+    } else {
+        Align2::LEFT_CENTER
+    };
+    painter.debug_text(
+        from.lerp(to, 0.5),
+        anchor,
+        stroke.color,
+        format!("{:.1}", from.distance(to)),
+    );
+}
+
+// ----------------------------------------------------------------------------
+
+/// Show the rulers and the guide lines, and handle dragging them.
+///
+/// Called at the end of each frame if [`crate::style::DebugOptions::show_rulers`] is set.
+#[cfg(debug_assertions)]
+pub(crate) fn rulers_ui(ctx: &Context) {
+    let screen_rect = ctx.screen_rect();
+    let layer_id = LayerId::new(Order::Foreground, Id::new("egui_debug_rulers"));
+    let painter = ctx.layer_painter(layer_id);
+    let pointer_pos = ctx.input(|i| i.pointer.interact_pos());
+    let visuals = ctx.style().visuals.clone();
+    let guide_stroke = Stroke::new(1.0, guide_color());
+
+    let mut guides = Guides::load(ctx);
+
+    for d in 0..2 {
+        let other = 1 - d;
+        let ruler_edge = screen_rect.min[d] + RULER_THICKNESS;
+        let guide_rect = |pos: f32| {
+            let mut rect = screen_rect;
+            rect.min[d] = pos;
+            rect.max[d] = pos;
+            rect
+        };
+        let cursor_icon = if d == 0 {
+            CursorIcon::ResizeColumn
+        } else {
+            CursorIcon::ResizeRow
+        };
+
+        // The existing guides:
+        let mut removed = None;
+        for (i, pos) in guides.positions[d].iter_mut().enumerate() {
+            let rect = guide_rect(*pos);
+            let response = ctx.create_widget(WidgetRect {
+                id: layer_id.id.with((d, i)),
+                layer_id,
+                rect,
+                interact_rect: rect.expand(2.0),
+                sense: Sense::drag(),
+                enabled: true,
+            });
+            if response.hovered() || response.dragged() {
+                ctx.set_cursor_icon(cursor_icon);
+            }
+            if response.dragged() {
+                *pos = ctx.round_to_pixel(*pos + response.drag_delta()[d]);
+            }
+            if response.drag_stopped() && *pos < ruler_edge {
+                removed = Some(i); // dragged back onto the ruler
+            }
+            painter.line_segment([rect.min, rect.max], guide_stroke);
+        }
+        if let Some(i) = removed {
+            guides.positions[d].remove(i);
+        }
+
+        // The ruler these guides are dragged out of, along the left or top edge:
+        let mut ruler_rect = screen_rect;
+        ruler_rect.max[d] = ruler_edge;
+        let response = ctx.create_widget(WidgetRect {
+            id: layer_id.id.with(("ruler", d)),
+            layer_id,
+            rect: ruler_rect,
+            interact_rect: ruler_rect,
+            sense: Sense::drag(),
+            enabled: true,
+        });
+        if let Some(pointer_pos) = pointer_pos {
+            let pos = ctx.round_to_pixel(pointer_pos[d]);
+            if response.dragged() {
+                ctx.set_cursor_icon(cursor_icon);
+                let rect = guide_rect(pos);
+                painter.line_segment([rect.min, rect.max], guide_stroke);
+            }
+            if response.drag_stopped() && ruler_edge <= pos {
+                guides.positions[d].push(pos);
+            }
+        }
+
+        painter.rect_filled(ruler_rect, 0.0, visuals.extreme_bg_color);
+        let tick_stroke = Stroke::new(1.0, visuals.weak_text_color());
+        let mut value = 0.0;
+        while screen_rect.min[other] + value <= screen_rect.max[other] {
+            let length = if value % 50.0 == 0.0 {
+                0.5 * RULER_THICKNESS
+            } else {
+                0.25 * RULER_THICKNESS
+            };
+            let mut from = ruler_rect.max;
+            from[other] = screen_rect.min[other] + value;
+            let mut to = from;
+            to[d] -= length;
+            painter.line_segment([from, to], tick_stroke);
+
+            if value % 100.0 == 0.0 && value > 0.0 {
+                let mut text_pos = ruler_rect.min;
+                text_pos[other] = from[other] + 2.0;
+                painter.text(
+                    text_pos,
+                    Align2::LEFT_TOP,
+                    format!("{value}"),
+                    FontId::monospace(8.0),
+                    visuals.weak_text_color(),
+                );
+            }
+            value += 10.0;
+        }
+    }
+
+    guides.store(ctx);
+}
+
+// ----------------------------------------------------------------------------
+
+/// Snap a dragged area to the edges and centers of the other windows, and to the guide lines.
+///
+/// Returns the new left-top position of the area.
+/// The alignments are shown with lines across the screen.
+///
+/// Used if [`crate::style::DebugOptions::show_alignment_guides`] is set.
+#[cfg(debug_assertions)]
+pub(crate) fn snap_dragged_area(
+    ctx: &Context,
+    layer_id: LayerId,
+    rect: Rect,
+    drag_delta: Vec2,
+    drag_started: bool,
+) -> Pos2 {
+    // Keep track of where the area would be without snapping,
+    // so that it doesn't get stuck at the snapped position:
+    let unsnapped_id = layer_id.id.with("alignment_guides");
+    let previous = if drag_started {
+        None
+    } else {
+        ctx.data(|d| d.get_temp::<Pos2>(unsnapped_id))
+    };
+    let unsnapped = previous.map_or(rect.min, |pos| pos + drag_delta);
+    ctx.data_mut(|d| d.insert_temp(unsnapped_id, unsnapped));
+    let rect = Rect::from_min_size(unsnapped, rect.size());
+
+    let guides = Guides::load(ctx);
+    let mut targets = [guides.positions[0].clone(), guides.positions[1].clone()];
+    let other_windows: Vec<Rect> = ctx.memory(|mem| {
+        mem.areas()
+            .windows()
+            .into_iter()
+            .filter(|(other, _, visible)| *visible && *other != layer_id)
+            .map(|(_, state, _)| state.rect())
+            .collect()
+    });
+    let screen_rect = ctx.screen_rect();
+    for other in other_windows.iter().chain(std::iter::once(&screen_rect)) {
+        for (d, targets) in targets.iter_mut().enumerate() {
+            targets.extend([other.min[d], other.center()[d], other.max[d]]);
+        }
+    }
+
+    let painter = ctx.debug_painter();
+    let stroke = Stroke::new(1.0, measure_color());
+    let mut offset = Vec2::ZERO;
+    for (d, targets) in targets.iter().enumerate() {
+        let edges = [rect.min[d], rect.center()[d], rect.max[d]];
+        let best = edges
+            .iter()
+            .flat_map(|edge| targets.iter().map(move |target| target - edge))
+            .filter(|delta| delta.abs() <= SNAP_DISTANCE)
+            .min_by(|a, b| a.abs().total_cmp(&b.abs()));
+
+        if let Some(delta) = best {
+            offset[d] = delta;
+
+            // Show every edge that is now aligned:
+            for edge in edges {
+                let pos = edge + delta;
+                if targets.iter().any(|target| (target - pos).abs() < 0.5) {
+                    let mut line = screen_rect;
+                    line.min[d] = pos;
+                    line.max[d] = pos;
+                    painter.line_segment([line.min, line.max], stroke);
+                }
+            }
+        }
+    }
+
+    rect.min + offset
+}
@@ -0,0 +1,71 @@
+//! Pausing, stepping and slowing down time, for debugging.
+//!
+//! See [`crate::Context::debug_pause_frames`].
+
+use crate::RawInput;
+
+/// Maps the real time of the integration to the time egui sees.
+#[derive(Clone, Debug)]
+pub(crate) struct DebugTime {
+    pub paused: bool,
+
+    /// Frames left to step while paused.
+    pub steps: u32,
+
+    /// How fast time passes when not paused.
+    pub time_scale: f32,
+
+    /// The real time of the last frame, and the time egui saw.
+    ///
+    /// Once time has been mapped, we keep mapping it so that it never jumps.
+    last: Option<(f64, f64)>,
+}
+
+impl Default for DebugTime {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            steps: 0,
+            time_scale: 1.0,
+            last: None,
+        }
+    }
+}
+
+impl DebugTime {
+    /// Change the time of the new input.
+    pub fn begin_frame(&mut self, raw: &mut RawInput) {
+        if !self.paused && self.time_scale == 1.0 && self.last.is_none() {
+            return; // Nothing to do
+        }
+
+        // `None` means one full predicted frame:
+        let time_scale = if !self.paused {
+            Some(self.time_scale)
+        } else if 0 < self.steps {
+            self.steps -= 1;
+            None
+        } else {
+            Some(0.0)
+        };
+
+        match (raw.time, self.last) {
+            (Some(real_time), Some((last_real_time, last_time))) => {
+                let dt = match time_scale {
+                    Some(time_scale) => (real_time - last_real_time) * time_scale as f64,
+                    None => raw.predicted_dt as f64,
+                };
+                let time = last_time + dt;
+                raw.time = Some(time);
+                self.last = Some((real_time, time));
+            }
+            (Some(real_time), None) => {
+                self.last = Some((real_time, real_time));
+            }
+            (None, _) => {
+                // egui will advance the time by `predicted_dt`:
+                raw.predicted_dt *= time_scale.unwrap_or(1.0);
+            }
+        }
+    }
+}
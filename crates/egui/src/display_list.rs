@@ -0,0 +1,98 @@
+use crate::{
+    emath::{Rect, Vec2},
+    epaint::{ClippedShape, Shape},
+};
+
+/// The shapes painted by a [`crate::Painter`], recorded with [`crate::Painter::record`].
+///
+/// A [`DisplayList`] can be replayed into another painter with [`crate::Painter::replay`],
+/// compared to another one (e.g. in a regression test), or, with the `serde` feature,
+/// saved to disk or sent to another process.
+///
+/// All coordinates are screen coordinates in points.
+/// [`Shape::Text`] is laid out for [`Self::pixels_per_point`], and may look blurry if replayed at another scale.
+/// [`Shape::Callback`] can be recorded and replayed, but not serialized.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let painter = ui.painter();
+/// let recording = painter.record(|painter| {
+///     painter.circle_filled(egui::pos2(10.0, 10.0), 5.0, egui::Color32::RED);
+/// });
+/// assert_eq!(recording.len(), 1);
+///
+/// // Paint the same thing again, a bit further down:
+/// painter.replay(&recording.translated(egui::vec2(0.0, 20.0)));
+/// # });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DisplayList {
+    /// The scale the shapes were painted at.
+    pub pixels_per_point: f32,
+
+    /// The recorded shapes, in the order they were painted.
+    pub shapes: Vec<ClippedShape>,
+}
+
+impl DisplayList {
+    /// Number of recorded shapes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    /// Were no shapes recorded?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// The recorded shapes, in the order they were painted.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &ClippedShape> {
+        self.shapes.iter()
+    }
+
+    /// The smallest rectangle that covers all visible parts of the shapes.
+    pub fn visual_bounding_rect(&self) -> Rect {
+        self.shapes.iter().fold(Rect::NOTHING, |rect, clipped| {
+            rect.union(
+                clipped
+                    .shape
+                    .visual_bounding_rect()
+                    .intersect(clipped.clip_rect),
+            )
+        })
+    }
+
+    /// Move all shapes and clip rectangles by this much.
+    pub fn translate(&mut self, delta: Vec2) {
+        for ClippedShape { clip_rect, shape } in &mut self.shapes {
+            *clip_rect = clip_rect.translate(delta);
+            shape.translate(delta);
+        }
+    }
+
+    /// Move all shapes and clip rectangles by this much.
+    #[must_use]
+    pub fn translated(mut self, delta: Vec2) -> Self {
+        self.translate(delta);
+        self
+    }
+
+    /// The indices of the shapes that differ between `self` and `other`.
+    ///
+    /// If one list is longer, its extra shapes are included too.
+    /// Useful for finding what changed in a paint-level regression test.
+    pub fn diff(&self, other: &Self) -> Vec<usize> {
+        let len = self.len().max(other.len());
+        (0..len)
+            .filter(|&i| self.shapes.get(i) != other.shapes.get(i))
+            .collect()
+    }
+
+    /// All recorded shapes, without their clip rectangles.
+    pub fn into_shapes(self) -> impl Iterator<Item = Shape> {
+        self.shapes.into_iter().map(|clipped| clipped.shape)
+    }
+}
@@ -0,0 +1,143 @@
+//! Statistics about the cost of a frame, see [`FrameStats`].
+
+use crate::{LayerId, Ui};
+
+/// How much was painted on a layer.
+///
+/// See [`FrameStats::layers`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LayerStats {
+    pub layer_id: LayerId,
+
+    /// Number of shapes painted this frame.
+    pub num_shapes: usize,
+
+    /// Number of vertices in the last tessellation of this layer.
+    pub num_vertices: usize,
+
+    /// Number of indices in the last tessellation of this layer.
+    pub num_indices: usize,
+}
+
+/// Statistics about a frame, to keep track of the rendering cost of an app.
+///
+/// This is in [`crate::FullOutput::stats`], and can also be read with [`crate::Context::frame_stats`]
+/// and shown with [`crate::Context::frame_stats_ui`].
+///
+/// The statistics are only collected if [`crate::Options::record_frame_stats`] is set,
+/// otherwise they are all zero.
+///
+/// The tessellation happens after the frame has ended, so the vertex, index and draw call counts
+/// are from the last call to [`crate::Context::tessellate`] for the viewport, i.e. usually from the previous frame.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FrameStats {
+    /// All layers that were painted to, in paint order (back to front).
+    pub layers: Vec<LayerStats>,
+
+    /// Total number of shapes painted this frame.
+    pub num_shapes: usize,
+
+    /// Total number of vertices in the last tessellation.
+    pub num_vertices: usize,
+
+    /// Total number of indices in the last tessellation.
+    pub num_indices: usize,
+
+    /// Number of meshes and paint callbacks in the last tessellation.
+    ///
+    /// This is roughly the number of draw calls the backend needs.
+    pub num_draw_calls: usize,
+
+    /// Number of allocated textures, including the font atlas.
+    pub num_textures: usize,
+
+    /// Memory used by all allocated textures, in bytes.
+    pub texture_bytes: usize,
+
+    /// Number of laid out texts in the galley cache.
+    pub num_galleys_in_cache: usize,
+
+    /// Number of widgets that were added this frame.
+    pub num_widgets: usize,
+}
+
+impl FrameStats {
+    pub fn ui(&self, ui: &mut Ui) {
+        let Self {
+            layers,
+            num_shapes,
+            num_vertices,
+            num_indices,
+            num_draw_calls,
+            num_textures,
+            texture_bytes,
+            num_galleys_in_cache,
+            num_widgets,
+        } = self;
+
+        crate::Grid::new("frame_stats").show(ui, |ui| {
+            ui.label("Widgets:");
+            ui.label(num_widgets.to_string());
+            ui.end_row();
+
+            ui.label("Shapes:");
+            ui.label(num_shapes.to_string());
+            ui.end_row();
+
+            ui.label("Vertices:");
+            ui.label(num_vertices.to_string());
+            ui.end_row();
+
+            ui.label("Indices:");
+            ui.label(format!("{num_indices} ({} triangles)", num_indices / 3));
+            ui.end_row();
+
+            ui.label("Draw calls:");
+            ui.label(num_draw_calls.to_string());
+            ui.end_row();
+
+            ui.label("Textures:");
+            ui.label(format!(
+                "{num_textures} ({:.2} MB)",
+                *texture_bytes as f64 * 1e-6
+            ));
+            ui.end_row();
+
+            ui.label("Cached galleys:");
+            ui.label(num_galleys_in_cache.to_string());
+            ui.end_row();
+        });
+
+        ui.collapsing(format!("Layers ({})", layers.len()), |ui| {
+            crate::Grid::new("frame_stats_layers")
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong("Layer");
+                    ui.strong("Shapes");
+                    ui.strong("Vertices");
+                    ui.strong("Indices");
+                    ui.end_row();
+
+                    for layer in layers {
+                        ui.label(layer.layer_id.short_debug_format());
+                        ui.label(layer.num_shapes.to_string());
+                        ui.label(layer.num_vertices.to_string());
+                        ui.label(layer.num_indices.to_string());
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+}
+
+/// The result of the last [`crate::Context::tessellate`] of a viewport.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TessellationStats {
+    /// Vertices and indices per layer.
+    pub layers: ahash::HashMap<LayerId, (usize, usize)>,
+    pub num_vertices: usize,
+    pub num_indices: usize,
+    pub num_draw_calls: usize,
+}
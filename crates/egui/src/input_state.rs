@@ -1,26 +1,150 @@
 mod touch_state;
 
 use crate::data::input::*;
-use crate::{emath::*, util::History};
+use crate::{emath::*, util::History, Margin, ViewportId};
 use std::collections::{BTreeMap, HashSet};
 
 pub use crate::Key;
 pub use touch_state::MultiTouchInfo;
 use touch_state::TouchState;
 
-/// If the pointer moves more than this, it won't become a click (but it is still a drag)
-const MAX_CLICK_DIST: f32 = 6.0; // TODO(emilk): move to settings
-
-/// If the pointer is down for longer than this it will no longer register as a click.
-///
-/// If a touch is held for this many seconds while still,
-/// then it will register as a "long-touch" which is equivalent to a secondary click.
+/// Timings and thresholds for interpreting input.
 ///
-/// This is to support "press and hold for context menu" on touch screens.
-const MAX_CLICK_DURATION: f64 = 0.8; // TODO(emilk): move to settings
+/// Set with [`crate::Options::input_options`], and read with [`InputState::options`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct InputOptions {
+    /// If the pointer moves more than this, it won't become a click (but it is still a drag).
+    pub max_click_dist: f32,
+
+    /// If the pointer is down for longer than this it will no longer register as a click.
+    ///
+    /// If a touch is held for this many seconds while still,
+    /// then it will register as a "long-touch" which is equivalent to a secondary click.
+    ///
+    /// This is to support "press and hold for context menu" on touch screens.
+    pub max_click_duration: f64,
+
+    /// The new pointer press must come within this many seconds from previous pointer release.
+    pub max_double_click_delay: f64,
+
+    /// How long a button must be held before it starts repeating, in seconds.
+    ///
+    /// Used by e.g. the buttons of [`crate::Stepper`].
+    pub key_repeat_delay: f64,
+
+    /// How many times per second a held button repeats at first.
+    pub key_repeat_rate: f64,
+
+    /// Use the timings from [`RawInput::system_timings`] when the integration provides them?
+    ///
+    /// This overwrites the timings here, as well as [`crate::style::Interaction::tooltip_delay`]
+    /// and the blinking of [`crate::style::TextCursorStyle`].
+    pub follow_system_timings: bool,
+}
+
+impl Default for InputOptions {
+    fn default() -> Self {
+        Self {
+            max_click_dist: 6.0,
+            max_click_duration: 0.8,
+            max_double_click_delay: 0.3,
+            key_repeat_delay: 0.5,
+            key_repeat_rate: 8.0,
+            follow_system_timings: true,
+        }
+    }
+}
+
+impl InputOptions {
+    /// Copy the timings that are known.
+    pub fn apply_system_timings(&mut self, timings: &SystemTimings) {
+        let SystemTimings {
+            double_click_delay,
+            key_repeat_delay,
+            key_repeat_rate,
+            cursor_blink_interval: _, // in `Style`
+            tooltip_delay: _,         // in `Style`
+        } = *timings;
+
+        if let Some(double_click_delay) = double_click_delay {
+            self.max_double_click_delay = double_click_delay;
+        }
+        if let Some(key_repeat_delay) = key_repeat_delay {
+            self.key_repeat_delay = key_repeat_delay;
+        }
+        if let Some(key_repeat_rate) = key_repeat_rate {
+            self.key_repeat_rate = key_repeat_rate;
+        }
+    }
 
-/// The new pointer press must come within this many seconds from previous pointer release
-const MAX_DOUBLE_CLICK_DELAY: f64 = 0.3; // TODO(emilk): move to settings
+    /// Show the options in the ui.
+    pub fn ui(&mut self, ui: &mut crate::Ui) {
+        let Self {
+            max_click_dist,
+            max_click_duration,
+            max_double_click_delay,
+            key_repeat_delay,
+            key_repeat_rate,
+            follow_system_timings,
+        } = self;
+
+        use crate::DragValue;
+
+        crate::Grid::new("input_options")
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Max click distance")
+                    .on_hover_text("If the pointer moves more than this, it is a drag");
+                ui.add(
+                    DragValue::new(max_click_dist)
+                        .clamp_range(0.0..=50.0)
+                        .suffix(" pt"),
+                );
+                ui.end_row();
+
+                ui.label("Max click duration")
+                    .on_hover_text("If the pointer is down for longer than this, it is a drag");
+                ui.add(
+                    DragValue::new(max_click_duration)
+                        .clamp_range(0.1..=5.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Double-click delay");
+                ui.add(
+                    DragValue::new(max_double_click_delay)
+                        .clamp_range(0.05..=2.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Repeat delay");
+                ui.add(
+                    DragValue::new(key_repeat_delay)
+                        .clamp_range(0.05..=2.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Repeat rate");
+                ui.add(
+                    DragValue::new(key_repeat_rate)
+                        .clamp_range(1.0..=100.0)
+                        .suffix(" /s"),
+                );
+                ui.end_row();
+            });
+
+        ui.checkbox(follow_system_timings, "Use timings from the OS");
+    }
+}
 
 /// Input state that egui updates each frame.
 ///
@@ -80,6 +204,12 @@ pub struct InputState {
     /// Position and size of the egui area.
     pub screen_rect: Rect,
 
+    /// How far in from each edge of [`Self::screen_rect`] things outside of egui
+    /// (display cutouts, OS bars, …) may cover the screen.
+    ///
+    /// See [`RawInput::safe_area_insets`].
+    pub safe_area_insets: Margin,
+
     /// Also known as device pixel ratio, > 1 for high resolution screens.
     pub pixels_per_point: f32,
 
@@ -143,6 +273,9 @@ pub struct InputState {
 
     /// In-order events received this frame
     pub events: Vec<Event>,
+
+    /// The options used for this frame, from [`crate::Options::input_options`].
+    pub options: InputOptions,
 }
 
 impl Default for InputState {
@@ -156,6 +289,7 @@ impl Default for InputState {
             smooth_scroll_delta: Vec2::ZERO,
             zoom_factor_delta: 1.0,
             screen_rect: Rect::from_min_size(Default::default(), vec2(10_000.0, 10_000.0)),
+            safe_area_insets: Margin::ZERO,
             pixels_per_point: 1.0,
             max_texture_side: 2048,
             time: 0.0,
@@ -166,6 +300,7 @@ impl Default for InputState {
             modifiers: Default::default(),
             keys_down: Default::default(),
             events: Default::default(),
+            options: Default::default(),
         }
     }
 }
@@ -177,6 +312,7 @@ impl InputState {
         mut new: RawInput,
         requested_immediate_repaint_prev_frame: bool,
         pixels_per_point: f32,
+        options: InputOptions,
     ) -> Self {
         crate::profile_function!();
 
@@ -192,11 +328,12 @@ impl InputState {
         };
 
         let screen_rect = new.screen_rect.unwrap_or(self.screen_rect);
+        let safe_area_insets = new.safe_area_insets.unwrap_or(self.safe_area_insets);
         self.create_touch_states_for_new_devices(&new.events);
         for touch_state in self.touch_states.values_mut() {
             touch_state.begin_frame(time, &new, self.pointer.interact_pos);
         }
-        let pointer = self.pointer.begin_frame(time, &new);
+        let pointer = self.pointer.begin_frame(time, &new, options);
 
         let mut keys_down = self.keys_down;
         let mut raw_scroll_delta = Vec2::ZERO;
@@ -257,6 +394,7 @@ impl InputState {
             smooth_scroll_delta,
             zoom_factor_delta,
             screen_rect,
+            safe_area_insets,
             pixels_per_point,
             max_texture_side: new.max_texture_side.unwrap_or(self.max_texture_side),
             time,
@@ -268,6 +406,7 @@ impl InputState {
             keys_down,
             events: new.events.clone(), // TODO(emilk): remove clone() and use raw.events
             raw: new,
+            options,
         }
     }
 
@@ -277,11 +416,77 @@ impl InputState {
         self.raw.viewport()
     }
 
+    /// Which way the device is being held.
+    ///
+    /// This is [`ViewportInfo::orientation`] if the integration knows it,
+    /// otherwise it is guessed from the shape of [`Self::screen_rect`].
+    pub fn orientation(&self) -> DeviceOrientation {
+        self.viewport().orientation.unwrap_or_else(|| {
+            if self.screen_rect.height() > self.screen_rect.width() {
+                DeviceOrientation::Portrait
+            } else {
+                DeviceOrientation::Landscape
+            }
+        })
+    }
+
+    /// The hinge or fold of a foldable or dual-screen device, if the viewport spans across it.
+    ///
+    /// See [`ViewportInfo::hinge_rect`] and [`crate::Ui::avoid_hinge`].
+    #[inline]
+    pub fn hinge_rect(&self) -> Option<Rect> {
+        self.viewport().hinge_rect
+    }
+
+    /// The files dropped on the given viewport this frame.
+    ///
+    /// egui hands files dropped on one viewport to the input of that viewport,
+    /// so you usually want `dropped_files_for(ctx.viewport_id())`.
+    /// See [`DroppedFile::viewport_id`].
+    pub fn dropped_files_for(
+        &self,
+        viewport_id: ViewportId,
+    ) -> impl Iterator<Item = &DroppedFile> + '_ {
+        self.raw
+            .dropped_files
+            .iter()
+            .filter(move |file| file.viewport_id.unwrap_or(self.raw.viewport_id) == viewport_id)
+    }
+
+    /// The files hovering the given viewport, about to be dropped on it.
+    ///
+    /// See [`HoveredFile::viewport_id`].
+    pub fn hovered_files_for(
+        &self,
+        viewport_id: ViewportId,
+    ) -> impl Iterator<Item = &HoveredFile> + '_ {
+        self.raw
+            .hovered_files
+            .iter()
+            .filter(move |file| file.viewport_id.unwrap_or(self.raw.viewport_id) == viewport_id)
+    }
+
+    /// The monitors (screens) of the computer, if known.
+    ///
+    /// See [`RawInput::monitors`].
+    #[inline]
+    pub fn monitors(&self) -> &[MonitorInfo] {
+        &self.raw.monitors
+    }
+
     #[inline(always)]
     pub fn screen_rect(&self) -> Rect {
         self.screen_rect
     }
 
+    /// The part of [`Self::screen_rect`] that is not covered by display cutouts, OS bars etc.
+    ///
+    /// See [`RawInput::safe_area_insets`].
+    #[inline]
+    pub fn safe_area_rect(&self) -> Rect {
+        self.screen_rect - self.safe_area_insets
+    }
+
     /// Zoom scale factor this frame (e.g. from ctrl-scroll or pinch gesture).
     /// * `zoom = 1`: no change
     /// * `zoom < 1`: pinch together
@@ -328,7 +533,7 @@ impl InputState {
             || !self.events.is_empty()
 
         // We need to wake up and check for press-and-hold for the context menu.
-        // TODO(emilk): wake up after `MAX_CLICK_DURATION` instead of every frame.
+        // TODO(emilk): wake up after `InputOptions::max_click_duration` instead of every frame.
         || (self.any_touches() && !self.pointer.is_decidedly_dragging())
     }
 
@@ -677,6 +882,9 @@ pub struct PointerState {
 
     /// All button events that occurred this frame
     pub(crate) pointer_events: Vec<PointerEvent>,
+
+    /// Thresholds for clicks etc.
+    options: InputOptions,
 }
 
 impl Default for PointerState {
@@ -698,16 +906,18 @@ impl Default for PointerState {
             last_last_click_time: std::f64::NEG_INFINITY,
             last_move_time: std::f64::NEG_INFINITY,
             pointer_events: vec![],
+            options: Default::default(),
         }
     }
 }
 
 impl PointerState {
     #[must_use]
-    pub(crate) fn begin_frame(mut self, time: f64, new: &RawInput) -> Self {
+    pub(crate) fn begin_frame(mut self, time: f64, new: &RawInput, options: InputOptions) -> Self {
         let was_decidedly_dragging = self.is_decidedly_dragging();
 
         self.time = time;
+        self.options = options;
 
         self.pointer_events.clear();
 
@@ -727,7 +937,7 @@ impl PointerState {
 
                     if let Some(press_origin) = self.press_origin {
                         self.has_moved_too_much_for_a_click |=
-                            press_origin.distance(pos) > MAX_CLICK_DIST;
+                            press_origin.distance(pos) > self.options.max_click_dist;
                     }
 
                     self.pointer_events.push(PointerEvent::Moved(pos));
@@ -765,10 +975,10 @@ impl PointerState {
                         let clicked = self.could_any_button_be_click();
 
                         let click = if clicked {
-                            let double_click =
-                                (time - self.last_click_time) < MAX_DOUBLE_CLICK_DELAY;
+                            let max_delay = self.options.max_double_click_delay;
+                            let double_click = (time - self.last_click_time) < max_delay;
                             let triple_click =
-                                (time - self.last_last_click_time) < (MAX_DOUBLE_CLICK_DELAY * 2.0);
+                                (time - self.last_last_click_time) < (max_delay * 2.0);
                             let count = if triple_click {
                                 3
                             } else if double_click {
@@ -1044,7 +1254,7 @@ impl PointerState {
             }
 
             if let Some(press_start_time) = self.press_start_time {
-                if self.time - press_start_time > MAX_CLICK_DURATION {
+                if self.time - press_start_time > self.options.max_click_duration {
                     return false;
                 }
             }
@@ -1080,7 +1290,7 @@ impl PointerState {
             && !self.has_moved_too_much_for_a_click
             && self.button_down(PointerButton::Primary)
             && self.press_start_time.map_or(false, |press_start_time| {
-                self.time - press_start_time > MAX_CLICK_DURATION
+                self.time - press_start_time > self.options.max_click_duration
             })
     }
 
@@ -1116,6 +1326,7 @@ impl InputState {
 
             zoom_factor_delta,
             screen_rect,
+            safe_area_insets,
             pixels_per_point,
             max_texture_side,
             time,
@@ -1126,6 +1337,7 @@ impl InputState {
             modifiers,
             keys_down,
             events,
+            options,
         } = self;
 
         ui.style_mut()
@@ -1159,6 +1371,7 @@ impl InputState {
         ));
         ui.label(format!("zoom_factor_delta: {zoom_factor_delta:4.2}x"));
         ui.label(format!("screen_rect: {screen_rect:?} points"));
+        ui.label(format!("safe_area_insets: {safe_area_insets:?} points"));
         ui.label(format!(
             "{pixels_per_point} physical pixels for each logical point"
         ));
@@ -1175,6 +1388,7 @@ impl InputState {
         ui.label(format!("focused:   {focused}"));
         ui.label(format!("modifiers: {modifiers:#?}"));
         ui.label(format!("keys_down: {keys_down:?}"));
+        ui.label(format!("options: {options:#?}"));
         ui.scope(|ui| {
             ui.set_min_height(150.0);
             ui.label(format!("events: {events:#?}"))
@@ -1202,6 +1416,7 @@ impl PointerState {
             last_last_click_time,
             pointer_events,
             last_move_time,
+            options: _, // shown by `InputState::ui`
         } = self;
 
         ui.label(format!("latest_pos: {latest_pos:?}"));
@@ -199,9 +199,19 @@ impl GraphicLayers {
         area_order: &[LayerId],
         transforms: &ahash::HashMap<LayerId, TSTransform>,
     ) -> Vec<ClippedShape> {
+        self.drain_layers(area_order, transforms).0
+    }
+
+    /// Like [`Self::drain`], but also returns which of the shapes belong to each layer, in paint order.
+    pub(crate) fn drain_layers(
+        &mut self,
+        area_order: &[LayerId],
+        transforms: &ahash::HashMap<LayerId, TSTransform>,
+    ) -> (Vec<ClippedShape>, Vec<(LayerId, std::ops::Range<usize>)>) {
         crate::profile_function!();
 
         let mut all_shapes: Vec<_> = Default::default();
+        let mut layers = vec![];
 
         for &order in &Order::ALL {
             let order_map = &mut self.0[order as usize];
@@ -221,7 +231,11 @@ impl GraphicLayers {
                                 clipped_shape.shape.transform(*transform);
                             }
                         }
+                        let start = all_shapes.len();
                         all_shapes.append(&mut list.0);
+                        if start < all_shapes.len() {
+                            layers.push((*layer_id, start..all_shapes.len()));
+                        }
                     }
                 }
             }
@@ -237,10 +251,14 @@ impl GraphicLayers {
                     }
                 }
 
+                let start = all_shapes.len();
                 all_shapes.append(&mut list.0);
+                if start < all_shapes.len() {
+                    layers.push((layer_id, start..all_shapes.len()));
+                }
             }
         }
 
-        all_shapes
+        (all_shapes, layers)
     }
 }
@@ -330,6 +330,40 @@ impl Layout {
         self.main_dir().is_vertical()
     }
 
+    /// Mirror this layout horizontally, for right-to-left languages.
+    ///
+    /// Left-to-right becomes right-to-left and vice versa,
+    /// and top-down and bottom-up layouts have their horizontal alignment flipped.
+    ///
+    /// See [`crate::Style::layout_direction`].
+    #[must_use]
+    pub fn mirrored(mut self) -> Self {
+        fn flip(align: Align) -> Align {
+            match align {
+                Align::Min => Align::Max,
+                Align::Center => Align::Center,
+                Align::Max => Align::Min,
+            }
+        }
+
+        match self.main_dir {
+            Direction::LeftToRight => self.main_dir = Direction::RightToLeft,
+            Direction::RightToLeft => self.main_dir = Direction::LeftToRight,
+            Direction::TopDown | Direction::BottomUp => self.cross_align = flip(self.cross_align),
+        }
+        self
+    }
+
+    /// This layout, mirrored if `direction` is [`crate::LayoutDirection::RightToLeft`].
+    #[must_use]
+    pub fn for_direction(self, direction: crate::LayoutDirection) -> Self {
+        if direction.is_right_to_left() {
+            self.mirrored()
+        } else {
+            self
+        }
+    }
+
     pub fn prefer_right_to_left(&self) -> bool {
         self.main_dir == Direction::RightToLeft
             || self.main_dir.is_vertical() && self.cross_align == Align::Max
@@ -374,13 +374,24 @@
 #![cfg_attr(feature = "puffin", deny(unsafe_code))]
 #![cfg_attr(not(feature = "puffin"), forbid(unsafe_code))]
 
+mod aligned_row;
 mod animation_manager;
+#[cfg(feature = "clipboard_history")]
+pub mod clipboard_history;
+mod clock;
+mod command_ack;
 pub mod containers;
 mod context;
+mod control_learn;
 mod data;
+mod debounce;
+mod debug_guides;
 pub mod debug_text;
+mod debug_time;
+mod display_list;
 mod drag_and_drop;
 mod frame_state;
+pub mod frame_stats;
 pub(crate) mod grid;
 pub mod gui_zoom;
 mod hit_test;
@@ -396,14 +407,23 @@ pub mod menu;
 pub mod os;
 mod painter;
 pub(crate) mod placer;
+pub mod promise;
+#[cfg(feature = "serde")]
+pub mod remote;
 mod response;
+mod screenshot;
 mod sense;
+pub mod settings;
+mod shortcut_registry;
 pub mod style;
 pub mod text_selection;
+pub mod tour;
 mod ui;
 pub mod util;
 pub mod viewport;
+mod widget_gallery;
 mod widget_rect;
+mod widget_state;
 pub mod widget_text;
 pub mod widgets;
 
@@ -431,7 +451,7 @@ pub use epaint::{
     text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
     textures::{TextureFilter, TextureOptions, TextureWrapMode, TexturesDelta},
     ClippedPrimitive, ColorImage, FontImage, ImageData, Margin, Mesh, PaintCallback,
-    PaintCallbackInfo, Rounding, Shadow, Shape, Stroke, TextureHandle, TextureId,
+    PaintCallbackInfo, Rounding, Shadow, Shape, Stroke, TextureHandle, TextureId, ViewportInPixels,
 };
 
 pub mod text {
@@ -443,6 +463,8 @@ pub mod text {
 }
 
 pub use {
+    aligned_row::AlignedRow,
+    clock::{Clock, FixedStepClock, ScaledClock},
     containers::*,
     context::{Context, RepaintCause, RequestRepaintInfo},
     data::{
@@ -452,22 +474,29 @@ pub use {
         },
         Key,
     },
+    display_list::DisplayList,
     drag_and_drop::DragAndDrop,
+    frame_stats::{FrameStats, LayerStats},
     grid::Grid,
     id::{Id, IdMap},
-    input_state::{InputState, MultiTouchInfo, PointerState},
+    input_state::{InputOptions, InputState, MultiTouchInfo, PointerState},
     layers::{LayerId, Order},
     layout::*,
     load::SizeHint,
     memory::{Memory, Options},
     painter::Painter,
+    promise::Promise,
     response::{InnerResponse, Response},
     sense::Sense,
-    style::{FontSelection, Style, TextStyle, Visuals},
+    shortcut_registry::RegisteredShortcut,
+    style::{FontSelection, LayoutDirection, Style, TextStyle, Visuals},
     text::{Galley, TextFormat},
+    tour::{Tour, TourStep},
     ui::Ui,
     viewport::*,
+    widget_gallery::{widget_gallery, GalleryOptions, GalleryState},
     widget_rect::{WidgetRect, WidgetRects},
+    widget_state::{WidgetInteraction, WidgetState},
     widget_text::{RichText, WidgetText},
     widgets::*,
 };
@@ -4,8 +4,8 @@ use ahash::HashMap;
 use epaint::emath::TSTransform;
 
 use crate::{
-    area, vec2, EventFilter, Id, IdMap, LayerId, Order, Pos2, Rangef, RawInput, Rect, Style, Vec2,
-    ViewportId, ViewportIdMap, ViewportIdSet,
+    area, id::IdSet, vec2, EventFilter, Id, IdMap, LayerId, Order, Pos2, Rangef, RawInput, Rect,
+    Style, Vec2, ViewportId, ViewportIdMap, ViewportIdSet,
 };
 
 // ----------------------------------------------------------------------------
@@ -98,6 +98,22 @@ pub struct Memory {
 
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub(crate) focus: ViewportIdMap<Focus>,
+
+    // -------------------------------------------------
+    /// Named layouts, see [`crate::Context::save_layout`].
+    pub(crate) saved_layouts: HashMap<String, SavedLayout>,
+
+    /// The panels shown so far, so that their sizes can be saved in a layout.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) panel_ids: IdSet,
+
+    /// The open-state of windows from the last loaded layout,
+    /// applied (and removed) the next time each window is shown.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) restored_window_open: IdMap<bool>,
+
+    /// The hints the user has closed, see [`crate::Ui::hint_once`].
+    pub(crate) dismissed_hints: IdSet,
 }
 
 impl Default for Memory {
@@ -114,6 +130,10 @@ impl Default for Memory {
             layer_transforms: Default::default(),
             popup: Default::default(),
             everything_is_visible: Default::default(),
+            saved_layouts: Default::default(),
+            panel_ids: Default::default(),
+            restored_window_open: Default::default(),
+            dismissed_hints: Default::default(),
         };
         slf.interactions.entry(slf.viewport_id).or_default();
         slf.areas.entry(slf.viewport_id).or_default();
@@ -227,6 +247,54 @@ pub struct Options {
     ///
     /// By default this is `true` in debug builds.
     pub warn_on_id_clash: bool,
+
+    /// Timings and thresholds for clicks, double-clicks, button repeat etc.
+    pub input_options: crate::InputOptions,
+
+    /// Honor [`crate::RawInput::system_preferences`], e.g. reduced motion and larger text?
+    ///
+    /// This is `true` by default.
+    pub follow_system_preferences: bool,
+
+    /// Preferences that take priority over [`crate::RawInput::system_preferences`].
+    ///
+    /// For instance, set [`crate::SystemPreferences::reduce_motion`] to `Some(false)`
+    /// to keep animations even if the user prefers reduced motion.
+    pub system_preference_overrides: crate::SystemPreferences,
+
+    /// Replace all animated transitions with instant changes.
+    ///
+    /// This affects collapsing headers, panels, windows, smooth scrolling, spinners etc.
+    /// Custom widgets can check [`crate::Context::reduce_motion`] to do the same.
+    ///
+    /// This is in addition to [`crate::SystemPreferences::reduce_motion`].
+    ///
+    /// Default: `false`.
+    pub reduce_motion: bool,
+
+    /// Save power or go for the smoothest experience, see [`crate::PowerHint`].
+    ///
+    /// `None` means following [`crate::SystemPreferences::power_hint`]
+    /// (e.g. the battery status, as given by the integration), which defaults to
+    /// [`crate::PowerHint::HighPerformance`].
+    ///
+    /// Default: `None`.
+    pub performance_mode: Option<crate::PowerHint>,
+
+    /// Record the [`crate::WidgetInfo`] of all widgets each frame,
+    /// so it can be read with [`crate::Context::widget_info`].
+    ///
+    /// Useful for testing harnesses, at the cost of some performance.
+    ///
+    /// Default: `false`.
+    pub record_widget_info: bool,
+
+    /// Collect the [`crate::FrameStats`] of each frame, in [`crate::FullOutput::stats`].
+    ///
+    /// This is turned on by [`crate::Context::frame_stats_ui`].
+    ///
+    /// Default: `false`.
+    pub record_frame_stats: bool,
 }
 
 impl Default for Options {
@@ -240,11 +308,102 @@ impl Default for Options {
             screen_reader: false,
             preload_font_glyphs: true,
             warn_on_id_clash: cfg!(debug_assertions),
+            input_options: Default::default(),
+            follow_system_preferences: true,
+            system_preference_overrides: Default::default(),
+            reduce_motion: false,
+            performance_mode: None,
+            record_widget_info: false,
+            record_frame_stats: false,
         }
     }
 }
 
 impl Options {
+    /// Use the interaction timings of the operating system, where known.
+    ///
+    /// Called by [`crate::Context`] when [`crate::InputOptions::follow_system_timings`] is set.
+    pub(crate) fn apply_system_timings(&mut self, timings: &crate::SystemTimings) {
+        self.input_options.apply_system_timings(timings);
+
+        let mut interaction = self.style.interaction.clone();
+        let mut text_cursor = self.style.visuals.text_cursor.clone();
+        if let Some(tooltip_delay) = timings.tooltip_delay {
+            interaction.tooltip_delay = tooltip_delay;
+        }
+        if let Some(interval) = timings.cursor_blink_interval {
+            text_cursor.blink = 0.0 < interval;
+            if text_cursor.blink {
+                text_cursor.on_duration = interval;
+                text_cursor.off_duration = interval;
+            }
+        }
+
+        // Only clone the style if something changed:
+        if interaction != self.style.interaction || text_cursor != self.style.visuals.text_cursor {
+            let style = std::sync::Arc::make_mut(&mut self.style);
+            style.interaction = interaction;
+            style.visuals.text_cursor = text_cursor;
+        }
+    }
+
+    /// Update the style after the effective system preferences changed from `old` to `new`.
+    ///
+    /// Reduced motion is not applied here, see [`crate::Context::reduce_motion`].
+    pub(crate) fn apply_system_preferences(
+        &mut self,
+        old: &crate::SystemPreferences,
+        new: &crate::SystemPreferences,
+    ) {
+        let style = std::sync::Arc::make_mut(&mut self.style);
+
+        let text_scale = new.text_scale.unwrap_or(1.0) / old.text_scale.unwrap_or(1.0);
+        if text_scale.is_finite() && 0.0 < text_scale && text_scale != 1.0 {
+            for font_id in style.text_styles.values_mut() {
+                font_id.size *= text_scale;
+            }
+        }
+
+        let default_visuals = if style.visuals.dark_mode {
+            crate::Visuals::dark()
+        } else {
+            crate::Visuals::light()
+        };
+
+        if new.accent_color != old.accent_color {
+            let visuals = &mut style.visuals;
+            if let Some(accent_color) = new.accent_color {
+                visuals.selection.bg_fill = accent_color;
+                visuals.hyperlink_color = accent_color;
+            } else {
+                visuals.selection.bg_fill = default_visuals.selection.bg_fill;
+                visuals.hyperlink_color = default_visuals.hyperlink_color;
+            }
+        }
+
+        if new.high_contrast != old.high_contrast {
+            let visuals = &mut style.visuals;
+            if new.high_contrast == Some(true) {
+                visuals.increase_contrast();
+            } else if old.high_contrast == Some(true) {
+                visuals.widgets = default_visuals.widgets;
+                visuals.window_stroke = default_visuals.window_stroke;
+                visuals.selection.stroke = default_visuals.selection.stroke;
+            }
+        }
+
+        if new.power_hint != old.power_hint {
+            let visuals = &mut style.visuals;
+            if new.power_hint == Some(crate::PowerHint::LowPower) {
+                visuals.window_shadow = epaint::Shadow::NONE;
+                visuals.popup_shadow = epaint::Shadow::NONE;
+            } else if old.power_hint == Some(crate::PowerHint::LowPower) {
+                visuals.window_shadow = default_visuals.window_shadow;
+                visuals.popup_shadow = default_visuals.popup_shadow;
+            }
+        }
+    }
+
     /// Show the options in the ui.
     pub fn ui(&mut self, ui: &mut crate::Ui) {
         let Self {
@@ -256,6 +415,13 @@ impl Options {
             screen_reader: _, // needs to come from the integration
             preload_font_glyphs: _,
             warn_on_id_clash,
+            input_options,
+            follow_system_preferences,
+            system_preference_overrides: _,
+            reduce_motion,
+            performance_mode,
+            record_widget_info: _,
+            record_frame_stats: _,
         } = self;
 
         use crate::Widget as _;
@@ -274,9 +440,37 @@ impl Options {
                 );
 
                 ui.checkbox(warn_on_id_clash, "Warn if two widgets have the same Id");
+
+                ui.checkbox(
+                    follow_system_preferences,
+                    "Follow system preferences (reduced motion, text scale, …)",
+                );
+
+                ui.checkbox(reduce_motion, "Reduce motion (no animations)");
+
+                ui.horizontal(|ui| {
+                    ui.label("Performance mode:");
+                    ui.selectable_value(performance_mode, None, "System");
+                    ui.selectable_value(
+                        performance_mode,
+                        Some(crate::PowerHint::LowPower),
+                        "Low power",
+                    );
+                    ui.selectable_value(
+                        performance_mode,
+                        Some(crate::PowerHint::HighPerformance),
+                        "High performance",
+                    );
+                });
             });
 
         use crate::containers::*;
+        CollapsingHeader::new("🖱 Input")
+            .default_open(false)
+            .show(ui, |ui| {
+                input_options.ui(ui);
+            });
+
         CollapsingHeader::new("🎑 Style")
             .default_open(true)
             .show(ui, |ui| {
@@ -554,7 +748,7 @@ impl Focus {
 
         let current_rect = self.focus_widgets_cache.get(&current_focused.id)?;
 
-        let mut best_score = std::f32::INFINITY;
+        let mut best_score = f32::INFINITY;
         let mut best_id = None;
 
         for (candidate_id, candidate_rect) in &self.focus_widgets_cache {
@@ -632,6 +826,21 @@ impl Memory {
         self.areas.entry(self.viewport_id).or_default()
     }
 
+    /// The windows of every viewport, see [`Areas::windows`].
+    pub(crate) fn windows_of_all_viewports(
+        &self,
+    ) -> ViewportIdMap<Vec<(LayerId, area::State, bool)>> {
+        self.areas
+            .iter()
+            .map(|(viewport_id, areas)| (*viewport_id, areas.windows()))
+            .collect()
+    }
+
+    /// Access the [`Areas`] of any viewport.
+    pub(crate) fn areas_of_mut(&mut self, viewport_id: ViewportId) -> &mut Areas {
+        self.areas.entry(viewport_id).or_default()
+    }
+
     /// Top-most layer at the given position.
     pub fn layer_id_at(&self, pos: Pos2) -> Option<LayerId> {
         self.areas().layer_id_at(pos, &self.layer_transforms)
@@ -915,7 +1124,7 @@ impl Areas {
     pub(crate) fn set_state(&mut self, layer_id: LayerId, state: area::State) {
         self.visible_current_frame.insert(layer_id);
         self.areas.insert(layer_id.id, state);
-        if !self.order.iter().any(|x| *x == layer_id) {
+        if !self.order.contains(&layer_id) {
             self.order.push(layer_id);
         }
     }
@@ -973,16 +1182,35 @@ impl Areas {
         self.visible_current_frame.insert(layer_id);
         self.wants_to_be_on_top.insert(layer_id);
 
-        if !self.order.iter().any(|x| *x == layer_id) {
+        if !self.order.contains(&layer_id) {
             self.order.push(layer_id);
         }
     }
 
+    /// The windows (areas in [`Order::Middle`]), back-to-front, and whether they are visible.
+    pub(crate) fn windows(&self) -> Vec<(LayerId, area::State, bool)> {
+        self.order
+            .iter()
+            .filter(|layer_id| layer_id.order == Order::Middle)
+            .filter_map(|layer_id| {
+                let state = *self.areas.get(&layer_id.id)?;
+                Some((*layer_id, state, self.is_visible(layer_id)))
+            })
+            .collect()
+    }
+
+    /// Move a window and put it on top, e.g. when loading a saved layout.
+    pub(crate) fn restore_window(&mut self, layer_id: LayerId, state: area::State) {
+        self.areas.insert(layer_id.id, state);
+        self.order.retain(|x| *x != layer_id);
+        self.order.push(layer_id);
+    }
+
     pub fn top_layer_id(&self, order: Order) -> Option<LayerId> {
         self.order
             .iter()
             .filter(|layer| layer.order == order)
-            .last()
+            .next_back()
             .copied()
     }
 
@@ -1004,6 +1232,44 @@ impl Areas {
 
 // ----------------------------------------------------------------------------
 
+/// A named snapshot of where the windows and panels are.
+///
+/// See [`crate::Context::save_layout`].
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub(crate) struct SavedLayout {
+    /// The windows of each viewport, back-to-front.
+    pub windows: ViewportIdMap<Vec<SavedWindow>>,
+
+    /// The size of each panel.
+    pub panels: IdMap<crate::containers::panel::PanelState>,
+
+    /// The native windows, if saved with [`crate::Context::save_layout_with_viewports`].
+    pub viewports: ViewportIdMap<crate::viewport::PersistedViewport>,
+}
+
+/// A [`crate::Window`] in a [`SavedLayout`].
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub(crate) struct SavedWindow {
+    pub layer_id: LayerId,
+
+    /// Position and size of the area.
+    pub state: area::State,
+
+    /// Was the window shown?
+    pub open: bool,
+
+    /// The size picked by the user, if the window is resizable.
+    pub size: Option<Vec2>,
+
+    /// Was the window expanded (not collapsed)?
+    pub expanded: Option<bool>,
+}
+
+// ----------------------------------------------------------------------------
+
 #[test]
 fn memory_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
@@ -139,15 +139,22 @@ pub(crate) fn menu_ui<'c, R>(
     menu_state_arc: &Arc<RwLock<MenuState>>,
     add_contents: impl FnOnce(&mut Ui) -> R + 'c,
 ) -> InnerResponse<R> {
-    let pos = {
+    // Right-to-left menus are anchored at their top right corner, so they grow towards the left.
+    let right_to_left = ctx.style().layout_direction.is_right_to_left();
+    let (pos, pivot) = {
         let mut menu_state = menu_state_arc.write();
         menu_state.entry_count = 0;
-        menu_state.rect.min
+        if right_to_left {
+            (menu_state.rect.right_top(), Align2::RIGHT_TOP)
+        } else {
+            (menu_state.rect.min, Align2::LEFT_TOP)
+        }
     };
 
     let area = Area::new(menu_id.with("__menu"))
         .order(Order::Foreground)
         .fixed_pos(pos)
+        .pivot(pivot)
         .constrain_to(ctx.screen_rect())
         .interactable(true)
         .sense(Sense::hover());
@@ -159,8 +166,9 @@ pub(crate) fn menu_ui<'c, R>(
             .show(ui, |ui| {
                 ui.set_max_width(ui.spacing().menu_width);
                 ui.set_menu_state(Some(menu_state_arc.clone()));
-                ui.with_layout(Layout::top_down_justified(Align::LEFT), add_contents)
-                    .inner
+                let layout =
+                    Layout::top_down_justified(Align::LEFT).for_direction(ui.layout_direction());
+                ui.with_layout(layout, add_contents).inner
             })
             .inner
     });
@@ -333,11 +341,18 @@ impl MenuRoot {
         {
             // menu not open and button clicked
             // or button hovered while other menu is open
-            let mut pos = button.rect.left_bottom();
-
-            let menu_frame = Frame::menu(&button.ctx.style());
-            pos.x -= menu_frame.total_margin().left; // Make fist button in menu align with the parent button
-            pos.y += button.ctx.style().spacing.menu_spacing;
+            let style = button.ctx.style();
+            let right_to_left = style.layout_direction.is_right_to_left();
+            let menu_frame = Frame::menu(&style);
+
+            // Make fist button in menu align with the parent button.
+            // Right-to-left menus are positioned by their top right corner.
+            let mut pos = if right_to_left {
+                button.rect.right_bottom() + vec2(menu_frame.total_margin().right, 0.0)
+            } else {
+                button.rect.left_bottom() - vec2(menu_frame.total_margin().left, 0.0)
+            };
+            pos.y += style.spacing.menu_spacing;
 
             if let Some(root) = root.inner.as_mut() {
                 let menu_rect = root.menu_state.read().rect;
@@ -347,7 +362,11 @@ impl MenuRoot {
                     pos.y = screen_rect.max.y - menu_rect.height() - button.rect.height();
                 }
 
-                if pos.x + menu_rect.width() > screen_rect.max.x {
+                if right_to_left {
+                    if pos.x - menu_rect.width() < screen_rect.min.x {
+                        pos.x = screen_rect.min.x + menu_rect.width();
+                    }
+                } else if pos.x + menu_rect.width() > screen_rect.max.x {
                     pos.x = screen_rect.max.x - menu_rect.width();
                 }
             }
@@ -498,10 +517,15 @@ impl SubMenuButton {
 
         if ui.is_rect_visible(rect) {
             let visuals = Self::visuals(ui, &response, menu_state, sub_id);
-            let text_pos = Align2::LEFT_CENTER
+            let (text_align, icon_align) = if ui.layout_direction().is_right_to_left() {
+                (Align2::RIGHT_CENTER, Align2::LEFT_CENTER)
+            } else {
+                (Align2::LEFT_CENTER, Align2::RIGHT_CENTER)
+            };
+            let text_pos = text_align
                 .align_size_within_rect(text_galley.size(), rect.shrink2(button_padding))
                 .min;
-            let icon_pos = Align2::RIGHT_CENTER
+            let icon_pos = icon_align
                 .align_size_within_rect(icon_galley.size(), rect.shrink2(button_padding))
                 .min;
 
@@ -541,7 +565,12 @@ impl SubMenu {
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<Option<R>> {
         let sub_id = ui.id().with(self.button.index);
-        let response = self.button.show(ui, &self.parent_state.read(), sub_id);
+        let button = if ui.layout_direction().is_right_to_left() {
+            self.button.icon("⏴")
+        } else {
+            self.button
+        };
+        let response = button.show(ui, &self.parent_state.read(), sub_id);
         self.parent_state
             .write()
             .submenu_button_interaction(ui, sub_id, &response);
@@ -569,6 +598,8 @@ pub(crate) struct MenuState {
 }
 
 impl MenuState {
+    /// `position` is the top left corner of the menu,
+    /// or the top right corner with [`crate::LayoutDirection::RightToLeft`].
     pub fn new(position: Pos2) -> Self {
         Self {
             rect: Rect::from_min_size(position, Vec2::ZERO),
@@ -631,7 +662,12 @@ impl MenuState {
         } else if !open && button.hovered() {
             // TODO(emilk): open menu to the left if there isn't enough space to the right
             let mut pos = button.rect.right_top();
-            pos.x = self.rect.right() + ui.spacing().menu_spacing;
+            pos.x = if ui.layout_direction().is_right_to_left() {
+                // The position is the top right corner of the submenu:
+                self.rect.left() - ui.spacing().menu_spacing
+            } else {
+                self.rect.right() + ui.spacing().menu_spacing
+            };
             pos.y -= Frame::menu(ui.style()).total_margin().top; // align the first button in the submenu with the parent button
 
             self.open_submenu(sub_id, pos);
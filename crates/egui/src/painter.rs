@@ -3,7 +3,7 @@ use std::sync::Arc;
 use crate::{
     emath::{Align2, Pos2, Rangef, Rect, Vec2},
     layers::{LayerId, PaintList, ShapeIdx},
-    Color32, Context, FontId,
+    Color32, Context, DisplayList, FontId,
 };
 use epaint::{
     text::{Fonts, Galley, LayoutJob},
@@ -227,6 +227,47 @@ impl Painter {
             }
         });
     }
+
+    /// Paint as usual, and also record everything that `add_contents` adds to this painter's layer.
+    ///
+    /// The recorded shapes have already been clipped, faded and made transparent like any other shape of this painter.
+    /// Shapes painted to other layers are not recorded,
+    /// and neither are changes to shapes added before the recording started (see [`Self::set`]).
+    ///
+    /// See [`DisplayList`] for an example.
+    pub fn record(&self, add_contents: impl FnOnce(&Self)) -> DisplayList {
+        let start = self.ctx.graphics(|g| {
+            g.get(self.layer_id)
+                .map_or(0, |list| list.all_entries().len())
+        });
+        add_contents(self);
+
+        let shapes = self.ctx.graphics(|g| {
+            g.get(self.layer_id)
+                .map(|list| list.all_entries().skip(start).cloned().collect())
+                .unwrap_or_default()
+        });
+        DisplayList {
+            pixels_per_point: self.ctx.pixels_per_point(),
+            shapes,
+        }
+    }
+
+    /// Paint all the shapes of a [`DisplayList`], e.g. one recorded with [`Self::record`].
+    ///
+    /// Each shape is clipped to both its recorded clip rectangle and the clip rectangle of this painter.
+    pub fn replay(&self, display_list: &DisplayList) {
+        if self.fade_to_color == Some(Color32::TRANSPARENT) || self.opacity_factor == 0.0 {
+            return;
+        }
+        self.paint_list(|l| {
+            for ClippedShape { clip_rect, shape } in &display_list.shapes {
+                let mut shape = shape.clone();
+                self.transform_shape(&mut shape);
+                l.add(clip_rect.intersect(self.clip_rect), shape);
+            }
+        });
+    }
 }
 
 /// ## Debug painting
@@ -0,0 +1,422 @@
+//! Run async tasks and background work, and poll for the result from your UI code.
+//!
+//! [`Context::spawn_async`] runs a future and returns a [`Promise`] for its output.
+//! Once it completes, egui is asked to repaint, so the UI can show the result right away.
+//!
+//! Dropping every clone of a [`Promise`] cancels the future: it is dropped instead of being polled again.
+//!
+//! ```
+//! # let ctx = egui::Context::default();
+//! # async fn fetch_weather() -> String { String::from("Sunny") }
+//! # ctx.run(Default::default(), |ctx| {
+//! # egui::CentralPanel::default().show(ctx, |ui| {
+//! # let mut weather: Option<egui::Promise<String>> = None;
+//! let promise = weather.get_or_insert_with(|| ui.ctx().spawn_async(fetch_weather()));
+//! match promise.ready() {
+//!     Some(weather) => ui.label(weather),
+//!     None => ui.spinner(),
+//! };
+//! # });
+//! # });
+//! ```
+//!
+//! ## Executors
+//! By default egui drives the futures itself, on the UI thread, whenever they are woken.
+//! That works for futures that don't need a specific runtime, e.g. most web requests on the web.
+//!
+//! Futures that need a runtime (e.g. `tokio`'s network and timer types) need to be spawned on it.
+//! Either enable the `tokio` feature, so that egui uses the current `tokio` runtime if there is one,
+//! or hook up any executor with [`Context::set_async_spawner`].
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, OnceLock,
+    },
+    task::{Poll, Wake},
+};
+
+use crate::{mutex::Mutex, Context, Id};
+
+/// A future as accepted by [`Context::set_async_spawner`].
+pub type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// Spawns futures on an executor, see [`Context::set_async_spawner`].
+pub type Spawner = Arc<dyn Fn(BoxedFuture) + Send + Sync>;
+
+/// The result of some work that may not have completed yet.
+///
+/// Created with [`Context::spawn_async`] or [`Context::spawn_worker`].
+/// Poll it every frame with [`Self::ready`].
+///
+/// Drop it (and all its clones) to cancel the work, see [`crate::promise`].
+pub struct Promise<T> {
+    slot: Arc<OnceLock<T>>,
+}
+
+impl<T> Clone for Promise<T> {
+    fn clone(&self) -> Self {
+        Self {
+            slot: self.slot.clone(),
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> std::fmt::Debug for Promise<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Promise")
+            .field("ready", &self.is_ready())
+            .finish()
+    }
+}
+
+impl<T: Send + Sync + 'static> Promise<T> {
+    /// A promise that is already fulfilled.
+    pub fn from_ready(value: T) -> Self {
+        let slot = Arc::new(OnceLock::new());
+        slot.set(value).ok();
+        Self { slot }
+    }
+
+    /// The result, if the work has completed.
+    pub fn ready(&self) -> Option<&T> {
+        self.slot.get()
+    }
+
+    /// Has the work completed?
+    pub fn is_ready(&self) -> bool {
+        self.slot.get().is_some()
+    }
+
+    /// Take the result, if the work has completed and this is the only clone of the promise.
+    pub fn try_take(self) -> Result<T, Self> {
+        if !self.is_ready() {
+            return Err(self);
+        }
+        match Arc::try_unwrap(self.slot) {
+            Ok(slot) => Ok(slot.into_inner().expect("checked above")),
+            Err(slot) => Err(Self { slot }),
+        }
+    }
+
+    /// Returns the promise, and the function to call when the result is available.
+    ///
+    /// The function only holds a weak reference, so that dropping the promise can cancel the work.
+    pub(crate) fn new_pending(ctx: &Context) -> (Self, impl FnOnce(T) + Send + 'static) {
+        let slot = Arc::new(OnceLock::new());
+        let sender = {
+            let slot = Arc::downgrade(&slot);
+            let ctx = ctx.clone();
+            move |value: T| {
+                if let Some(slot) = slot.upgrade() {
+                    slot.set(value).ok();
+                    ctx.request_repaint();
+                }
+            }
+        };
+        (Self { slot }, sender)
+    }
+
+    /// Has every clone of the promise been dropped?
+    fn is_cancelled(slot: &std::sync::Weak<OnceLock<T>>) -> bool {
+        slot.strong_count() == 0
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+pub(crate) fn spawn_async<T: Send + Sync + 'static>(
+    ctx: &Context,
+    future: impl Future<Output = T> + Send + 'static,
+) -> Promise<T> {
+    let (promise, fulfill) = Promise::new_pending(ctx);
+    let slot = Arc::downgrade(&promise.slot);
+    let is_cancelled = {
+        let slot = slot.clone();
+        move || Promise::is_cancelled(&slot)
+    };
+    let mut future = Box::pin(future);
+    let mut fulfill = Some(fulfill);
+    let future: BoxedFuture = Box::pin(std::future::poll_fn(move |cx| {
+        // Other executors may keep polling a cancelled future, so stop it here:
+        if Promise::is_cancelled(&slot) {
+            return Poll::Ready(());
+        }
+        future.as_mut().poll(cx).map(|value| {
+            if let Some(fulfill) = fulfill.take() {
+                fulfill(value);
+            }
+        })
+    }));
+
+    if let Some(spawner) = ctx.data(|d| d.get_temp::<State>(Id::NULL).and_then(|s| s.spawner)) {
+        spawner(future);
+        return promise;
+    }
+
+    #[cfg(feature = "tokio")]
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        handle.spawn(future);
+        return promise;
+    }
+
+    let task = Arc::new(Task {
+        future: Mutex::new(Some(future)),
+        woken: AtomicBool::new(true),
+        is_cancelled: Box::new(is_cancelled),
+        ctx: ctx.clone(),
+    });
+    ctx.data_mut(|d| {
+        d.get_temp_mut_or_default::<State>(Id::NULL)
+            .tasks
+            .lock()
+            .push(task);
+    });
+    ctx.request_repaint();
+    promise
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn spawn_worker<T: Send + Sync + 'static>(
+    ctx: &Context,
+    work: impl FnOnce() -> T + Send + 'static,
+) -> Promise<T> {
+    let (promise, fulfill) = Promise::new_pending(ctx);
+    std::thread::Builder::new()
+        .name("egui_worker".to_owned())
+        .spawn(move || fulfill(work()))
+        .expect("Failed to spawn thread");
+    promise
+}
+
+pub(crate) fn set_spawner(ctx: &Context, spawner: Option<Spawner>) {
+    ctx.data_mut(|d| d.get_temp_mut_or_default::<State>(Id::NULL).spawner = spawner);
+}
+
+/// Polls the woken tasks of the built-in executor at the start of every frame.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_begin_frame("promise", Arc::new(State::begin_frame));
+}
+
+/// A future driven by egui's built-in executor.
+struct Task {
+    /// `None` once completed.
+    future: Mutex<Option<BoxedFuture>>,
+
+    /// Should the future be polled?
+    woken: AtomicBool,
+
+    /// Has every [`Promise`] for the output been dropped?
+    is_cancelled: Box<dyn Fn() -> bool + Send + Sync>,
+
+    /// Woken tasks are polled at the start of the next frame, so wake the UI.
+    ctx: Context,
+}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::SeqCst);
+        self.ctx.request_repaint();
+    }
+}
+
+/// The built-in executor, and the hook for a custom one.
+#[derive(Clone, Default)]
+struct State {
+    spawner: Option<Spawner>,
+
+    /// Tasks driven by the built-in executor.
+    tasks: Arc<Mutex<Vec<Arc<Task>>>>,
+}
+
+impl State {
+    fn begin_frame(ctx: &Context) {
+        let tasks = ctx.data_mut(|d| d.get_temp_mut_or_default::<Self>(Id::NULL).tasks.clone());
+
+        // Don't hold the lock while polling, since the futures may spawn more tasks:
+        let mut pending = std::mem::take(&mut *tasks.lock());
+        pending.retain(|task| {
+            if (task.is_cancelled)() {
+                return false;
+            }
+            if !task.woken.swap(false, Ordering::SeqCst) {
+                return true;
+            }
+            let mut future = task.future.lock();
+            let Some(running) = future.as_mut() else {
+                return false;
+            };
+            let waker: std::task::Waker = task.clone().into();
+            let mut cx = std::task::Context::from_waker(&waker);
+            match running.as_mut().poll(&mut cx) {
+                Poll::Ready(()) => {
+                    *future = None;
+                    false
+                }
+                Poll::Pending => true,
+            }
+        });
+        tasks.lock().append(&mut pending);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::task::Waker;
+
+    /// A future that completes once [`Oneshot::send`] is called.
+    #[derive(Clone, Default)]
+    struct Oneshot {
+        inner: Arc<Mutex<(Option<u32>, Option<Waker>)>>,
+    }
+
+    impl Oneshot {
+        fn send(&self, value: u32) {
+            let mut inner = self.inner.lock();
+            inner.0 = Some(value);
+            if let Some(waker) = inner.1.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    impl Future for Oneshot {
+        type Output = u32;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<u32> {
+            let mut inner = self.inner.lock();
+            match inner.0.take() {
+                Some(value) => Poll::Ready(value),
+                None => {
+                    inner.1 = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        }
+    }
+
+    /// Sets the flag when dropped.
+    struct DropGuard(Arc<AtomicBool>);
+
+    impl Drop for DropGuard {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn count_repaints(ctx: &Context) -> Arc<AtomicUsize> {
+        let repaints = Arc::new(AtomicUsize::new(0));
+        ctx.set_request_repaint_callback({
+            let repaints = repaints.clone();
+            move |_| {
+                repaints.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        repaints
+    }
+
+    fn run_frame(ctx: &Context) {
+        let _ = ctx.run(Default::default(), |_| {});
+    }
+
+    #[test]
+    fn from_ready() {
+        let promise = Promise::from_ready(7);
+        assert!(promise.is_ready());
+        assert_eq!(promise.ready(), Some(&7));
+        assert_eq!(promise.try_take().ok(), Some(7));
+    }
+
+    #[test]
+    fn try_take_needs_a_ready_and_unshared_promise() {
+        let ctx = Context::default();
+        let (promise, fulfill) = Promise::new_pending(&ctx);
+        let promise = promise.try_take().expect_err("still pending");
+        assert_eq!(promise.ready(), None);
+
+        fulfill(3);
+        let clone = promise.clone();
+        let promise = promise.try_take().expect_err("still shared");
+        drop(clone);
+        assert_eq!(promise.try_take().ok(), Some(3));
+    }
+
+    #[test]
+    fn spawn_async_completes_and_repaints() {
+        let ctx = Context::default();
+        let oneshot = Oneshot::default();
+        let promise = ctx.spawn_async(oneshot.clone());
+
+        // Run until egui goes idle:
+        for _ in 0..3 {
+            run_frame(&ctx);
+        }
+        assert!(!promise.is_ready(), "nothing sent yet");
+        assert!(!ctx.has_requested_repaint());
+
+        oneshot.send(42);
+        assert!(ctx.has_requested_repaint(), "waking should repaint");
+        assert!(!promise.is_ready(), "only polled at the start of a frame");
+
+        run_frame(&ctx);
+        assert_eq!(promise.ready(), Some(&42));
+    }
+
+    #[test]
+    fn spawn_worker_completes_and_repaints() {
+        let ctx = Context::default();
+        let repaints = count_repaints(&ctx);
+        let promise = ctx.spawn_worker(|| 5);
+        while !promise.is_ready() {
+            std::thread::yield_now();
+        }
+        assert_eq!(promise.ready(), Some(&5));
+        assert!(repaints.load(Ordering::SeqCst) > 0);
+    }
+
+    #[test]
+    fn dropping_the_promise_cancels_the_future() {
+        let ctx = Context::default();
+        let dropped = Arc::new(AtomicBool::new(false));
+        let promise = ctx.spawn_async({
+            let guard = DropGuard(dropped.clone());
+            async move {
+                let _guard = guard;
+                std::future::pending::<()>().await;
+            }
+        });
+
+        run_frame(&ctx);
+        assert!(!dropped.load(Ordering::SeqCst), "still running");
+
+        let clone = promise.clone();
+        drop(promise);
+        run_frame(&ctx);
+        assert!(!dropped.load(Ordering::SeqCst), "a clone is still alive");
+
+        drop(clone);
+        run_frame(&ctx);
+        assert!(
+            dropped.load(Ordering::SeqCst),
+            "the future should be dropped"
+        );
+    }
+
+    #[test]
+    fn dropped_worker_result_is_discarded() {
+        let ctx = Context::default();
+        let (promise, fulfill) = Promise::<u32>::new_pending(&ctx);
+        drop(promise);
+        let repaints = count_repaints(&ctx);
+        fulfill(1);
+        assert_eq!(repaints.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,247 @@
+//! A protocol for running the egui logic in one process, and showing it in another.
+//!
+//! The _server_ is a headless process (e.g. on an embedded device) that owns the [`Context`] and the app state.
+//! The _client_ is a thin integration that owns the window and the renderer.
+//!
+//! Each frame, the client sends its input as a [`ClientMessage::Input`],
+//! and the server responds with a [`ServerMessage::Frame`] holding everything the client needs to paint.
+//!
+//! The messages can be encoded with any `serde` format, and sent over any transport.
+//! Make sure the format you pick supports enums, and that both sides use the same [`PROTOCOL_VERSION`].
+//!
+//! Only the root viewport is supported,
+//! so keep [`Context::embed_viewports`] on (the default) on the server.
+//! [`crate::PaintCallback`]s can not be sent, and will fail to serialize.
+//!
+//! ## Server
+//! ```
+//! use egui::remote::{ClientMessage, Frame, ServerMessage, Tessellation};
+//!
+//! let ctx = egui::Context::default();
+//! # let messages_from_client = vec![ClientMessage::input(Default::default())];
+//! for message in messages_from_client {
+//!     let ClientMessage::Input(raw_input) = message else {
+//!         continue;
+//!     };
+//!     let full_output = ctx.run(*raw_input, |ctx| {
+//!         egui::CentralPanel::default().show(ctx, |ui| {
+//!             ui.label("Hello from the server!");
+//!         });
+//!     });
+//!     let frame = Frame::from_full_output(&ctx, full_output, Tessellation::Server);
+//!     let message = ServerMessage::Frame(frame);
+//!     // … send `message` to the client.
+//!     # drop(message);
+//! }
+//! ```
+//!
+//! ## Client
+//! The client applies [`Frame::textures_delta`] and paints [`FrameContent::into_primitives`] just like any other integration
+//! handles [`crate::FullOutput`], and handles [`Frame::platform_output`] and [`Frame::commands`] for its window.
+
+use std::time::Duration;
+
+use epaint::{
+    tessellator::{TessellationOptions, Tessellator},
+    textures::TexturesDelta,
+    ClippedPrimitive, PreparedDisc,
+};
+
+use crate::{
+    Context, DisplayList, FullOutput, PlatformOutput, RawInput, ViewportCommand, ViewportId,
+};
+
+/// Bump this whenever the messages change in an incompatible way.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Sent from the client (which renders) to the server (which runs egui).
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub enum ClientMessage {
+    /// Sent once, when connecting.
+    ///
+    /// The server should disconnect if the version doesn't match [`PROTOCOL_VERSION`].
+    Hello { protocol_version: u32 },
+
+    /// Run a frame with this input.
+    ///
+    /// Pass it to [`Context::run`] on the server.
+    Input(Box<RawInput>),
+}
+
+impl ClientMessage {
+    /// The first message to send.
+    pub fn hello() -> Self {
+        Self::Hello {
+            protocol_version: PROTOCOL_VERSION,
+        }
+    }
+
+    /// Run a frame with this input.
+    pub fn input(raw_input: RawInput) -> Self {
+        Self::Input(Box::new(raw_input))
+    }
+}
+
+/// Sent from the server (which runs egui) to the client (which renders).
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub enum ServerMessage {
+    /// The output of a frame, in response to a [`ClientMessage::Input`].
+    Frame(Frame),
+}
+
+/// Where to turn the shapes into triangles.
+///
+/// See [`Frame::from_full_output`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum Tessellation {
+    /// Send triangle meshes, so that the client only needs to render them.
+    ///
+    /// More data to send, but less work for the client.
+    #[default]
+    Server,
+
+    /// Send a [`DisplayList`] of shapes, and let the client tessellate them.
+    ///
+    /// Less data to send, but the client needs to run the tessellator.
+    Client,
+}
+
+/// What to paint, see [`Frame::content`].
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub enum FrameContent {
+    /// Tessellated on the server.
+    Primitives(Vec<ClippedPrimitive>),
+
+    /// To be tessellated on the client.
+    Shapes {
+        display_list: DisplayList,
+
+        /// The size of the font atlas on the server, needed for tessellation.
+        font_tex_size: [usize; 2],
+
+        /// The pre-rasterized discs in the font atlas on the server, needed for tessellation.
+        prepared_discs: Vec<PreparedDisc>,
+    },
+}
+
+/// Everything the client needs to show a frame.
+///
+/// Created on the server from a [`FullOutput`] with [`Self::from_full_output`].
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct Frame {
+    /// Non-rendering related output, e.g. the cursor icon and copied text.
+    pub platform_output: PlatformOutput,
+
+    /// Texture changes since last frame (including the font texture).
+    ///
+    /// The client must apply all of them, in order, even for frames it skips painting.
+    pub textures_delta: TexturesDelta,
+
+    /// What to paint.
+    pub content: FrameContent,
+
+    /// The number of physical pixels per logical ui point.
+    pub pixels_per_point: f32,
+
+    /// Commands for the window of the client, e.g. to change its title.
+    pub commands: Vec<ViewportCommand>,
+
+    /// When the client should send new input, even if nothing happens.
+    ///
+    /// [`Duration::MAX`] means only when there are new events.
+    pub repaint_delay: Duration,
+}
+
+impl std::fmt::Debug for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // `PlatformOutput` doesn't implement `Debug`, so we skip it.
+        f.debug_struct("Frame")
+            .field("textures_delta", &self.textures_delta)
+            .field("content", &self.content)
+            .field("pixels_per_point", &self.pixels_per_point)
+            .field("commands", &self.commands)
+            .field("repaint_delay", &self.repaint_delay)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Frame {
+    /// Convert the output of [`Context::run`] into a frame to send to the client.
+    ///
+    /// With [`Tessellation::Server`] this calls [`Context::tessellate`].
+    pub fn from_full_output(
+        ctx: &Context,
+        full_output: FullOutput,
+        tessellation: Tessellation,
+    ) -> Self {
+        crate::profile_function!();
+
+        let FullOutput {
+            platform_output,
+            textures_delta,
+            shapes,
+            pixels_per_point,
+            mut viewport_output,
+            stats: _,
+        } = full_output;
+
+        let root = viewport_output.remove(&ViewportId::ROOT);
+        let (commands, repaint_delay) = root.map_or((vec![], Duration::MAX), |root| {
+            (root.commands, root.repaint_delay)
+        });
+
+        let content = match tessellation {
+            Tessellation::Server => {
+                FrameContent::Primitives(ctx.tessellate(shapes, pixels_per_point))
+            }
+            Tessellation::Client => {
+                let (font_tex_size, prepared_discs) = ctx.fonts(|fonts| {
+                    let atlas = fonts.texture_atlas();
+                    let atlas = atlas.lock();
+                    (atlas.size(), atlas.prepared_discs())
+                });
+                FrameContent::Shapes {
+                    display_list: DisplayList {
+                        pixels_per_point,
+                        shapes,
+                    },
+                    font_tex_size,
+                    prepared_discs,
+                }
+            }
+        };
+
+        Self {
+            platform_output,
+            textures_delta,
+            content,
+            pixels_per_point,
+            commands,
+            repaint_delay,
+        }
+    }
+}
+
+impl FrameContent {
+    /// What to paint, tessellating it first if needed.
+    ///
+    /// The `options` are only used for [`Self::Shapes`].
+    pub fn into_primitives(self, options: TessellationOptions) -> Vec<ClippedPrimitive> {
+        crate::profile_function!();
+
+        match self {
+            Self::Primitives(primitives) => primitives,
+            Self::Shapes {
+                display_list,
+                font_tex_size,
+                prepared_discs,
+            } => Tessellator::new(
+                display_list.pixels_per_point,
+                options,
+                font_tex_size,
+                prepared_discs,
+            )
+            .tessellate_shapes(display_list.shapes),
+        }
+    }
+}
@@ -501,6 +501,26 @@ impl Response {
         self.changed
     }
 
+    /// Has the data stopped changing for `secs` seconds, after it was changed?
+    ///
+    /// Returns `true` once per pause, e.g. to only recompute something expensive after
+    /// the user has stopped dragging a slider for a moment:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut radius = 1.0;
+    /// # fn recompute(_: f32) {}
+    /// if ui.add(egui::Slider::new(&mut radius, 0.0..=10.0)).changed_debounced(0.5) {
+    ///     recompute(radius);
+    /// }
+    /// # });
+    /// ```
+    ///
+    /// This must be called every frame for the widget. See also [`Ui::debounce`].
+    pub fn changed_debounced(&self, secs: f32) -> bool {
+        crate::debounce::changed_debounced(self, secs)
+    }
+
     /// Report the data shown by this widget changed.
     ///
     /// This must be called by widgets that represent some mutable data,
@@ -740,6 +760,28 @@ impl Response {
         });
     }
 
+    /// Attach some data to this widget, to be read in later frames with [`Self::user_data`].
+    ///
+    /// The data is removed automatically once the widget is no longer shown.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.label("Some text");
+    /// let previous_width: Option<f32> = response.user_data();
+    /// response.set_user_data(response.rect.width());
+    /// # });
+    /// ```
+    ///
+    /// See [`Context::set_widget_user_data`].
+    pub fn set_user_data<T: 'static + Send + Sync>(&self, value: T) {
+        self.ctx.set_widget_user_data(self.id, value);
+    }
+
+    /// Read the data attached to this widget with [`Self::set_user_data`], e.g. in a previous frame.
+    pub fn user_data<T: 'static + Clone>(&self) -> Option<T> {
+        self.ctx.widget_user_data(self.id)
+    }
+
     /// For accessibility.
     ///
     /// Call after interacting and potential calls to [`Self::mark_changed`].
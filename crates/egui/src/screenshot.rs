@@ -0,0 +1,123 @@
+//! Screenshots of individual viewports, see [`Context::viewport_screenshot`].
+
+use std::sync::Arc;
+
+use crate::{mutex::Mutex, ColorImage, Context, Event, Id, Promise, ViewportCommand, ViewportId};
+
+type Fulfill = Box<dyn FnOnce(Arc<ColorImage>) + Send>;
+
+/// The screenshots we are waiting for.
+#[derive(Clone, Default)]
+struct State {
+    pending: Arc<Mutex<crate::ViewportIdMap<Vec<Fulfill>>>>,
+}
+
+pub(crate) fn request(ctx: &Context, viewport_id: ViewportId) -> Promise<Arc<ColorImage>> {
+    let (promise, fulfill) = Promise::new_pending(ctx);
+
+    let pending = ctx.data_mut(|d| d.get_temp_mut_or_default::<State>(Id::NULL).pending.clone());
+    let is_first = {
+        let mut pending = pending.lock();
+        let waiting = pending.entry(viewport_id).or_default();
+        waiting.push(Box::new(fulfill));
+        waiting.len() == 1
+    };
+
+    // One screenshot is enough for everyone waiting for this viewport:
+    if is_first {
+        ctx.send_viewport_cmd_to(viewport_id, ViewportCommand::Screenshot);
+    }
+    promise
+}
+
+/// Hands the screenshots of this frame to the requests waiting for them.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_end_frame("screenshot", Arc::new(end_frame));
+}
+
+fn end_frame(ctx: &Context) {
+    let Some(State { pending }) = ctx.data(|d| d.get_temp::<State>(Id::NULL)) else {
+        return;
+    };
+    {
+        let mut pending = pending.lock();
+        // Nobody will take a screenshot of a closed viewport:
+        pending.retain(|viewport_id, _| ctx.viewport_exists(*viewport_id));
+        if pending.is_empty() {
+            return;
+        }
+    }
+
+    let screenshots: Vec<(ViewportId, Arc<ColorImage>)> = ctx.input(|i| {
+        i.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Screenshot { viewport_id, image } => Some((*viewport_id, image.clone())),
+                _ => None,
+            })
+            .collect()
+    });
+
+    for (viewport_id, image) in screenshots {
+        let waiting = pending.lock().remove(&viewport_id).unwrap_or_default();
+        for fulfill in waiting {
+            fulfill(image.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending_viewports(ctx: &Context) -> Vec<ViewportId> {
+        ctx.data(|d| d.get_temp::<State>(Id::NULL))
+            .map(|state| state.pending.lock().keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    fn run_frame(ctx: &Context, events: Vec<Event>) {
+        let _ = ctx.run(
+            crate::RawInput {
+                events,
+                ..Default::default()
+            },
+            |_| {},
+        );
+    }
+
+    #[test]
+    fn fulfilled_by_the_screenshot_event() {
+        let ctx = Context::default();
+        let promise = ctx.viewport_screenshot(ViewportId::ROOT);
+        let second = ctx.viewport_screenshot(ViewportId::ROOT);
+
+        run_frame(&ctx, vec![]);
+        assert_eq!(pending_viewports(&ctx), [ViewportId::ROOT]);
+
+        let image = Arc::new(ColorImage::new([2, 2], crate::Color32::RED));
+        run_frame(
+            &ctx,
+            vec![Event::Screenshot {
+                viewport_id: ViewportId::ROOT,
+                image: image.clone(),
+            }],
+        );
+        assert_eq!(promise.ready(), Some(&image));
+        assert_eq!(second.ready(), Some(&image));
+        assert!(pending_viewports(&ctx).is_empty());
+    }
+
+    #[test]
+    fn forgotten_when_the_viewport_is_removed() {
+        let ctx = Context::default();
+        let child = ViewportId::from_hash_of("child");
+        let _promise = ctx.viewport_screenshot(child);
+
+        // The child is never shown, so egui removes it:
+        run_frame(&ctx, vec![]);
+        run_frame(&ctx, vec![]);
+        assert!(!ctx.viewport_exists(child));
+        assert!(pending_viewports(&ctx).is_empty());
+    }
+}
@@ -0,0 +1,598 @@
+//! A registry of app settings, with an automatically generated preferences UI.
+//!
+//! Declare your settings once with [`Context::register_setting`],
+//! read them with [`Context::setting`], and let the user edit them with [`Ui::settings_editor`].
+//!
+//! The values are stored in [`crate::Memory`], so they are persisted
+//! if egui is compiled with the `persistence` feature.
+//!
+//! ```
+//! # egui::__run_test_ctx(|ctx| {
+//! use egui::settings::Setting;
+//!
+//! ctx.register_setting(
+//!     Setting::bool("autosave", "Autosave", true)
+//!         .category("Editor")
+//!         .description("Save open files every minute."),
+//! );
+//! ctx.register_setting(Setting::integer("tab_width", "Tab width", 4, 1..=16).category("Editor"));
+//! ctx.register_setting(Setting::choice("theme", "Theme", ["Dark", "Light"], 0).category("Appearance"));
+//!
+//! let tab_width: i64 = ctx.setting("tab_width").unwrap_or(4);
+//!
+//! egui::Window::new("Preferences").show(ctx, |ui| {
+//!     ui.settings_editor();
+//! });
+//! # });
+//! ```
+
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+/// The value of a setting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SettingValue {
+    Bool(bool),
+    Number(f64),
+
+    /// Index into the options of a [`Setting::choice`].
+    Choice(usize),
+
+    Color(Color32),
+    KeyBinding(KeyboardShortcut),
+}
+
+/// A type that can be read from and written to a setting.
+pub trait SettingType: Sized {
+    fn from_value(value: SettingValue) -> Option<Self>;
+
+    fn into_value(self) -> SettingValue;
+}
+
+impl SettingType for bool {
+    fn from_value(value: SettingValue) -> Option<Self> {
+        match value {
+            SettingValue::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> SettingValue {
+        SettingValue::Bool(self)
+    }
+}
+
+macro_rules! impl_number_setting_type {
+    ($($t: ty),*) => {
+        $(
+            impl SettingType for $t {
+                fn from_value(value: SettingValue) -> Option<Self> {
+                    match value {
+                        SettingValue::Number(value) => Some(value as $t),
+                        _ => None,
+                    }
+                }
+
+                fn into_value(self) -> SettingValue {
+                    SettingValue::Number(self as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl SettingType for f64 {
+    fn from_value(value: SettingValue) -> Option<Self> {
+        match value {
+            SettingValue::Number(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> SettingValue {
+        SettingValue::Number(self)
+    }
+}
+
+impl_number_setting_type!(f32, i32, i64, u32, u64);
+
+/// The index of the chosen option of a [`Setting::choice`].
+impl SettingType for usize {
+    fn from_value(value: SettingValue) -> Option<Self> {
+        match value {
+            SettingValue::Choice(index) => Some(index),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> SettingValue {
+        SettingValue::Choice(self)
+    }
+}
+
+impl SettingType for Color32 {
+    fn from_value(value: SettingValue) -> Option<Self> {
+        match value {
+            SettingValue::Color(color) => Some(color),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> SettingValue {
+        SettingValue::Color(self)
+    }
+}
+
+impl SettingType for KeyboardShortcut {
+    fn from_value(value: SettingValue) -> Option<Self> {
+        match value {
+            SettingValue::KeyBinding(shortcut) => Some(shortcut),
+            _ => None,
+        }
+    }
+
+    fn into_value(self) -> SettingValue {
+        SettingValue::KeyBinding(self)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum SettingKind {
+    Bool,
+    Number {
+        range: RangeInclusive<f64>,
+        integer: bool,
+    },
+    Choice(Vec<String>),
+    Color,
+    KeyBinding,
+}
+
+/// The declaration of a setting, registered with [`Context::register_setting`].
+#[derive(Clone, Debug)]
+pub struct Setting {
+    key: String,
+    label: String,
+    category: String,
+    description: String,
+    kind: SettingKind,
+    default: SettingValue,
+}
+
+impl Setting {
+    fn new(
+        key: impl ToString,
+        label: impl ToString,
+        kind: SettingKind,
+        default: SettingValue,
+    ) -> Self {
+        Self {
+            key: key.to_string(),
+            label: label.to_string(),
+            category: "General".to_owned(),
+            description: String::new(),
+            kind,
+            default,
+        }
+    }
+
+    /// A checkbox.
+    ///
+    /// `key` identifies the setting, and is what you pass to [`Context::setting`].
+    /// `label` is what the user sees.
+    pub fn bool(key: impl ToString, label: impl ToString, default: bool) -> Self {
+        Self::new(key, label, SettingKind::Bool, SettingValue::Bool(default))
+    }
+
+    /// A slider for a number.
+    pub fn number(
+        key: impl ToString,
+        label: impl ToString,
+        default: f64,
+        range: RangeInclusive<f64>,
+    ) -> Self {
+        let kind = SettingKind::Number {
+            range,
+            integer: false,
+        };
+        Self::new(key, label, kind, SettingValue::Number(default))
+    }
+
+    /// A slider for a whole number.
+    pub fn integer(
+        key: impl ToString,
+        label: impl ToString,
+        default: i64,
+        range: RangeInclusive<i64>,
+    ) -> Self {
+        let kind = SettingKind::Number {
+            range: *range.start() as f64..=*range.end() as f64,
+            integer: true,
+        };
+        Self::new(key, label, kind, SettingValue::Number(default as f64))
+    }
+
+    /// A drop-down menu with the given options.
+    ///
+    /// The value is the index of the chosen option, which you read as a `usize`.
+    pub fn choice(
+        key: impl ToString,
+        label: impl ToString,
+        options: impl IntoIterator<Item = impl ToString>,
+        default: usize,
+    ) -> Self {
+        let options = options.into_iter().map(|o| o.to_string()).collect();
+        Self::new(
+            key,
+            label,
+            SettingKind::Choice(options),
+            SettingValue::Choice(default),
+        )
+    }
+
+    /// A color picker.
+    pub fn color(key: impl ToString, label: impl ToString, default: Color32) -> Self {
+        Self::new(key, label, SettingKind::Color, SettingValue::Color(default))
+    }
+
+    /// A keyboard shortcut, which the user can change by clicking it and pressing new keys.
+    pub fn key_binding(
+        key: impl ToString,
+        label: impl ToString,
+        default: KeyboardShortcut,
+    ) -> Self {
+        Self::new(
+            key,
+            label,
+            SettingKind::KeyBinding,
+            SettingValue::KeyBinding(default),
+        )
+    }
+
+    /// Which section of the settings editor the setting is shown in.
+    ///
+    /// Default: "General".
+    #[inline]
+    pub fn category(mut self, category: impl ToString) -> Self {
+        self.category = category.to_string();
+        self
+    }
+
+    /// Explains the setting to the user. Shown on hover, and searched.
+    #[inline]
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        query.is_empty()
+            || [&self.key, &self.label, &self.category, &self.description]
+                .iter()
+                .any(|text| text.to_lowercase().contains(query))
+    }
+}
+
+/// All registered settings, in the order they were registered.
+#[derive(Clone, Default)]
+struct Registry {
+    settings: Vec<Setting>,
+}
+
+fn registry(ctx: &Context) -> Registry {
+    ctx.data_mut(|d| d.get_temp_mut_or_default::<Registry>(Id::NULL).clone())
+}
+
+fn value_id(key: &str) -> Id {
+    Id::new(("egui_setting", key))
+}
+
+/// See [`Context::register_setting`].
+pub(crate) fn register(ctx: &Context, setting: Setting) {
+    ctx.data_mut(|d| {
+        let registry = d.get_temp_mut_or_default::<Registry>(Id::NULL);
+        if let Some(existing) = registry.settings.iter_mut().find(|s| s.key == setting.key) {
+            *existing = setting;
+        } else {
+            registry.settings.push(setting);
+        }
+    });
+}
+
+/// See [`Context::setting`].
+pub(crate) fn get<T: SettingType>(ctx: &Context, key: &str) -> Option<T> {
+    let value = ctx
+        .data_mut(|d| d.get_persisted::<SettingValue>(value_id(key)))
+        .or_else(|| {
+            registry(ctx)
+                .settings
+                .iter()
+                .find(|s| s.key == key)
+                .map(|s| s.default)
+        })?;
+    T::from_value(value)
+}
+
+/// See [`Context::set_setting`].
+pub(crate) fn set<T: SettingType>(ctx: &Context, key: &str, value: T) {
+    ctx.data_mut(|d| d.insert_persisted(value_id(key), value.into_value()));
+}
+
+#[derive(Clone, Default)]
+struct EditorState {
+    search: String,
+
+    /// The key binding that is waiting for the user to press a key.
+    capturing: Option<String>,
+}
+
+/// See [`Ui::settings_editor`].
+pub(crate) fn editor_ui(ui: &mut Ui) -> Response {
+    let ctx = ui.ctx().clone();
+    let id = ui.make_persistent_id("settings_editor");
+    let mut state: EditorState = ui.data(|d| d.get_temp(id)).unwrap_or_default();
+    let registry = registry(&ctx);
+    let mut changed = false;
+
+    let mut response = ui
+        .vertical(|ui| {
+            ui.add(
+                TextEdit::singleline(&mut state.search)
+                    .hint_text("🔍 Search settings…")
+                    .desired_width(f32::INFINITY),
+            );
+            let query = state.search.trim().to_lowercase();
+
+            let mut categories: Vec<&str> = vec![];
+            for setting in &registry.settings {
+                if !categories.contains(&setting.category.as_str()) {
+                    categories.push(&setting.category);
+                }
+            }
+
+            ScrollArea::vertical()
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    let mut any_match = false;
+                    for category in categories {
+                        let settings: Vec<&Setting> = registry
+                            .settings
+                            .iter()
+                            .filter(|s| s.category == category && s.matches(&query))
+                            .collect();
+                        if settings.is_empty() {
+                            continue;
+                        }
+                        any_match = true;
+
+                        // Show all matches while searching:
+                        let open = (!query.is_empty()).then_some(true);
+                        CollapsingHeader::new(category)
+                            .id_source(id.with(category))
+                            .default_open(true)
+                            .open(open)
+                            .show(ui, |ui| {
+                                Grid::new(id.with(("grid", category)))
+                                    .num_columns(3)
+                                    .striped(true)
+                                    .show(ui, |ui| {
+                                        for setting in settings {
+                                            changed |= setting_row_ui(ui, &mut state, setting);
+                                            ui.end_row();
+                                        }
+                                    });
+                            });
+                    }
+
+                    if !any_match {
+                        ui.weak(if registry.settings.is_empty() {
+                            "No settings have been registered."
+                        } else {
+                            "No matching settings."
+                        });
+                    }
+                });
+        })
+        .response;
+
+    ui.data_mut(|d| d.insert_temp(id, state));
+
+    if changed {
+        response.mark_changed();
+    }
+    response
+}
+
+/// Returns `true` if the value was changed.
+fn setting_row_ui(ui: &mut Ui, state: &mut EditorState, setting: &Setting) -> bool {
+    let ctx = ui.ctx().clone();
+    let old_value = ctx
+        .data_mut(|d| d.get_persisted::<SettingValue>(value_id(&setting.key)))
+        .unwrap_or(setting.default);
+    let mut value = old_value;
+
+    let label = ui.label(&setting.label);
+    if !setting.description.is_empty() {
+        label.on_hover_text(&setting.description);
+    }
+
+    match (&setting.kind, &mut value) {
+        (SettingKind::Bool, SettingValue::Bool(checked)) => {
+            ui.checkbox(checked, "");
+        }
+        (SettingKind::Number { range, integer }, SettingValue::Number(number)) => {
+            let mut slider = Slider::new(number, range.clone());
+            if *integer {
+                slider = slider.integer();
+            }
+            ui.add(slider);
+        }
+        (SettingKind::Choice(options), SettingValue::Choice(index)) => {
+            let selected_text = options.get(*index).cloned().unwrap_or_default();
+            ComboBox::from_id_source(value_id(&setting.key))
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    for (i, option) in options.iter().enumerate() {
+                        ui.selectable_value(index, i, option);
+                    }
+                });
+        }
+        (SettingKind::Color, SettingValue::Color(color)) => {
+            ui.color_edit_button_srgba(color);
+        }
+        (SettingKind::KeyBinding, SettingValue::KeyBinding(shortcut)) => {
+            key_binding_ui(ui, state, &setting.key, shortcut);
+        }
+        _ => {
+            // The stored value is of the wrong type, e.g. because the setting changed type.
+            if ui.button("Reset").clicked() {
+                value = setting.default;
+            }
+        }
+    }
+
+    let is_default = value == setting.default;
+    if ui
+        .add_enabled(!is_default, Button::new("↺").frame(false))
+        .on_hover_text("Reset to default")
+        .clicked()
+    {
+        value = setting.default;
+    }
+
+    if value != old_value {
+        ctx.data_mut(|d| d.insert_persisted(value_id(&setting.key), value));
+        true
+    } else {
+        false
+    }
+}
+
+fn key_binding_ui(
+    ui: &mut Ui,
+    state: &mut EditorState,
+    key: &str,
+    shortcut: &mut KeyboardShortcut,
+) {
+    let is_capturing = state.capturing.as_deref() == Some(key);
+
+    if is_capturing {
+        // Wait for a key press, and make sure nothing else reacts to it:
+        let pressed = ui.input_mut(|i| {
+            let pressed = i.events.iter().find_map(|event| match event {
+                Event::Key {
+                    key,
+                    pressed: true,
+                    modifiers,
+                    ..
+                } => Some(KeyboardShortcut::new(*modifiers, *key)),
+                _ => None,
+            });
+            if let Some(pressed) = pressed {
+                i.consume_shortcut(&pressed);
+            }
+            pressed
+        });
+        match pressed {
+            Some(pressed) if pressed.logical_key == Key::Escape => {
+                state.capturing = None;
+            }
+            Some(pressed) => {
+                *shortcut = pressed;
+                state.capturing = None;
+            }
+            None => {}
+        }
+    }
+
+    let text = if is_capturing {
+        "Press a key…".to_owned()
+    } else {
+        ui.ctx().format_shortcut(shortcut)
+    };
+    let response = ui
+        .add(Button::new(text).selected(is_capturing))
+        .on_hover_text("Click, then press the new shortcut. Escape cancels.");
+    if response.clicked() {
+        state.capturing = if is_capturing {
+            None
+        } else {
+            Some(key.to_owned())
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn register_all(ctx: &Context) {
+        ctx.register_setting(Setting::bool("autosave", "Autosave", true));
+        ctx.register_setting(Setting::integer("tab_width", "Tab width", 4, 1..=16));
+        ctx.register_setting(Setting::choice("theme", "Theme", ["Dark", "Light"], 1));
+        ctx.register_setting(Setting::color("accent", "Accent", Color32::RED));
+    }
+
+    fn registered() -> Context {
+        let ctx = Context::default();
+        register_all(&ctx);
+        ctx
+    }
+
+    #[test]
+    fn defaults_until_set() {
+        let ctx = registered();
+        assert_eq!(ctx.setting::<bool>("autosave"), Some(true));
+        assert_eq!(ctx.setting::<i64>("tab_width"), Some(4));
+        assert_eq!(ctx.setting::<usize>("theme"), Some(1));
+        assert_eq!(ctx.setting::<Color32>("accent"), Some(Color32::RED));
+    }
+
+    #[test]
+    fn missing_key_or_wrong_type_is_none() {
+        let ctx = registered();
+        assert_eq!(ctx.setting::<bool>("no_such_setting"), None);
+        assert_eq!(ctx.setting::<bool>("tab_width"), None);
+        assert_eq!(ctx.setting::<Color32>("autosave"), None);
+    }
+
+    #[test]
+    fn set_and_get() {
+        let ctx = registered();
+        ctx.set_setting("autosave", false);
+        ctx.set_setting("tab_width", 8_i64);
+        ctx.set_setting("accent", Color32::BLUE);
+        assert_eq!(ctx.setting::<bool>("autosave"), Some(false));
+        assert_eq!(ctx.setting::<i64>("tab_width"), Some(8));
+        assert_eq!(ctx.setting::<u32>("tab_width"), Some(8));
+        assert_eq!(ctx.setting::<Color32>("accent"), Some(Color32::BLUE));
+    }
+
+    #[test]
+    fn registering_again_replaces_the_declaration() {
+        let ctx = registered();
+        ctx.register_setting(Setting::bool("autosave", "Save automatically", false));
+        assert_eq!(registry(&ctx).settings.len(), 4);
+        assert_eq!(ctx.setting::<bool>("autosave"), Some(false));
+    }
+
+    #[cfg(feature = "persistence")]
+    #[test]
+    fn values_survive_a_store_and_load() {
+        let ctx = registered();
+        ctx.set_setting("tab_width", 2_i64);
+        ctx.set_setting("theme", 0_usize);
+        let stored = ctx.memory(ron::to_string).unwrap();
+
+        // Apps load their memory at startup, before declaring their settings:
+        let ctx = Context::default();
+        let loaded: Memory = ron::from_str(&stored).unwrap();
+        ctx.memory_mut(|m| *m = loaded);
+        register_all(&ctx);
+        assert_eq!(ctx.setting::<i64>("tab_width"), Some(2));
+        assert_eq!(ctx.setting::<usize>("theme"), Some(0));
+        assert_eq!(ctx.setting::<bool>("autosave"), Some(true), "never set");
+    }
+}
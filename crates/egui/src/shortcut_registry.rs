@@ -0,0 +1,178 @@
+//! Keyboard shortcuts registered with [`Context::register_shortcut`],
+//! listed by [`Context::shortcut_help_window`].
+
+use std::sync::Arc;
+
+use crate::{Button, Context, Grid, Id, KeyboardShortcut, RichText, TextEdit, Window};
+
+/// A keyboard shortcut registered with [`Context::register_shortcut`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegisteredShortcut {
+    /// What part of the app the shortcut belongs to, e.g. `"File"` or `"Editor"`.
+    pub category: String,
+
+    /// What the shortcut does, e.g. `"Save"`.
+    pub name: String,
+
+    pub shortcut: KeyboardShortcut,
+}
+
+impl RegisteredShortcut {
+    fn is_same_command(&self, category: &str, name: &str) -> bool {
+        self.category == category && self.name == name
+    }
+}
+
+#[derive(Clone, Default)]
+struct State {
+    /// Registered this frame.
+    current: Vec<RegisteredShortcut>,
+
+    /// Registered last frame.
+    previous: Vec<RegisteredShortcut>,
+
+    /// A command clicked in the help window, and the frame it was clicked.
+    ///
+    /// It is triggered the next time it is registered.
+    triggered: Option<(u64, String, String)>,
+}
+
+/// Starts a new list of registered shortcuts every frame, and forgets stale clicks.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_end_frame("shortcut_registry", Arc::new(end_frame));
+}
+
+fn end_frame(ctx: &Context) {
+    let frame_nr = ctx.frame_nr();
+    ctx.data_mut(|d| {
+        let state = d.get_temp_mut_or_default::<State>(Id::NULL);
+        state.previous = std::mem::take(&mut state.current);
+        // Forget clicks on commands that were not registered the next frame:
+        if let Some((clicked_frame_nr, _, _)) = &state.triggered {
+            if *clicked_frame_nr < frame_nr {
+                state.triggered = None;
+            }
+        }
+    });
+}
+
+pub(crate) fn register_shortcut(
+    ctx: &Context,
+    category: String,
+    name: String,
+    shortcut: KeyboardShortcut,
+) -> bool {
+    let pressed = ctx.input_mut(|i| i.consume_shortcut(&shortcut));
+    ctx.data_mut(|d| {
+        let state = d.get_temp_mut_or_default::<State>(Id::NULL);
+        let clicked = matches!(
+            &state.triggered,
+            Some((_, c, n)) if *c == category && *n == name
+        );
+        if clicked {
+            state.triggered = None;
+        }
+        state.current.push(RegisteredShortcut {
+            category,
+            name,
+            shortcut,
+        });
+        pressed || clicked
+    })
+}
+
+pub(crate) fn registered_shortcuts(ctx: &Context) -> Vec<RegisteredShortcut> {
+    ctx.data(|d| {
+        let Some(state) = d.get_temp::<State>(Id::NULL) else {
+            return Vec::new();
+        };
+        let mut shortcuts = state.current.clone();
+        // Include the shortcuts that haven't been registered yet this frame:
+        for old in state.previous {
+            if !shortcuts
+                .iter()
+                .any(|s| s.is_same_command(&old.category, &old.name))
+            {
+                shortcuts.push(old);
+            }
+        }
+        shortcuts
+    })
+}
+
+pub(crate) fn help_window(ctx: &Context, open: &mut bool) {
+    let search_id = Id::new("egui_shortcut_help_search");
+    let mut search: String = ctx.data(|d| d.get_temp(search_id)).unwrap_or_default();
+    let mut clicked = None;
+
+    Window::new("⌨ Keyboard shortcuts")
+        .open(open)
+        .vscroll(true)
+        .show(ctx, |ui| {
+            ui.add(TextEdit::singleline(&mut search).hint_text("Search…"));
+            ui.separator();
+
+            let query = search.to_lowercase();
+            let shortcuts: Vec<(RegisteredShortcut, String)> = registered_shortcuts(ctx)
+                .into_iter()
+                .map(|s| {
+                    let formatted = ctx.format_shortcut(&s.shortcut);
+                    (s, formatted)
+                })
+                .filter(|(s, formatted)| {
+                    [&s.category, &s.name, formatted]
+                        .iter()
+                        .any(|text| text.to_lowercase().contains(&query))
+                })
+                .collect();
+
+            if shortcuts.is_empty() {
+                ui.weak("No matching shortcuts");
+            }
+
+            // Keep the categories in the order they were first registered:
+            let mut categories: Vec<&str> = Vec::new();
+            for (s, _) in &shortcuts {
+                if !categories.contains(&s.category.as_str()) {
+                    categories.push(&s.category);
+                }
+            }
+
+            for category in categories {
+                ui.strong(category);
+                Grid::new(("egui_shortcut_help", category))
+                    .num_columns(2)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (s, formatted) in
+                            shortcuts.iter().filter(|(s, _)| s.category == category)
+                        {
+                            if ui
+                                .add(Button::new(&s.name).frame(false))
+                                .on_hover_text("Click to run")
+                                .clicked()
+                            {
+                                clicked = Some((s.category.clone(), s.name.clone()));
+                            }
+                            ui.label(RichText::new(formatted).monospace());
+                            ui.end_row();
+                        }
+                    });
+                ui.add_space(4.0);
+            }
+        });
+
+    let frame_nr = ctx.frame_nr();
+    let any_clicked = clicked.is_some();
+    ctx.data_mut(|d| {
+        d.insert_temp(search_id, search);
+        if let Some((category, name)) = clicked {
+            d.get_temp_mut_or_default::<State>(Id::NULL).triggered =
+                Some((frame_nr, category, name));
+        }
+    });
+    if any_clicked {
+        // Run the command right away:
+        ctx.request_repaint();
+    }
+}
@@ -219,6 +219,37 @@ pub struct Style {
 
     /// If true and scrolling is enabled for only one direction, allow horizontal scrolling without pressing shift
     pub always_scroll_the_only_direction: bool,
+
+    /// Which way the ui is read.
+    ///
+    /// With [`LayoutDirection::RightToLeft`] (e.g. for Arabic and Hebrew) the ui is mirrored:
+    /// [`crate::Ui::horizontal`] and [`crate::Ui::vertical`] start from the right,
+    /// [`crate::SidePanel::left`] goes on the right side, menus open towards the left,
+    /// horizontal sliders grow towards the left,
+    /// and the icons of checkboxes, radio buttons and collapsing headers are on the right.
+    ///
+    /// Layouts you create explicitly (e.g. with [`crate::Layout::left_to_right`]) are not mirrored,
+    /// but see [`crate::Layout::mirrored`].
+    pub layout_direction: LayoutDirection,
+}
+
+/// Which way the ui is read, see [`Style::layout_direction`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LayoutDirection {
+    /// E.g. English.
+    #[default]
+    LeftToRight,
+
+    /// E.g. Arabic and Hebrew.
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    #[inline]
+    pub fn is_right_to_left(self) -> bool {
+        self == Self::RightToLeft
+    }
 }
 
 impl Style {
@@ -323,6 +354,15 @@ pub struct Spacing {
 
     /// Controls the spacing of a [`crate::ScrollArea`].
     pub scroll: ScrollStyle,
+
+    /// Default width/height of each symbol of a [`crate::Rating`].
+    pub rating_symbol_size: f32,
+
+    /// Width/height of the `-` and `+` buttons of a [`crate::Stepper`].
+    pub stepper_button_size: f32,
+
+    /// Horizontal spacing between the buttons and the number of a [`crate::Stepper`].
+    pub stepper_spacing: f32,
 }
 
 impl Spacing {
@@ -339,6 +379,22 @@ impl Spacing {
 
         (small_icon_rect, big_icon_rect)
     }
+
+    /// Like [`Self::icon_rectangles`], but with the icon at the right end of `rect`
+    /// for [`LayoutDirection::RightToLeft`].
+    pub fn icon_rectangles_for_direction(
+        &self,
+        rect: Rect,
+        direction: LayoutDirection,
+    ) -> (Rect, Rect) {
+        if direction.is_right_to_left() {
+            let mut icon_rect = rect;
+            icon_rect.min.x = rect.max.x - self.icon_width;
+            self.icon_rectangles(icon_rect)
+        } else {
+            self.icon_rectangles(rect)
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -666,6 +722,10 @@ pub struct TextCursorStyle {
 
     /// When blinking, this is how long the cursor is invisible.
     pub off_duration: f32,
+
+    /// When selecting text on a touch screen, show a magnified copy of the text
+    /// above the finger, and handles for dragging the ends of the selection.
+    pub touch_loupe: bool,
 }
 
 impl Default for TextCursorStyle {
@@ -676,6 +736,7 @@ impl Default for TextCursorStyle {
             blink: true,
             on_duration: 0.5,
             off_duration: 0.5,
+            touch_loupe: true,
         }
     }
 }
@@ -796,6 +857,14 @@ pub struct Visuals {
 
     /// How to display numeric color values.
     pub numeric_color_space: NumericColorSpace,
+
+    /// Color of the filled part of a [`crate::Rating`].
+    pub rating_filled_color: Color32,
+
+    /// Color of the empty part of a [`crate::Rating`].
+    ///
+    /// If `None` (default), [`Self::weak_text_color`] is used.
+    pub rating_empty_color: Option<Color32>,
 }
 
 impl Visuals {
@@ -842,6 +911,15 @@ impl Visuals {
     pub fn gray_out(&self, color: Color32) -> Color32 {
         crate::ecolor::tint_color_towards(color, self.fade_out_to_color())
     }
+
+    /// The color of the `index`:th data series of a chart or plot.
+    ///
+    /// These are distinct colors that stand out against the background of this theme.
+    /// See [`crate::ecolor::color_palette::categorical`].
+    #[inline]
+    pub fn series_color(&self, index: usize) -> Color32 {
+        crate::ecolor::color_palette::categorical(index, self.dark_mode)
+    }
 }
 
 /// Selected text, selected elements etc
@@ -989,6 +1067,15 @@ pub struct DebugOptions {
 
     /// Show interesting widgets under the mouse cursor.
     pub show_widget_hits: bool,
+
+    /// Show rulers along the top and left edges of the screen.
+    ///
+    /// Drag from a ruler to add a guide line, and drag it back onto the ruler to remove it.
+    pub show_rulers: bool,
+
+    /// Snap dragged windows to the edges and centers of other windows and to the guide lines,
+    /// and show where they align.
+    pub show_alignment_guides: bool,
 }
 
 #[cfg(debug_assertions)]
@@ -1004,6 +1091,8 @@ impl Default for DebugOptions {
             show_resize: false,
             show_interactive_widgets: false,
             show_widget_hits: false,
+            show_rulers: false,
+            show_alignment_guides: false,
         }
     }
 }
@@ -1041,6 +1130,7 @@ impl Default for Style {
             explanation_tooltips: false,
             url_in_tooltip: false,
             always_scroll_the_only_direction: false,
+            layout_direction: LayoutDirection::LeftToRight,
         }
     }
 }
@@ -1067,6 +1157,9 @@ impl Default for Spacing {
             combo_height: 200.0,
             scroll: Default::default(),
             indent_ends_with_horizontal_line: false,
+            rating_symbol_size: 18.0,
+            stepper_button_size: 18.0,
+            stepper_spacing: 2.0,
         }
     }
 }
@@ -1141,6 +1234,9 @@ impl Visuals {
             image_loading_spinners: true,
 
             numeric_color_space: NumericColorSpace::GammaByte,
+
+            rating_filled_color: Color32::from_rgb(255, 143, 0), // orange
+            rating_empty_color: None,
         }
     }
 
@@ -1180,9 +1276,37 @@ impl Visuals {
                 ..Default::default()
             },
 
+            rating_filled_color: Color32::from_rgb(255, 100, 0), // same as `warn_fg_color`
+
             ..Self::dark()
         }
     }
+
+    /// Make the text and outlines of widgets stand out more against their background.
+    ///
+    /// Applied when the user prefers high contrast, see [`crate::SystemPreferences::high_contrast`].
+    pub fn increase_contrast(&mut self) {
+        let strong = if self.dark_mode {
+            Color32::WHITE
+        } else {
+            Color32::BLACK
+        };
+
+        let Widgets {
+            noninteractive,
+            inactive,
+            hovered,
+            active,
+            open,
+        } = &mut self.widgets;
+        for widget in [noninteractive, inactive, hovered, active, open] {
+            widget.fg_stroke.color = strong;
+            widget.bg_stroke = Stroke::new(widget.bg_stroke.width.max(1.0), strong);
+        }
+
+        self.window_stroke.color = strong;
+        self.selection.stroke.color = strong;
+    }
 }
 
 impl Default for Visuals {
@@ -1332,6 +1456,7 @@ impl Style {
             explanation_tooltips,
             url_in_tooltip,
             always_scroll_the_only_direction,
+            layout_direction,
         } = self;
 
         visuals.light_dark_radio_buttons(ui);
@@ -1389,6 +1514,21 @@ impl Style {
                     .suffix(" s"),
             );
             ui.end_row();
+
+            ui.label("Layout direction");
+            ui.horizontal(|ui| {
+                ui.radio_value(
+                    layout_direction,
+                    LayoutDirection::LeftToRight,
+                    "Left to right",
+                );
+                ui.radio_value(
+                    layout_direction,
+                    LayoutDirection::RightToLeft,
+                    "Right to left",
+                );
+            });
+            ui.end_row();
         });
 
         ui.collapsing("🔠 Text Styles", |ui| text_styles_ui(ui, text_styles));
@@ -1451,6 +1591,9 @@ impl Spacing {
             indent_ends_with_horizontal_line,
             combo_height,
             scroll,
+            rating_symbol_size,
+            stepper_button_size,
+            stepper_spacing,
         } = self;
 
         Grid::new("spacing")
@@ -1512,6 +1655,25 @@ impl Spacing {
                 ui.add(DragValue::new(menu_spacing).clamp_range(0.0..=10.0));
                 ui.end_row();
 
+                ui.label("Rating symbol size");
+                ui.add(DragValue::new(rating_symbol_size).clamp_range(4.0..=60.0));
+                ui.end_row();
+
+                ui.label("Stepper");
+                ui.vertical(|ui| {
+                    ui.add(
+                        DragValue::new(stepper_button_size)
+                            .prefix("button size:")
+                            .clamp_range(4.0..=60.0),
+                    );
+                    ui.add(
+                        DragValue::new(stepper_spacing)
+                            .prefix("spacing:")
+                            .clamp_range(0.0..=20.0),
+                    );
+                });
+                ui.end_row();
+
                 ui.label("Checkboxes etc");
                 ui.vertical(|ui| {
                     ui.add(
@@ -1786,6 +1948,9 @@ impl Visuals {
             image_loading_spinners,
 
             numeric_color_space,
+
+            rating_filled_color,
+            rating_empty_color,
         } = self;
 
         ui.collapsing("Background Colors", |ui| {
@@ -1916,6 +2081,23 @@ impl Visuals {
             });
         });
 
+        ui.collapsing("Rating", |ui| {
+            ui_color(ui, rating_filled_color, "Filled");
+
+            ui.horizontal(|ui| {
+                let mut override_empty = rating_empty_color.is_some();
+                ui.checkbox(&mut override_empty, "Empty");
+                if override_empty {
+                    let color = rating_empty_color.get_or_insert(Color32::GRAY);
+                    ui.color_edit_button_srgba(color);
+                } else {
+                    *rating_empty_color = None;
+                }
+            })
+            .response
+            .on_hover_text("If unchecked, the weak text color is used");
+        });
+
         ui.vertical_centered(|ui| reset_button(ui, self, "Reset visuals"));
     }
 }
@@ -1928,6 +2110,7 @@ impl TextCursorStyle {
             blink,
             on_duration,
             off_duration,
+            touch_loupe,
         } = self;
 
         ui.horizontal(|ui| {
@@ -1960,6 +2143,8 @@ impl TextCursorStyle {
                 ui.end_row();
             });
         }
+
+        ui.checkbox(touch_loupe, "Loupe and handles for touch selection");
     }
 }
 
@@ -1975,6 +2160,8 @@ impl DebugOptions {
             show_resize,
             show_interactive_widgets,
             show_widget_hits,
+            show_rulers,
+            show_alignment_guides,
         } = self;
 
         {
@@ -2004,6 +2191,12 @@ impl DebugOptions {
 
         ui.checkbox(show_widget_hits, "Show widgets under mouse pointer");
 
+        ui.checkbox(show_rulers, "Show rulers (drag from them to add guides)");
+        ui.checkbox(
+            show_alignment_guides,
+            "Snap dragged windows to aligned edges and guides",
+        );
+
         ui.vertical_centered(|ui| reset_button(ui, self, "Reset debug options"));
     }
 }
@@ -1,10 +1,15 @@
+use std::sync::Arc;
+
 use crate::{
     layers::ShapeIdx, text::CCursor, text_selection::CCursorRange, Context, CursorIcon, Event,
     Galley, Id, LayerId, Pos2, Rect, Response, Ui,
 };
 
 use super::{
-    text_cursor_state::cursor_rect, visuals::paint_text_selection, CursorRange, TextCursorState,
+    text_cursor_state::cursor_rect,
+    touch::{self, SelectionEnd, SelectionHandles},
+    visuals::paint_text_selection,
+    CursorRange, TextCursorState,
 };
 
 /// Turn on to help debug this
@@ -292,7 +297,12 @@ impl LabelSelectionState {
     ///
     /// This should be called after painting the text, because this will also
     /// paint the text cursor/selection on top.
-    pub fn label_text_selection(ui: &Ui, response: &Response, galley_pos: Pos2, galley: &Galley) {
+    pub fn label_text_selection(
+        ui: &Ui,
+        response: &Response,
+        galley_pos: Pos2,
+        galley: &Arc<Galley>,
+    ) {
         let mut state = Self::load(ui.ctx());
         state.on_label(ui, response, galley_pos, galley);
         state.store(ui.ctx());
@@ -468,7 +478,45 @@ impl LabelSelectionState {
         }
     }
 
-    fn on_label(&mut self, ui: &Ui, response: &Response, galley_pos: Pos2, galley: &Galley) {
+    /// On touch screens: show handles for moving the ends of the selection that are in this widget.
+    fn touch_selection_handles(
+        &mut self,
+        ui: &Ui,
+        response: &Response,
+        galley_pos: Pos2,
+        galley: &Galley,
+    ) -> SelectionHandles {
+        let Some(selection) = &mut self.selection else {
+            return SelectionHandles::default();
+        };
+
+        let is_empty = selection.primary.widget_id == selection.secondary.widget_id
+            && selection.primary.ccursor == selection.secondary.ccursor;
+        if is_empty || selection.layer_id != response.layer_id || !touch::is_enabled(ui) {
+            return SelectionHandles::default();
+        }
+
+        let ends: Vec<_> = [
+            (SelectionEnd::Primary, selection.primary),
+            (SelectionEnd::Secondary, selection.secondary),
+        ]
+        .into_iter()
+        .filter(|(_, cursor)| cursor.widget_id == response.id)
+        .map(|(end, cursor)| (end, galley.from_ccursor(cursor.ccursor)))
+        .collect();
+
+        let handles = touch::selection_handles(ui, response.id, galley_pos, galley, &ends);
+        if let Some(drag) = handles.dragged {
+            let cursor = WidgetTextCursor::new(response.id, drag.cursor, galley_pos, galley);
+            match drag.end {
+                SelectionEnd::Primary => selection.primary = cursor,
+                SelectionEnd::Secondary => selection.secondary = cursor,
+            }
+        }
+        handles
+    }
+
+    fn on_label(&mut self, ui: &Ui, response: &Response, galley_pos: Pos2, galley: &Arc<Galley>) {
         let widget_id = response.id;
 
         if response.hovered {
@@ -478,6 +526,9 @@ impl LabelSelectionState {
         self.any_hovered |= response.hovered();
         self.is_dragging |= response.is_pointer_button_down_on(); // we don't want the initial latency of drag vs click decision
 
+        let handles = self.touch_selection_handles(ui, response, galley_pos, galley);
+        self.any_hovered |= handles.hovered;
+
         let old_selection = self.selection;
 
         let mut cursor_state = self.cursor_for(ui, response, galley_pos, galley);
@@ -485,7 +536,7 @@ impl LabelSelectionState {
         let old_range = cursor_state.range(galley);
 
         if let Some(pointer_pos) = ui.ctx().pointer_interact_pos() {
-            if response.contains_pointer() {
+            if response.contains_pointer() && !handles.hovered {
                 let cursor_at_pointer = galley.cursor_from_pos(pointer_pos - galley_pos);
 
                 // This is where we handle start-of-drag and double-click-to-select.
@@ -582,6 +633,30 @@ impl LabelSelectionState {
             &cursor_state,
             &mut self.painted_shape_idx,
         );
+
+        let is_touch_dragging_here = self.is_dragging
+            && touch::is_enabled(ui)
+            && ui.input(|i| i.any_touches())
+            && self
+                .selection
+                .map_or(false, |selection| selection.primary.widget_id == widget_id);
+        let loupe_focus = if let Some(drag) = handles.dragged {
+            Some(drag.pos)
+        } else if is_touch_dragging_here {
+            ui.ctx().pointer_interact_pos()
+        } else {
+            None
+        };
+        if let Some(focus) = loupe_focus {
+            touch::paint_loupe(
+                ui,
+                focus,
+                galley_pos,
+                galley,
+                ui.visuals().text_color(),
+                new_range.as_ref(),
+            );
+        }
     }
 }
 
@@ -629,7 +704,7 @@ fn selected_text(galley: &Galley, cursor_range: &CursorRange) -> String {
     }
 }
 
-fn estimate_row_height(galley: &Galley) -> f32 {
+pub(super) fn estimate_row_height(galley: &Galley) -> f32 {
     if let Some(row) = galley.rows.first() {
         row.rect.height()
     } else {
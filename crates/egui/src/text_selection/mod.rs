@@ -6,6 +6,7 @@ pub mod accesskit_text;
 mod cursor_range;
 mod label_text_selection;
 pub mod text_cursor_state;
+pub mod touch;
 pub mod visuals;
 
 pub use cursor_range::{CCursorRange, CursorRange, PCursorRange};
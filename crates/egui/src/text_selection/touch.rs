@@ -0,0 +1,205 @@
+//! Text selection on touch screens, where the finger hides what is being selected.
+//!
+//! We show a magnifying loupe above the finger, and handles for dragging the ends of the selection.
+
+use std::sync::Arc;
+
+use emath::TSTransform;
+use epaint::text::{cursor::Cursor, Galley};
+
+use crate::*;
+
+use super::{
+    label_text_selection::estimate_row_height,
+    text_cursor_state::cursor_rect,
+    visuals::{paint_cursor_end, selection_rects},
+    CursorRange,
+};
+
+/// How much the loupe magnifies the text.
+const LOUPE_ZOOM: f32 = 2.0;
+
+/// Width of the loupe, in points.
+const LOUPE_WIDTH: f32 = 120.0;
+
+/// Should we show the loupe and the selection handles?
+///
+/// Only on touch screens, and only if [`crate::style::TextCursorStyle::touch_loupe`] is set.
+pub fn is_enabled(ui: &Ui) -> bool {
+    ui.visuals().text_cursor.touch_loupe && ui.input(|i| i.has_touch_screen())
+}
+
+/// Which end of a selection a handle belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SelectionEnd {
+    /// See [`CursorRange::primary`].
+    Primary,
+
+    /// See [`CursorRange::secondary`].
+    Secondary,
+}
+
+/// A selection handle that is being dragged.
+#[derive(Clone, Copy, Debug)]
+pub struct HandleDrag {
+    /// Which end of the selection is being moved.
+    pub end: SelectionEnd,
+
+    /// The new position of that end.
+    pub cursor: Cursor,
+
+    /// The point in the text the handle is aimed at.
+    ///
+    /// This is a bit above the finger, so this is where the loupe should look.
+    pub pos: Pos2,
+}
+
+/// The result of [`selection_handles`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SelectionHandles {
+    /// Is the pointer on, or pressing, any of the handles?
+    pub hovered: bool,
+
+    /// Set if one of the handles is being dragged.
+    pub dragged: Option<HandleDrag>,
+}
+
+/// Show and interact with a draggable handle below each of the given ends of a selection.
+///
+/// The handles are identified by `id` and their [`SelectionEnd`],
+/// so they stay the same while the two ends of the selection cross over each other.
+pub fn selection_handles(
+    ui: &Ui,
+    id: Id,
+    galley_pos: Pos2,
+    galley: &Galley,
+    ends: &[(SelectionEnd, Cursor)],
+) -> SelectionHandles {
+    let row_height = estimate_row_height(galley);
+    let radius = 0.5 * ui.spacing().interact_size.y;
+    let stroke = ui.visuals().text_cursor.stroke;
+
+    let mut handles = SelectionHandles::default();
+
+    for &(end, cursor) in ends {
+        let knob_rect = |cursor: &Cursor| {
+            let cursor_rect = cursor_rect(galley_pos, galley, cursor, row_height);
+            let center = cursor_rect.center_bottom() + vec2(0.0, radius);
+            (
+                cursor_rect,
+                Rect::from_center_size(center, Vec2::splat(2.0 * radius)),
+            )
+        };
+
+        let (cursor_rect, knob) = knob_rect(&cursor);
+        // Sense clicks too, so a tap on a handle isn't a tap on the text below it:
+        let response = ui.interact(knob, id.with(end), Sense::click_and_drag());
+        handles.hovered |= response.hovered() || response.is_pointer_button_down_on();
+
+        let mut cursor = cursor;
+        if response.dragged() {
+            if let Some(pointer_pos) = response.interact_pointer_pos() {
+                // Aim for the row above the knob, not for the knob under the finger:
+                let pos = pointer_pos - vec2(0.0, radius + 0.5 * cursor_rect.height());
+                cursor = galley.cursor_from_pos(pos - galley_pos);
+                handles.dragged = Some(HandleDrag { end, cursor, pos });
+            }
+        }
+
+        let (cursor_rect, knob) = knob_rect(&cursor);
+        if ui.is_rect_visible(knob) {
+            let painter = ui.painter();
+            painter.line_segment([cursor_rect.center_bottom(), knob.center()], stroke);
+            painter.circle_filled(knob.center(), 0.75 * radius, stroke.color);
+        }
+    }
+
+    handles
+}
+
+/// Paint a magnified copy of the text around `focus`, above it,
+/// so that it can be seen even though it is hidden under a finger.
+///
+/// The text and selection are painted again, transformed, on a layer above everything else.
+/// `fallback_color` is the color the galley was painted with.
+pub fn paint_loupe(
+    ui: &Ui,
+    focus: Pos2,
+    galley_pos: Pos2,
+    galley: &Arc<Galley>,
+    fallback_color: Color32,
+    cursor_range: Option<&CursorRange>,
+) {
+    let row_height = estimate_row_height(galley);
+
+    // Center on the row under the focus, so the loupe doesn't wobble when dragging along a row:
+    let focused_row = cursor_rect(
+        galley_pos,
+        galley,
+        &galley.cursor_from_pos(focus - galley_pos),
+        row_height,
+    );
+    let focus = pos2(focus.x, focused_row.center().y);
+
+    // The widget may be in a transformed layer, but the loupe is not:
+    let layer_transform = ui
+        .memory(|m| m.layer_transforms.get(&ui.layer_id()).copied())
+        .unwrap_or_default();
+    let focus_on_screen = layer_transform * focus;
+    let row_on_screen = layer_transform * focused_row;
+
+    let margin = ui.spacing().item_spacing.y;
+    let size = vec2(
+        LOUPE_WIDTH,
+        LOUPE_ZOOM * row_on_screen.height() + 2.0 * margin,
+    );
+    let gap = ui.spacing().interact_size.y;
+    let mut loupe_rect = Rect::from_center_size(
+        pos2(focus_on_screen.x, row_on_screen.top() - gap - 0.5 * size.y),
+        size,
+    );
+
+    // Keep it on screen:
+    let screen_rect = ui.ctx().screen_rect();
+    loupe_rect = loupe_rect.translate(vec2(
+        (screen_rect.left() - loupe_rect.left()).max(0.0)
+            + (screen_rect.right() - loupe_rect.right()).min(0.0),
+        (screen_rect.top() - loupe_rect.top()).max(0.0),
+    ));
+
+    let layer_id = LayerId::new(Order::Tooltip, Id::new("text_selection_loupe"));
+    let painter = ui.ctx().layer_painter(layer_id);
+    let visuals = ui.visuals();
+    let rounding = visuals.menu_rounding;
+
+    painter.add(visuals.popup_shadow.as_shape(loupe_rect, rounding));
+    painter.rect_filled(loupe_rect, rounding, visuals.extreme_bg_color);
+
+    // Map the text around the focus onto the center of the loupe:
+    let transform = TSTransform::from_translation(loupe_rect.center().to_vec2())
+        * TSTransform::from_scaling(LOUPE_ZOOM)
+        * TSTransform::from_translation(-focus_on_screen.to_vec2())
+        * layer_transform;
+
+    let content_painter = painter.with_clip_rect(loupe_rect.shrink(0.5 * margin));
+    let mut content = vec![Shape::galley(galley_pos, galley.clone(), fallback_color)];
+    if let Some(cursor_range) = cursor_range {
+        let color = visuals.selection.bg_fill.linear_multiply(0.5);
+        content.extend(
+            selection_rects(galley_pos, galley, cursor_range)
+                .into_iter()
+                .map(|rect| Shape::rect_filled(rect, 0.0, color)),
+        );
+    }
+    for mut shape in content {
+        shape.transform(transform);
+        content_painter.add(shape);
+    }
+    if let Some(cursor_range) = cursor_range {
+        let primary_cursor_rect =
+            cursor_rect(galley_pos, galley, &cursor_range.primary, row_height);
+        paint_cursor_end(&content_painter, visuals, transform * primary_cursor_rect);
+    }
+
+    painter.rect_stroke(loupe_rect, rounding, visuals.window_stroke);
+}
@@ -12,42 +12,51 @@ pub fn paint_text_selection(
     cursor_range: &CursorRange,
     mut out_shaped_idx: Option<&mut Vec<ShapeIdx>>,
 ) {
+    // We paint the cursor selection on top of the text, so make it transparent:
+    let color = visuals.selection.bg_fill.linear_multiply(0.5);
+
+    for rect in selection_rects(galley_pos, galley, cursor_range) {
+        let shape_idx = painter.rect_filled(rect, 0.0, color);
+        if let Some(out_shaped_idx) = &mut out_shaped_idx {
+            out_shaped_idx.push(shape_idx);
+        }
+    }
+}
+
+/// The rectangles covered by the selection, one per row.
+pub fn selection_rects(galley_pos: Pos2, galley: &Galley, cursor_range: &CursorRange) -> Vec<Rect> {
     if cursor_range.is_empty() {
-        return;
+        return vec![];
     }
 
-    // We paint the cursor selection on top of the text, so make it transparent:
-    let color = visuals.selection.bg_fill.linear_multiply(0.5);
     let [min, max] = cursor_range.sorted_cursors();
     let min = min.rcursor;
     let max = max.rcursor;
 
-    for ri in min.row..=max.row {
-        let row = &galley.rows[ri];
-        let left = if ri == min.row {
-            row.x_offset(min.column)
-        } else {
-            row.rect.left()
-        };
-        let right = if ri == max.row {
-            row.x_offset(max.column)
-        } else {
-            let newline_size = if row.ends_with_newline {
-                row.height() / 2.0 // visualize that we select the newline
+    (min.row..=max.row)
+        .map(|ri| {
+            let row = &galley.rows[ri];
+            let left = if ri == min.row {
+                row.x_offset(min.column)
             } else {
-                0.0
+                row.rect.left()
             };
-            row.rect.right() + newline_size
-        };
-        let rect = Rect::from_min_max(
-            galley_pos + vec2(left, row.min_y()),
-            galley_pos + vec2(right, row.max_y()),
-        );
-        let shape_idx = painter.rect_filled(rect, 0.0, color);
-        if let Some(out_shaped_idx) = &mut out_shaped_idx {
-            out_shaped_idx.push(shape_idx);
-        }
-    }
+            let right = if ri == max.row {
+                row.x_offset(max.column)
+            } else {
+                let newline_size = if row.ends_with_newline {
+                    row.height() / 2.0 // visualize that we select the newline
+                } else {
+                    0.0
+                };
+                row.rect.right() + newline_size
+            };
+            Rect::from_min_max(
+                galley_pos + vec2(left, row.min_y()),
+                galley_pos + vec2(right, row.max_y()),
+            )
+        })
+        .collect()
 }
 
 /// Paint one end of the selection, e.g. the primary cursor.
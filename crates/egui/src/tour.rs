@@ -0,0 +1,292 @@
+//! Guided tours that highlight and explain one widget at a time, e.g. for onboarding new users.
+//!
+//! See [`Tour`].
+
+use crate::{
+    emath::{Align2, Rect, Vec2},
+    epaint::{Color32, Stroke},
+    Area, Context, Frame, Id, LayerId, Order, Sense, WidgetRect, WidgetText,
+};
+
+/// One step of a [`Tour`].
+#[derive(Clone)]
+pub struct TourStep {
+    /// The widget to highlight, e.g. [`crate::Response::id`].
+    pub target: Id,
+
+    /// Shown in bold at the top of the explanation bubble.
+    pub title: WidgetText,
+
+    /// The explanation.
+    pub text: WidgetText,
+}
+
+/// The persisted state of a [`Tour`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct TourState {
+    /// Index of the current step.
+    step: usize,
+
+    /// Is the tour being shown?
+    running: bool,
+
+    /// Has the user gone through (or skipped) the tour?
+    finished: bool,
+}
+
+/// A guided tour, highlighting one widget at a time.
+///
+/// While running, everything but the current target widget is dimmed and can't be interacted with,
+/// and an explanation bubble with "Next" and "Skip" buttons is shown next to the target.
+/// Interacting with the target widget also advances the tour.
+///
+/// Call [`Self::show`] each frame, _after_ adding the widgets it refers to.
+/// Whether the tour is finished is stored in [`crate::Memory`], and persisted with the `persistence` feature,
+/// so you can use [`Self::is_finished`] to only show the tour on the first run.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// egui::CentralPanel::default().show(ctx, |ui| {
+///     let save = ui.button("Save");
+///     let open = ui.button("Open");
+///
+///     let tour = egui::Tour::new("onboarding")
+///         .step(save.id, "Save", "Click here to save your work.")
+///         .step(open.id, "Open", "And here to open it again.");
+///
+///     if !tour.is_finished(ctx) && !tour.is_running(ctx) {
+///         tour.start(ctx);
+///     }
+///     tour.show(ctx);
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone)]
+pub struct Tour {
+    id: Id,
+    steps: Vec<TourStep>,
+    dim_color: Option<Color32>,
+    margin: f32,
+}
+
+impl Tour {
+    /// The `id_source` must be unique, and stable between runs if you want the state to be persisted.
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            steps: Vec::new(),
+            dim_color: None,
+            margin: 4.0,
+        }
+    }
+
+    /// Add a step, highlighting the widget with the given id.
+    #[inline]
+    pub fn step(
+        mut self,
+        target: Id,
+        title: impl Into<WidgetText>,
+        text: impl Into<WidgetText>,
+    ) -> Self {
+        self.steps.push(TourStep {
+            target,
+            title: title.into(),
+            text: text.into(),
+        });
+        self
+    }
+
+    /// The color covering everything but the target widget.
+    ///
+    /// Default: [`crate::Visuals::extreme_bg_color`] with some transparency.
+    #[inline]
+    pub fn dim_color(mut self, dim_color: Color32) -> Self {
+        self.dim_color = Some(dim_color);
+        self
+    }
+
+    /// How much space to leave around the target widget.
+    ///
+    /// Default: `4.0`.
+    #[inline]
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// The steps of the tour.
+    pub fn steps(&self) -> &[TourStep] {
+        &self.steps
+    }
+
+    fn load(&self, ctx: &Context) -> TourState {
+        ctx.data_mut(|d| d.get_persisted(self.id))
+            .unwrap_or_default()
+    }
+
+    fn store(&self, ctx: &Context, state: TourState) {
+        ctx.data_mut(|d| d.insert_persisted(self.id, state));
+    }
+
+    /// Start (or restart) the tour from the first step.
+    pub fn start(&self, ctx: &Context) {
+        self.store(
+            ctx,
+            TourState {
+                step: 0,
+                running: true,
+                finished: false,
+            },
+        );
+        ctx.request_repaint();
+    }
+
+    /// Stop the tour, and mark it as finished.
+    pub fn stop(&self, ctx: &Context) {
+        let state = self.load(ctx);
+        self.store(
+            ctx,
+            TourState {
+                running: false,
+                finished: true,
+                ..state
+            },
+        );
+        ctx.request_repaint();
+    }
+
+    /// Is the tour currently being shown?
+    pub fn is_running(&self, ctx: &Context) -> bool {
+        self.load(ctx).running
+    }
+
+    /// Has the user gone through all the steps, or skipped the tour?
+    pub fn is_finished(&self, ctx: &Context) -> bool {
+        self.load(ctx).finished
+    }
+
+    /// The index of the current step, if running.
+    pub fn current_step(&self, ctx: &Context) -> Option<usize> {
+        let state = self.load(ctx);
+        state.running.then_some(state.step)
+    }
+
+    fn advance(&self, ctx: &Context, mut state: TourState) {
+        state.step += 1;
+        if self.steps.len() <= state.step {
+            state.running = false;
+            state.finished = true;
+        }
+        self.store(ctx, state);
+        ctx.request_repaint();
+    }
+
+    /// Show the current step, if the tour is running.
+    ///
+    /// Call this after the target widgets have been added.
+    pub fn show(&self, ctx: &Context) {
+        let state = self.load(ctx);
+        if !state.running {
+            return;
+        }
+        let Some(step) = self.steps.get(state.step) else {
+            self.stop(ctx);
+            return;
+        };
+
+        let target = ctx.read_response(step.target);
+        if target.as_ref().map_or(false, |response| {
+            response.clicked() || response.drag_stopped()
+        }) {
+            self.advance(ctx, state);
+            return;
+        }
+
+        let screen_rect = ctx.screen_rect();
+        let spotlight = target.map(|response| response.rect.expand(self.margin));
+
+        // Dim and block everything but the target:
+        let layer_id = LayerId::new(Order::Foreground, self.id.with("dim"));
+        let dim_color = self
+            .dim_color
+            .unwrap_or_else(|| ctx.style().visuals.extreme_bg_color.gamma_multiply(0.75));
+        let painter = ctx.layer_painter(layer_id);
+        for (i, rect) in dimmed_rects(screen_rect, spotlight).into_iter().enumerate() {
+            if !rect.is_positive() {
+                continue;
+            }
+            painter.rect_filled(rect, 0.0, dim_color);
+            ctx.create_widget(WidgetRect {
+                id: self.id.with(("dim", i)),
+                layer_id,
+                rect,
+                interact_rect: rect,
+                sense: Sense::click_and_drag(),
+                enabled: true,
+            });
+        }
+        if let Some(spotlight) = spotlight {
+            let stroke = Stroke::new(2.0, ctx.style().visuals.selection.stroke.color);
+            painter.rect_stroke(spotlight, 4.0, stroke);
+        }
+
+        // The explanation bubble:
+        let gap = 8.0;
+        let (pos, pivot) = match spotlight {
+            Some(spotlight) if spotlight.bottom() + 120.0 < screen_rect.bottom() => (
+                spotlight.center_bottom() + Vec2::new(0.0, gap),
+                Align2::CENTER_TOP,
+            ),
+            Some(spotlight) => (
+                spotlight.center_top() - Vec2::new(0.0, gap),
+                Align2::CENTER_BOTTOM,
+            ),
+            None => (screen_rect.center(), Align2::CENTER_CENTER),
+        };
+
+        Area::new(self.id.with("bubble"))
+            .order(Order::Tooltip)
+            .fixed_pos(pos)
+            .pivot(pivot)
+            .constrain(true)
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_max_width(280.0);
+
+                    ui.label(step.title.clone().strong());
+                    ui.label(step.text.clone());
+
+                    ui.add_space(ui.spacing().item_spacing.y);
+                    ui.horizontal(|ui| {
+                        ui.weak(format!("{}/{}", state.step + 1, self.steps.len()));
+
+                        ui.with_layout(crate::Layout::right_to_left(crate::Align::Center), |ui| {
+                            let is_last = state.step + 1 == self.steps.len();
+                            if ui.button(if is_last { "Done" } else { "Next" }).clicked() {
+                                self.advance(ctx, state);
+                            }
+                            if !is_last && ui.button("Skip").clicked() {
+                                self.stop(ctx);
+                            }
+                        });
+                    });
+                });
+            });
+    }
+}
+
+/// The parts of the screen outside of the spotlight: above, below, left and right of it.
+fn dimmed_rects(screen_rect: Rect, spotlight: Option<Rect>) -> Vec<Rect> {
+    let Some(spot) = spotlight else {
+        return vec![screen_rect];
+    };
+    let spot = spot.intersect(screen_rect);
+    vec![
+        Rect::from_x_y_ranges(screen_rect.x_range(), screen_rect.top()..=spot.top()),
+        Rect::from_x_y_ranges(screen_rect.x_range(), spot.bottom()..=screen_rect.bottom()),
+        Rect::from_x_y_ranges(screen_rect.left()..=spot.left(), spot.y_range()),
+        Rect::from_x_y_ranges(spot.right()..=screen_rect.right(), spot.y_range()),
+    ]
+}
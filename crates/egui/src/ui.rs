@@ -12,6 +12,14 @@ use crate::{
 
 // ----------------------------------------------------------------------------
 
+/// Adds the contents of several [`Ui`]s at once, see [`Ui::columns`] and [`Ui::avoid_hinge`].
+type AddContentsToSlice<'c, R> = Box<dyn FnOnce(&mut [Ui]) -> R + 'c>;
+
+/// Picks one of the flags of a [`GroupBinding`].
+type GroupFlagAccessor = fn(&mut GroupBinding) -> &mut Option<GroupFlag>;
+
+// ----------------------------------------------------------------------------
+
 /// This is what you use to place widgets.
 ///
 /// Represents a region of the screen with a type of layout (horizontal or vertical).
@@ -75,12 +83,13 @@ impl Ui {
     /// [`SidePanel`], [`TopBottomPanel`], [`CentralPanel`], [`Window`] or [`Area`].
     pub fn new(ctx: Context, layer_id: LayerId, id: Id, max_rect: Rect, clip_rect: Rect) -> Self {
         let style = ctx.style();
+        let layout = Layout::default().for_direction(style.layout_direction);
         let ui = Ui {
             id,
             next_auto_id_source: id.with("auto").value(),
             painter: Painter::new(ctx, layer_id, clip_rect),
             style,
-            placer: Placer::new(max_rect, Layout::default()),
+            placer: Placer::new(max_rect, layout),
             enabled: true,
             menu_state: None,
         };
@@ -201,6 +210,13 @@ impl Ui {
         &mut self.style_mut().spacing
     }
 
+    /// Which way this [`Ui`] is read.
+    /// Short for `ui.style().layout_direction`.
+    #[inline]
+    pub fn layout_direction(&self) -> crate::LayoutDirection {
+        self.style.layout_direction
+    }
+
     /// The current visuals settings of this [`Ui`].
     /// Short for `ui.style().visuals`.
     #[inline]
@@ -724,6 +740,50 @@ impl Ui {
     pub fn ui_contains_pointer(&self) -> bool {
         self.rect_contains_pointer(self.min_rect())
     }
+
+    /// Returns `value` once it has stopped changing for `secs` seconds.
+    ///
+    /// Call this every frame with the latest value.
+    /// Returns `None` while it is still changing, and when it is the same as the last returned value.
+    /// The first value given is not returned.
+    ///
+    /// Useful for expensive operations that should only run once the user pauses, e.g. a live search:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut query = String::new();
+    /// # fn search(_: &str) {}
+    /// ui.text_edit_singleline(&mut query);
+    /// if let Some(query) = ui.debounce(egui::Id::new("search"), query.clone(), 0.3) {
+    ///     search(&query);
+    /// }
+    /// # });
+    /// ```
+    ///
+    /// See also [`Self::throttle`] and [`Response::changed_debounced`].
+    pub fn debounce<T>(&self, id: Id, value: T, secs: f32) -> Option<T>
+    where
+        T: 'static + Clone + PartialEq + Send + Sync,
+    {
+        crate::debounce::debounce(self.ctx(), id, value, secs)
+    }
+
+    /// Returns `value` when it changes, but at most once every `secs` seconds.
+    ///
+    /// Call this every frame with the latest value.
+    /// The latest value is always returned eventually, even if it stops changing in between.
+    /// The first value given is not returned.
+    ///
+    /// Useful for operations that should keep up with the user, but not run every frame,
+    /// e.g. recomputing a preview while a slider is dragged.
+    ///
+    /// See also [`Self::debounce`].
+    pub fn throttle<T>(&self, id: Id, value: T, secs: f32) -> Option<T>
+    where
+        T: 'static + Clone + PartialEq + Send + Sync,
+    {
+        crate::debounce::throttle(self.ctx(), id, value, secs)
+    }
 }
 
 /// # Allocating space: where do I put my widgets?
@@ -1268,6 +1328,132 @@ impl Ui {
         })
     }
 
+    /// Enable or disable all [`Self::bound_scope`]s of the `group`, depending on `condition`.
+    ///
+    /// This lets many widgets follow e.g. one checkbox, without passing the condition around.
+    /// The condition is evaluated at most once per frame, however often this is called.
+    /// Scopes shown earlier in the frame than the binding follow its value from the previous frame.
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut use_proxy = false;
+    /// # let mut host = String::new();
+    /// # let mut port = 8080;
+    /// ui.checkbox(&mut use_proxy, "Use a proxy");
+    /// ui.bind_enabled("proxy_settings", || use_proxy);
+    ///
+    /// ui.bound_scope("proxy_settings", |ui| ui.text_edit_singleline(&mut host));
+    /// ui.bound_scope("proxy_settings", |ui| ui.add(egui::DragValue::new(&mut port)));
+    /// # });
+    /// ```
+    pub fn bind_enabled(&self, group: impl Into<Id>, condition: impl FnOnce() -> bool) {
+        self.bind_group_flag(group.into(), condition, |binding| &mut binding.enabled);
+    }
+
+    /// Show or hide all [`Self::bound_scope`]s of the `group`, depending on `condition`.
+    ///
+    /// Hidden scopes still take up space, just like with [`Self::add_visible_ui`].
+    /// See [`Self::bind_enabled`] for details.
+    pub fn bind_visible(&self, group: impl Into<Id>, condition: impl FnOnce() -> bool) {
+        self.bind_group_flag(group.into(), condition, |binding| &mut binding.visible);
+    }
+
+    fn bind_group_flag(
+        &self,
+        group: Id,
+        condition: impl FnOnce() -> bool,
+        flag: GroupFlagAccessor,
+    ) {
+        let frame_nr = self.ctx().frame_nr();
+        let already_bound = self.ctx().data_mut(|data| {
+            let binding = data.get_temp_mut_or_default::<GroupBinding>(group);
+            flag(binding).map_or(false, |flag| flag.frame_nr == frame_nr)
+        });
+        if already_bound {
+            return;
+        }
+
+        // Evaluate the condition outside of the lock, since it may use the context too:
+        let value = condition();
+        self.ctx().data_mut(|data| {
+            let binding = data.get_temp_mut_or_default::<GroupBinding>(group);
+            *flag(binding) = Some(GroupFlag { frame_nr, value });
+        });
+    }
+
+    /// Add a section that follows the bindings of the `group`,
+    /// see [`Self::bind_enabled`] and [`Self::bind_visible`].
+    ///
+    /// Without a binding this frame or the previous one, the section is shown as usual.
+    pub fn bound_scope<R>(
+        &mut self,
+        group: impl Into<Id>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let frame_nr = self.ctx().frame_nr();
+        let binding = self
+            .ctx()
+            .data(|data| data.get_temp::<GroupBinding>(group.into()))
+            .unwrap_or_default();
+        let current = |flag: Option<GroupFlag>| {
+            flag.filter(|flag| frame_nr <= flag.frame_nr + 1)
+                .map(|flag| flag.value)
+        };
+
+        self.scope(|ui| {
+            if current(binding.enabled) == Some(false) {
+                ui.set_enabled(false);
+            }
+            if current(binding.visible) == Some(false) {
+                ui.set_visible(false);
+            }
+            add_contents(ui)
+        })
+    }
+
+    /// Show a hint with a close button, until the user closes it.
+    ///
+    /// Which hints have been closed is stored in [`crate::Memory`], and persisted with the `persistence` feature,
+    /// so each hint is only shown until dismissed, also across runs.
+    /// Use [`Context::reset_all_hints`] to show them all again.
+    ///
+    /// The `id` is global (not relative to this [`Ui`]), so that the same id can be used with
+    /// [`Context::dismiss_hint`] and [`Context::reset_hint`].
+    ///
+    /// Returns `None` if the hint has been dismissed.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.hint_once("hint_drag_to_pan", |ui| {
+    ///     ui.label("Tip: drag with the right mouse button to pan the view.");
+    /// });
+    /// # });
+    /// ```
+    pub fn hint_once<R>(
+        &mut self,
+        id: impl Into<Id>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let id = id.into();
+        if self.ctx().is_hint_dismissed(id) {
+            return None;
+        }
+
+        let frame = Frame::group(self.style()).fill(self.visuals().faint_bg_color);
+        let InnerResponse { inner, response } = frame.show(self, |ui| {
+            ui.with_layout(Layout::right_to_left(Align::Min), |ui| {
+                if ui.small_button("✖").on_hover_text("Dismiss").clicked() {
+                    ui.ctx().dismiss_hint(id);
+                }
+                ui.with_layout(Layout::top_down(Align::Min), add_contents)
+                    .inner
+            })
+            .inner
+        });
+        Some(InnerResponse::new(inner, response))
+    }
+
     /// Add extra space before the next widget.
     ///
     /// The direction is dependent on the layout.
@@ -1398,6 +1584,15 @@ impl Ui {
         Hyperlink::from_label_and_url(label, url).ui(self)
     }
 
+    /// Show all settings declared with [`Context::register_setting`], grouped by category and searchable.
+    ///
+    /// The response is marked as changed when the user changes a setting.
+    ///
+    /// See [`crate::settings`] for an example.
+    pub fn settings_editor(&mut self) -> Response {
+        crate::settings::editor_ui(self)
+    }
+
     /// No newlines (`\n`) allowed. Pressing enter key will result in the [`TextEdit`] losing focus (`response.lost_focus`).
     ///
     /// See also [`TextEdit`].
@@ -1842,8 +2037,13 @@ impl Ui {
         );
 
         let indent = self.spacing().indent;
+        let right_to_left = self.layout_direction().is_right_to_left();
         let mut child_rect = self.placer.available_rect_before_wrap();
-        child_rect.min.x += indent;
+        if right_to_left {
+            child_rect.max.x -= indent;
+        } else {
+            child_rect.min.x += indent;
+        }
 
         let mut child_ui = self.child_ui_with_id_source(child_rect, *self.layout(), id_source);
         let ret = add_contents(&mut child_ui);
@@ -1857,19 +2057,28 @@ impl Ui {
             }
 
             let stroke = self.visuals().widgets.noninteractive.bg_stroke;
-            let left_top = child_rect.min - 0.5 * indent * Vec2::X;
+            let left_top = if right_to_left {
+                child_rect.right_top() + 0.5 * indent * Vec2::X
+            } else {
+                child_rect.min - 0.5 * indent * Vec2::X
+            };
             let left_top = self.painter().round_pos_to_pixels(left_top);
             let left_bottom = pos2(left_top.x, child_ui.min_rect().bottom() - 2.0);
             let left_bottom = self.painter().round_pos_to_pixels(left_bottom);
 
             if left_vline {
-                // draw a faint line on the left to mark the indented section
+                // draw a faint line on the leading side to mark the indented section
                 self.painter.line_segment([left_top, left_bottom], stroke);
             }
 
             if end_with_horizontal_line {
                 let fudge = 2.0; // looks nicer with button rounding in collapsing headers
-                let right_bottom = pos2(child_ui.min_rect().right() - fudge, left_bottom.y);
+                let end_x = if right_to_left {
+                    child_ui.min_rect().left() + fudge
+                } else {
+                    child_ui.min_rect().right() - fudge
+                };
+                let right_bottom = pos2(end_x, left_bottom.y);
                 self.painter
                     .line_segment([left_bottom, right_bottom], stroke);
             }
@@ -1879,6 +2088,37 @@ impl Ui {
         InnerResponse::new(ret, response)
     }
 
+    /// A row of widgets that are measured before they are placed,
+    /// so that the text of labels, buttons and custom widgets line up on the same baseline.
+    ///
+    /// Uses [`Widget::preferred_size`] and [`Widget::baseline`].
+    /// Widgets without a baseline are centered vertically.
+    /// With [`AlignedRow::justified`], the remaining width is shared out between the widgets.
+    ///
+    /// Returns the response of each widget, in order.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let responses = ui
+    ///     .aligned_row(|row| {
+    ///         row.justified(true);
+    ///         row.label("Big and small:");
+    ///         row.add(egui::Button::new(egui::RichText::new("OK").heading()));
+    ///         row.add(egui::Button::new("Cancel").small());
+    ///     })
+    ///     .inner;
+    /// if responses[2].clicked() {
+    ///     /* … */
+    /// }
+    /// # });
+    /// ```
+    pub fn aligned_row<'a>(
+        &mut self,
+        add_contents: impl FnOnce(&mut AlignedRow<'_, 'a>),
+    ) -> InnerResponse<Vec<Response>> {
+        crate::aligned_row::show(self, add_contents)
+    }
+
     /// Start a ui with horizontal layout.
     /// After you have called this, the function registers the contents as any other widget.
     ///
@@ -1909,13 +2149,18 @@ impl Ui {
         self.horizontal_with_main_wrap_dyn(false, Box::new(add_contents))
     }
 
+    /// Should horizontal layouts started in this [`Ui`] go from right to left?
+    fn prefer_right_to_left(&self) -> bool {
+        self.placer.prefer_right_to_left() || self.layout_direction().is_right_to_left()
+    }
+
     /// Like [`Self::horizontal`], but allocates the full vertical height and then centers elements vertically.
     pub fn horizontal_centered<R>(
         &mut self,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
         let initial_size = self.available_size_before_wrap();
-        let layout = if self.placer.prefer_right_to_left() {
+        let layout = if self.prefer_right_to_left() {
             Layout::right_to_left(Align::Center)
         } else {
             Layout::left_to_right(Align::Center)
@@ -1930,7 +2175,7 @@ impl Ui {
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
         let initial_size = self.available_size_before_wrap();
-        let layout = if self.placer.prefer_right_to_left() {
+        let layout = if self.prefer_right_to_left() {
             Layout::right_to_left(Align::Center)
         } else {
             Layout::left_to_right(Align::Center)
@@ -1971,7 +2216,7 @@ impl Ui {
             self.spacing().interact_size.y, // Assume there will be something interactive on the horizontal layout
         );
 
-        let layout = if self.placer.prefer_right_to_left() {
+        let layout = if self.prefer_right_to_left() {
             Layout::right_to_left(Align::Center)
         } else {
             Layout::left_to_right(Align::Center)
@@ -1982,7 +2227,7 @@ impl Ui {
     }
 
     /// Start a ui with vertical layout.
-    /// Widgets will be left-justified.
+    /// Widgets will be left-justified (right-justified with [`crate::LayoutDirection::RightToLeft`]).
     ///
     /// ```
     /// # egui::__run_test_ui(|ui| {
@@ -1996,7 +2241,8 @@ impl Ui {
     /// See also [`Self::with_layout`] for more options.
     #[inline]
     pub fn vertical<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
-        self.with_layout_dyn(Layout::top_down(Align::Min), Box::new(add_contents))
+        let layout = Layout::top_down(Align::Min).for_direction(self.layout_direction());
+        self.with_layout_dyn(layout, Box::new(add_contents))
     }
 
     /// Start a ui with vertical layout.
@@ -2135,7 +2381,7 @@ impl Ui {
     fn columns_dyn<'c, R>(
         &mut self,
         num_columns: usize,
-        add_contents: Box<dyn FnOnce(&mut [Self]) -> R + 'c>,
+        add_contents: AddContentsToSlice<'c, R>,
     ) -> R {
         // TODO(emilk): ensure there is space
         let spacing = self.spacing().item_spacing.x;
@@ -2174,6 +2420,73 @@ impl Ui {
         result
     }
 
+    /// Split the [`Ui`] in two around the hinge of a foldable or dual-screen device,
+    /// so that no content ends up in the fold.
+    ///
+    /// If the hinge (see [`crate::InputState::hinge_rect`]) crosses this [`Ui`],
+    /// `add_contents` gets two [`Ui`]s, one on each side of it:
+    /// left and right of a vertical hinge, or above and below a horizontal one.
+    /// Otherwise it gets a single [`Ui`] with all the available space.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.avoid_hinge(|panes| match panes {
+    ///     [list, details] => {
+    ///         list.label("List");
+    ///         details.label("Details");
+    ///     }
+    ///     panes => {
+    ///         panes[0].label("List and details");
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn avoid_hinge<R>(&mut self, add_contents: impl FnOnce(&mut [Self]) -> R) -> R {
+        self.avoid_hinge_dyn(Box::new(add_contents))
+    }
+
+    fn avoid_hinge_dyn<'c, R>(&mut self, add_contents: AddContentsToSlice<'c, R>) -> R {
+        let rect = self.available_rect_before_wrap();
+        let pane_rects = match self.ctx().input(|i| i.hinge_rect()) {
+            Some(hinge)
+                if hinge.height() >= hinge.width()
+                    && rect.left() < hinge.left()
+                    && hinge.right() < rect.right() =>
+            {
+                vec![
+                    Rect::from_x_y_ranges(rect.left()..=hinge.left(), rect.y_range()),
+                    Rect::from_x_y_ranges(hinge.right()..=rect.right(), rect.y_range()),
+                ]
+            }
+            Some(hinge)
+                if hinge.width() > hinge.height()
+                    && rect.top() < hinge.top()
+                    && hinge.bottom() < rect.bottom() =>
+            {
+                vec![
+                    Rect::from_x_y_ranges(rect.x_range(), rect.top()..=hinge.top()),
+                    Rect::from_x_y_ranges(rect.x_range(), hinge.bottom()..=rect.bottom()),
+                ]
+            }
+            _ => vec![rect],
+        };
+
+        let layout = *self.layout();
+        let mut panes: Vec<Self> = pane_rects
+            .into_iter()
+            .map(|pane_rect| self.child_ui(pane_rect, layout))
+            .collect();
+
+        let result = add_contents(&mut panes[..]);
+
+        let used_rect = panes.iter().fold(Rect::NOTHING, |used_rect, pane| {
+            used_rect.union(pane.min_rect())
+        });
+        self.advance_cursor_after_rect(used_rect);
+        result
+    }
+
     /// Create something that can be drag-and-dropped.
     ///
     /// The `id` needs to be globally unique.
@@ -2475,6 +2788,20 @@ fn register_rect(ui: &Ui, rect: Rect) {
 #[cfg(not(debug_assertions))]
 fn register_rect(_ui: &Ui, _rect: Rect) {}
 
+/// The bindings of a group of scopes, see [`Ui::bind_enabled`] and [`Ui::bind_visible`].
+#[derive(Clone, Copy, Debug, Default)]
+struct GroupBinding {
+    enabled: Option<GroupFlag>,
+    visible: Option<GroupFlag>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct GroupFlag {
+    /// The frame the condition was evaluated.
+    frame_nr: u64,
+    value: bool,
+}
+
 #[test]
 fn ui_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
@@ -3,9 +3,11 @@
 pub mod cache;
 pub(crate) mod fixed_cache;
 pub mod id_type_map;
+pub mod undo_stack;
 pub mod undoer;
 
 pub use id_type_map::IdTypeMap;
+pub use undo_stack::UndoStack;
 
 pub use epaint::emath::History;
 pub use epaint::util::{hash, hash_with};
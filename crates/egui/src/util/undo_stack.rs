@@ -0,0 +1,355 @@
+//! Undo/redo for app state that is edited through widgets.
+
+use std::collections::VecDeque;
+
+use crate::{Context, Id, Key, KeyboardShortcut, Modifiers, Response};
+
+/// What new changes are merged into, instead of creating a new undo point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OpenEdit {
+    /// Changes made by this widget, until it is released and loses focus.
+    Widget(Id),
+
+    /// Changes recorded with this merge key, see [`UndoStack::record`].
+    Key(Id),
+
+    /// Everything between [`UndoStack::begin_transaction`] and [`UndoStack::end_transaction`].
+    Transaction,
+}
+
+/// Undo/redo for app state that is edited through widgets.
+///
+/// Unlike [`super::undoer::Undoer`], which creates undo points when the state has been stable for a while,
+/// [`UndoStack`] creates one undo point per _edit_:
+///
+/// * All changes made by the same widget are merged until it is released and loses focus,
+///   so dragging a [`crate::Slider`] or [`crate::DragValue`] and then pressing Ctrl+Z restores
+///   the value from before the drag, and a [`crate::TextEdit`] is restored to before it got focus.
+/// * All changes made in the same frame are merged, e.g. a "Reset all" button changing many values.
+/// * Consecutive changes with the same merge key are merged, see [`Self::record`].
+/// * Everything between [`Self::begin_transaction`] and [`Self::end_transaction`] is merged, even across frames.
+///
+/// Each undo point is a clone of the whole state, so keep `max_undos` low if your state is big.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # #[derive(Clone, PartialEq)]
+/// # struct Params { volume: f32, speed: f32, name: String }
+/// # let mut undo_stack = egui::util::UndoStack::default();
+/// # let mut params = Params { volume: 0.5, speed: 1.0, name: String::new() };
+/// undo_stack.begin_frame(&params);
+///
+/// undo_stack.track(&ui.add(egui::Slider::new(&mut params.volume, 0.0..=1.0)));
+/// undo_stack.track(&ui.add(egui::DragValue::new(&mut params.speed)));
+/// undo_stack.track(&ui.text_edit_singleline(&mut params.name));
+///
+/// ui.add_enabled_ui(undo_stack.has_undo(), |ui| {
+///     if ui.button("⟲ Undo").clicked() {
+///         if let Some(old) = undo_stack.undo(&params) {
+///             params = old;
+///         }
+///     }
+/// });
+/// undo_stack.handle_shortcuts(ui.ctx(), &mut params);
+/// # });
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct UndoStack<T> {
+    max_undos: usize,
+
+    /// The state before each edit. New undo points are added to the back.
+    undos: VecDeque<T>,
+
+    /// Stores redos immediately after a sequence of undos.
+    /// Gets cleared every time a new edit is recorded.
+    redos: Vec<T>,
+
+    /// The state at the start of this frame, i.e. before any edits made this frame.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    baseline: Option<T>,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    open_edit: Option<OpenEdit>,
+
+    #[cfg_attr(feature = "serde", serde(skip))]
+    in_transaction: bool,
+
+    /// The frame in which the last edit was recorded.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_edit_frame: Option<u64>,
+}
+
+impl<T> Default for UndoStack<T> {
+    fn default() -> Self {
+        Self {
+            max_undos: 100,
+            undos: Default::default(),
+            redos: Default::default(),
+            baseline: None,
+            open_edit: None,
+            in_transaction: false,
+            last_edit_frame: None,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for UndoStack<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { undos, redos, .. } = self;
+        f.debug_struct("UndoStack")
+            .field("undo count", &undos.len())
+            .field("redo count", &redos.len())
+            .finish()
+    }
+}
+
+impl<T: Clone + PartialEq> UndoStack<T> {
+    /// Maximum number of undo points.
+    ///
+    /// Default: `100`.
+    #[inline]
+    pub fn with_max_undos(mut self, max_undos: usize) -> Self {
+        self.max_undos = max_undos;
+        self
+    }
+
+    /// Is there anything to undo?
+    pub fn has_undo(&self) -> bool {
+        !self.undos.is_empty()
+    }
+
+    /// Do we have a redo point?
+    pub fn has_redo(&self) -> bool {
+        !self.redos.is_empty()
+    }
+
+    /// Forget all undo and redo points.
+    pub fn clear(&mut self) {
+        self.undos.clear();
+        self.redos.clear();
+        self.open_edit = None;
+        self.last_edit_frame = None;
+    }
+
+    /// Call once per frame, before showing the widgets that edit `state`.
+    ///
+    /// This remembers `state`, so that it can be restored if it is edited this frame.
+    pub fn begin_frame(&mut self, state: &T) {
+        if self.baseline.as_ref() != Some(state) {
+            self.baseline = Some(state.clone());
+        }
+    }
+
+    /// Record the edits made through a widget, e.g. a [`crate::Slider`], [`crate::DragValue`] or [`crate::TextEdit`].
+    ///
+    /// Call this with the response of every widget that edits the state, every frame.
+    /// All changes made by the widget are one undo point, until it is released and loses focus.
+    pub fn track(&mut self, response: &Response) {
+        let id = response.id;
+        if response.changed() {
+            self.record_impl(response.ctx.frame_nr(), Some(OpenEdit::Widget(id)));
+        }
+
+        let still_editing =
+            response.is_pointer_button_down_on() || response.ctx.memory(|m| m.has_focus(id));
+        if self.open_edit == Some(OpenEdit::Widget(id)) && !still_editing {
+            self.open_edit = None;
+        }
+    }
+
+    /// Record an edit made by something other than a tracked widget, e.g. a menu command.
+    ///
+    /// Call this after [`Self::begin_frame`], in the same frame as the edit.
+    ///
+    /// Consecutive edits with the same `merge_key` become a single undo point,
+    /// e.g. repeatedly nudging a selection with the arrow keys.
+    /// With `None`, each edit is its own undo point (unless in the same frame as another edit).
+    pub fn record(&mut self, ctx: &Context, merge_key: Option<Id>) {
+        self.record_impl(ctx.frame_nr(), merge_key.map(OpenEdit::Key));
+    }
+
+    /// Start merging all edits into a single undo point, until [`Self::end_transaction`].
+    ///
+    /// Useful for multi-step operations that span several frames or widgets.
+    pub fn begin_transaction(&mut self) {
+        self.in_transaction = true;
+        self.open_edit = None;
+    }
+
+    /// Stop merging edits, see [`Self::begin_transaction`].
+    pub fn end_transaction(&mut self) {
+        self.in_transaction = false;
+        self.open_edit = None;
+    }
+
+    /// Are we between [`Self::begin_transaction`] and [`Self::end_transaction`]?
+    pub fn is_in_transaction(&self) -> bool {
+        self.in_transaction
+    }
+
+    fn record_impl(&mut self, frame_nr: u64, edit: Option<OpenEdit>) {
+        let edit = if self.in_transaction {
+            Some(OpenEdit::Transaction)
+        } else {
+            edit
+        };
+
+        let same_frame = self.last_edit_frame == Some(frame_nr);
+        self.last_edit_frame = Some(frame_nr);
+        if edit.is_some() && edit == self.open_edit {
+            return; // merge with the edit in progress
+        }
+        if same_frame {
+            return; // all edits in a frame are a single undo point
+        }
+
+        let Some(baseline) = self.baseline.clone() else {
+            return; // `begin_frame` was never called
+        };
+        self.undos.push_back(baseline);
+        while self.max_undos < self.undos.len() {
+            self.undos.pop_front();
+        }
+        self.redos.clear();
+        self.open_edit = edit;
+    }
+
+    /// Returns the state to go back to, if any.
+    ///
+    /// Undo points equal to `current_state` are skipped.
+    pub fn undo(&mut self, current_state: &T) -> Option<T> {
+        while let Some(state) = self.undos.pop_back() {
+            if &state != current_state {
+                self.redos.push(current_state.clone());
+                self.jumped_to(&state);
+                return Some(state);
+            }
+        }
+        None
+    }
+
+    /// Returns the state to go forward to, if any.
+    pub fn redo(&mut self, current_state: &T) -> Option<T> {
+        let state = self.redos.pop()?;
+        self.undos.push_back(current_state.clone());
+        self.jumped_to(&state);
+        Some(state)
+    }
+
+    fn jumped_to(&mut self, state: &T) {
+        self.baseline = Some(state.clone());
+        self.open_edit = None;
+        self.last_edit_frame = None;
+    }
+
+    /// Undo on `Cmd+Z`, and redo on `Cmd+Shift+Z` or `Cmd+Y`.
+    ///
+    /// Does nothing while a [`crate::TextEdit`] has keyboard focus, since it has its own undo.
+    ///
+    /// Returns `true` if `state` was changed.
+    pub fn handle_shortcuts(&mut self, ctx: &Context, state: &mut T) -> bool {
+        const UNDO: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Z);
+        const REDO: KeyboardShortcut =
+            KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::Z);
+        const REDO_ALT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Y);
+
+        if ctx.wants_keyboard_input() {
+            return false;
+        }
+
+        let (undo, redo) = ctx.input_mut(|i| {
+            // Redo first, since `Cmd+Shift+Z` also matches `Cmd+Z`:
+            let redo = i.consume_shortcut(&REDO) || i.consume_shortcut(&REDO_ALT);
+            let undo = i.consume_shortcut(&UNDO);
+            (undo, redo)
+        });
+
+        let new_state = if redo {
+            self.redo(state)
+        } else if undo {
+            self.undo(state)
+        } else {
+            None
+        };
+
+        if let Some(new_state) = new_state {
+            *state = new_state;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UndoStack;
+    use crate::{Context, Id};
+
+    fn run_frames(
+        ctx: &Context,
+        stack: &mut UndoStack<i32>,
+        value: &mut i32,
+        edits: &[Option<Id>],
+    ) {
+        for merge_key in edits {
+            let _ = ctx.run(Default::default(), |ctx| {
+                stack.begin_frame(value);
+                *value += 1;
+                stack.record(ctx, *merge_key);
+            });
+        }
+    }
+
+    #[test]
+    fn merge_keys() {
+        let ctx = Context::default();
+        let mut stack = UndoStack::default();
+        let mut value = 0;
+
+        let drag = Some(Id::new("drag"));
+        run_frames(
+            &ctx,
+            &mut stack,
+            &mut value,
+            &[drag, drag, drag, None, None],
+        );
+        assert_eq!(value, 5);
+
+        assert_eq!(stack.undo(&value), Some(4));
+        assert_eq!(stack.undo(&4), Some(3));
+        assert_eq!(stack.undo(&3), Some(0));
+        assert_eq!(stack.undo(&0), None);
+
+        assert_eq!(stack.redo(&0), Some(3));
+        assert_eq!(stack.redo(&3), Some(4));
+        assert_eq!(stack.redo(&4), Some(5));
+        assert_eq!(stack.redo(&5), None);
+    }
+
+    #[test]
+    fn same_frame_and_transactions() {
+        let ctx = Context::default();
+        let mut stack = UndoStack::default();
+        let mut value = 0;
+
+        let _ = ctx.run(Default::default(), |ctx| {
+            stack.begin_frame(&value);
+            value += 1;
+            stack.record(ctx, None);
+            value += 1;
+            stack.record(ctx, Some(Id::new("other")));
+        });
+        assert_eq!(value, 2);
+
+        stack.begin_transaction();
+        run_frames(&ctx, &mut stack, &mut value, &[None, None, None]);
+        stack.end_transaction();
+        assert_eq!(value, 5);
+
+        assert_eq!(stack.undo(&value), Some(2));
+        assert_eq!(stack.undo(&2), Some(0));
+        assert!(!stack.has_undo());
+    }
+}
@@ -67,9 +67,11 @@
 //! There are several more things related to viewports that we want to add.
 //! Read more at <https://github.com/emilk/egui/issues/3556>.
 
+pub mod custom_titlebar;
+
 use std::sync::Arc;
 
-use epaint::{Pos2, Vec2};
+use epaint::{Pos2, Rect, Vec2};
 
 use crate::{Context, Id};
 
@@ -241,7 +243,9 @@ impl ViewportIdPair {
 }
 
 /// The user-code that shows the ui in the viewport, used for deferred viewports.
-pub type DeferredViewportUiCallback = dyn Fn(&Context) + Sync + Send;
+///
+/// The integration calls it with the ids of the viewport it is drawing, and its parent.
+pub type DeferredViewportUiCallback = dyn Fn(&Context, ViewportIdPair) + Sync + Send;
 
 /// Render the given viewport, calling the given ui callback.
 pub type ImmediateViewportRendererCallback = dyn for<'a> Fn(&Context, ImmediateViewport<'a>);
@@ -266,7 +270,7 @@ pub struct ViewportBuilder {
     /// `eframe` will use this as the title of the native window.
     pub title: Option<String>,
 
-    /// This is wayland only. See [`Self::with_app_id`].
+    /// See [`Self::with_app_id`].
     pub app_id: Option<String>,
 
     /// The desired outer position of the window.
@@ -300,6 +304,15 @@ pub struct ViewportBuilder {
 
     pub window_level: Option<WindowLevel>,
 
+    /// See [`Self::with_modal`].
+    pub modal: Option<ViewportId>,
+
+    /// See [`Self::with_persist`].
+    pub persist: Option<bool>,
+
+    /// See [`Self::with_snap_distance`].
+    pub snap_distance: Option<Vec2>,
+
     pub mouse_passthrough: Option<bool>,
 
     // X11
@@ -455,6 +468,15 @@ impl ViewportBuilder {
         self
     }
 
+    /// windows: Keep the window out of the taskbar, e.g. for tool palettes and overlays.
+    ///
+    /// The same as `with_taskbar(!skip)`.
+    /// This is applied when the window is created, so it never flashes by in the taskbar.
+    #[inline]
+    pub fn with_skip_taskbar(self, skip: bool) -> Self {
+        self.with_taskbar(!skip)
+    }
+
     /// Requests the window to be of specific dimensions.
     ///
     /// If this is not set, some platform-specific dimensions will be used.
@@ -535,17 +557,21 @@ impl ViewportBuilder {
         self
     }
 
-    /// ### On Wayland
-    /// On Wayland this sets the Application ID for the window.
+    /// Sets the application id of the window.
     ///
-    /// The application ID is used in several places of the compositor, e.g. for
-    /// grouping windows of the same application. It is also important for
+    /// The application id is used by the desktop environment, e.g. for
+    /// grouping windows of the same application in the taskbar. It is also important for
     /// connecting the configuration of a `.desktop` file with the window, by
-    /// using the application ID as file name. This allows e.g. a proper icon
+    /// using the application id as file name. This allows e.g. a proper icon
     /// handling under Wayland.
     ///
-    /// See [Waylands XDG shell documentation][xdg-shell] for more information
-    /// on this Wayland-specific option.
+    /// Integrations map this to what the platform uses for this:
+    ///
+    /// * Wayland: the `app_id` of the toplevel, see the [XDG shell documentation][xdg-shell].
+    /// * X11: both the class and the instance of `WM_CLASS`.
+    /// * Windows: the `AppUserModelID`. This is per process, so it applies to all windows of the app.
+    ///
+    /// Other platforms ignore it.
     ///
     /// The `app_id` should match the `.desktop` file distributed with your program.
     ///
@@ -564,18 +590,71 @@ impl ViewportBuilder {
     }
 
     /// Control if window is always-on-top, always-on-bottom, or neither.
+    ///
+    /// This is applied when the window is created,
+    /// so there is no need to send a [`ViewportCommand::WindowLevel`] after the first frame.
+    /// Changing it later sends that command for you.
     #[inline]
     pub fn with_window_level(mut self, level: WindowLevel) -> Self {
         self.window_level = Some(level);
         self
     }
 
-    /// This window is always on top
+    /// This window is always on top.
+    ///
+    /// The same as `with_window_level(WindowLevel::AlwaysOnTop)`.
     #[inline]
     pub fn with_always_on_top(self) -> Self {
         self.with_window_level(WindowLevel::AlwaysOnTop)
     }
 
+    /// Make this viewport a modal dialog of the `parent` viewport.
+    ///
+    /// The viewport stays on top of its parent (unless you set a [`Self::with_window_level`]),
+    /// and the parent ignores all keyboard and pointer input for as long as this viewport is shown.
+    /// Clicking the parent focuses this viewport instead.
+    ///
+    /// When embedded (see [`crate::Context::embed_viewports`]) this has no effect.
+    #[inline]
+    pub fn with_modal(mut self, parent: ViewportId) -> Self {
+        self.modal = Some(parent);
+        self
+    }
+
+    /// Remember the position, size, maximized and fullscreen state of this viewport in [`crate::Memory`],
+    /// keyed by its [`ViewportId`], and restore them when the viewport is created again.
+    ///
+    /// With the `persistence` feature this is also restored across runs of the app.
+    /// The restored state takes priority over [`Self::with_position`], [`Self::with_inner_size`],
+    /// [`Self::with_maximized`] and [`Self::with_fullscreen`].
+    ///
+    /// This has no effect on the root viewport, nor on embedded viewports.
+    ///
+    /// The default is `false`.
+    #[inline]
+    pub fn with_persist(mut self, persist: bool) -> Self {
+        self.persist = Some(persist);
+        self
+    }
+
+    /// Snap the viewport to the edges of its parent and sibling viewports when it is moved
+    /// to within `distance` points of them, like a magnet.
+    ///
+    /// Sibling viewports are the other viewports with the same parent.
+    /// The edges snap both next to each other, and in line with each other.
+    ///
+    /// What the viewport is snapped to is reported in [`ViewportOutput::snapped`]
+    /// and [`crate::Context::viewport_snapped`], so that you can move tool palettes along with their parent.
+    ///
+    /// This has no effect on the root viewport, nor on embedded viewports.
+    ///
+    /// The default is `0.0`, meaning no snapping.
+    #[inline]
+    pub fn with_snap_distance(mut self, distance: f32) -> Self {
+        self.snap_distance = Some(Vec2::splat(distance));
+        self
+    }
+
     /// On desktop: mouse clicks pass through the window, used for non-interactable overlays.
     ///
     /// Generally you would use this in conjunction with [`Self::with_transparent`]
@@ -623,6 +702,9 @@ impl ViewportBuilder {
             minimize_button: new_minimize_button,
             maximize_button: new_maximize_button,
             window_level: new_window_level,
+            modal: new_modal,
+            persist: new_persist,
+            snap_distance: new_snap_distance,
             mouse_passthrough: new_mouse_passthrough,
             taskbar: new_taskbar,
             window_type: new_window_type,
@@ -804,19 +886,175 @@ impl ViewportBuilder {
             recreate_window = true;
         }
 
+        if new_persist.is_some() {
+            self.persist = new_persist; // handled by egui
+        }
+
+        if new_snap_distance.is_some() {
+            self.snap_distance = new_snap_distance; // handled by egui
+        }
+
+        if new_modal.is_some() && self.modal != new_modal {
+            self.modal = new_modal;
+            recreate_window = true;
+        }
+
         (commands, recreate_window)
     }
 }
 
+/// Where a window is stacked relative to other windows.
+///
+/// See [`ViewportCommand::WindowLevel`].
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum WindowLevel {
+    /// The default.
     #[default]
     Normal,
+
+    /// Always behind other windows.
     AlwaysOnBottom,
+
+    /// Always in front of other windows.
     AlwaysOnTop,
 }
 
+/// The state of a viewport that is remembered with [`ViewportBuilder::with_persist`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub(crate) struct PersistedViewport {
+    /// Outer position, when neither maximized nor fullscreen.
+    pub position: Option<Pos2>,
+
+    /// Inner size, when neither maximized nor fullscreen.
+    pub inner_size: Option<Vec2>,
+
+    pub maximized: Option<bool>,
+    pub fullscreen: Option<bool>,
+}
+
+impl PersistedViewport {
+    /// Where in [`crate::Memory::data`] the state of the viewport is stored.
+    pub fn id(viewport_id: ViewportId) -> Id {
+        viewport_id.0.with("persisted_viewport")
+    }
+
+    /// Update with the latest info from the integration.
+    ///
+    /// The position and size are only updated when the window is in its normal state,
+    /// so that un-maximizing a restored window gives back its previous size.
+    pub fn update(&mut self, info: &crate::ViewportInfo) {
+        if info.minimized == Some(true) {
+            return;
+        }
+
+        self.maximized = info.maximized.or(self.maximized);
+        self.fullscreen = info.fullscreen.or(self.fullscreen);
+
+        if self.maximized != Some(true) && self.fullscreen != Some(true) {
+            if let Some(outer_rect) = info.outer_rect {
+                self.position = Some(outer_rect.min);
+            }
+            if let Some(inner_rect) = info.inner_rect {
+                self.inner_size = Some(inner_rect.size());
+            }
+        }
+    }
+
+    pub fn apply(&self, mut builder: ViewportBuilder) -> ViewportBuilder {
+        builder.position = self.position.or(builder.position);
+        builder.inner_size = self.inner_size.or(builder.inner_size);
+        builder.maximized = self.maximized.or(builder.maximized);
+        builder.fullscreen = self.fullscreen.or(builder.fullscreen);
+        builder
+    }
+}
+
+/// How a viewport is snapped to another one, see [`ViewportBuilder::with_snap_distance`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewportSnap {
+    /// The parent or sibling viewport we are snapped to.
+    pub to: ViewportId,
+
+    /// Our outer position relative to the outer position of [`Self::to`].
+    ///
+    /// To move a snapped viewport along with the one it is snapped to,
+    /// send it a [`ViewportCommand::OuterPosition`] with the new position of [`Self::to`] plus this offset.
+    pub offset: Vec2,
+
+    /// Is our left or right edge snapped to the left or right edge of [`Self::to`]?
+    pub horizontal: bool,
+
+    /// Is our top or bottom edge snapped to the top or bottom edge of [`Self::to`]?
+    pub vertical: bool,
+}
+
+/// Snap `rect` to the closest of the edges of `others` within `distance`.
+///
+/// Returns how much to move `rect`, and what it is then snapped to.
+pub(crate) fn snap_to_viewports(
+    rect: Rect,
+    others: &[(ViewportId, Rect)],
+    distance: Vec2,
+) -> (Vec2, Vec<ViewportSnap>) {
+    let mut delta = Vec2::ZERO;
+    let mut targets: [Option<(ViewportId, Rect)>; 2] = [None; 2];
+
+    for d in 0..2 {
+        let across = 1 - d;
+        let mut best: Option<f32> = None;
+        for &(other_id, other) in others {
+            // Only snap to viewports that are next to us:
+            if rect.max[across] + distance[across] < other.min[across]
+                || other.max[across] + distance[across] < rect.min[across]
+            {
+                continue;
+            }
+
+            let candidates = [
+                other.max[d] - rect.min[d], // next to each other
+                other.min[d] - rect.max[d], // next to each other
+                other.min[d] - rect.min[d], // in line
+                other.max[d] - rect.max[d], // in line
+            ];
+            for diff in candidates {
+                if diff.abs() <= distance[d] && best.map_or(true, |best| diff.abs() < best.abs()) {
+                    best = Some(diff);
+                    targets[d] = Some((other_id, other));
+                }
+            }
+        }
+        delta[d] = best.unwrap_or(0.0);
+    }
+
+    let mut snapped: Vec<ViewportSnap> = Vec::new();
+    for (d, target) in targets.into_iter().enumerate() {
+        let Some((to, other)) = target else {
+            continue;
+        };
+        let snap = if let Some(snap) = snapped.iter_mut().find(|snap| snap.to == to) {
+            snap
+        } else {
+            snapped.push(ViewportSnap {
+                to,
+                offset: rect.min + delta - other.min,
+                horizontal: false,
+                vertical: false,
+            });
+            snapped.last_mut().unwrap()
+        };
+        if d == 0 {
+            snap.horizontal = true;
+        } else {
+            snap.vertical = true;
+        }
+    }
+
+    (delta, snapped)
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum X11WindowType {
@@ -872,30 +1110,54 @@ pub enum X11WindowType {
     Dnd,
 }
 
+/// What the text being edited with an IME is for.
+///
+/// See [`ViewportCommand::IMEPurpose`].
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum IMEPurpose {
+    /// Normal text.
     #[default]
     Normal,
+
+    /// A password, which the IME should not remember or show.
     Password,
+
+    /// Terminal input, e.g. with shortcuts like `ctrl+c`.
     Terminal,
 }
 
+/// The theme of the window decorations.
+///
+/// See [`ViewportCommand::SetTheme`].
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum SystemTheme {
+    /// Follow the operating system.
     #[default]
     SystemDefault,
+
+    /// Light window decorations.
     Light,
+
+    /// Dark window decorations.
     Dark,
 }
 
+/// How the cursor is kept inside the window.
+///
+/// See [`ViewportCommand::CursorGrab`].
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum CursorGrab {
+    /// The cursor can move freely.
     #[default]
     None,
+
+    /// The cursor can move, but not leave the window.
     Confined,
+
+    /// The cursor is locked in place.
     Locked,
 }
 
@@ -921,7 +1183,7 @@ pub enum ResizeDirection {
 /// All coordinates are in logical points.
 ///
 /// This is essentially a way to diff [`ViewportBuilder`].
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ViewportCommand {
     /// Request this viewport to be closed.
@@ -951,6 +1213,16 @@ pub enum ViewportCommand {
     /// Set the outer position of the viewport, i.e. moves the window.
     OuterPosition(Pos2),
 
+    /// Set the outer position of the viewport relative to the top left corner of a monitor,
+    /// i.e. moves the window to that monitor.
+    ///
+    /// `monitor` is an index into [`crate::RawInput::monitors`].
+    /// See also [`Self::center_on_monitor`].
+    OuterPositionOnMonitor {
+        monitor: usize,
+        position: Pos2,
+    },
+
     /// Should be bigger than 0
     InnerSize(Vec2),
 
@@ -1037,7 +1309,14 @@ pub enum ViewportCommand {
     /// Take a screenshot.
     ///
     /// The results are returned in `crate::Event::Screenshot`.
+    /// See also [`crate::Context::viewport_screenshot`].
     Screenshot,
+
+    /// Set the zoom factor of this viewport, overriding [`crate::Context::zoom_factor`].
+    ///
+    /// This is handled by egui itself, and never reaches the integration.
+    /// See [`crate::Context::viewport_zoom_factor`].
+    ZoomFactor(f32),
 }
 
 impl ViewportCommand {
@@ -1057,12 +1336,56 @@ impl ViewportCommand {
         })
     }
 
+    /// Construct a command to center the viewport on the given monitor, if possible.
+    ///
+    /// `monitor` is an index into [`crate::InputState::monitors`].
+    pub fn center_on_monitor(ctx: &crate::Context, monitor: usize) -> Option<Self> {
+        ctx.input(|i| {
+            let size = i.viewport().outer_rect?.size();
+            let monitor_size = i.monitors().get(monitor)?.rect.size();
+            let position = ((monitor_size - size) / 2.0).max(Vec2::ZERO).to_pos2();
+            Some(Self::OuterPositionOnMonitor { monitor, position })
+        })
+    }
+
     /// This command requires the parent viewport to repaint.
     pub fn requires_parent_repaint(&self) -> bool {
         self == &Self::Close
     }
 }
 
+/// What became of a [`ViewportCommand`] sent with [`crate::Context::viewport_command_with_ack`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewportCommandAck {
+    /// The command that was sent.
+    pub command: ViewportCommand,
+
+    /// Is the viewport in the requested state?
+    pub status: ViewportCommandStatus,
+
+    /// The state of the viewport in the frame after the command was sent.
+    pub info: crate::ViewportInfo,
+}
+
+/// See [`ViewportCommandAck::status`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ViewportCommandStatus {
+    /// The viewport is in the requested state, e.g. has the requested size.
+    Applied,
+
+    /// The viewport is not in the requested state.
+    ///
+    /// The OS (or the integration) may have refused or altered the request,
+    /// e.g. clamped the size to the monitor, or it may still be in progress,
+    /// e.g. an animated switch to fullscreen.
+    /// Look at [`ViewportCommandAck::info`] for the actual state.
+    Rejected,
+
+    /// We can't tell, because the command doesn't change anything in [`crate::ViewportInfo`],
+    /// or because the integration doesn't report it.
+    Unknown,
+}
+
 /// Describes a viewport, i.e. a native window.
 ///
 /// This is returned by [`crate::Context::run`] on each frame, and should be applied
@@ -1100,6 +1423,9 @@ pub struct ViewportOutput {
     ///
     /// If the duration is zero, schedule a repaint immediately.
     pub repaint_delay: std::time::Duration,
+
+    /// What this viewport is snapped to, see [`ViewportBuilder::with_snap_distance`].
+    pub snapped: Vec<ViewportSnap>,
 }
 
 impl ViewportOutput {
@@ -1112,6 +1438,7 @@ impl ViewportOutput {
             viewport_ui_cb,
             mut commands,
             repaint_delay,
+            snapped,
         } = newer;
 
         self.parent = parent;
@@ -1120,6 +1447,7 @@ impl ViewportOutput {
         self.viewport_ui_cb = viewport_ui_cb;
         self.commands.append(&mut commands);
         self.repaint_delay = self.repaint_delay.min(repaint_delay);
+        self.snapped = snapped;
     }
 }
 
@@ -1133,3 +1461,82 @@ pub struct ImmediateViewport<'a> {
     /// The user-code that shows the GUI.
     pub viewport_ui_cb: Box<dyn FnOnce(&Context) + 'a>,
 }
+
+#[cfg(test)]
+mod tests {
+    use epaint::{pos2, vec2};
+
+    use super::*;
+
+    #[test]
+    fn snap_next_to_and_in_line_with_parent() {
+        let parent = ViewportId::from_hash_of("parent");
+        let others = [(
+            parent,
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0)),
+        )];
+
+        // Just right of the parent, a bit below its top edge:
+        let rect = Rect::from_min_size(pos2(105.0, 3.0), vec2(50.0, 50.0));
+        let (delta, snapped) = snap_to_viewports(rect, &others, Vec2::splat(10.0));
+        assert_eq!(delta, vec2(-5.0, -3.0));
+        assert_eq!(
+            snapped,
+            vec![ViewportSnap {
+                to: parent,
+                offset: vec2(100.0, 0.0),
+                horizontal: true,
+                vertical: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn no_snap_beyond_distance() {
+        let parent = ViewportId::from_hash_of("parent");
+        let others = [(
+            parent,
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0)),
+        )];
+
+        let rect = Rect::from_min_size(pos2(120.0, 3.0), vec2(50.0, 50.0));
+        let (delta, snapped) = snap_to_viewports(rect, &others, Vec2::splat(10.0));
+        assert_eq!(delta, Vec2::ZERO);
+        assert!(snapped.is_empty());
+    }
+
+    #[test]
+    fn snap_to_closest_edge_per_axis() {
+        let left = ViewportId::from_hash_of("left");
+        let above = ViewportId::from_hash_of("above");
+        let others = [
+            (left, Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0))),
+            (
+                above,
+                Rect::from_min_max(pos2(0.0, -200.0), pos2(300.0, 1.0)),
+            ),
+        ];
+
+        // Closest to the right edge of `left`, and to the bottom edge of `above`:
+        let rect = Rect::from_min_size(pos2(104.0, 3.0), vec2(50.0, 50.0));
+        let (delta, snapped) = snap_to_viewports(rect, &others, Vec2::splat(10.0));
+        assert_eq!(delta, vec2(-4.0, -2.0));
+        assert_eq!(
+            snapped,
+            vec![
+                ViewportSnap {
+                    to: left,
+                    offset: vec2(100.0, 1.0),
+                    horizontal: true,
+                    vertical: false,
+                },
+                ViewportSnap {
+                    to: above,
+                    offset: vec2(100.0, 201.0),
+                    horizontal: false,
+                    vertical: true,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! A title bar and resize edges for viewports without OS decorations.
+//!
+//! When you create a viewport with [`super::ViewportBuilder::with_decorations`] set to `false`,
+//! the window has no title bar to drag it around with, no buttons to close it,
+//! and (on most platforms) no edges to resize it with.
+//! Use [`TitleBar`] and [`resize_edges`] to add them back.
+//!
+//! ```
+//! # egui::__run_test_ctx(|ctx| {
+//! use egui::viewport::custom_titlebar::{resize_edges, TitleBar};
+//!
+//! egui::CentralPanel::default().show(ctx, |ui| {
+//!     TitleBar::new("My app").show(ui);
+//!     ui.label("The contents of the window.");
+//! });
+//! resize_edges(ctx, 4.0);
+//! # });
+//! ```
+
+use crate::{
+    emath::{vec2, Align2, Rect, Vec2},
+    Align, Button, Context, CursorIcon, Id, LayerId, Layout, Order, Response, RichText, Sense, Ui,
+    WidgetRect, WidgetText,
+};
+
+use super::{ResizeDirection, ViewportCommand};
+
+/// A title strip for a viewport without decorations.
+///
+/// Dragging the strip moves the window ([`ViewportCommand::StartDrag`]),
+/// and double-clicking it toggles [`ViewportCommand::Maximized`].
+/// To the right are buttons to minimize, maximize/restore and close the window.
+///
+/// See the [module-level docs](self) for an example.
+#[must_use = "You should call .show()"]
+pub struct TitleBar {
+    title: WidgetText,
+    height: f32,
+    minimize_button: bool,
+    maximize_button: bool,
+    close_button: bool,
+}
+
+impl TitleBar {
+    pub fn new(title: impl Into<WidgetText>) -> Self {
+        Self {
+            title: title.into(),
+            height: 32.0,
+            minimize_button: true,
+            maximize_button: true,
+            close_button: true,
+        }
+    }
+
+    /// The height of the title bar.
+    ///
+    /// Default: `32.0`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Show a button that minimizes the window?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn minimize_button(mut self, minimize_button: bool) -> Self {
+        self.minimize_button = minimize_button;
+        self
+    }
+
+    /// Show a button that maximizes/restores the window,
+    /// and toggle maximized on double-click?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn maximize_button(mut self, maximize_button: bool) -> Self {
+        self.maximize_button = maximize_button;
+        self
+    }
+
+    /// Show a button that closes the window, by sending [`ViewportCommand::Close`]?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn close_button(mut self, close_button: bool) -> Self {
+        self.close_button = close_button;
+        self
+    }
+
+    /// Show the title bar at the top of the available space of the given [`Ui`],
+    /// spanning its full width.
+    ///
+    /// Returns the response of the draggable strip.
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let Self {
+            title,
+            height,
+            minimize_button,
+            maximize_button,
+            close_button,
+        } = self;
+
+        let (rect, _) = ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::hover());
+        let response = ui.interact(
+            rect,
+            ui.id().with("custom_titlebar"),
+            Sense::click_and_drag(),
+        );
+
+        let ctx = ui.ctx().clone();
+        let is_maximized = ui.input(|i| i.viewport().maximized.unwrap_or(false));
+
+        // Paint the title:
+        let galley = title.into_galley(ui, Some(false), rect.width(), crate::TextStyle::Body);
+        let text_pos = Align2::CENTER_CENTER
+            .align_size_within_rect(galley.size(), rect)
+            .min;
+        ui.painter()
+            .galley(text_pos, galley, ui.visuals().text_color());
+
+        // Paint the line under the title:
+        ui.painter().hline(
+            rect.x_range(),
+            rect.bottom(),
+            ui.visuals().widgets.noninteractive.bg_stroke,
+        );
+
+        if maximize_button && response.double_clicked() {
+            ctx.send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
+        } else if response.drag_started_by(crate::PointerButton::Primary) {
+            // We wait for the drag to start instead of sending this on press,
+            // or the OS would swallow the second click of a double-click.
+            ctx.send_viewport_cmd(ViewportCommand::StartDrag);
+        }
+
+        // The buttons, added after the strip so that they are on top of it:
+        ui.allocate_ui_at_rect(rect, |ui| {
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                ui.visuals_mut().button_frame = false;
+                ui.add_space(8.0);
+
+                let button_height = 12.0;
+                let button = |icon: &str| Button::new(RichText::new(icon).size(button_height));
+
+                if close_button
+                    && ui
+                        .add(button("❌"))
+                        .on_hover_text("Close the window")
+                        .clicked()
+                {
+                    ctx.send_viewport_cmd(ViewportCommand::Close);
+                }
+
+                if maximize_button {
+                    let hover_text = if is_maximized {
+                        "Restore window"
+                    } else {
+                        "Maximize window"
+                    };
+                    if ui.add(button("🗗")).on_hover_text(hover_text).clicked() {
+                        ctx.send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
+                    }
+                }
+
+                if minimize_button
+                    && ui
+                        .add(button("🗕"))
+                        .on_hover_text("Minimize the window")
+                        .clicked()
+                {
+                    ctx.send_viewport_cmd(ViewportCommand::Minimized(true));
+                }
+            });
+        });
+
+        response
+    }
+}
+
+/// Make the edges and corners of the current viewport resize it when dragged,
+/// using [`ViewportCommand::BeginResize`].
+///
+/// `thickness` is the width of the edges, in points. The corners are twice as large.
+///
+/// Call this once per frame, after adding the rest of the ui.
+/// Does nothing while the viewport is maximized or fullscreen.
+pub fn resize_edges(ctx: &Context, thickness: f32) {
+    let (maximized, fullscreen) = ctx.input(|i| {
+        let info = i.viewport();
+        (info.maximized == Some(true), info.fullscreen == Some(true))
+    });
+    if maximized || fullscreen {
+        return;
+    }
+
+    let rect = ctx.screen_rect();
+    let corner = Vec2::splat(2.0 * thickness);
+    let zones = [
+        // Edges first, so the corners are on top of them:
+        (
+            ResizeDirection::North,
+            CursorIcon::ResizeNorth,
+            Rect::from_x_y_ranges(rect.x_range(), rect.top()..=rect.top() + thickness),
+        ),
+        (
+            ResizeDirection::South,
+            CursorIcon::ResizeSouth,
+            Rect::from_x_y_ranges(rect.x_range(), rect.bottom() - thickness..=rect.bottom()),
+        ),
+        (
+            ResizeDirection::West,
+            CursorIcon::ResizeWest,
+            Rect::from_x_y_ranges(rect.left()..=rect.left() + thickness, rect.y_range()),
+        ),
+        (
+            ResizeDirection::East,
+            CursorIcon::ResizeEast,
+            Rect::from_x_y_ranges(rect.right() - thickness..=rect.right(), rect.y_range()),
+        ),
+        (
+            ResizeDirection::NorthWest,
+            CursorIcon::ResizeNorthWest,
+            Rect::from_min_size(rect.left_top(), corner),
+        ),
+        (
+            ResizeDirection::NorthEast,
+            CursorIcon::ResizeNorthEast,
+            Align2::RIGHT_TOP.align_size_within_rect(corner, rect),
+        ),
+        (
+            ResizeDirection::SouthWest,
+            CursorIcon::ResizeSouthWest,
+            Align2::LEFT_BOTTOM.align_size_within_rect(corner, rect),
+        ),
+        (
+            ResizeDirection::SouthEast,
+            CursorIcon::ResizeSouthEast,
+            Align2::RIGHT_BOTTOM.align_size_within_rect(corner, rect),
+        ),
+    ];
+
+    let layer_id = LayerId::new(Order::Foreground, Id::new("custom_titlebar_resize_edges"));
+    for (i, (direction, cursor_icon, zone)) in zones.into_iter().enumerate() {
+        let response = ctx.create_widget(WidgetRect {
+            id: layer_id.id.with(i),
+            layer_id,
+            rect: zone,
+            interact_rect: zone,
+            sense: Sense::drag(),
+            enabled: true,
+        });
+
+        if response.hovered() || response.dragged() {
+            ctx.set_cursor_icon(cursor_icon);
+        }
+        if response.hovered() && ctx.input(|i| i.pointer.primary_pressed()) {
+            ctx.send_viewport_cmd(ViewportCommand::BeginResize(direction));
+        }
+    }
+}
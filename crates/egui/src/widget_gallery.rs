@@ -0,0 +1,208 @@
+//! Show all the built-in widgets in all their interaction states, see [`widget_gallery`].
+
+use crate::{
+    style::WidgetVisuals, Button, Checkbox, CollapsingHeader, Color32, ComboBox, DragValue, Grid,
+    ProgressBar, RadioButton, Slider, Spinner, TextEdit, Ui,
+};
+
+/// An interaction state shown by [`widget_gallery`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum GalleryState {
+    /// Painted with [`crate::style::Widgets::inactive`].
+    Inactive,
+
+    /// Painted with [`crate::style::Widgets::hovered`], as if the pointer was over the widget.
+    Hovered,
+
+    /// Painted with [`crate::style::Widgets::active`], as if the widget was being clicked or dragged.
+    Active,
+
+    /// Painted with [`crate::style::Widgets::open`], as if e.g. a combo box was open.
+    Open,
+
+    /// Disabled, see [`Ui::set_enabled`].
+    Disabled,
+}
+
+impl GalleryState {
+    pub const ALL: [Self; 5] = [
+        Self::Inactive,
+        Self::Hovered,
+        Self::Active,
+        Self::Open,
+        Self::Disabled,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Inactive => "Inactive",
+            Self::Hovered => "Hovered",
+            Self::Active => "Active",
+            Self::Open => "Open",
+            Self::Disabled => "Disabled",
+        }
+    }
+
+    /// Make all widgets in this [`Ui`] look like they are in this state,
+    /// no matter how the user interacts with them.
+    fn apply(self, ui: &mut Ui) {
+        let widgets = &ui.visuals().widgets;
+        let visuals: WidgetVisuals = match self {
+            Self::Inactive | Self::Disabled => widgets.inactive,
+            Self::Hovered => widgets.hovered,
+            Self::Active => widgets.active,
+            Self::Open => widgets.open,
+        };
+        let widgets = &mut ui.visuals_mut().widgets;
+        widgets.inactive = visuals;
+        widgets.hovered = visuals;
+        widgets.active = visuals;
+        widgets.open = visuals;
+        if self == Self::Disabled {
+            ui.set_enabled(false);
+        }
+    }
+}
+
+/// Options for [`widget_gallery`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GalleryOptions {
+    /// The states to show, one column each. Default: [`GalleryState::ALL`].
+    pub states: Vec<GalleryState>,
+
+    /// Show the name of each widget and state. Default: `true`.
+    pub show_names: bool,
+
+    /// Include widgets that animate, like [`Spinner`].
+    ///
+    /// These repaint every frame and never look the same twice,
+    /// so turn this off for golden-image tests. Default: `true`.
+    pub animated: bool,
+}
+
+impl Default for GalleryOptions {
+    fn default() -> Self {
+        Self {
+            states: GalleryState::ALL.to_vec(),
+            show_names: true,
+            animated: true,
+        }
+    }
+}
+
+/// Show every built-in widget in every [`GalleryState`], as a grid with one column per state.
+///
+/// The hovered, active and open states are simulated by changing [`crate::style::Widgets`],
+/// so the whole gallery can be seen at once, without touching anything.
+/// Use it to preview a [`crate::Style`] while designing a theme,
+/// or to take a screenshot of it in a golden-image test.
+///
+/// The widgets have no state of their own: changing a value has no lasting effect.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::widget_gallery(
+///     ui,
+///     egui::GalleryOptions {
+///         animated: false,
+///         ..Default::default()
+///     },
+/// );
+/// # });
+/// ```
+pub fn widget_gallery(ui: &mut Ui, options: GalleryOptions) {
+    ui.push_id("widget_gallery", |ui| {
+        Grid::new("widget_gallery_grid")
+            .striped(true)
+            .show(ui, |ui| gallery_rows(ui, &options));
+    });
+}
+
+fn gallery_rows(ui: &mut Ui, options: &GalleryOptions) {
+    if options.show_names {
+        ui.label("");
+        for state in &options.states {
+            ui.strong(state.name());
+        }
+        ui.end_row();
+    }
+
+    gallery_row(ui, options, "Label", |ui, _| {
+        ui.label("Label");
+    });
+    gallery_row(ui, options, "Hyperlink", |ui, _| {
+        ui.hyperlink_to("Hyperlink", "https://www.egui.rs/");
+    });
+    gallery_row(ui, options, "Button", |ui, _| {
+        ui.add(Button::new("Button"));
+    });
+    gallery_row(ui, options, "Small button", |ui, _| {
+        let _ = ui.small_button("Small");
+    });
+    gallery_row(ui, options, "Checkbox", |ui, _| {
+        ui.add(Checkbox::new(&mut true, "Checked"));
+        ui.add(Checkbox::new(&mut false, "Unchecked"));
+    });
+    gallery_row(ui, options, "Radio button", |ui, _| {
+        ui.add(RadioButton::new(true, "Selected"));
+        ui.add(RadioButton::new(false, "Unselected"));
+    });
+    gallery_row(ui, options, "Selectable label", |ui, _| {
+        let _ = ui.selectable_label(true, "Selected");
+        let _ = ui.selectable_label(false, "Unselected");
+    });
+    gallery_row(ui, options, "Slider", |ui, _| {
+        ui.add(Slider::new(&mut 0.5, 0.0..=1.0));
+    });
+    gallery_row(ui, options, "Drag value", |ui, _| {
+        ui.add(DragValue::new(&mut 42.0));
+    });
+    gallery_row(ui, options, "Text edit", |ui, _| {
+        ui.add(TextEdit::singleline(&mut String::from("Text")).desired_width(80.0));
+    });
+    gallery_row(ui, options, "Combo box", |ui, state| {
+        ComboBox::from_id_source(("combo_box", state))
+            .selected_text("Combo box")
+            .show_ui(ui, |ui| {
+                let _ = ui.selectable_label(true, "Combo box");
+            });
+    });
+    gallery_row(ui, options, "Color picker", |ui, _| {
+        ui.color_edit_button_srgba(&mut Color32::from_rgb(100, 150, 200));
+    });
+    gallery_row(ui, options, "Progress bar", |ui, _| {
+        ui.add(ProgressBar::new(0.5).desired_width(80.0).show_percentage());
+    });
+    gallery_row(ui, options, "Collapsing header", |ui, state| {
+        CollapsingHeader::new("Collapsing")
+            .id_source(("collapsing_header", state))
+            .show(ui, |ui| {
+                ui.label("Contents");
+            });
+    });
+    if options.animated {
+        gallery_row(ui, options, "Spinner", |ui, _| {
+            ui.add(Spinner::new());
+        });
+    }
+}
+
+/// One widget in all the states of `options`.
+fn gallery_row(
+    ui: &mut Ui,
+    options: &GalleryOptions,
+    name: &str,
+    add_widget: impl Fn(&mut Ui, GalleryState),
+) {
+    if options.show_names {
+        ui.label(name);
+    }
+    for &state in &options.states {
+        ui.scope(|ui| {
+            state.apply(ui);
+            ui.horizontal(|ui| add_widget(ui, state));
+        });
+    }
+    ui.end_row();
+}
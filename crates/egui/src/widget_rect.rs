@@ -96,7 +96,10 @@ impl WidgetRects {
 
     /// All widgets in this layer, sorted back-to-front.
     #[inline]
-    pub fn get_layer(&self, layer_id: LayerId) -> impl Iterator<Item = &WidgetRect> + '_ {
+    pub fn get_layer(
+        &self,
+        layer_id: LayerId,
+    ) -> impl DoubleEndedIterator<Item = &WidgetRect> + '_ {
         self.by_layer.get(&layer_id).into_iter().flatten()
     }
 
@@ -0,0 +1,172 @@
+//! Track how the user interacts with a custom widget, and animate between the matching visuals.
+//!
+//! See [`WidgetState`].
+
+use crate::{emath::lerp, style::WidgetVisuals, Color32, Response, Rounding, Stroke, Ui};
+
+/// How the user is interacting with a widget, from least to most engaged.
+///
+/// See [`WidgetState`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WidgetInteraction {
+    /// Not interacted with.
+    #[default]
+    Idle,
+
+    /// The pointer is over the widget (or it is highlighted).
+    Hovered,
+
+    /// The widget has keyboard focus.
+    Focused,
+
+    /// A pointer button is down on the widget, but it is not being dragged.
+    Pressed,
+
+    /// The widget is being dragged.
+    Dragged,
+}
+
+impl WidgetInteraction {
+    fn from_response(response: &Response) -> Self {
+        if response.dragged() {
+            Self::Dragged
+        } else if response.is_pointer_button_down_on() {
+            Self::Pressed
+        } else if response.has_focus() {
+            Self::Focused
+        } else if response.hovered() || response.highlighted() {
+            Self::Hovered
+        } else {
+            Self::Idle
+        }
+    }
+}
+
+/// The interaction state of a custom widget, with animated transitions and matching [`WidgetVisuals`].
+///
+/// This replaces the usual boilerplate of picking visuals and animating hover effects
+/// when painting a custom widget with [`Ui::allocate_exact_size`] and a [`crate::Painter`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let (rect, response) = ui.allocate_exact_size(egui::vec2(60.0, 20.0), egui::Sense::click_and_drag());
+/// let state = egui::WidgetState::new(ui, &response);
+/// if state.entered(egui::WidgetInteraction::Pressed) {
+///     println!("Pressed!");
+/// }
+///
+/// let visuals = state.visuals;
+/// ui.painter().rect(
+///     rect.expand(visuals.expansion),
+///     visuals.rounding,
+///     visuals.bg_fill,
+///     visuals.bg_stroke,
+/// );
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WidgetState {
+    /// The interaction this frame.
+    pub interaction: WidgetInteraction,
+
+    /// The interaction last frame.
+    pub previous: WidgetInteraction,
+
+    /// Animates from 0 to 1 when the widget is hovered (or more).
+    pub hover_t: f32,
+
+    /// Animates from 0 to 1 when the widget is focused, pressed or dragged.
+    pub active_t: f32,
+
+    /// The visuals to paint the widget with, interpolated between
+    /// [`crate::style::Widgets::inactive`], [`crate::style::Widgets::hovered`] and [`crate::style::Widgets::active`]
+    /// using [`Self::hover_t`] and [`Self::active_t`].
+    ///
+    /// [`crate::style::Widgets::noninteractive`] if the widget doesn't sense clicks or drags.
+    pub visuals: WidgetVisuals,
+}
+
+impl WidgetState {
+    /// Call this once per frame for the widget, after allocating its [`Response`].
+    ///
+    /// The animations use [`crate::Style::animation_time`].
+    pub fn new(ui: &Ui, response: &Response) -> Self {
+        let id = response.id.with("__widget_state");
+        let interaction = WidgetInteraction::from_response(response);
+        let previous = ui.data(|d| d.get_temp::<WidgetInteraction>(id));
+        ui.data_mut(|d| d.insert_temp(id, interaction));
+        let previous = previous.unwrap_or(interaction);
+
+        let ctx = ui.ctx();
+        let hover_t = ctx.animate_bool(id.with("hover"), WidgetInteraction::Hovered <= interaction);
+        let active_t =
+            ctx.animate_bool(id.with("active"), WidgetInteraction::Focused <= interaction);
+
+        let widgets = &ui.style().visuals.widgets;
+        let visuals = if response.sense.interactive() {
+            let hovered = lerp_visuals(&widgets.inactive, &widgets.hovered, hover_t);
+            lerp_visuals(&hovered, &widgets.active, active_t)
+        } else {
+            widgets.noninteractive
+        };
+
+        Self {
+            interaction,
+            previous,
+            hover_t,
+            active_t,
+            visuals,
+        }
+    }
+
+    /// Did the interaction change since last frame?
+    pub fn changed(&self) -> bool {
+        self.interaction != self.previous
+    }
+
+    /// Did the interaction become `interaction` this frame?
+    pub fn entered(&self, interaction: WidgetInteraction) -> bool {
+        self.interaction == interaction && self.previous != interaction
+    }
+
+    /// Was the interaction `interaction` last frame, but not anymore?
+    pub fn left(&self, interaction: WidgetInteraction) -> bool {
+        self.previous == interaction && self.interaction != interaction
+    }
+}
+
+fn lerp_visuals(a: &WidgetVisuals, b: &WidgetVisuals, t: f32) -> WidgetVisuals {
+    if t <= 0.0 {
+        return *a;
+    }
+    if 1.0 <= t {
+        return *b;
+    }
+    WidgetVisuals {
+        bg_fill: lerp_color(a.bg_fill, b.bg_fill, t),
+        weak_bg_fill: lerp_color(a.weak_bg_fill, b.weak_bg_fill, t),
+        bg_stroke: lerp_stroke(a.bg_stroke, b.bg_stroke, t),
+        rounding: Rounding {
+            nw: lerp(a.rounding.nw..=b.rounding.nw, t),
+            ne: lerp(a.rounding.ne..=b.rounding.ne, t),
+            sw: lerp(a.rounding.sw..=b.rounding.sw, t),
+            se: lerp(a.rounding.se..=b.rounding.se, t),
+        },
+        fg_stroke: lerp_stroke(a.fg_stroke, b.fg_stroke, t),
+        expansion: lerp(a.expansion..=b.expansion, t),
+    }
+}
+
+fn lerp_stroke(a: Stroke, b: Stroke, t: f32) -> Stroke {
+    Stroke::new(lerp(a.width..=b.width, t), lerp_color(a.color, b.color, t))
+}
+
+fn lerp_color(a: Color32, b: Color32, t: f32) -> Color32 {
+    let channel = |a: u8, b: u8| lerp(f32::from(a)..=f32::from(b), t).round() as u8;
+    Color32::from_rgba_premultiplied(
+        channel(a.r(), b.r()),
+        channel(a.g(), b.g()),
+        channel(a.b(), b.b()),
+        channel(a.a(), b.a()),
+    )
+}
@@ -0,0 +1,210 @@
+use std::{cell::RefCell, rc::Rc, sync::mpsc::Sender};
+
+use super::TextBuffer;
+
+type GetSetValue<'a, T> = Box<dyn 'a + FnMut(Option<T>) -> T>;
+
+/// Where a widget reads its value from, and writes changes to.
+///
+/// Usually you just give a widget a `&mut T`, but if your state is shared or message-driven
+/// you can use a [`Binding`] instead of copying the value in and out every frame.
+///
+/// A [`Binding`] can be given to [`crate::Slider::from_binding`], [`crate::DragValue::from_binding`],
+/// [`crate::Checkbox::from_binding`] and (via [`BoundText`]) [`crate::TextEdit`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use std::{cell::RefCell, rc::Rc};
+/// use egui::Binding;
+///
+/// let volume = Rc::new(RefCell::new(0.5_f32));
+/// ui.add(egui::Slider::from_binding(Binding::shared(&volume), 0.0..=1.0));
+///
+/// enum Message {
+///     SetMuted(bool),
+/// }
+/// # let muted = false;
+/// let (sender, receiver) = std::sync::mpsc::channel();
+/// ui.add(egui::Checkbox::from_binding(
+///     Binding::message(muted, sender, Message::SetMuted),
+///     "Muted",
+/// ));
+/// # drop(receiver);
+/// # });
+/// ```
+pub struct Binding<'a, T> {
+    get_set_value: GetSetValue<'a, T>,
+}
+
+impl<'a, T> Binding<'a, T> {
+    /// `get` reads the current value, and `set` writes a new one.
+    pub fn new(mut get: impl 'a + FnMut() -> T, mut set: impl 'a + FnMut(T)) -> Self {
+        Self::from_get_set(move |value| {
+            if let Some(value) = value {
+                set(value);
+            }
+            get()
+        })
+    }
+
+    /// Called with `Some` to write a new value, and always returns the current value.
+    pub fn from_get_set(get_set_value: impl 'a + FnMut(Option<T>) -> T) -> Self {
+        Self {
+            get_set_value: Box::new(get_set_value),
+        }
+    }
+
+    /// Read the current value.
+    pub fn get(&mut self) -> T {
+        (self.get_set_value)(None)
+    }
+
+    /// Write a new value.
+    pub fn set(&mut self, value: T) {
+        (self.get_set_value)(Some(value));
+    }
+
+    /// Convert to a binding of another type, e.g. to show an enum as a `bool`.
+    pub fn map<U>(
+        mut self,
+        mut from: impl 'a + FnMut(T) -> U,
+        mut to: impl 'a + FnMut(U) -> T,
+    ) -> Binding<'a, U>
+    where
+        T: 'a,
+    {
+        Binding::from_get_set(move |value: Option<U>| {
+            from((self.get_set_value)(value.map(&mut to)))
+        })
+    }
+}
+
+impl<'a, T: 'a + Clone> Binding<'a, T> {
+    /// Bind to a value behind a [`RefCell`].
+    ///
+    /// The value is only borrowed while it is being read or written.
+    pub fn ref_cell(value: &'a RefCell<T>) -> Self {
+        Self::from_get_set(move |new_value| {
+            if let Some(new_value) = new_value {
+                *value.borrow_mut() = new_value;
+            }
+            value.borrow().clone()
+        })
+    }
+
+    /// Bind to a value shared with other parts of the app.
+    ///
+    /// The value is only borrowed while it is being read or written.
+    pub fn shared(value: &Rc<RefCell<T>>) -> Self {
+        let value = value.clone();
+        Self::from_get_set(move |new_value| {
+            if let Some(new_value) = new_value {
+                *value.borrow_mut() = new_value;
+            }
+            value.borrow().clone()
+        })
+    }
+
+    /// Show `current`, and send changes over a channel instead of writing them.
+    ///
+    /// Useful when the state is owned by another thread.
+    /// See also [`Self::message`].
+    pub fn channel(current: T, sender: Sender<T>) -> Self {
+        Self::message(current, sender, |value| value)
+    }
+
+    /// Show `current`, and send changes as messages, e.g. `Message::SetVolume(new_volume)`.
+    ///
+    /// The change is shown right away, even though `current` won't be updated until the message is handled.
+    pub fn message<M: 'a>(
+        mut current: T,
+        sender: Sender<M>,
+        to_message: impl 'a + Fn(T) -> M,
+    ) -> Self {
+        Self::from_get_set(move |new_value| {
+            if let Some(new_value) = new_value {
+                current = new_value.clone();
+                sender.send(to_message(new_value)).ok();
+            }
+            current.clone()
+        })
+    }
+}
+
+impl<'a, T: Clone> From<&'a mut T> for Binding<'a, T> {
+    fn from(value: &'a mut T) -> Self {
+        Self::from_get_set(move |new_value| {
+            if let Some(new_value) = new_value {
+                *value = new_value;
+            }
+            value.clone()
+        })
+    }
+}
+
+impl<'a, T: 'a + Clone> From<&'a RefCell<T>> for Binding<'a, T> {
+    fn from(value: &'a RefCell<T>) -> Self {
+        Self::ref_cell(value)
+    }
+}
+
+impl<'a, T> std::fmt::Debug for Binding<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Binding").finish_non_exhaustive()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A [`TextBuffer`] that writes every change through a [`Binding`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use std::{cell::RefCell, rc::Rc};
+/// use egui::{Binding, BoundText};
+///
+/// let name = Rc::new(RefCell::new(String::from("Alice")));
+/// ui.text_edit_singleline(&mut BoundText::new(Binding::shared(&name)));
+/// # });
+/// ```
+pub struct BoundText<'a> {
+    text: String,
+    binding: Binding<'a, String>,
+}
+
+impl<'a> BoundText<'a> {
+    pub fn new(binding: impl Into<Binding<'a, String>>) -> Self {
+        let mut binding = binding.into();
+        Self {
+            text: binding.get(),
+            binding,
+        }
+    }
+}
+
+impl<'a> TextBuffer for BoundText<'a> {
+    fn is_mutable(&self) -> bool {
+        true
+    }
+
+    fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    fn insert_text(&mut self, text: &str, char_index: usize) -> usize {
+        let inserted = self.text.insert_text(text, char_index);
+        self.binding.set(self.text.clone());
+        inserted
+    }
+
+    fn delete_char_range(&mut self, char_range: std::ops::Range<usize>) {
+        self.text.delete_char_range(char_range);
+        self.binding.set(self.text.clone());
+    }
+}
+
+impl<'a> std::fmt::Debug for BoundText<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("BoundText").field(&self.text).finish()
+    }
+}
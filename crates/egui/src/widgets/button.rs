@@ -159,7 +159,77 @@ impl<'a> Button<'a> {
     }
 }
 
+impl Button<'_> {
+    /// The size of the button if its text is not wrapped,
+    /// and the baseline of the text (which is centered vertically).
+    fn measure(&self, ui: &Ui) -> (Vec2, Option<f32>) {
+        let frame = self.frame.unwrap_or_else(|| ui.visuals().button_frame);
+        let mut button_padding = if frame {
+            ui.spacing().button_padding
+        } else {
+            Vec2::ZERO
+        };
+        if self.small {
+            button_padding.y = 0.0;
+        }
+
+        let galley = self
+            .text
+            .clone()
+            .map(|text| text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button));
+
+        let mut desired_size = Vec2::ZERO;
+        if let Some(image) = &self.image {
+            let space_available_for_image = if let Some(galley) = &galley {
+                Vec2::splat(galley.size().y)
+            } else {
+                ui.available_size() - 2.0 * button_padding
+            };
+            let image_size = image
+                .load_and_calc_size(ui, space_available_for_image)
+                .unwrap_or(space_available_for_image);
+            desired_size = image_size;
+            if galley.is_some() {
+                desired_size.x += ui.spacing().icon_spacing;
+            }
+        }
+        if let Some(galley) = &galley {
+            desired_size.x += galley.size().x;
+            desired_size.y = desired_size.y.max(galley.size().y);
+        }
+        if !self.shortcut_text.is_empty() {
+            let shortcut_galley = self.shortcut_text.clone().into_galley(
+                ui,
+                Some(false),
+                f32::INFINITY,
+                TextStyle::Button,
+            );
+            desired_size.x += ui.spacing().item_spacing.x + shortcut_galley.size().x;
+            desired_size.y = desired_size.y.max(shortcut_galley.size().y);
+        }
+        desired_size += 2.0 * button_padding;
+        if !self.small {
+            desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
+        }
+        desired_size = desired_size.at_least(self.min_size);
+
+        let baseline = galley.as_ref().and_then(|galley| {
+            let top = 0.5 * (desired_size.y - galley.size().y);
+            Some(top + super::galley_baseline(galley)?)
+        });
+        (desired_size, baseline)
+    }
+}
+
 impl Widget for Button<'_> {
+    fn preferred_size(&self, ui: &Ui) -> Vec2 {
+        self.measure(ui).0
+    }
+
+    fn baseline(&self, ui: &Ui) -> Option<f32> {
+        self.measure(ui).1
+    }
+
     fn ui(self, ui: &mut Ui) -> Response {
         let Button {
             text,
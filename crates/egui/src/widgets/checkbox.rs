@@ -15,15 +15,23 @@ use crate::*;
 /// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Checkbox<'a> {
-    checked: &'a mut bool,
+    checked: Binding<'a, bool>,
     text: WidgetText,
     indeterminate: bool,
 }
 
 impl<'a> Checkbox<'a> {
     pub fn new(checked: &'a mut bool, text: impl Into<WidgetText>) -> Self {
+        Self::from_binding(checked, text)
+    }
+
+    /// A checkbox that reads and writes its state through a [`Binding`].
+    pub fn from_binding(
+        checked: impl Into<Binding<'a, bool>>,
+        text: impl Into<WidgetText>,
+    ) -> Self {
         Checkbox {
-            checked,
+            checked: checked.into(),
             text: text.into(),
             indeterminate: false,
         }
@@ -47,10 +55,11 @@ impl<'a> Checkbox<'a> {
 impl<'a> Widget for Checkbox<'a> {
     fn ui(self, ui: &mut Ui) -> Response {
         let Checkbox {
-            checked,
+            checked: mut binding,
             text,
             indeterminate,
         } = self;
+        let mut checked = binding.get();
 
         let spacing = &ui.spacing();
         let icon_width = spacing.icon_width;
@@ -75,7 +84,8 @@ impl<'a> Widget for Checkbox<'a> {
         let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
 
         if response.clicked() {
-            *checked = !*checked;
+            checked = !checked;
+            binding.set(checked);
             response.mark_changed();
         }
         response.widget_info(|| {
@@ -87,16 +97,19 @@ impl<'a> Widget for Checkbox<'a> {
             } else {
                 WidgetInfo::selected(
                     WidgetType::Checkbox,
-                    *checked,
+                    checked,
                     galley.as_ref().map_or("", |x| x.text()),
                 )
             }
         });
 
         if ui.is_rect_visible(rect) {
-            // let visuals = ui.style().interact_selectable(&response, *checked); // too colorful
+            // let visuals = ui.style().interact_selectable(&response, checked); // too colorful
             let visuals = ui.style().interact(&response);
-            let (small_icon_rect, big_icon_rect) = ui.spacing().icon_rectangles(rect);
+            let right_to_left = ui.layout_direction().is_right_to_left();
+            let (small_icon_rect, big_icon_rect) = ui
+                .spacing()
+                .icon_rectangles_for_direction(rect, ui.layout_direction());
             ui.painter().add(epaint::RectShape::new(
                 big_icon_rect.expand(visuals.expansion),
                 visuals.rounding,
@@ -111,7 +124,7 @@ impl<'a> Widget for Checkbox<'a> {
                     small_icon_rect.center().y,
                     visuals.fg_stroke,
                 ));
-            } else if *checked {
+            } else if checked {
                 // Check mark:
                 ui.painter().add(Shape::line(
                     vec![
@@ -123,10 +136,12 @@ impl<'a> Widget for Checkbox<'a> {
                 ));
             }
             if let Some(galley) = galley {
-                let text_pos = pos2(
-                    rect.min.x + icon_width + icon_spacing,
-                    rect.center().y - 0.5 * galley.size().y,
-                );
+                let text_x = if right_to_left {
+                    rect.max.x - icon_width - icon_spacing - galley.size().x
+                } else {
+                    rect.min.x + icon_width + icon_spacing
+                };
+                let text_pos = pos2(text_x, rect.center().y - 0.5 * galley.size().y);
                 ui.painter().galley(text_pos, galley, visuals.text_color());
             }
         }
@@ -17,7 +17,7 @@ fn contrast_color(color: impl Into<Rgba>) -> Color32 {
 /// Should always be a multiple of 6 to hit the peak hues in HSV/HSL (every 60°).
 const N: u32 = 6 * 6;
 
-fn background_checkers(painter: &Painter, rect: Rect) {
+pub(crate) fn background_checkers(painter: &Painter, rect: Rect) {
     let rect = rect.shrink(0.5); // Small hack to avoid the checkers from peeking through the sides
     if !rect.is_positive() {
         return;
@@ -43,6 +43,7 @@ pub struct DragValue<'a> {
     custom_formatter: Option<NumFormatter<'a>>,
     custom_parser: Option<NumParser<'a>>,
     update_while_editing: bool,
+    control_param: Option<Id>,
 }
 
 impl<'a> DragValue<'a> {
@@ -63,6 +64,25 @@ impl<'a> DragValue<'a> {
         }
     }
 
+    /// Reads and writes the value through a [`Binding`].
+    pub fn from_binding<Num: emath::Numeric>(binding: impl Into<Binding<'a, Num>>) -> Self {
+        let mut binding = binding.into();
+        let slf = Self::from_get_set(move |v: Option<f64>| {
+            if let Some(v) = v {
+                binding.set(Num::from_f64(v));
+            }
+            binding.get().to_f64()
+        });
+
+        if Num::INTEGRAL {
+            slf.max_decimals(0)
+                .clamp_range(Num::MIN..=Num::MAX)
+                .speed(0.25)
+        } else {
+            slf
+        }
+    }
+
     pub fn from_get_set(get_set_value: impl 'a + FnMut(Option<f64>) -> f64) -> Self {
         Self {
             get_set_value: Box::new(get_set_value),
@@ -75,6 +95,7 @@ impl<'a> DragValue<'a> {
             custom_formatter: None,
             custom_parser: None,
             update_while_editing: true,
+            control_param: None,
         }
     }
 
@@ -345,6 +366,17 @@ impl<'a> DragValue<'a> {
         .custom_parser(|s| i64::from_str_radix(s, 16).map(|n| n as f64).ok())
     }
 
+    /// Let a control on an external controller (e.g. a MIDI knob) change this value,
+    /// once it has been bound to `param` with [`Context::learn_control`].
+    ///
+    /// The whole range of the control is mapped to [`Self::clamp_range`],
+    /// so this only has an effect if both ends of that range are finite.
+    #[inline]
+    pub fn control_param(mut self, param: Id) -> Self {
+        self.control_param = Some(param);
+        self
+    }
+
     /// Update the value on each key press when text-editing the value.
     ///
     /// Default: `true`.
@@ -369,6 +401,7 @@ impl<'a> Widget for DragValue<'a> {
             custom_formatter,
             custom_parser,
             update_while_editing,
+            control_param,
         } = self;
 
         let shift = ui.input(|i| i.modifiers.shift_only());
@@ -439,6 +472,14 @@ impl<'a> Widget for DragValue<'a> {
             });
         }
 
+        if let Some(normalized) = control_param.and_then(|param| ui.ctx().control_value(param)) {
+            let (min, max) = (*clamp_range.start(), *clamp_range.end());
+            if min.is_finite() && max.is_finite() {
+                value = emath::lerp(min..=max, normalized as f64);
+                value = emath::round_to_decimals(value, max_decimals);
+            }
+        }
+
         if change != 0.0 {
             value += speed * change;
             value = emath::round_to_decimals(value, auto_decimals);
@@ -594,6 +635,14 @@ impl<'a> Widget for DragValue<'a> {
 
         response.changed = get(&mut get_set_value) != old_value;
 
+        if control_param.is_some() && control_param == ui.ctx().learning_control() {
+            ui.painter().rect_stroke(
+                response.rect,
+                ui.visuals().widgets.inactive.rounding,
+                ui.visuals().selection.stroke,
+            );
+        }
+
         response.widget_info(|| WidgetInfo::drag_value(value));
 
         #[cfg(feature = "accesskit")]
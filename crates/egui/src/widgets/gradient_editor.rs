@@ -0,0 +1,489 @@
+use crate::{widgets::color_picker::Alpha, *};
+
+/// How the colors between the stops of a [`Gradient`] are blended.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum GradientInterpolation {
+    /// Blend in linear RGB space, which is physically correct.
+    #[default]
+    Linear,
+
+    /// Blend the gamma-encoded (sRGB) values, like many image editors do.
+    Gamma,
+
+    /// No blending: each stop has its color until the next stop.
+    Step,
+}
+
+impl GradientInterpolation {
+    pub const ALL: [Self; 3] = [Self::Linear, Self::Gamma, Self::Step];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Linear => "Linear",
+            Self::Gamma => "Gamma",
+            Self::Step => "Step",
+        }
+    }
+}
+
+/// A color at some position in a [`Gradient`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct GradientStop {
+    /// Position in the gradient, in `0.0..=1.0`.
+    pub t: f32,
+
+    pub color: Color32,
+}
+
+impl GradientStop {
+    #[inline]
+    pub fn new(t: f32, color: impl Into<Color32>) -> Self {
+        Self {
+            t,
+            color: color.into(),
+        }
+    }
+}
+
+/// A series of colors, blended into each other, e.g. for a color map or a particle system.
+///
+/// Edit it with a [`GradientEditor`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Gradient {
+    /// The stops, sorted by [`GradientStop::t`].
+    pub stops: Vec<GradientStop>,
+
+    pub interpolation: GradientInterpolation,
+}
+
+impl Default for Gradient {
+    /// From black to white.
+    fn default() -> Self {
+        Self::evenly_spaced([Color32::BLACK, Color32::WHITE])
+    }
+}
+
+impl Gradient {
+    /// The stops are sorted by position.
+    pub fn new(mut stops: Vec<GradientStop>) -> Self {
+        stops.sort_by(|a, b| a.t.total_cmp(&b.t));
+        Self {
+            stops,
+            interpolation: GradientInterpolation::default(),
+        }
+    }
+
+    /// The colors at `0`, `1` and evenly spaced in between.
+    pub fn evenly_spaced(colors: impl IntoIterator<Item = Color32>) -> Self {
+        let colors: Vec<Color32> = colors.into_iter().collect();
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        Self::new(
+            colors
+                .into_iter()
+                .enumerate()
+                .map(|(i, color)| GradientStop::new(i as f32 / last, color))
+                .collect(),
+        )
+    }
+
+    #[inline]
+    pub fn interpolation(mut self, interpolation: GradientInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Add a stop at `t`, with the color the gradient already has there, so it looks the same.
+    ///
+    /// Returns the index of the new stop.
+    pub fn insert_stop(&mut self, t: f32) -> usize {
+        let stop = GradientStop::new(t, self.color_at(t));
+        let index = self.stops.partition_point(|s| s.t <= t);
+        self.stops.insert(index, stop);
+        index
+    }
+
+    /// Remove the stop at `index`, unless it is the last one left.
+    ///
+    /// Returns `true` if the stop was removed.
+    pub fn remove_stop(&mut self, index: usize) -> bool {
+        if 1 < self.stops.len() && index < self.stops.len() {
+            self.stops.remove(index);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// The color at the given position, in `0.0..=1.0`.
+    ///
+    /// Before the first stop and after the last one, the color of that stop is used.
+    /// A gradient without stops is transparent.
+    pub fn color_at(&self, t: f32) -> Color32 {
+        let next = self.stops.partition_point(|stop| stop.t <= t);
+        let (Some(before), Some(after)) = (
+            next.checked_sub(1).and_then(|i| self.stops.get(i)),
+            self.stops.get(next),
+        ) else {
+            return self
+                .stops
+                .get(next.min(self.stops.len().saturating_sub(1)))
+                .map_or(Color32::TRANSPARENT, |stop| stop.color);
+        };
+
+        let f = remap_clamp(t, before.t..=after.t, 0.0..=1.0);
+        match self.interpolation {
+            GradientInterpolation::Linear => {
+                (Rgba::from(before.color) * (1.0 - f) + Rgba::from(after.color) * f).into()
+            }
+            GradientInterpolation::Gamma => {
+                let [r0, g0, b0, a0] = before.color.to_array();
+                let [r1, g1, b1, a1] = after.color.to_array();
+                let mix = |a: u8, b: u8| lerp(a as f32..=b as f32, f).round() as u8;
+                Color32::from_rgba_premultiplied(mix(r0, r1), mix(g0, g1), mix(b0, b1), mix(a0, a1))
+            }
+            GradientInterpolation::Step => before.color,
+        }
+    }
+}
+
+/// Edit a [`Gradient`]: drag the color stops along a bar, click the bar to add a stop,
+/// and pick the color of the selected stop.
+///
+/// Remove the selected stop with the 🗑 button, or by pressing `Delete` while dragging it.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut gradient = egui::Gradient::default();
+/// if ui.add(egui::GradientEditor::new(&mut gradient)).changed() {
+///     // …
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct GradientEditor<'a> {
+    gradient: &'a mut Gradient,
+    size: Option<Vec2>,
+    alpha: Alpha,
+    interpolation_picker: bool,
+}
+
+impl<'a> GradientEditor<'a> {
+    pub fn new(gradient: &'a mut Gradient) -> Self {
+        Self {
+            gradient,
+            size: None,
+            alpha: Alpha::OnlyBlend,
+            interpolation_picker: true,
+        }
+    }
+
+    /// Size of the gradient bar.
+    ///
+    /// Defaults to [`crate::style::Spacing::slider_width`] wide
+    /// and the height of [`crate::style::Spacing::interact_size`].
+    #[inline]
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Which alpha options to show in the color picker. Default: [`Alpha::OnlyBlend`].
+    #[inline]
+    pub fn alpha(mut self, alpha: Alpha) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Show a combo box for picking the [`GradientInterpolation`]. Default: `true`.
+    #[inline]
+    pub fn interpolation_picker(mut self, interpolation_picker: bool) -> Self {
+        self.interpolation_picker = interpolation_picker;
+        self
+    }
+}
+
+impl<'a> Widget for GradientEditor<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            gradient,
+            size,
+            alpha,
+            interpolation_picker,
+        } = self;
+
+        ui.vertical(|ui| {
+            let bar_size = size
+                .unwrap_or_else(|| vec2(ui.spacing().slider_width, ui.spacing().interact_size.y));
+            let handle_size = Vec2::splat(0.6 * ui.spacing().interact_size.y);
+            let (rect, mut response) =
+                ui.allocate_exact_size(bar_size + vec2(0.0, handle_size.y), Sense::click());
+            let bar_rect = Rect::from_min_size(rect.min, bar_size);
+            let id = response.id;
+            let selected_id = id.with("selected");
+            let mut selected: Option<usize> = ui
+                .data(|d| d.get_temp(selected_id))
+                .filter(|i| *i < gradient.stops.len());
+            let mut changed = false;
+
+            let t_at = |x: f32| remap_clamp(x, bar_rect.x_range(), 0.0..=1.0);
+
+            // Click on the bar to add a stop:
+            if response.clicked() {
+                if let Some(pos) = response.interact_pointer_pos() {
+                    selected = Some(gradient.insert_stop(t_at(pos.x)));
+                    changed = true;
+                }
+            }
+
+            // Drag the handles, keeping the stops sorted:
+            let mut remove = None;
+            for i in 0..gradient.stops.len() {
+                let x = lerp(bar_rect.x_range(), gradient.stops[i].t);
+                let handle_rect = Rect::from_center_size(
+                    pos2(x, bar_rect.bottom() + 0.5 * handle_size.y),
+                    handle_size,
+                );
+                let handle_response =
+                    ui.interact(handle_rect, id.with(("stop", i)), Sense::click_and_drag());
+
+                if handle_response.clicked() || handle_response.drag_started() {
+                    selected = Some(i);
+                }
+                if handle_response.dragged() {
+                    if let Some(pos) = handle_response.interact_pointer_pos() {
+                        let min = i.checked_sub(1).map_or(0.0, |j| gradient.stops[j].t);
+                        let max = gradient.stops.get(i + 1).map_or(1.0, |s| s.t);
+                        let t = t_at(pos.x).clamp(min, max);
+                        if gradient.stops[i].t != t {
+                            gradient.stops[i].t = t;
+                            changed = true;
+                        }
+                    }
+                    if ui.input(|input| input.key_pressed(Key::Delete)) {
+                        remove = Some(i);
+                    }
+                }
+
+                if ui.is_rect_visible(handle_rect) {
+                    let visuals = if selected == Some(i) {
+                        &ui.visuals().widgets.active
+                    } else {
+                        ui.style().interact(&handle_response)
+                    };
+                    ui.painter().add(Shape::convex_polygon(
+                        vec![
+                            pos2(x, bar_rect.bottom()),
+                            handle_rect.right_center(),
+                            handle_rect.right_bottom(),
+                            handle_rect.left_bottom(),
+                            handle_rect.left_center(),
+                        ],
+                        gradient.stops[i].color.to_opaque(),
+                        visuals.fg_stroke,
+                    ));
+                }
+
+                response |= handle_response;
+            }
+
+            ui.horizontal(|ui| {
+                if let Some(i) = selected {
+                    let color_response = color_picker::color_edit_button_srgba(
+                        ui,
+                        &mut gradient.stops[i].color,
+                        alpha,
+                    );
+                    changed |= color_response.changed();
+                    if ui
+                        .add_enabled(1 < gradient.stops.len(), Button::new("🗑"))
+                        .on_hover_text("Remove the selected stop")
+                        .clicked()
+                    {
+                        remove = Some(i);
+                    }
+                }
+
+                if interpolation_picker {
+                    let before = gradient.interpolation;
+                    ComboBox::from_id_source(id.with("interpolation"))
+                        .selected_text(gradient.interpolation.name())
+                        .show_ui(ui, |ui| {
+                            for interpolation in GradientInterpolation::ALL {
+                                ui.selectable_value(
+                                    &mut gradient.interpolation,
+                                    interpolation,
+                                    interpolation.name(),
+                                );
+                            }
+                        });
+                    changed |= gradient.interpolation != before;
+                }
+            });
+
+            if let Some(i) = remove {
+                if gradient.remove_stop(i) {
+                    selected = None;
+                    changed = true;
+                }
+            }
+
+            ui.data_mut(|d| match selected {
+                Some(i) => d.insert_temp(selected_id, i),
+                None => d.remove::<usize>(selected_id),
+            });
+
+            if ui.is_rect_visible(bar_rect) {
+                paint_gradient(ui.painter(), bar_rect, gradient);
+                let visuals = ui.style().interact(&response);
+                ui.painter().rect_stroke(bar_rect, 0.0, visuals.bg_stroke);
+            }
+
+            if changed {
+                response.mark_changed();
+            }
+            response.widget_info(|| WidgetInfo::new(WidgetType::Other));
+            response
+        })
+        .inner
+    }
+}
+
+fn paint_gradient(painter: &Painter, rect: Rect, gradient: &Gradient) {
+    const N: usize = 64;
+
+    color_picker::background_checkers(painter, rect);
+
+    let mut mesh = Mesh::default();
+    for i in 0..N {
+        let t0 = i as f32 / N as f32;
+        let t1 = (i + 1) as f32 / N as f32;
+        let (c0, c1) = if gradient.interpolation == GradientInterpolation::Step {
+            let color = gradient.color_at(0.5 * (t0 + t1));
+            (color, color)
+        } else {
+            (gradient.color_at(t0), gradient.color_at(t1))
+        };
+        let x0 = lerp(rect.x_range(), t0);
+        let x1 = lerp(rect.x_range(), t1);
+
+        let idx = mesh.vertices.len() as u32;
+        mesh.colored_vertex(pos2(x0, rect.top()), c0);
+        mesh.colored_vertex(pos2(x0, rect.bottom()), c0);
+        mesh.colored_vertex(pos2(x1, rect.top()), c1);
+        mesh.colored_vertex(pos2(x1, rect.bottom()), c1);
+        mesh.add_triangle(idx, idx + 1, idx + 2);
+        mesh.add_triangle(idx + 1, idx + 2, idx + 3);
+    }
+    painter.add(Shape::mesh(mesh));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gray(gradient: &Gradient, t: f32) -> u8 {
+        gradient.color_at(t).r()
+    }
+
+    #[test]
+    fn new_sorts_the_stops() {
+        let gradient = Gradient::new(vec![
+            GradientStop::new(1.0, Color32::WHITE),
+            GradientStop::new(0.0, Color32::BLACK),
+            GradientStop::new(0.5, Color32::RED),
+        ]);
+        let ts: Vec<f32> = gradient.stops.iter().map(|s| s.t).collect();
+        assert_eq!(ts, [0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn insert_stop_keeps_the_order_and_the_look() {
+        let mut gradient = Gradient::default();
+        let before = gradient.color_at(0.25);
+        assert_eq!(gradient.insert_stop(0.25), 1);
+        assert_eq!(gradient.stops[1].t, 0.25);
+        assert_eq!(gradient.stops[1].color, before);
+        assert_eq!(gradient.insert_stop(0.75), 2);
+        assert_eq!(gradient.insert_stop(0.1), 1);
+        assert!(gradient.stops.windows(2).all(|w| w[0].t <= w[1].t));
+    }
+
+    #[test]
+    fn remove_stop_keeps_the_last_one() {
+        let mut gradient = Gradient::default();
+        assert!(!gradient.remove_stop(2), "out of bounds");
+        assert!(gradient.remove_stop(0));
+        assert_eq!(gradient.stops, [GradientStop::new(1.0, Color32::WHITE)]);
+        assert!(!gradient.remove_stop(0));
+        assert_eq!(gradient.stops.len(), 1);
+    }
+
+    #[test]
+    fn color_at_the_ends() {
+        let gradient = Gradient::new(vec![
+            GradientStop::new(0.2, Color32::BLACK),
+            GradientStop::new(0.8, Color32::WHITE),
+        ]);
+        assert_eq!(gradient.color_at(0.0), Color32::BLACK);
+        assert_eq!(gradient.color_at(0.2), Color32::BLACK);
+        assert_eq!(gradient.color_at(0.8), Color32::WHITE);
+        assert_eq!(gradient.color_at(1.0), Color32::WHITE);
+
+        assert_eq!(Gradient::new(vec![]).color_at(0.5), Color32::TRANSPARENT);
+    }
+
+    #[test]
+    fn color_between_stops() {
+        let gradient = Gradient::default();
+        let gamma = gradient.clone().interpolation(GradientInterpolation::Gamma);
+        let step = gradient.clone().interpolation(GradientInterpolation::Step);
+
+        assert_eq!(gray(&gamma, 0.5), 128);
+        // Half the light is brighter than half the sRGB value:
+        assert!(150 < gray(&gradient, 0.5), "{}", gray(&gradient, 0.5));
+        assert!(gray(&gradient, 0.25) < gray(&gradient, 0.5));
+        assert_eq!(step.color_at(0.99), Color32::BLACK);
+    }
+
+    #[test]
+    fn click_on_the_bar_adds_a_stop() {
+        let ctx = Context::default();
+        let mut gradient = Gradient::default();
+        let mut run = |events: Vec<Event>| {
+            let mut response = None;
+            let _ = ctx.run(
+                RawInput {
+                    events,
+                    ..Default::default()
+                },
+                |ctx| {
+                    CentralPanel::default().show(ctx, |ui| {
+                        response = Some(ui.add(GradientEditor::new(&mut gradient)));
+                    });
+                },
+            );
+            response.unwrap()
+        };
+
+        let rect = run(vec![]).rect;
+        let pos = pos2(rect.center().x, rect.top() + 2.0);
+        let button = |pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        run(vec![Event::PointerMoved(pos), button(true)]);
+        assert!(run(vec![button(false)]).changed());
+
+        assert_eq!(gradient.stops.len(), 3);
+        assert!(
+            (gradient.stops[1].t - 0.5).abs() < 0.01,
+            "{:?}",
+            gradient.stops
+        );
+    }
+}
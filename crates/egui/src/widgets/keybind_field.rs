@@ -0,0 +1,242 @@
+use crate::*;
+
+/// The shortcuts of the [`KeybindField`]s shown recently, used to warn about conflicts.
+#[derive(Clone, Default)]
+struct KeybindRegistry(IdMap<RegisteredKeybind>);
+
+#[derive(Clone)]
+struct RegisteredKeybind {
+    frame_nr: u64,
+    shortcut: KeyboardShortcut,
+    name: Option<String>,
+}
+
+/// Shows a [`KeyboardShortcut`], and lets the user change it by clicking the field and pressing a new key chord.
+///
+/// While capturing, `Escape` cancels, and clicking elsewhere keeps the old shortcut.
+///
+/// The recorded modifiers are normalized so that the shortcut works on all platforms:
+/// `Ctrl` (or `Cmd` on Mac) is recorded as [`Modifiers::COMMAND`].
+///
+/// The field warns if the shortcut is also used by another [`KeybindField`],
+/// by a shortcut registered with [`Context::register_shortcut`],
+/// or by one of the shortcuts given to [`Self::reserved`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{Key, KeyboardShortcut, Modifiers};
+/// # let mut save_shortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+/// ui.add(
+///     egui::KeybindField::new(&mut save_shortcut)
+///         .name("Save")
+///         .reserved(KeyboardShortcut::new(Modifiers::COMMAND, Key::Q), "Quit"),
+/// );
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct KeybindField<'a> {
+    shortcut: &'a mut KeyboardShortcut,
+    name: Option<String>,
+    reserved: Vec<(KeyboardShortcut, String)>,
+}
+
+impl<'a> KeybindField<'a> {
+    pub fn new(shortcut: &'a mut KeyboardShortcut) -> Self {
+        Self {
+            shortcut,
+            name: None,
+            reserved: Vec::new(),
+        }
+    }
+
+    /// What the shortcut does, e.g. `"Save"`.
+    ///
+    /// Shown in the conflict warnings of other [`KeybindField`]s.
+    /// A shortcut registered with [`Context::register_shortcut`] under the same name
+    /// is not considered a conflict, since it is probably the one being edited.
+    #[inline]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Warn if the user picks this shortcut, e.g. because your app already uses it for something else.
+    #[inline]
+    pub fn reserved(mut self, shortcut: KeyboardShortcut, description: impl Into<String>) -> Self {
+        self.reserved.push((shortcut, description.into()));
+        self
+    }
+}
+
+impl<'a> Widget for KeybindField<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            shortcut,
+            name,
+            reserved,
+        } = self;
+
+        let id = ui.next_auto_id();
+        let is_mac = matches!(
+            ui.ctx().os(),
+            os::OperatingSystem::Mac | os::OperatingSystem::IOS
+        );
+        let mut capturing = ui.data(|d| d.get_temp::<bool>(id)).unwrap_or(false)
+            && ui.memory(|mem| mem.has_focus(id));
+        let mut changed = false;
+
+        if capturing {
+            ui.memory_mut(|mem| {
+                mem.set_focus_lock_filter(
+                    id,
+                    EventFilter {
+                        tab: true,
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        escape: true,
+                    },
+                );
+            });
+
+            let pressed = ui.input(|i| {
+                i.events.iter().find_map(|event| match event {
+                    Event::Key {
+                        key,
+                        pressed: true,
+                        modifiers,
+                        ..
+                    } => Some((*key, *modifiers)),
+                    _ => None,
+                })
+            });
+            if let Some((key, modifiers)) = pressed {
+                // Don't let the key trigger anything else:
+                ui.input_mut(|i| i.consume_key(modifiers, key));
+
+                if key == Key::Escape && modifiers.is_none() {
+                    capturing = false;
+                } else {
+                    let new_shortcut = KeyboardShortcut::new(normalized(modifiers, is_mac), key);
+                    changed = *shortcut != new_shortcut;
+                    *shortcut = new_shortcut;
+                    capturing = false;
+                }
+            }
+        }
+
+        let conflicts = conflicts(ui, id, *shortcut, name, &reserved);
+
+        let text = if capturing {
+            RichText::new("Press a key…").italics()
+        } else {
+            RichText::new(ui.ctx().format_shortcut(shortcut))
+        };
+        let mut button = Button::new(text)
+            .selected(capturing)
+            .min_size(vec2(ui.spacing().interact_size.x * 2.0, 0.0));
+        if !conflicts.is_empty() {
+            button = button.stroke(Stroke::new(1.0, ui.visuals().error_fg_color));
+        }
+        let mut response = ui.add(button);
+
+        if response.clicked() {
+            capturing = !capturing;
+            if capturing {
+                response.request_focus();
+            }
+        }
+        if capturing && response.clicked_elsewhere() {
+            capturing = false;
+        }
+        if !capturing && response.has_focus() && !response.clicked() {
+            // Stop swallowing tab and arrow keys:
+            ui.memory_mut(|mem| mem.set_focus_lock_filter(id, EventFilter::default()));
+        }
+        ui.data_mut(|d| d.insert_temp(id, capturing));
+
+        if changed {
+            response.mark_changed();
+        }
+        if !conflicts.is_empty() {
+            response = response.on_hover_ui(|ui| {
+                ui.colored_label(ui.visuals().error_fg_color, "⚠ Also used by:");
+                for conflict in &conflicts {
+                    ui.label(conflict);
+                }
+            });
+        }
+        response.widget_info(|| {
+            WidgetInfo::labeled(WidgetType::Button, ui.ctx().format_shortcut(shortcut))
+        });
+        response
+    }
+}
+
+/// Record `Ctrl` (or `Cmd` on Mac) as [`Modifiers::command`], so that the shortcut works on all platforms.
+fn normalized(mut modifiers: Modifiers, is_mac: bool) -> Modifiers {
+    if is_mac {
+        modifiers.command = modifiers.mac_cmd;
+        modifiers.mac_cmd = false;
+    } else {
+        modifiers.command = modifiers.ctrl;
+        modifiers.ctrl = false;
+    }
+    modifiers
+}
+
+/// Register the shortcut, and return descriptions of everything else using it.
+fn conflicts(
+    ui: &Ui,
+    id: Id,
+    shortcut: KeyboardShortcut,
+    name: Option<String>,
+    reserved: &[(KeyboardShortcut, String)],
+) -> Vec<String> {
+    let frame_nr = ui.ctx().frame_nr();
+
+    let mut conflicts: Vec<String> = reserved
+        .iter()
+        .filter(|(reserved, _)| *reserved == shortcut)
+        .map(|(_, description)| description.clone())
+        .collect();
+
+    conflicts.extend(
+        ui.ctx()
+            .registered_shortcuts()
+            .into_iter()
+            .filter(|registered| {
+                registered.shortcut == shortcut && Some(&registered.name) != name.as_ref()
+            })
+            .map(|registered| format!("{}: {}", registered.category, registered.name)),
+    );
+
+    ui.data_mut(|d| {
+        let registry = d.get_temp_mut_or_default::<KeybindRegistry>(Id::NULL);
+        // Forget fields that are no longer shown:
+        registry
+            .0
+            .retain(|_, keybind| keybind.frame_nr + 1 >= frame_nr);
+        conflicts.extend(
+            registry
+                .0
+                .iter()
+                .filter(|(other_id, keybind)| **other_id != id && keybind.shortcut == shortcut)
+                .map(|(_, keybind)| {
+                    keybind
+                        .name
+                        .clone()
+                        .unwrap_or_else(|| "Another keybinding".to_owned())
+                }),
+        );
+        registry.0.insert(
+            id,
+            RegisteredKeybind {
+                frame_nr,
+                shortcut,
+                name,
+            },
+        );
+    });
+
+    conflicts
+}
@@ -108,6 +108,13 @@ impl Label {
 }
 
 impl Label {
+    /// The galley of the text, unwrapped.
+    fn preferred_galley(&self, ui: &Ui) -> Arc<Galley> {
+        self.text
+            .clone()
+            .into_galley(ui, Some(false), f32::INFINITY, TextStyle::Body)
+    }
+
     /// Do layout and position the galley in the ui, without painting it or adding widget info.
     pub fn layout_in_ui(self, ui: &mut Ui) -> (Pos2, Arc<Galley>, Response) {
         let selectable = self
@@ -224,6 +231,14 @@ impl Label {
 }
 
 impl Widget for Label {
+    fn preferred_size(&self, ui: &Ui) -> Vec2 {
+        self.preferred_galley(ui).size()
+    }
+
+    fn baseline(&self, ui: &Ui) -> Option<f32> {
+        super::galley_baseline(&self.preferred_galley(ui))
+    }
+
     fn ui(self, ui: &mut Ui) -> Response {
         // Interactive = the uses asked to sense interaction.
         // We DON'T want to have the color respond just because the text is selectable;
@@ -6,37 +6,47 @@
 
 use crate::*;
 
+mod binding;
 mod button;
 mod checkbox;
 pub mod color_picker;
 pub(crate) mod drag_value;
+mod gradient_editor;
 mod hyperlink;
 mod image;
 mod image_button;
+mod keybind_field;
 mod label;
 mod progress_bar;
 mod radio_button;
+mod rating;
 mod selected_label;
 mod separator;
 mod slider;
 mod spinner;
+mod stepper;
 pub mod text_edit;
 
 pub use self::{
+    binding::{Binding, BoundText},
     button::Button,
     checkbox::Checkbox,
     drag_value::DragValue,
+    gradient_editor::{Gradient, GradientEditor, GradientInterpolation, GradientStop},
     hyperlink::{Hyperlink, Link},
     image::{paint_texture_at, Image, ImageFit, ImageOptions, ImageSize, ImageSource},
     image_button::ImageButton,
+    keybind_field::KeybindField,
     label::Label,
     progress_bar::ProgressBar,
     radio_button::RadioButton,
+    rating::Rating,
     selected_label::SelectableLabel,
     separator::Separator,
     slider::{Slider, SliderOrientation},
     spinner::Spinner,
-    text_edit::{TextBuffer, TextEdit},
+    stepper::Stepper,
+    text_edit::{AutoComplete, TextBuffer, TextEdit, TriggerPopup},
 };
 
 // ----------------------------------------------------------------------------
@@ -64,6 +74,33 @@ pub trait Widget {
     ///
     /// Tip: you can `impl Widget for &mut YourObject { }`.
     fn ui(self, ui: &mut Ui) -> Response;
+
+    /// How much space this widget would like, without actually adding it.
+    ///
+    /// Implementing this is optional, but lets layouts such as [`Ui::aligned_row`]
+    /// measure the widget before placing it, e.g. to share out the remaining width.
+    ///
+    /// The default is [`crate::style::Spacing::interact_size`], a reasonable guess for a small widget.
+    fn preferred_size(&self, ui: &Ui) -> Vec2 {
+        ui.spacing().interact_size
+    }
+
+    /// The distance from the top of the widget to the baseline of its text, if it has any,
+    /// when it is given its [`Self::preferred_size`].
+    ///
+    /// Used by [`Ui::aligned_row`] to line up the text of different widgets.
+    ///
+    /// The default is `None`, meaning the widget is centered vertically instead.
+    fn baseline(&self, _ui: &Ui) -> Option<f32> {
+        None
+    }
+}
+
+/// The distance from the top of the galley to the baseline of its first row.
+pub(crate) fn galley_baseline(galley: &Galley) -> Option<f32> {
+    let row = galley.rows.first()?;
+    let glyph = row.glyphs.first()?;
+    Some(glyph.pos.y)
 }
 
 /// This enables functions that return `impl Widget`, so that you can
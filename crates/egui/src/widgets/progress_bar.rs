@@ -75,6 +75,8 @@ impl ProgressBar {
     /// If [`Self::rounding`] and [`Self::animate`] are used simultaneously, the animation is not
     /// rendered, since it requires a perfect circle to render correctly. However, the UI is still
     /// redrawn.
+    ///
+    /// The animation is skipped if [`crate::Context::reduce_motion`] is set.
     #[inline]
     pub fn animate(mut self, animate: bool) -> Self {
         self.animate = animate;
@@ -105,7 +107,7 @@ impl Widget for ProgressBar {
             rounding,
         } = self;
 
-        let animate = animate && progress < 1.0;
+        let animate = animate && progress < 1.0 && !ui.ctx().reduce_motion();
 
         let desired_width =
             desired_width.unwrap_or_else(|| ui.available_size_before_wrap().x.at_least(96.0));
@@ -72,7 +72,10 @@ impl Widget for RadioButton {
             // let visuals = ui.style().interact_selectable(&response, checked); // too colorful
             let visuals = ui.style().interact(&response);
 
-            let (small_icon_rect, big_icon_rect) = ui.spacing().icon_rectangles(rect);
+            let right_to_left = ui.layout_direction().is_right_to_left();
+            let (small_icon_rect, big_icon_rect) = ui
+                .spacing()
+                .icon_rectangles_for_direction(rect, ui.layout_direction());
 
             let painter = ui.painter();
 
@@ -94,10 +97,12 @@ impl Widget for RadioButton {
             }
 
             if let Some(galley) = galley {
-                let text_pos = pos2(
-                    rect.min.x + icon_width + icon_spacing,
-                    rect.center().y - 0.5 * galley.size().y,
-                );
+                let text_x = if right_to_left {
+                    rect.max.x - icon_width - icon_spacing - galley.size().x
+                } else {
+                    rect.min.x + icon_width + icon_spacing
+                };
+                let text_pos = pos2(text_x, rect.center().y - 0.5 * galley.size().y);
                 ui.painter().galley(text_pos, galley, visuals.text_color());
             }
         }
@@ -0,0 +1,233 @@
+use crate::*;
+
+/// A row of stars (or hearts, or any other symbol) for picking a rating, e.g. 3.5 out of 5.
+///
+/// Click a symbol to pick it. With [`Self::half_steps`], clicking the left half of a symbol picks a half step.
+/// When focused, the rating can be adjusted with the arrow keys, `Home` and `End`.
+///
+/// The size and colors default to [`crate::style::Spacing::rating_symbol_size`],
+/// [`Visuals::rating_filled_color`] and [`Visuals::rating_empty_color`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_rating = 3.0;
+/// ui.add(egui::Rating::new(&mut my_rating).half_steps(true));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Rating<'a> {
+    value: &'a mut f32,
+    max: usize,
+    half_steps: bool,
+    symbol: char,
+    size: Option<f32>,
+    filled_color: Option<Color32>,
+    empty_color: Option<Color32>,
+}
+
+impl<'a> Rating<'a> {
+    /// `value` is in `0.0..=max` (default `max` is 5).
+    pub fn new(value: &'a mut f32) -> Self {
+        Self {
+            value,
+            max: 5,
+            half_steps: false,
+            symbol: '★',
+            size: None,
+            filled_color: None,
+            empty_color: None,
+        }
+    }
+
+    /// How many symbols to show. Default: 5.
+    #[inline]
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Allow picking half a symbol. Default: `false`.
+    #[inline]
+    pub fn half_steps(mut self, half_steps: bool) -> Self {
+        self.half_steps = half_steps;
+        self
+    }
+
+    /// The symbol to show, e.g. `'★'` (default) or `'❤'`.
+    #[inline]
+    pub fn symbol(mut self, symbol: char) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Size of each symbol, in points.
+    ///
+    /// Defaults to [`crate::style::Spacing::rating_symbol_size`].
+    #[inline]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Color of the filled part of the rating.
+    ///
+    /// Defaults to [`Visuals::rating_filled_color`].
+    #[inline]
+    pub fn filled_color(mut self, color: impl Into<Color32>) -> Self {
+        self.filled_color = Some(color.into());
+        self
+    }
+
+    /// Color of the empty part of the rating.
+    ///
+    /// Defaults to [`Visuals::rating_empty_color`].
+    #[inline]
+    pub fn empty_color(mut self, color: impl Into<Color32>) -> Self {
+        self.empty_color = Some(color.into());
+        self
+    }
+}
+
+impl<'a> Widget for Rating<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            max,
+            half_steps,
+            symbol,
+            size,
+            filled_color,
+            empty_color,
+        } = self;
+
+        let step = if half_steps { 0.5 } else { 1.0 };
+        let max_value = max as f32;
+        let symbol_size = size.unwrap_or_else(|| ui.spacing().rating_symbol_size);
+        let desired_size = vec2(symbol_size * max_value, symbol_size);
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+
+        let value_at = |pos: Pos2| {
+            let t = (pos.x - rect.left()) / symbol_size;
+            rating_at(t, step, max_value)
+        };
+
+        if let Some(pos) = response.interact_pointer_pos() {
+            if response.clicked() {
+                let new_value = value_at(pos);
+                if *value != new_value {
+                    *value = new_value;
+                    response.mark_changed();
+                }
+            }
+        }
+
+        if response.has_focus() {
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    response.id,
+                    EventFilter {
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                );
+            });
+
+            let new_value = ui.input(|i| {
+                let increments = i.num_presses(Key::ArrowRight) + i.num_presses(Key::ArrowUp);
+                let decrements = i.num_presses(Key::ArrowLeft) + i.num_presses(Key::ArrowDown);
+                let mut new_value = *value + (increments as f32 - decrements as f32) * step;
+                if i.key_pressed(Key::Home) {
+                    new_value = 0.0;
+                }
+                if i.key_pressed(Key::End) {
+                    new_value = max_value;
+                }
+                new_value.clamp(0.0, max_value)
+            });
+            if *value != new_value {
+                *value = new_value;
+                response.mark_changed();
+            }
+        }
+
+        response.widget_info(|| WidgetInfo::slider(*value as f64, "Rating"));
+
+        if ui.is_rect_visible(rect) {
+            // Preview the rating under the mouse:
+            let shown_value = response.hover_pos().map_or(*value, value_at);
+            let visuals = ui.visuals();
+            let filled_color = filled_color.unwrap_or(visuals.rating_filled_color);
+            let empty_color = empty_color
+                .or(visuals.rating_empty_color)
+                .unwrap_or_else(|| visuals.weak_text_color());
+            let font_id = FontId::proportional(symbol_size);
+            let galley =
+                ui.painter()
+                    .layout_no_wrap(symbol.to_string(), font_id, Color32::PLACEHOLDER);
+
+            for i in 0..max {
+                let symbol_rect = Rect::from_min_size(
+                    rect.min + vec2(i as f32 * symbol_size, 0.0),
+                    Vec2::splat(symbol_size),
+                );
+                let pos = symbol_rect.center() - 0.5 * galley.size();
+                let fill = (shown_value - i as f32).clamp(0.0, 1.0);
+                if fill < 1.0 {
+                    ui.painter().galley(pos, galley.clone(), empty_color);
+                }
+                if fill > 0.0 {
+                    let mut filled_rect = symbol_rect;
+                    filled_rect.set_width(fill * symbol_size);
+                    ui.painter()
+                        .with_clip_rect(filled_rect.intersect(ui.clip_rect()))
+                        .galley(pos, galley.clone(), filled_color);
+                }
+            }
+
+            if response.has_focus() {
+                ui.painter().rect_stroke(
+                    rect.expand(2.0),
+                    visuals.widgets.hovered.rounding,
+                    visuals.selection.stroke,
+                );
+            }
+        }
+
+        response
+    }
+}
+
+/// The rating picked by clicking `t` symbols from the left: at least one step, at most `max_value`.
+fn rating_at(t: f32, step: f32, max_value: f32) -> f32 {
+    // With `max(0)` there is nothing to pick, and `clamp` would panic if `max_value < step`:
+    ((t / step).ceil() * step).clamp(step.min(max_value), max_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rating_at;
+
+    #[test]
+    fn test_rating_at() {
+        assert_eq!(rating_at(0.0, 1.0, 5.0), 1.0);
+        assert_eq!(rating_at(2.3, 1.0, 5.0), 3.0);
+        assert_eq!(rating_at(2.3, 0.5, 5.0), 2.5);
+        assert_eq!(rating_at(7.0, 1.0, 5.0), 5.0);
+        assert_eq!(rating_at(-1.0, 0.5, 5.0), 0.5);
+    }
+
+    #[test]
+    fn test_rating_at_max_zero() {
+        assert_eq!(rating_at(0.0, 1.0, 0.0), 0.0);
+        assert_eq!(rating_at(0.3, 0.5, 0.0), 0.0);
+    }
+
+    #[test]
+    fn max_zero_does_not_panic() {
+        crate::__run_test_ui(|ui| {
+            let mut value = 0.0;
+            ui.add(crate::Rating::new(&mut value).max(0));
+        });
+    }
+}
@@ -88,6 +88,7 @@ pub struct Slider<'a> {
     custom_parser: Option<NumParser<'a>>,
     trailing_fill: Option<bool>,
     handle_shape: Option<HandleShape>,
+    control_param: Option<Id>,
 }
 
 impl<'a> Slider<'a> {
@@ -108,6 +109,34 @@ impl<'a> Slider<'a> {
         }
     }
 
+    /// Creates a new horizontal slider that reads and writes its value through a [`Binding`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let volume = std::cell::RefCell::new(0.5_f32);
+    /// ui.add(egui::Slider::from_binding(egui::Binding::ref_cell(&volume), 0.0..=1.0));
+    /// # });
+    /// ```
+    pub fn from_binding<Num: emath::Numeric>(
+        binding: impl Into<Binding<'a, Num>>,
+        range: RangeInclusive<Num>,
+    ) -> Self {
+        let mut binding = binding.into();
+        let range_f64 = range.start().to_f64()..=range.end().to_f64();
+        let slf = Self::from_get_set(range_f64, move |v: Option<f64>| {
+            if let Some(v) = v {
+                binding.set(Num::from_f64(v));
+            }
+            binding.get().to_f64()
+        });
+
+        if Num::INTEGRAL {
+            slf.integer()
+        } else {
+            slf
+        }
+    }
+
     pub fn from_get_set(
         range: RangeInclusive<f64>,
         get_set_value: impl 'a + FnMut(Option<f64>) -> f64,
@@ -135,6 +164,7 @@ impl<'a> Slider<'a> {
             custom_parser: None,
             trailing_fill: None,
             handle_shape: None,
+            control_param: None,
         }
     }
 
@@ -314,6 +344,18 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Let a control on an external controller (e.g. a MIDI knob) move this slider,
+    /// once it has been bound to `param` with [`Context::learn_control`].
+    ///
+    /// The whole range of the control is mapped to the range of the slider,
+    /// taking [`Self::logarithmic`] into account.
+    /// The slider is highlighted while waiting for a control to be bound to it.
+    #[inline]
+    pub fn control_param(mut self, param: Id) -> Self {
+        self.control_param = Some(param);
+        self
+    }
+
     /// Set custom formatter defining how numbers are converted into text.
     ///
     /// A custom formatter takes a `f64` for the numeric value and a `RangeInclusive<usize>` representing
@@ -585,7 +627,7 @@ impl<'a> Slider<'a> {
         let handle_shape = self
             .handle_shape
             .unwrap_or_else(|| ui.style().visuals.handle_shape);
-        let position_range = self.position_range(rect, &handle_shape);
+        let position_range = self.position_range(ui, rect, &handle_shape);
 
         if let Some(pointer_position_2d) = response.interact_pointer_pos() {
             let position = self.pointer_position(pointer_position_2d);
@@ -676,6 +718,13 @@ impl<'a> Slider<'a> {
             });
         }
 
+        if let Some(param) = self.control_param {
+            if let Some(normalized) = ui.ctx().control_value(param) {
+                let new_value = value_from_normalized(normalized as f64, self.range(), &self.spec);
+                self.set_value(new_value);
+            }
+        }
+
         // Paint it:
         if ui.is_rect_visible(response.rect) {
             let value = self.get_value();
@@ -687,6 +736,14 @@ impl<'a> Slider<'a> {
             let rail_radius = (spacing.slider_rail_height / 2.0).at_least(0.0);
             let rail_rect = self.rail_rect(rect, rail_radius);
 
+            if self.control_param.is_some() && self.control_param == ui.ctx().learning_control() {
+                ui.painter().rect_stroke(
+                    rect.expand(visuals.expansion),
+                    visuals.rounding,
+                    ui.visuals().selection.stroke,
+                );
+            }
+
             ui.painter().rect_filled(
                 rail_rect,
                 widget_visuals.inactive.rounding,
@@ -708,6 +765,9 @@ impl<'a> Slider<'a> {
                 // The trailing rect has to be drawn differently depending on the orientation.
                 match self.orientation {
                     SliderOrientation::Vertical => trailing_rail_rect.min.y = center.y,
+                    SliderOrientation::Horizontal if ui.layout_direction().is_right_to_left() => {
+                        trailing_rail_rect.min.x = center.x;
+                    }
                     SliderOrientation::Horizontal => trailing_rail_rect.max.x = center.x,
                 };
 
@@ -760,13 +820,17 @@ impl<'a> Slider<'a> {
         }
     }
 
-    fn position_range(&self, rect: &Rect, handle_shape: &style::HandleShape) -> Rangef {
+    fn position_range(&self, ui: &Ui, rect: &Rect, handle_shape: &style::HandleShape) -> Rangef {
         let handle_radius = self.handle_radius(rect);
         let handle_radius = match handle_shape {
             style::HandleShape::Circle => handle_radius,
             style::HandleShape::Rect { aspect_ratio } => handle_radius * aspect_ratio,
         };
         match self.orientation {
+            // In right-to-left layouts the largest value is to the left:
+            SliderOrientation::Horizontal if ui.layout_direction().is_right_to_left() => {
+                rect.x_range().shrink(handle_radius).flip()
+            }
             SliderOrientation::Horizontal => rect.x_range().shrink(handle_radius),
             // The vertical case has to be flipped because the largest slider value maps to the
             // lowest y value (which is at the top)
@@ -883,7 +947,7 @@ impl<'a> Slider<'a> {
             let handle_shape = self
                 .handle_shape
                 .unwrap_or_else(|| ui.style().visuals.handle_shape);
-            let position_range = self.position_range(&response.rect, &handle_shape);
+            let position_range = self.position_range(ui, &response.rect, &handle_shape);
             let value_response = self.value_ui(ui, position_range);
             if value_response.gained_focus()
                 || value_response.has_focus()
@@ -37,14 +37,22 @@ impl Spinner {
     /// Paint the spinner in the given rectangle.
     pub fn paint_at(&self, ui: &Ui, rect: Rect) {
         if ui.is_rect_visible(rect) {
-            ui.ctx().request_repaint(); // because it is animated
+            // With reduced motion we show a still arc instead of animating:
+            let reduce_motion = ui.ctx().reduce_motion();
+            if !reduce_motion {
+                ui.ctx().request_animation_repaint(); // because it is animated
+            }
 
             let color = self
                 .color
                 .unwrap_or_else(|| ui.visuals().strong_text_color());
             let radius = (rect.height() / 2.0) - 2.0;
             let n_points = 20;
-            let time = ui.input(|i| i.time);
+            let time = if reduce_motion {
+                1.0
+            } else {
+                ui.input(|i| i.time)
+            };
             let start_angle = time * std::f64::consts::TAU;
             let end_angle = start_angle + 240f64.to_radians() * time.sin();
             let points: Vec<Pos2> = (0..n_points)
@@ -0,0 +1,277 @@
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+/// How much faster a stepper button repeats after being held for a long time.
+const REPEAT_ACCELERATION: f64 = 5.0;
+
+/// A number with a `-` and a `+` button on either side.
+///
+/// Holding down a button repeats the step, faster and faster.
+/// The buttons step onto multiples of [`Self::step`] from the start of the range.
+/// The number itself is a [`DragValue`], so it can also be dragged, typed in,
+/// or stepped with the arrow keys when focused.
+///
+/// The size and spacing of the buttons come from [`crate::style::Spacing::stepper_button_size`]
+/// and [`crate::style::Spacing::stepper_spacing`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_count = 3;
+/// ui.add(egui::Stepper::new(&mut my_count, 0..=10));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Stepper<'a> {
+    get_set_value: Box<dyn 'a + FnMut(Option<f64>) -> f64>,
+    range: RangeInclusive<f64>,
+    step: f64,
+    integer: bool,
+}
+
+impl<'a> Stepper<'a> {
+    pub fn new<Num: emath::Numeric>(value: &'a mut Num, range: RangeInclusive<Num>) -> Self {
+        let range_f64 = range.start().to_f64()..=range.end().to_f64();
+        Self {
+            get_set_value: Box::new(move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *value = Num::from_f64(v);
+                }
+                value.to_f64()
+            }),
+            range: range_f64,
+            step: 1.0,
+            integer: Num::INTEGRAL,
+        }
+    }
+
+    /// How much each click changes the value. Default: `1`.
+    #[inline]
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+}
+
+impl<'a> Widget for Stepper<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            mut get_set_value,
+            range,
+            step,
+            integer,
+        } = self;
+
+        let old_value = get_set_value(None);
+
+        let inner = ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = ui.spacing().stepper_spacing;
+
+            let button_size = Vec2::splat(ui.spacing().stepper_button_size);
+            let minus = ui.add_enabled(
+                old_value > *range.start(),
+                Button::new("➖").min_size(button_size),
+            );
+
+            let mut value = old_value;
+            let mut drag_value = DragValue::new(&mut value)
+                .clamp_range(range.clone())
+                .speed(step);
+            if integer {
+                drag_value = drag_value.fixed_decimals(0);
+            }
+            let value_response = ui.add(drag_value);
+
+            let plus = ui.add_enabled(
+                old_value < *range.end(),
+                Button::new("➕").min_size(button_size),
+            );
+
+            let steps = repeat_steps(ui, &plus) as f64 - repeat_steps(ui, &minus) as f64;
+            if steps != 0.0 {
+                value = step_value(value, steps, step, *range.start());
+            }
+
+            (value, value_response | minus | plus)
+        });
+        let (value, mut response) = inner.inner;
+
+        let value = clamp_value(value, &range, integer);
+        if value != old_value {
+            get_set_value(Some(value));
+            response.mark_changed();
+        }
+
+        response.widget_info(|| WidgetInfo::drag_value(value));
+        response
+    }
+}
+
+/// Take `steps` steps (negative is down) from `value`,
+/// landing on a multiple of `step` from `start`.
+///
+/// A `value` between two multiples first snaps to the one in the direction of the steps.
+fn step_value(value: f64, steps: f64, step: f64, start: f64) -> f64 {
+    if step <= 0.0 {
+        return value;
+    }
+
+    let mut num_steps = (value - start) / step;
+    let nearest = num_steps.round();
+    if (num_steps - nearest).abs() < 1e-6 {
+        // Already on a multiple (up to rounding errors):
+        num_steps = nearest;
+    }
+    let num_steps = if 0.0 < steps {
+        num_steps.floor() + steps
+    } else {
+        num_steps.ceil() + steps
+    };
+    start + num_steps * step
+}
+
+/// Keep `value` in `range`, and round it if the stepped number is an integer.
+fn clamp_value(value: f64, range: &RangeInclusive<f64>, integer: bool) -> f64 {
+    let value = value.clamp(*range.start(), *range.end());
+    if integer {
+        value.round()
+    } else {
+        value
+    }
+}
+
+/// How many steps a stepper button should take this frame:
+/// one on click, and then repeating (with acceleration) while held down.
+fn repeat_steps(ui: &Ui, button: &Response) -> usize {
+    let (now, options) = ui.input(|i| (i.time, i.options));
+    let repeat_delay = options.key_repeat_delay;
+
+    if !button.is_pointer_button_down_on() {
+        // (time the button was pressed, time of the last repeat)
+        let state = ui.data_mut(|d| {
+            let state = d.get_temp::<(f64, f64)>(button.id);
+            d.remove::<(f64, f64)>(button.id);
+            state
+        });
+        let was_repeating = state.map_or(false, |(pressed_time, _)| {
+            repeat_delay <= now - pressed_time
+        });
+        return usize::from(button.clicked() && !was_repeating);
+    }
+
+    let (pressed_time, last_repeat) = ui.data_mut(|d| *d.get_temp_mut_or(button.id, (now, now)));
+    ui.ctx().request_repaint();
+
+    let held_for = now - pressed_time;
+    if held_for < repeat_delay {
+        return 0;
+    }
+
+    // Accelerate linearly over two seconds:
+    let t = ((held_for - repeat_delay) / 2.0).min(1.0);
+    let min_rate = options.key_repeat_rate.max(1.0);
+    let rate = emath::lerp(min_rate..=REPEAT_ACCELERATION * min_rate, t);
+    let interval = 1.0 / rate;
+    let last_repeat = last_repeat.max(pressed_time + repeat_delay - interval);
+    let steps = ((now - last_repeat) / interval).floor();
+    if 0.0 < steps {
+        ui.data_mut(|d| d.insert_temp(button.id, (pressed_time, last_repeat + steps * interval)));
+    }
+    steps as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_value() {
+        assert_eq!(clamp_value(5.0, &(0.0..=10.0), false), 5.0);
+        assert_eq!(clamp_value(-3.0, &(0.0..=10.0), false), 0.0);
+        assert_eq!(clamp_value(12.0, &(0.0..=10.0), false), 10.0);
+        assert_eq!(clamp_value(2.4, &(0.0..=10.0), true), 2.0);
+        assert_eq!(clamp_value(2.6, &(0.0..=10.0), true), 3.0);
+    }
+
+    #[test]
+    fn test_step_value() {
+        assert_eq!(step_value(3.0, 1.0, 1.0, 0.0), 4.0);
+        assert_eq!(step_value(3.0, -2.0, 1.0, 0.0), 1.0);
+
+        // The steps are counted from the start of the range:
+        assert_eq!(step_value(1.0, 1.0, 2.0, 1.0), 3.0);
+        assert_eq!(step_value(1.0, 1.0, 2.0, 0.0), 2.0);
+
+        // Off-step values snap to the next multiple in the direction of the step:
+        assert_eq!(step_value(2.5, 1.0, 1.0, 0.0), 3.0);
+        assert_eq!(step_value(2.5, -1.0, 1.0, 0.0), 2.0);
+
+        // Zero step does nothing:
+        assert_eq!(step_value(2.5, 1.0, 0.0, 0.0), 2.5);
+    }
+
+    #[test]
+    fn step_value_does_not_drift() {
+        let mut value = 0.0;
+        for _ in 0..30 {
+            value = step_value(value, 1.0, 0.1, 0.0);
+        }
+        assert!((value - 3.0).abs() < 1e-9, "{value}");
+        for _ in 0..30 {
+            value = step_value(value, -1.0, 0.1, 0.0);
+        }
+        assert!(value.abs() < 1e-9, "{value}");
+    }
+
+    fn run_stepper(ctx: &Context, input: RawInput, value: &mut i32) -> Response {
+        let mut response = None;
+        let _ = ctx.run(input, |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                response = Some(ui.add(Stepper::new(value, 0..=3)));
+            });
+        });
+        response.unwrap()
+    }
+
+    #[test]
+    fn keyboard_stepping_is_clamped() {
+        let ctx = Context::default();
+        let mut value = 1;
+        let response = run_stepper(&ctx, Default::default(), &mut value);
+        ctx.memory_mut(|mem| mem.request_focus(response.id));
+
+        let arrow = |key| Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        };
+
+        let input = RawInput {
+            events: vec![arrow(Key::ArrowUp)],
+            ..Default::default()
+        };
+        let response = run_stepper(&ctx, input, &mut value);
+        assert_eq!(value, 2);
+        assert!(response.changed());
+
+        let input = RawInput {
+            events: vec![
+                arrow(Key::ArrowUp),
+                arrow(Key::ArrowUp),
+                arrow(Key::ArrowUp),
+            ],
+            ..Default::default()
+        };
+        run_stepper(&ctx, input, &mut value);
+        assert_eq!(value, 3);
+
+        let input = RawInput {
+            events: vec![arrow(Key::ArrowDown)],
+            ..Default::default()
+        };
+        run_stepper(&ctx, input, &mut value);
+        assert_eq!(value, 2);
+    }
+}
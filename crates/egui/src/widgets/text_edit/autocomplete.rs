@@ -0,0 +1,375 @@
+use crate::{
+    text::{CCursor, CCursorRange},
+    *,
+};
+
+use super::{TextEdit, TextEditOutput, TextEditState};
+
+/// What the provider of an [`AutoComplete`] returns for the current text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Suggestions {
+    /// The suggestions to show. An empty list hides the popup.
+    Ready(Vec<String>),
+
+    /// The suggestions are still being computed, e.g. by a background thread or a web request.
+    ///
+    /// A "Loading…" row is shown until the provider returns [`Self::Ready`].
+    /// The provider is called again each frame, so it should cache its results.
+    Loading,
+}
+
+impl From<Vec<String>> for Suggestions {
+    #[inline]
+    fn from(suggestions: Vec<String>) -> Self {
+        Self::Ready(suggestions)
+    }
+}
+
+/// The output of [`AutoComplete::show`].
+pub struct AutoCompleteOutput {
+    /// The output of the [`TextEdit`].
+    pub text_edit: TextEditOutput,
+
+    /// The suggestion that was picked this frame, if any.
+    ///
+    /// It has already replaced the text of the [`TextEdit`].
+    pub accepted: Option<String>,
+}
+
+/// Shows a popup with suggestions below a [`TextEdit`] while it is being typed in.
+///
+/// * `ArrowDown`/`ArrowUp` highlight a suggestion
+/// * `Enter` picks the highlighted suggestion
+/// * `Tab` picks the highlighted suggestion, or the first one
+/// * `Escape` closes the popup until the text changes
+/// * Clicking a suggestion picks it
+///
+/// A picked suggestion replaces the whole text, and can be undone with the usual undo shortcut.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut language = String::new();
+/// let output = egui::AutoComplete::candidates(["Rust", "Ruby", "Python", "Zig"])
+///     .show(ui, egui::TextEdit::singleline(&mut language));
+/// if let Some(picked) = output.accepted {
+///     println!("Picked {picked}");
+/// }
+/// # });
+/// ```
+///
+/// Suggestions that take time to compute can be returned as [`Suggestions::Loading`]:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut city = String::new();
+/// # fn fetch_cities(_query: &str) -> Option<Vec<String>> { None }
+/// use egui::text_edit::Suggestions;
+///
+/// egui::AutoComplete::new(|query| match fetch_cities(query) {
+///     Some(cities) => Suggestions::Ready(cities),
+///     None => Suggestions::Loading,
+/// })
+/// .show(ui, egui::TextEdit::singleline(&mut city));
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct AutoComplete<'a> {
+    provider: Box<dyn 'a + FnMut(&str) -> Suggestions>,
+    max_suggestions: usize,
+    min_chars: usize,
+}
+
+impl<'a> AutoComplete<'a> {
+    /// `provider` is called with the current text and returns the suggestions for it.
+    ///
+    /// It is only called while the [`TextEdit`] has keyboard focus.
+    pub fn new(provider: impl 'a + FnMut(&str) -> Suggestions) -> Self {
+        Self {
+            provider: Box::new(provider),
+            max_suggestions: 8,
+            min_chars: 1,
+        }
+    }
+
+    /// Suggest the candidates that contain the current text, ignoring case.
+    pub fn candidates(candidates: impl IntoIterator<Item = impl ToString>) -> Self {
+        let candidates: Vec<String> = candidates.into_iter().map(|c| c.to_string()).collect();
+        Self::new(move |text| {
+            let query = text.trim().to_lowercase();
+            candidates
+                .iter()
+                .filter(|candidate| candidate.as_str() != text)
+                .filter(|candidate| candidate.to_lowercase().contains(&query))
+                .cloned()
+                .collect::<Vec<_>>()
+                .into()
+        })
+    }
+
+    /// Show at most this many suggestions. Default: 8.
+    #[inline]
+    pub fn max_suggestions(mut self, max_suggestions: usize) -> Self {
+        self.max_suggestions = max_suggestions;
+        self
+    }
+
+    /// Only show suggestions once at least this many characters have been typed. Default: 1.
+    #[inline]
+    pub fn min_chars(mut self, min_chars: usize) -> Self {
+        self.min_chars = min_chars;
+        self
+    }
+
+    /// Show the [`TextEdit`] with the suggestion popup.
+    pub fn show(self, ui: &mut Ui, mut text_edit: TextEdit<'_>) -> AutoCompleteOutput {
+        let Self {
+            mut provider,
+            max_suggestions,
+            min_chars,
+        } = self;
+
+        let id = text_edit.fix_id(ui);
+        let popup_id = id.with("autocomplete");
+        let mut state: SuggestionState = ui.data(|d| d.get_temp(popup_id)).unwrap_or_default();
+
+        let text = text_edit.text_buffer().as_str().to_owned();
+        let has_focus = ui.memory(|m| m.has_focus(id));
+        let wants_suggestions =
+            has_focus && !state.is_dismissed(&text) && min_chars <= text.chars().count();
+        let (suggestions, loading) = if wants_suggestions {
+            provider(&text).take(max_suggestions)
+        } else {
+            (vec![], false)
+        };
+
+        let mut accepted = state.handle_keys(ui, &text, &suggestions, loading);
+
+        // The popup is shown before the `TextEdit`, so that a clicked suggestion can go into this frame's text.
+        // That means we anchor it to where the `TextEdit` was last frame.
+        if let Some(rect) = ui.ctx().read_response(id).map(|response| response.rect) {
+            if accepted.is_none() {
+                accepted = state.popup_ui(
+                    ui,
+                    popup_id,
+                    rect.left_bottom(),
+                    rect.width(),
+                    &text,
+                    &suggestions,
+                    loading,
+                );
+            }
+        }
+
+        if let Some(completion) = &accepted {
+            let text_buffer = text_edit.text_buffer();
+            let all = 0..text_buffer.as_str().chars().count();
+            replace_char_range(ui.ctx(), id, text_buffer, all, completion);
+            state.accepted(ui, id, completion);
+        }
+        let popup_open = accepted.is_none() && state.is_open(&text, &suggestions, loading);
+
+        let event_filter = text_edit.get_event_filter();
+        let text_edit = text_edit.show(ui);
+        if popup_open {
+            lock_popup_keys(ui, id, event_filter);
+        }
+        ui.data_mut(|d| d.insert_temp(popup_id, state));
+
+        AutoCompleteOutput {
+            text_edit,
+            accepted,
+        }
+    }
+}
+
+impl Suggestions {
+    /// The first `max` suggestions, and whether they are still loading.
+    pub(super) fn take(self, max: usize) -> (Vec<String>, bool) {
+        match self {
+            Self::Ready(mut suggestions) => {
+                suggestions.truncate(max);
+                (suggestions, false)
+            }
+            Self::Loading => (vec![], true),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The keyboard handling and popup shared by [`AutoComplete`] and [`super::TriggerPopup`].
+#[derive(Clone, Default)]
+pub(super) struct SuggestionState {
+    /// Index of the highlighted suggestion, if any.
+    selected: Option<usize>,
+
+    /// The popup was closed (with `Escape`, or by picking a suggestion) while the text was this.
+    /// It stays closed until the text changes.
+    dismissed_for: Option<String>,
+}
+
+impl SuggestionState {
+    pub(super) fn is_dismissed(&self, text: &str) -> bool {
+        self.dismissed_for.as_deref() == Some(text)
+    }
+
+    pub(super) fn is_open(&self, text: &str, suggestions: &[String], loading: bool) -> bool {
+        !self.is_dismissed(text) && (!suggestions.is_empty() || loading)
+    }
+
+    /// Handle the keys for the popup.
+    ///
+    /// Must be called before the [`TextEdit`] is shown, so that it doesn't get the keys.
+    /// Returns the picked suggestion, if any.
+    pub(super) fn handle_keys(
+        &mut self,
+        ui: &Ui,
+        text: &str,
+        suggestions: &[String],
+        loading: bool,
+    ) -> Option<String> {
+        let n = suggestions.len();
+        if n == 0 {
+            self.selected = None;
+        } else if let Some(selected) = &mut self.selected {
+            *selected = (*selected).min(n - 1);
+        }
+
+        if !self.is_open(text, suggestions, loading) {
+            return None;
+        }
+
+        let mut accepted = None;
+        ui.input_mut(|i| {
+            if 0 < n {
+                if i.consume_key(Modifiers::NONE, Key::ArrowDown) {
+                    self.selected = Some(self.selected.map_or(0, |s| (s + 1) % n));
+                }
+                if i.consume_key(Modifiers::NONE, Key::ArrowUp) {
+                    self.selected = Some(self.selected.map_or(n - 1, |s| (s + n - 1) % n));
+                }
+                if i.consume_key(Modifiers::NONE, Key::Tab) {
+                    accepted = Some(suggestions[self.selected.unwrap_or(0)].clone());
+                }
+                if let Some(selected) = self.selected {
+                    if i.consume_key(Modifiers::NONE, Key::Enter) {
+                        accepted = Some(suggestions[selected].clone());
+                    }
+                }
+            }
+            if i.consume_key(Modifiers::NONE, Key::Escape) {
+                self.dismissed_for = Some(text.to_owned());
+            }
+        });
+        accepted
+    }
+
+    /// Show the popup, if it is open, with its top left corner at `pos`.
+    ///
+    /// Returns the clicked suggestion, if any.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn popup_ui(
+        &mut self,
+        ui: &Ui,
+        popup_id: Id,
+        pos: Pos2,
+        width: f32,
+        text: &str,
+        suggestions: &[String],
+        loading: bool,
+    ) -> Option<String> {
+        if !self.is_open(text, suggestions, loading) {
+            return None;
+        }
+
+        let mut accepted = None;
+        Area::new(popup_id)
+            .order(Order::Foreground)
+            .constrain(true)
+            .fixed_pos(pos)
+            .show(ui.ctx(), |ui| {
+                let frame = Frame::popup(ui.style());
+                let frame_margin = frame.total_margin();
+                frame.show(ui, |ui| {
+                    ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                        ui.set_width(width - frame_margin.sum().x);
+                        for (i, suggestion) in suggestions.iter().enumerate() {
+                            let response =
+                                ui.selectable_label(self.selected == Some(i), suggestion);
+                            if response.hovered() {
+                                self.selected = Some(i);
+                            }
+                            // Pressing elsewhere takes the focus from the `TextEdit`,
+                            // so we pick the suggestion on press rather than on click:
+                            if response.is_pointer_button_down_on() {
+                                accepted = Some(suggestion.clone());
+                            }
+                        }
+                        if loading {
+                            ui.horizontal(|ui| {
+                                ui.spinner();
+                                ui.weak("Loading…");
+                            });
+                        }
+                    });
+                });
+            });
+
+        accepted
+    }
+
+    /// Call after a suggestion has been inserted, resulting in `new_text`.
+    pub(super) fn accepted(&mut self, ui: &Ui, text_edit_id: Id, new_text: &str) {
+        self.selected = None;
+        self.dismissed_for = Some(new_text.to_owned());
+        ui.memory_mut(|m| m.request_focus(text_edit_id));
+    }
+}
+
+/// Call after the [`TextEdit`] has been shown, while the popup is open.
+///
+/// Keeps the keys used by the popup from moving the focus next frame.
+pub(super) fn lock_popup_keys(ui: &Ui, text_edit_id: Id, event_filter: EventFilter) {
+    if ui.memory(|m| m.has_focus(text_edit_id)) {
+        ui.memory_mut(|m| {
+            m.set_focus_lock_filter(
+                text_edit_id,
+                EventFilter {
+                    tab: true,
+                    vertical_arrows: true,
+                    escape: true,
+                    ..event_filter
+                },
+            );
+        });
+    }
+}
+
+/// Replace a range of characters with `replacement`, as a single undo step,
+/// and put the cursor after it.
+pub(super) fn replace_char_range(
+    ctx: &Context,
+    id: Id,
+    text: &mut dyn TextBuffer,
+    char_range: std::ops::Range<usize>,
+    replacement: &str,
+) {
+    let mut state = TextEditState::load(ctx, id).unwrap_or_default();
+
+    let old_text = text.as_str().to_owned();
+    let old_cursor = state
+        .cursor
+        .char_range()
+        .unwrap_or_else(|| CCursorRange::one(CCursor::new(char_range.end)));
+
+    text.delete_char_range(char_range.clone());
+    let inserted = text.insert_text(replacement, char_range.start);
+    let new_cursor = CCursorRange::one(CCursor::new(char_range.start + inserted));
+    state.cursor.set_char_range(Some(new_cursor));
+    {
+        let mut undoer = state.undoer.lock();
+        undoer.add_undo(&(old_cursor, old_text));
+        undoer.add_undo(&(new_cursor, text.as_str().to_owned()));
+    }
+    state.store(ctx, id);
+}
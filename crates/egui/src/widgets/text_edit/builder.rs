@@ -6,13 +6,19 @@ use crate::{
     os::OperatingSystem,
     output::OutputEvent,
     text_selection::{
-        text_cursor_state::cursor_rect, visuals::paint_text_selection, CCursorRange, CursorRange,
+        text_cursor_state::cursor_rect,
+        touch::{self, SelectionEnd},
+        visuals::paint_text_selection,
+        CCursorRange, CursorRange,
     },
     *,
 };
 
 use super::{TextEditOutput, TextEditState};
 
+/// Rates a password, see [`TextEdit::password_strength`].
+type PasswordStrengthFn<'t> = dyn Fn(&str) -> f32 + 't;
+
 /// A text region that the user can edit the contents of.
 ///
 /// See also [`Ui::text_edit_singleline`] and [`Ui::text_edit_multiline`].
@@ -79,6 +85,11 @@ pub struct TextEdit<'t> {
     clip_text: bool,
     char_limit: usize,
     return_key: KeyboardShortcut,
+    password_reveal_button: bool,
+    password_peek_last_char: bool,
+    password_strength: Option<&'t PasswordStrengthFn<'t>>,
+    allow_copy: Option<bool>,
+    allow_paste: bool,
 }
 
 impl<'t> WidgetWithState for TextEdit<'t> {
@@ -136,6 +147,11 @@ impl<'t> TextEdit<'t> {
             clip_text: false,
             char_limit: usize::MAX,
             return_key: KeyboardShortcut::new(Modifiers::NONE, Key::Enter),
+            password_reveal_button: false,
+            password_peek_last_char: false,
+            password_strength: None,
+            allow_copy: None,
+            allow_paste: true,
         }
     }
 
@@ -190,12 +206,79 @@ impl<'t> TextEdit<'t> {
     }
 
     /// If true, hide the letters from view and prevent copying from the field.
+    ///
+    /// See also [`Self::password_reveal_button`], [`Self::password_peek_last_char`]
+    /// and [`Self::password_strength`].
     #[inline]
     pub fn password(mut self, password: bool) -> Self {
         self.password = password;
         self
     }
 
+    /// Show an eye button at the end of a [`Self::password`] field,
+    /// which shows the password while it is held down.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn password_reveal_button(mut self, show: bool) -> Self {
+        self.password_reveal_button = show;
+        self
+    }
+
+    /// Briefly show the last typed character of a [`Self::password`] field before masking it.
+    ///
+    /// This is common on touch screens, where typos are easy to make.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn password_peek_last_char(mut self, peek: bool) -> Self {
+        self.password_peek_last_char = peek;
+        self
+    }
+
+    /// Show a strength meter along the bottom of the field.
+    ///
+    /// The function is given the current text, and should return a strength in `0.0..=1.0`.
+    /// The meter uses the error, warning and selection colors of the [`Visuals`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut password = String::new();
+    /// let strength = |password: &str| (password.chars().count() as f32 / 12.0).min(1.0);
+    /// ui.add(
+    ///     egui::TextEdit::singleline(&mut password)
+    ///         .password(true)
+    ///         .password_reveal_button(true)
+    ///         .password_strength(&strength),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn password_strength(mut self, strength: &'t dyn Fn(&str) -> f32) -> Self {
+        self.password_strength = Some(strength);
+        self
+    }
+
+    /// Can the text be copied (or cut) to the clipboard?
+    ///
+    /// If `false`, cutting still removes the text, but it is not put on the clipboard.
+    ///
+    /// Default: `true`, except for [`Self::password`] fields.
+    #[inline]
+    pub fn allow_copy(mut self, allow_copy: bool) -> Self {
+        self.allow_copy = Some(allow_copy);
+        self
+    }
+
+    /// Can text be pasted into the field?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn allow_paste(mut self, allow_paste: bool) -> Self {
+        self.allow_paste = allow_paste;
+        self
+    }
+
     /// Pick a [`FontId`] or [`TextStyle`].
     #[inline]
     pub fn font(mut self, font_selection: impl Into<FontSelection>) -> Self {
@@ -360,6 +443,30 @@ impl<'t> TextEdit<'t> {
     }
 }
 
+/// Used by [`super::AutoComplete`], which needs to know about the [`TextEdit`] before it is shown.
+impl<'t> TextEdit<'t> {
+    /// Decide on the [`Id`] now, so that it is known before calling [`Self::show`].
+    pub(crate) fn fix_id(&mut self, ui: &Ui) -> Id {
+        let id = self
+            .id
+            .or_else(|| {
+                self.id_source
+                    .map(|id_source| ui.make_persistent_id(id_source))
+            })
+            .unwrap_or_else(|| ui.next_auto_id());
+        self.id = Some(id);
+        id
+    }
+
+    pub(crate) fn text_buffer(&mut self) -> &mut dyn TextBuffer {
+        self.text
+    }
+
+    pub(crate) fn get_event_filter(&self) -> EventFilter {
+        self.event_filter
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 impl<'t> Widget for TextEdit<'t> {
@@ -384,11 +491,16 @@ impl<'t> TextEdit<'t> {
     /// }
     /// # });
     /// ```
-    pub fn show(self, ui: &mut Ui) -> TextEditOutput {
+    pub fn show(mut self, ui: &mut Ui) -> TextEditOutput {
         let is_mutable = self.text.is_mutable();
         let frame = self.frame;
         let where_to_put_background = ui.painter().add(Shape::Noop);
 
+        if self.password && self.password_reveal_button {
+            // Make room for the reveal button:
+            self.margin.right += ui.spacing().icon_width + ui.spacing().icon_spacing;
+        }
+
         let margin = self.margin;
         let mut output = self.show_content(ui);
 
@@ -455,6 +567,11 @@ impl<'t> TextEdit<'t> {
             clip_text,
             char_limit,
             return_key,
+            password_reveal_button,
+            password_peek_last_char,
+            password_strength,
+            allow_copy,
+            allow_paste,
         } = self;
 
         let text_color = text_color
@@ -475,9 +592,32 @@ impl<'t> TextEdit<'t> {
             desired_width.min(available_width)
         };
 
+        // Is the password being revealed with the reveal button?
+        // We need to know before we layout the text, so we use the interaction from the start of the frame.
+        let reveal_button_id = id
+            .or_else(|| id_source.map(|id_source| ui.make_persistent_id(id_source)))
+            .unwrap_or_else(|| ui.next_auto_id())
+            .with("password_reveal");
+        let revealed = password
+            && password_reveal_button
+            && ui
+                .ctx()
+                .read_response(reveal_button_id)
+                .map_or(false, |response| response.is_pointer_button_down_on());
+        let mask = password && !revealed;
+
+        // Should the last character of a password be shown, because it was just typed?
+        // Shared with the layouter, which must not borrow anything on the stack.
+        let peek_last_char = std::rc::Rc::new(std::cell::Cell::new(false));
+        let peek_last_char_ref = peek_last_char.clone();
+
         let font_id_clone = font_id.clone();
         let mut default_layouter = move |ui: &Ui, text: &str, wrap_width: f32| {
-            let text = mask_if_password(password, text);
+            let text = if mask {
+                mask_password(text, peek_last_char_ref.get())
+            } else {
+                text.to_owned()
+            };
             let layout_job = if multiline {
                 LayoutJob::simple(text, font_id_clone.clone(), text_color, wrap_width)
             } else {
@@ -510,6 +650,23 @@ impl<'t> TextEdit<'t> {
         });
         let mut state = TextEditState::load(ui.ctx(), id).unwrap_or_default();
 
+        const PASSWORD_PEEK_DURATION: f64 = 1.0;
+        if mask && password_peek_last_char {
+            if let Some(last_char_typed_time) = state.last_char_typed_time {
+                let since_typed = ui.input(|i| i.time) - last_char_typed_time;
+                if since_typed < PASSWORD_PEEK_DURATION {
+                    peek_last_char.set(true);
+                    galley = layouter(ui, text.as_str(), wrap_width);
+                    ui.ctx()
+                        .request_repaint_after(std::time::Duration::from_secs_f64(
+                            PASSWORD_PEEK_DURATION - since_typed,
+                        ));
+                } else {
+                    state.last_char_typed_time = None;
+                }
+            }
+        }
+
         // On touch screens (e.g. mobile in `eframe` web), should
         // dragging select text, or scroll the enclosing [`ScrollArea`] (if any)?
         // Since currently copying selected text in not supported on `eframe` web,
@@ -582,6 +739,11 @@ impl<'t> TextEdit<'t> {
                 CursorRange::default()
             };
 
+            let clipboard = ClipboardPermissions {
+                copy: allow_copy.unwrap_or(!password),
+                paste: allow_paste,
+            };
+
             let (changed, new_cursor_range) = events(
                 ui,
                 &mut state,
@@ -591,7 +753,7 @@ impl<'t> TextEdit<'t> {
                 id,
                 wrap_width,
                 multiline,
-                password,
+                clipboard,
                 default_cursor_range,
                 char_limit,
                 event_filter,
@@ -600,6 +762,23 @@ impl<'t> TextEdit<'t> {
 
             if changed {
                 response.mark_changed();
+
+                if mask && password_peek_last_char {
+                    let typed_a_char = text.as_str().chars().count() > prev_text.chars().count();
+                    if typed_a_char {
+                        state.last_char_typed_time = Some(ui.input(|i| i.time));
+                        ui.ctx()
+                            .request_repaint_after(std::time::Duration::from_secs_f64(
+                                PASSWORD_PEEK_DURATION,
+                            ));
+                    } else {
+                        state.last_char_typed_time = None;
+                    }
+                    if peek_last_char.get() != typed_a_char {
+                        peek_last_char.set(typed_a_char);
+                        galley = layouter(ui, text.as_str(), wrap_width);
+                    }
+                }
             }
             cursor_range = Some(new_cursor_range);
         }
@@ -646,6 +825,48 @@ impl<'t> TextEdit<'t> {
             false
         };
 
+        // On touch screens the finger hides the text, so we show handles for moving
+        // the ends of the selection, and a loupe with a magnified copy of the text:
+        let mut loupe_focus = None;
+        if interactive && touch::is_enabled(ui) {
+            // Pressing a handle outside of the text edit makes us lose focus, so check for that too:
+            let has_or_lost_focus = ui.memory(|mem| mem.has_focus(id) || mem.lost_focus(id));
+            if let (true, Some(mut range)) = (has_or_lost_focus, state.cursor.range(&galley)) {
+                if !range.is_empty() {
+                    let is_visible = |cursor: &Cursor| {
+                        text_clip_rect.expand(1.0).contains(
+                            galley_pos + galley.pos_from_cursor(cursor).center().to_vec2(),
+                        )
+                    };
+                    let ends: Vec<_> = [
+                        (SelectionEnd::Primary, range.primary),
+                        (SelectionEnd::Secondary, range.secondary),
+                    ]
+                    .into_iter()
+                    .filter(|(_, cursor)| is_visible(cursor))
+                    .collect();
+
+                    let handles = touch::selection_handles(ui, id, galley_pos, &galley, &ends);
+                    if handles.hovered {
+                        ui.memory_mut(|mem| mem.request_focus(id));
+                    }
+                    if let Some(drag) = handles.dragged {
+                        match drag.end {
+                            SelectionEnd::Primary => range.primary = drag.cursor,
+                            SelectionEnd::Secondary => range.secondary = drag.cursor,
+                        }
+                        state.cursor.set_range(Some(range));
+                        cursor_range = Some(range);
+                        loupe_focus = Some(drag.pos);
+                    }
+                }
+            }
+
+            if response.dragged() && ui.input(|i| i.any_touches()) {
+                loupe_focus = response.interact_pointer_pos();
+            }
+        }
+
         if ui.is_rect_visible(rect) {
             painter.galley(galley_pos, galley.clone(), text_color);
 
@@ -716,6 +937,66 @@ impl<'t> TextEdit<'t> {
             }
         }
 
+        if let Some(focus) = loupe_focus {
+            let cursor_range = state.cursor.range(&galley);
+            touch::paint_loupe(
+                ui,
+                focus,
+                galley_pos,
+                &galley,
+                text_color,
+                cursor_range.as_ref(),
+            );
+        }
+
+        if password && password_reveal_button {
+            let button_width = ui.spacing().icon_width;
+            let button_rect = Rect::from_min_max(
+                pos2(rect.right() + ui.spacing().icon_spacing, rect.top()),
+                pos2(
+                    rect.right() + ui.spacing().icon_spacing + button_width,
+                    rect.bottom(),
+                ),
+            );
+            let button_response = ui
+                .interact(button_rect, id.with("password_reveal"), Sense::click())
+                .on_hover_text("Hold to show password");
+            if ui.is_rect_visible(button_rect) {
+                let color = if button_response.is_pointer_button_down_on() {
+                    ui.visuals().strong_text_color()
+                } else {
+                    ui.style().interact(&button_response).text_color()
+                };
+                ui.painter().text(
+                    button_rect.center(),
+                    Align2::CENTER_CENTER,
+                    "👁",
+                    FontId::proportional(0.8 * row_height),
+                    color,
+                );
+            }
+        }
+
+        if let Some(password_strength) = password_strength {
+            let strength = password_strength(text.as_str()).clamp(0.0, 1.0);
+            if ui.is_rect_visible(outer_rect) && !text.as_str().is_empty() {
+                let bar_height = 2.0;
+                let bar_rect = Rect::from_min_max(
+                    pos2(outer_rect.left(), outer_rect.bottom() - bar_height),
+                    pos2(lerp(outer_rect.x_range(), strength), outer_rect.bottom()),
+                );
+                let visuals = ui.visuals();
+                let color = if strength < 0.5 {
+                    visuals.error_fg_color
+                } else if strength < 0.8 {
+                    visuals.warn_fg_color
+                } else {
+                    visuals.selection.bg_fill
+                };
+                ui.painter().rect_filled(bar_rect, 0.0, color);
+            }
+        }
+
         state.clone().store(ui.ctx(), id);
 
         if response.changed {
@@ -775,19 +1056,35 @@ impl<'t> TextEdit<'t> {
 }
 
 fn mask_if_password(is_password: bool, text: &str) -> String {
-    fn mask_password(text: &str) -> String {
-        std::iter::repeat(epaint::text::PASSWORD_REPLACEMENT_CHAR)
-            .take(text.chars().count())
-            .collect::<String>()
-    }
-
     if is_password {
-        mask_password(text)
+        mask_password(text, false)
     } else {
         text.to_owned()
     }
 }
 
+/// Replace all characters with [`epaint::text::PASSWORD_REPLACEMENT_CHAR`],
+/// except optionally the last one.
+fn mask_password(text: &str, keep_last_char: bool) -> String {
+    let num_chars = text.chars().count();
+    let num_masked = if keep_last_char {
+        num_chars.saturating_sub(1)
+    } else {
+        num_chars
+    };
+    std::iter::repeat(epaint::text::PASSWORD_REPLACEMENT_CHAR)
+        .take(num_masked)
+        .chain(text.chars().skip(num_masked))
+        .collect::<String>()
+}
+
+/// What clipboard operations a [`TextEdit`] allows.
+#[derive(Clone, Copy)]
+struct ClipboardPermissions {
+    copy: bool,
+    paste: bool,
+}
+
 // ----------------------------------------------------------------------------
 
 /// Check for (keyboard) events to edit the cursor and/or text.
@@ -801,7 +1098,7 @@ fn events(
     id: Id,
     wrap_width: f32,
     multiline: bool,
-    password: bool,
+    clipboard: ClipboardPermissions,
     default_cursor_range: CursorRange,
     char_limit: usize,
     event_filter: EventFilter,
@@ -818,8 +1115,8 @@ fn events(
         &(cursor_range.as_ccursor_range(), text.as_str().to_owned()),
     );
 
-    let copy_if_not_password = |ui: &Ui, text: String| {
-        if !password {
+    let copy_if_allowed = |ui: &Ui, text: String| {
+        if clipboard.copy {
             ui.ctx().copy_text(text);
         }
     };
@@ -834,23 +1131,23 @@ fn events(
 
             Event::Copy => {
                 if cursor_range.is_empty() {
-                    copy_if_not_password(ui, text.as_str().to_owned());
+                    copy_if_allowed(ui, text.as_str().to_owned());
                 } else {
-                    copy_if_not_password(ui, cursor_range.slice_str(text.as_str()).to_owned());
+                    copy_if_allowed(ui, cursor_range.slice_str(text.as_str()).to_owned());
                 }
                 None
             }
             Event::Cut => {
                 if cursor_range.is_empty() {
-                    copy_if_not_password(ui, text.take());
+                    copy_if_allowed(ui, text.take());
                     Some(CCursorRange::default())
                 } else {
-                    copy_if_not_password(ui, cursor_range.slice_str(text.as_str()).to_owned());
+                    copy_if_allowed(ui, cursor_range.slice_str(text.as_str()).to_owned());
                     Some(CCursorRange::one(text.delete_selected(&cursor_range)))
                 }
             }
             Event::Paste(text_to_insert) => {
-                if !text_to_insert.is_empty() {
+                if clipboard.paste && !text_to_insert.is_empty() {
                     let mut ccursor = text.delete_selected(&cursor_range);
 
                     text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
@@ -872,6 +1169,31 @@ fn events(
                     None
                 }
             }
+            Event::TextRangeReplace {
+                range,
+                text: replacement,
+            } => {
+                let num_chars = text.as_str().chars().count();
+                let start = range.start.min(num_chars);
+                let end = range.end.clamp(start, num_chars);
+
+                // Make the replacement a step of its own in the undo history:
+                state
+                    .undoer
+                    .lock()
+                    .add_undo(&(cursor_range.as_ccursor_range(), text.as_str().to_owned()));
+
+                text.delete_char_range(start..end);
+                let mut ccursor = CCursor::new(start);
+                text.insert_text_at(&mut ccursor, replacement, char_limit);
+
+                let new_ccursor_range = CCursorRange::one(ccursor);
+                state
+                    .undoer
+                    .lock()
+                    .add_undo(&(new_ccursor_range, text.as_str().to_owned()));
+                Some(new_ccursor_range)
+            }
             Event::Key {
                 key: Key::Tab,
                 pressed: true,
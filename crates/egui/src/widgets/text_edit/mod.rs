@@ -1,9 +1,16 @@
+mod autocomplete;
 mod builder;
 mod output;
 mod state;
 mod text_buffer;
+mod trigger_popup;
 
 pub use {
-    crate::text_selection::TextCursorState, builder::TextEdit, output::TextEditOutput,
-    state::TextEditState, text_buffer::TextBuffer,
+    crate::text_selection::TextCursorState,
+    autocomplete::{AutoComplete, AutoCompleteOutput, Suggestions},
+    builder::TextEdit,
+    output::TextEditOutput,
+    state::TextEditState,
+    text_buffer::TextBuffer,
+    trigger_popup::{Trigger, TriggerPopup, TriggerPopupOutput},
 };
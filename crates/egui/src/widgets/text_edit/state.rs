@@ -56,6 +56,11 @@ pub struct TextEditState {
     /// Used to pause the cursor animation when typing.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) last_edit_time: f64,
+
+    /// When did the user last type a character?
+    /// Used for [`crate::TextEdit::password_peek_last_char`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) last_char_typed_time: Option<f64>,
 }
 
 impl TextEditState {
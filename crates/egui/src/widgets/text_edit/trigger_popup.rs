@@ -0,0 +1,263 @@
+use std::ops::Range;
+
+use crate::{text::CCursor, *};
+
+use super::{
+    autocomplete::{lock_popup_keys, replace_char_range, SuggestionState},
+    Suggestions, TextEdit, TextEditOutput, TextEditState,
+};
+
+/// A character that opens a [`TriggerPopup`], e.g. `@` for mentions or `/` for commands.
+pub struct Trigger<'a> {
+    trigger: char,
+    provider: Box<dyn 'a + FnMut(&str) -> Suggestions>,
+    replacement: Box<dyn 'a + Fn(&str) -> String>,
+}
+
+impl<'a> Trigger<'a> {
+    /// `provider` is called with the query, i.e. what has been typed between the trigger character and the cursor.
+    ///
+    /// The query can be empty, e.g. right after typing `@`.
+    pub fn new(trigger: char, provider: impl 'a + FnMut(&str) -> Suggestions) -> Self {
+        Self {
+            trigger,
+            provider: Box::new(provider),
+            replacement: Box::new(move |picked| format!("{trigger}{picked} ")),
+        }
+    }
+
+    /// Suggest the candidates that contain the query, ignoring case.
+    pub fn candidates(trigger: char, candidates: impl IntoIterator<Item = impl ToString>) -> Self {
+        let candidates: Vec<String> = candidates.into_iter().map(|c| c.to_string()).collect();
+        Self::new(trigger, move |query| {
+            let query = query.to_lowercase();
+            candidates
+                .iter()
+                .filter(|candidate| candidate.to_lowercase().contains(&query))
+                .cloned()
+                .collect::<Vec<_>>()
+                .into()
+        })
+    }
+
+    /// What the trigger character and the query are replaced with when a suggestion is picked.
+    ///
+    /// Default: the trigger character, the suggestion and a space, e.g. `"@alice "`.
+    #[inline]
+    pub fn replacement(mut self, replacement: impl 'a + Fn(&str) -> String) -> Self {
+        self.replacement = Box::new(replacement);
+        self
+    }
+}
+
+/// The output of [`TriggerPopup::show`].
+pub struct TriggerPopupOutput {
+    /// The output of the [`TextEdit`].
+    pub text_edit: TextEditOutput,
+
+    /// The trigger character and the suggestion that was picked this frame, if any.
+    ///
+    /// The replacement has already been inserted into the text of the [`TextEdit`].
+    pub accepted: Option<(char, String)>,
+}
+
+#[derive(Clone, Default)]
+struct TriggerPopupState {
+    suggestions: SuggestionState,
+
+    /// Below the trigger character, as of last frame.
+    anchor: Option<Pos2>,
+}
+
+/// Shows a picker when a trigger character is typed into a [`TextEdit`],
+/// e.g. `@` for mentions, `:` for emojis or `/` for commands.
+///
+/// A trigger only counts at the start of the text or after whitespace.
+/// Everything typed after it, up to the cursor, is the query.
+/// Picking a suggestion replaces the trigger character and the query
+/// (see [`Trigger::replacement`]), as a single undo step.
+///
+/// The popup is controlled in the same way as [`super::AutoComplete`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut message = String::new();
+/// use egui::text_edit::{Trigger, TriggerPopup};
+///
+/// TriggerPopup::new()
+///     .trigger(Trigger::candidates('@', ["alice", "bob", "carol"]))
+///     .trigger(Trigger::candidates('/', ["shrug", "giphy"]).replacement(|cmd| format!("/{cmd} ")))
+///     .show(ui, egui::TextEdit::multiline(&mut message));
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct TriggerPopup<'a> {
+    triggers: Vec<Trigger<'a>>,
+    max_suggestions: usize,
+    popup_width: f32,
+}
+
+impl<'a> Default for TriggerPopup<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> TriggerPopup<'a> {
+    pub fn new() -> Self {
+        Self {
+            triggers: vec![],
+            max_suggestions: 8,
+            popup_width: 200.0,
+        }
+    }
+
+    /// Add a trigger character.
+    #[inline]
+    pub fn trigger(mut self, trigger: Trigger<'a>) -> Self {
+        self.triggers.push(trigger);
+        self
+    }
+
+    /// Show at most this many suggestions. Default: 8.
+    #[inline]
+    pub fn max_suggestions(mut self, max_suggestions: usize) -> Self {
+        self.max_suggestions = max_suggestions;
+        self
+    }
+
+    /// Width of the popup. Default: 200.
+    #[inline]
+    pub fn popup_width(mut self, popup_width: f32) -> Self {
+        self.popup_width = popup_width;
+        self
+    }
+
+    /// Show the [`TextEdit`], with a popup while a trigger is active.
+    pub fn show(self, ui: &mut Ui, mut text_edit: TextEdit<'_>) -> TriggerPopupOutput {
+        let Self {
+            mut triggers,
+            max_suggestions,
+            popup_width,
+        } = self;
+
+        let id = text_edit.fix_id(ui);
+        let popup_id = id.with("trigger_popup");
+        let mut state: TriggerPopupState = ui.data(|d| d.get_temp(popup_id)).unwrap_or_default();
+        let trigger_chars: Vec<char> = triggers.iter().map(|t| t.trigger).collect();
+
+        let text = text_edit.text_buffer().as_str().to_owned();
+        let cursor = TextEditState::load(ui.ctx(), id)
+            .and_then(|state| state.cursor.char_range())
+            .filter(|range| range.primary.index == range.secondary.index)
+            .map(|range| range.primary.index);
+        let has_focus = ui.memory(|m| m.has_focus(id));
+        let active = cursor
+            .filter(|_| has_focus && !state.suggestions.is_dismissed(&text))
+            .and_then(|cursor| find_trigger(&text, cursor, &trigger_chars));
+
+        let (suggestions, loading) = if let Some((index, range)) = &active {
+            let query: String = text
+                .chars()
+                .skip(range.start + 1)
+                .take(range.len() - 1)
+                .collect();
+            (triggers[*index].provider)(&query).take(max_suggestions)
+        } else {
+            (vec![], false)
+        };
+
+        let mut picked = state
+            .suggestions
+            .handle_keys(ui, &text, &suggestions, loading);
+        if let (None, Some(anchor)) = (&picked, state.anchor) {
+            picked = state.suggestions.popup_ui(
+                ui,
+                popup_id,
+                anchor,
+                popup_width,
+                &text,
+                &suggestions,
+                loading,
+            );
+        }
+
+        let mut accepted = None;
+        if let (Some(picked), Some((index, range))) = (picked, active) {
+            let trigger = &triggers[index];
+            let replacement = (trigger.replacement)(&picked);
+            let text_buffer = text_edit.text_buffer();
+            replace_char_range(ui.ctx(), id, text_buffer, range, &replacement);
+            state.suggestions.accepted(ui, id, text_buffer.as_str());
+            accepted = Some((trigger.trigger, picked));
+        }
+        let popup_open =
+            accepted.is_none() && state.suggestions.is_open(&text, &suggestions, loading);
+
+        let event_filter = text_edit.get_event_filter();
+        let text_edit = text_edit.show(ui);
+        if popup_open {
+            lock_popup_keys(ui, id, event_filter);
+        }
+
+        // Remember where the trigger character is, so we can put the popup below it next frame:
+        state.anchor = text_edit
+            .cursor_range
+            .filter(|range| range.is_empty())
+            .and_then(|range| {
+                find_trigger(
+                    text_edit.galley.text(),
+                    range.primary.ccursor.index,
+                    &trigger_chars,
+                )
+            })
+            .map(|(_, range)| {
+                let rect = text_edit.galley.pos_from_ccursor(CCursor::new(range.start));
+                text_edit.galley_pos + rect.left_bottom().to_vec2()
+            });
+        ui.data_mut(|d| d.insert_temp(popup_id, state));
+
+        TriggerPopupOutput {
+            text_edit,
+            accepted,
+        }
+    }
+}
+
+/// Find the trigger that the cursor is in the query of.
+///
+/// Returns the index of the trigger in `triggers`, and the character range
+/// from the trigger character up to the cursor.
+fn find_trigger(text: &str, cursor: usize, triggers: &[char]) -> Option<(usize, Range<usize>)> {
+    let chars: Vec<char> = text.chars().take(cursor).collect();
+    for start in (0..chars.len()).rev() {
+        let c = chars[start];
+        if c.is_whitespace() {
+            return None;
+        }
+        let at_word_start = start == 0 || chars[start - 1].is_whitespace();
+        if at_word_start {
+            if let Some(index) = triggers.iter().position(|&t| t == c) {
+                return Some((index, start..chars.len()));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_trigger;
+
+    #[test]
+    fn test_find_trigger() {
+        let triggers = ['@', ':'];
+        assert_eq!(find_trigger("hi @al", 6, &triggers), Some((0, 3..6)));
+        assert_eq!(find_trigger("@", 1, &triggers), Some((0, 0..1)));
+        assert_eq!(find_trigger(":smi", 4, &triggers), Some((1, 0..4)));
+        assert_eq!(find_trigger("hi @al", 3, &triggers), None);
+        assert_eq!(find_trigger("hi @al bob", 10, &triggers), None);
+        assert_eq!(find_trigger("mail@example", 12, &triggers), None);
+        assert_eq!(find_trigger("@a@b", 4, &triggers), Some((0, 0..4)));
+    }
+}
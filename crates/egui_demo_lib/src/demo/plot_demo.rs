@@ -6,7 +6,7 @@ use egui::*;
 use egui_plot::{
     Arrows, AxisHints, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, CoordinatesFormatter, Corner,
     GridInput, GridMark, HLine, Legend, Line, LineStyle, MarkerShape, Plot, PlotImage, PlotPoint,
-    PlotPoints, PlotResponse, Points, Polygon, Text, VLine,
+    PlotPoints, PlotResponse, Points, Polygon, StepKind, Text, VLine,
 };
 
 // ----------------------------------------------------------------------------
@@ -216,6 +216,7 @@ impl LineDemo {
                             LineStyle::dashed_loose(),
                             LineStyle::dotted_dense(),
                             LineStyle::dotted_loose(),
+                            LineStyle::Step(StepKind::Post),
                         ] {
                             ui.selectable_value(line_style, *style, style.to_string());
                         }
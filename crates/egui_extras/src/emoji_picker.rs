@@ -0,0 +1,755 @@
+use egui::{
+    text_edit::{Suggestions, Trigger},
+    Button, Color32, Context, Id, InnerResponse, RichText, ScrollArea, TextEdit, Ui, Vec2,
+};
+
+/// Skin tone variants of people and hand emojis, using the Fitzpatrick modifiers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum SkinTone {
+    /// The yellow, cartoon-like default.
+    #[default]
+    Default,
+    Light,
+    MediumLight,
+    Medium,
+    MediumDark,
+    Dark,
+}
+
+impl SkinTone {
+    pub const ALL: [Self; 6] = [
+        Self::Default,
+        Self::Light,
+        Self::MediumLight,
+        Self::Medium,
+        Self::MediumDark,
+        Self::Dark,
+    ];
+
+    /// The modifier character to put after an emoji, if any.
+    pub fn modifier(self) -> Option<char> {
+        match self {
+            Self::Default => None,
+            Self::Light => Some('\u{1F3FB}'),
+            Self::MediumLight => Some('\u{1F3FC}'),
+            Self::Medium => Some('\u{1F3FD}'),
+            Self::MediumDark => Some('\u{1F3FE}'),
+            Self::Dark => Some('\u{1F3FF}'),
+        }
+    }
+
+    /// The color of the swatch shown in the picker.
+    fn color(self) -> Color32 {
+        match self {
+            Self::Default => Color32::from_rgb(255, 204, 77),
+            Self::Light => Color32::from_rgb(247, 222, 206),
+            Self::MediumLight => Color32::from_rgb(243, 210, 162),
+            Self::Medium => Color32::from_rgb(213, 171, 136),
+            Self::MediumDark => Color32::from_rgb(175, 126, 87),
+            Self::Dark => Color32::from_rgb(124, 83, 62),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Default => "Default",
+            Self::Light => "Light",
+            Self::MediumLight => "Medium-light",
+            Self::Medium => "Medium",
+            Self::MediumDark => "Medium-dark",
+            Self::Dark => "Dark",
+        }
+    }
+
+    /// Apply the skin tone to the emoji, if it supports skin tones.
+    fn apply_if_supported(self, emoji: &str) -> String {
+        if find_emoji(emoji).map_or(false, |&(_, _, skin_tones)| skin_tones) {
+            self.apply(emoji)
+        } else {
+            emoji.to_owned()
+        }
+    }
+
+    fn apply(self, emoji: &str) -> String {
+        let mut chars = emoji.chars();
+        match (self.modifier(), chars.next()) {
+            (Some(modifier), Some(first)) => {
+                let mut result = String::from(first);
+                result.push(modifier);
+                result.extend(chars);
+                result
+            }
+            _ => emoji.to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Category {
+    Smileys,
+    People,
+    Nature,
+    Food,
+    Activities,
+    Travel,
+    Objects,
+    Symbols,
+}
+
+impl Category {
+    const ALL: [Self; 8] = [
+        Self::Smileys,
+        Self::People,
+        Self::Nature,
+        Self::Food,
+        Self::Activities,
+        Self::Travel,
+        Self::Objects,
+        Self::Symbols,
+    ];
+
+    fn icon(self) -> &'static str {
+        match self {
+            Self::Smileys => "😀",
+            Self::People => "👋",
+            Self::Nature => "🐻",
+            Self::Food => "🍔",
+            Self::Activities => "⚽",
+            Self::Travel => "🚗",
+            Self::Objects => "💡",
+            Self::Symbols => "❤",
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Smileys => "Smileys",
+            Self::People => "People",
+            Self::Nature => "Animals & Nature",
+            Self::Food => "Food & Drink",
+            Self::Activities => "Activities",
+            Self::Travel => "Travel & Places",
+            Self::Objects => "Objects",
+            Self::Symbols => "Symbols",
+        }
+    }
+
+    /// `(emoji, name, supports skin tones)`
+    fn emojis(self) -> &'static [(&'static str, &'static str, bool)] {
+        match self {
+            Self::Smileys => SMILEYS,
+            Self::People => PEOPLE,
+            Self::Nature => NATURE,
+            Self::Food => FOOD,
+            Self::Activities => ACTIVITIES,
+            Self::Travel => TRAVEL,
+            Self::Objects => OBJECTS,
+            Self::Symbols => SYMBOLS,
+        }
+    }
+}
+
+const SMILEYS: &[(&str, &str, bool)] = &[
+    ("😀", "grinning face", false),
+    ("😃", "grinning face with big eyes", false),
+    ("😄", "grinning face with smiling eyes", false),
+    ("😁", "beaming face", false),
+    ("😆", "laughing", false),
+    ("😅", "sweat smile", false),
+    ("😂", "joy", false),
+    ("😊", "blush", false),
+    ("😇", "innocent", false),
+    ("😉", "wink", false),
+    ("😍", "heart eyes", false),
+    ("😘", "kissing heart", false),
+    ("😋", "yum", false),
+    ("😛", "tongue out", false),
+    ("😎", "sunglasses", false),
+    ("😏", "smirk", false),
+    ("😐", "neutral face", false),
+    ("😑", "expressionless", false),
+    ("😒", "unamused", false),
+    ("😔", "pensive", false),
+    ("😕", "confused", false),
+    ("😮", "open mouth", false),
+    ("😲", "astonished", false),
+    ("😳", "flushed", false),
+    ("😢", "cry", false),
+    ("😭", "sob", false),
+    ("😱", "scream", false),
+    ("😡", "rage", false),
+    ("😠", "angry", false),
+    ("😴", "sleeping", false),
+    ("😷", "mask", false),
+    ("😈", "smiling imp", false),
+    ("💀", "skull", false),
+    ("💩", "poop", false),
+    ("👻", "ghost", false),
+    ("👽", "alien", false),
+    ("🤖", "robot", false),
+];
+
+const PEOPLE: &[(&str, &str, bool)] = &[
+    ("👋", "wave", true),
+    ("✋", "raised hand", true),
+    ("👌", "ok hand", true),
+    ("✌", "victory hand", true),
+    ("👈", "point left", true),
+    ("👉", "point right", true),
+    ("👆", "point up", true),
+    ("👇", "point down", true),
+    ("👍", "thumbs up", true),
+    ("👎", "thumbs down", true),
+    ("✊", "raised fist", true),
+    ("👊", "punch", true),
+    ("👏", "clap", true),
+    ("🙌", "raised hands", true),
+    ("🙏", "folded hands", true),
+    ("💪", "flexed biceps", true),
+    ("👀", "eyes", false),
+    ("👂", "ear", true),
+    ("👃", "nose", true),
+    ("👶", "baby", true),
+    ("👦", "boy", true),
+    ("👧", "girl", true),
+    ("👨", "man", true),
+    ("👩", "woman", true),
+    ("👴", "old man", true),
+    ("👵", "old woman", true),
+    ("👮", "police officer", true),
+    ("👷", "construction worker", true),
+    ("💁", "person tipping hand", true),
+    ("🙋", "person raising hand", true),
+    ("🏃", "person running", true),
+    ("💃", "woman dancing", true),
+];
+
+const NATURE: &[(&str, &str, bool)] = &[
+    ("🐶", "dog", false),
+    ("🐱", "cat", false),
+    ("🐭", "mouse", false),
+    ("🐰", "rabbit", false),
+    ("🐻", "bear", false),
+    ("🐼", "panda", false),
+    ("🐨", "koala", false),
+    ("🐯", "tiger", false),
+    ("🐮", "cow", false),
+    ("🐷", "pig", false),
+    ("🐸", "frog", false),
+    ("🐵", "monkey", false),
+    ("🐔", "chicken", false),
+    ("🐧", "penguin", false),
+    ("🐦", "bird", false),
+    ("🐢", "turtle", false),
+    ("🐍", "snake", false),
+    ("🐙", "octopus", false),
+    ("🐟", "fish", false),
+    ("🐳", "whale", false),
+    ("🐝", "bee", false),
+    ("🐛", "bug", false),
+    ("🌵", "cactus", false),
+    ("🌲", "evergreen tree", false),
+    ("🌴", "palm tree", false),
+    ("🍀", "four leaf clover", false),
+    ("🍁", "maple leaf", false),
+    ("🌸", "cherry blossom", false),
+    ("🌹", "rose", false),
+    ("🌻", "sunflower", false),
+    ("🌞", "sun with face", false),
+    ("🌙", "crescent moon", false),
+    ("⭐", "star", false),
+    ("⚡", "high voltage", false),
+    ("🔥", "fire", false),
+    ("🌈", "rainbow", false),
+    ("❄", "snowflake", false),
+];
+
+const FOOD: &[(&str, &str, bool)] = &[
+    ("🍏", "green apple", false),
+    ("🍎", "red apple", false),
+    ("🍐", "pear", false),
+    ("🍊", "tangerine", false),
+    ("🍋", "lemon", false),
+    ("🍌", "banana", false),
+    ("🍉", "watermelon", false),
+    ("🍇", "grapes", false),
+    ("🍓", "strawberry", false),
+    ("🍒", "cherries", false),
+    ("🍑", "peach", false),
+    ("🍍", "pineapple", false),
+    ("🍅", "tomato", false),
+    ("🍆", "eggplant", false),
+    ("🌽", "corn", false),
+    ("🍞", "bread", false),
+    ("🧀", "cheese", false),
+    ("🍔", "hamburger", false),
+    ("🍟", "fries", false),
+    ("🍕", "pizza", false),
+    ("🍝", "spaghetti", false),
+    ("🍣", "sushi", false),
+    ("🍦", "ice cream", false),
+    ("🍩", "doughnut", false),
+    ("🍪", "cookie", false),
+    ("🎂", "birthday cake", false),
+    ("🍫", "chocolate bar", false),
+    ("☕", "coffee", false),
+    ("🍵", "tea", false),
+    ("🍺", "beer", false),
+    ("🍷", "wine glass", false),
+];
+
+const ACTIVITIES: &[(&str, &str, bool)] = &[
+    ("⚽", "soccer ball", false),
+    ("🏀", "basketball", false),
+    ("🏈", "american football", false),
+    ("⚾", "baseball", false),
+    ("🎾", "tennis", false),
+    ("🏐", "volleyball", false),
+    ("🎱", "pool 8 ball", false),
+    ("🏓", "ping pong", false),
+    ("⛳", "golf", false),
+    ("🎣", "fishing", false),
+    ("🎿", "skis", false),
+    ("🏆", "trophy", false),
+    ("🏅", "medal", false),
+    ("🎯", "direct hit", false),
+    ("🎮", "video game", false),
+    ("🎲", "game die", false),
+    ("🧩", "puzzle piece", false),
+    ("🎨", "artist palette", false),
+    ("🎬", "clapper board", false),
+    ("🎤", "microphone", false),
+    ("🎧", "headphone", false),
+    ("🎸", "guitar", false),
+    ("🎹", "musical keyboard", false),
+    ("🎺", "trumpet", false),
+    ("🎻", "violin", false),
+    ("🎉", "party popper", false),
+    ("🎁", "gift", false),
+];
+
+const TRAVEL: &[(&str, &str, bool)] = &[
+    ("🚗", "car", false),
+    ("🚕", "taxi", false),
+    ("🚌", "bus", false),
+    ("🚓", "police car", false),
+    ("🚑", "ambulance", false),
+    ("🚒", "fire engine", false),
+    ("🚚", "truck", false),
+    ("🚲", "bicycle", false),
+    ("🚂", "locomotive", false),
+    ("🚄", "high-speed train", false),
+    ("✈", "airplane", false),
+    ("🚀", "rocket", false),
+    ("🚁", "helicopter", false),
+    ("⛵", "sailboat", false),
+    ("🚢", "ship", false),
+    ("⚓", "anchor", false),
+    ("🏠", "house", false),
+    ("🏢", "office building", false),
+    ("🏥", "hospital", false),
+    ("🏫", "school", false),
+    ("🏰", "castle", false),
+    ("⛪", "church", false),
+    ("🗻", "mount fuji", false),
+    ("🌋", "volcano", false),
+    ("🏖", "beach", false),
+    ("🌍", "globe", false),
+    ("🗺", "world map", false),
+];
+
+const OBJECTS: &[(&str, &str, bool)] = &[
+    ("⌚", "watch", false),
+    ("📱", "mobile phone", false),
+    ("💻", "laptop", false),
+    ("⌨", "keyboard", false),
+    ("🖥", "desktop computer", false),
+    ("🖨", "printer", false),
+    ("🖱", "computer mouse", false),
+    ("💾", "floppy disk", false),
+    ("📷", "camera", false),
+    ("📺", "television", false),
+    ("⏰", "alarm clock", false),
+    ("🔋", "battery", false),
+    ("🔌", "electric plug", false),
+    ("💡", "light bulb", false),
+    ("🔦", "flashlight", false),
+    ("💰", "money bag", false),
+    ("💳", "credit card", false),
+    ("🔧", "wrench", false),
+    ("🔨", "hammer", false),
+    ("🔩", "nut and bolt", false),
+    ("⚙", "gear", false),
+    ("🔑", "key", false),
+    ("🔒", "locked", false),
+    ("🔓", "unlocked", false),
+    ("✏", "pencil", false),
+    ("📎", "paperclip", false),
+    ("📌", "pushpin", false),
+    ("📅", "calendar", false),
+    ("📚", "books", false),
+    ("📦", "package", false),
+    ("✉", "envelope", false),
+    ("🔔", "bell", false),
+];
+
+const SYMBOLS: &[(&str, &str, bool)] = &[
+    ("❤", "red heart", false),
+    ("💛", "yellow heart", false),
+    ("💚", "green heart", false),
+    ("💙", "blue heart", false),
+    ("💜", "purple heart", false),
+    ("💔", "broken heart", false),
+    ("💯", "hundred points", false),
+    ("✔", "check mark", false),
+    ("✅", "check mark button", false),
+    ("❌", "cross mark", false),
+    ("❓", "question mark", false),
+    ("❗", "exclamation mark", false),
+    ("⚠", "warning", false),
+    ("⛔", "no entry", false),
+    ("🚫", "prohibited", false),
+    ("♻", "recycling", false),
+    ("➕", "plus", false),
+    ("➖", "minus", false),
+    ("➗", "divide", false),
+    ("✖", "multiply", false),
+    ("➡", "right arrow", false),
+    ("⬅", "left arrow", false),
+    ("⬆", "up arrow", false),
+    ("⬇", "down arrow", false),
+    ("🔄", "counterclockwise arrows", false),
+    ("🎵", "musical note", false),
+    ("💤", "zzz", false),
+    ("💬", "speech balloon", false),
+    ("💭", "thought balloon", false),
+    ("🔴", "red circle", false),
+    ("🔵", "blue circle", false),
+];
+
+/// Persisted between sessions.
+#[derive(Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+struct EmojiPickerMemory {
+    /// Most recent first.
+    recent: Vec<String>,
+    skin_tone: SkinTone,
+}
+
+impl EmojiPickerMemory {
+    fn load(ctx: &Context, id: Id) -> Self {
+        ctx.data_mut(|d| d.get_persisted(id)).unwrap_or_default()
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+
+    /// Remember a picked emoji, and return it with the current skin tone applied.
+    fn pick(ctx: &Context, id: Id, emoji: &str, max_recent: usize) -> String {
+        let mut memory = Self::load(ctx, id);
+        memory.recent.retain(|recent| recent != emoji);
+        memory.recent.insert(0, emoji.to_owned());
+        memory.recent.truncate(max_recent);
+
+        let picked = memory.skin_tone.apply_if_supported(emoji);
+        memory.store(ctx, id);
+        picked
+    }
+}
+
+/// Not persisted.
+#[derive(Clone, Default)]
+struct EmojiPickerState {
+    search: String,
+
+    /// `None` means the "recently used" tab.
+    category: Option<Category>,
+}
+
+/// A grid of emojis to pick from, with category tabs, search, recently used emojis and skin tones.
+///
+/// The recently used emojis and the chosen skin tone are persisted in [`egui::Memory`].
+///
+/// It can be shown on its own, e.g. in a popup:
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut message = String::new();
+/// if let Some(emoji) = egui_extras::EmojiPicker::new("emoji_picker").show(ui).inner {
+///     message.push_str(&emoji);
+/// }
+/// # });
+/// ```
+///
+/// …or pick emojis with `:` in a text field, using [`Self::trigger`]:
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut message = String::new();
+/// let emoji_trigger = egui_extras::EmojiPicker::new("emoji_picker").trigger(ui.ctx(), ':');
+/// egui::TriggerPopup::new()
+///     .trigger(emoji_trigger)
+///     .show(ui, egui::TextEdit::multiline(&mut message));
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct EmojiPicker {
+    id: Id,
+    columns: usize,
+    emoji_size: f32,
+    max_height: f32,
+    max_recent: usize,
+}
+
+impl EmojiPicker {
+    /// Pickers with the same `id_source` share the recently used emojis and skin tone.
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            columns: 8,
+            emoji_size: 20.0,
+            max_height: 200.0,
+            max_recent: 24,
+        }
+    }
+
+    /// Emojis per row. Default: 8.
+    #[inline]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Font size of the emojis. Default: 20.
+    #[inline]
+    pub fn emoji_size(mut self, emoji_size: f32) -> Self {
+        self.emoji_size = emoji_size;
+        self
+    }
+
+    /// Height of the emoji grid before it scrolls. Default: 200.
+    #[inline]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// How many recently used emojis to remember. Default: 24.
+    #[inline]
+    pub fn max_recent(mut self, max_recent: usize) -> Self {
+        self.max_recent = max_recent;
+        self
+    }
+
+    /// Show the picker. Returns the picked emoji, with the chosen skin tone applied.
+    pub fn show(self, ui: &mut Ui) -> InnerResponse<Option<String>> {
+        let Self {
+            id,
+            columns,
+            emoji_size,
+            max_height,
+            max_recent,
+        } = self;
+
+        let mut state: EmojiPickerState = ui.data(|d| d.get_temp(id)).unwrap_or_default();
+        let mut memory = EmojiPickerMemory::load(ui.ctx(), id);
+        if state.category.is_none() && memory.recent.is_empty() {
+            state.category = Some(Category::Smileys);
+        }
+
+        let cell_size = Vec2::splat(emoji_size + 2.0 * ui.spacing().button_padding.y);
+        let grid_width = columns as f32 * (cell_size.x + ui.spacing().item_spacing.x);
+
+        let mut picked = None;
+        let response = ui
+            .vertical(|ui| {
+                ui.set_width(grid_width);
+
+                ui.add(
+                    TextEdit::singleline(&mut state.search)
+                        .hint_text("Search…")
+                        .desired_width(f32::INFINITY),
+                );
+
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 2.0;
+                    ui.selectable_value(&mut state.category, None, "🕓")
+                        .on_hover_text("Recently used");
+                    for category in Category::ALL {
+                        ui.selectable_value(&mut state.category, Some(category), category.icon())
+                            .on_hover_text(category.name());
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 2.0;
+                    for skin_tone in SkinTone::ALL {
+                        let swatch = RichText::new("⬤").color(skin_tone.color());
+                        ui.selectable_value(&mut memory.skin_tone, skin_tone, swatch)
+                            .on_hover_text(skin_tone.name());
+                    }
+                });
+
+                ui.separator();
+
+                let query = state.search.trim().to_lowercase();
+                let emojis: Vec<(&str, &str)> = if !query.is_empty() {
+                    search(&query)
+                        .map(|&(emoji, name, _)| (emoji, name))
+                        .collect()
+                } else if let Some(category) = state.category {
+                    category
+                        .emojis()
+                        .iter()
+                        .map(|&(emoji, name, _)| (emoji, name))
+                        .collect()
+                } else {
+                    memory
+                        .recent
+                        .iter()
+                        .map(|emoji| {
+                            let name = find_emoji(emoji).map_or("", |&(_, name, _)| name);
+                            (emoji.as_str(), name)
+                        })
+                        .collect()
+                };
+
+                ScrollArea::vertical()
+                    .id_source(id.with("grid"))
+                    .max_height(max_height)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        if emojis.is_empty() {
+                            ui.weak(if query.is_empty() {
+                                "No recently used emojis"
+                            } else {
+                                "No matching emojis"
+                            });
+                        }
+                        ui.horizontal_wrapped(|ui| {
+                            for &(emoji, name) in &emojis {
+                                let shown = memory.skin_tone.apply_if_supported(emoji);
+                                let button = Button::new(RichText::new(shown).size(emoji_size))
+                                    .frame(false)
+                                    .min_size(cell_size);
+                                if ui.add(button).on_hover_text(name).clicked() {
+                                    picked = Some(emoji.to_owned());
+                                }
+                            }
+                        });
+                    });
+            })
+            .response;
+
+        memory.store(ui.ctx(), id);
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        let picked = picked.map(|emoji| EmojiPickerMemory::pick(ui.ctx(), id, &emoji, max_recent));
+        InnerResponse::new(picked, response)
+    }
+
+    /// A [`Trigger`] for [`egui::TriggerPopup`] that suggests emojis by name,
+    /// e.g. typing `:hea` suggests "❤ red heart".
+    ///
+    /// The picked emoji replaces the trigger and the query,
+    /// and is added to the recently used emojis of this picker.
+    pub fn trigger<'a>(&self, ctx: &Context, trigger: char) -> Trigger<'a> {
+        let Self { id, max_recent, .. } = *self;
+        let ctx = ctx.clone();
+        Trigger::new(trigger, move |query| {
+            let query = query.to_lowercase();
+            if query.is_empty() {
+                return Suggestions::Ready(vec![]);
+            }
+            search(&query)
+                .map(|(emoji, name, _)| format!("{emoji} {name}"))
+                .collect::<Vec<_>>()
+                .into()
+        })
+        .replacement(move |suggestion| {
+            let emoji = suggestion.split(' ').next().unwrap_or_default();
+            EmojiPickerMemory::pick(&ctx, id, emoji, max_recent)
+        })
+    }
+}
+
+/// All emojis whose name contains the lowercase `query`, as `(emoji, name, supports skin tones)`.
+fn search(query: &str) -> impl Iterator<Item = &'static (&'static str, &'static str, bool)> + '_ {
+    Category::ALL
+        .iter()
+        .flat_map(|category| category.emojis())
+        .filter(move |(_, name, _)| name.contains(query))
+}
+
+/// `(emoji, name, supports skin tones)`
+fn find_emoji(emoji: &str) -> Option<&'static (&'static str, &'static str, bool)> {
+    Category::ALL
+        .iter()
+        .flat_map(|category| category.emojis())
+        .find(|(e, _, _)| *e == emoji)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skin_tone_is_applied_after_first_char() {
+        assert_eq!(SkinTone::Default.apply("👍"), "👍");
+        assert_eq!(SkinTone::Dark.apply("👍"), "👍\u{1F3FF}");
+        assert_eq!(SkinTone::Light.apply(""), "");
+    }
+
+    #[test]
+    fn skin_tone_only_applies_to_supported_emojis() {
+        assert_eq!(SkinTone::Medium.apply_if_supported("👋"), "👋\u{1F3FD}");
+        assert_eq!(SkinTone::Medium.apply_if_supported("👀"), "👀");
+        assert_eq!(SkinTone::Medium.apply_if_supported("🐶"), "🐶");
+        assert_eq!(
+            SkinTone::Medium.apply_if_supported("not an emoji"),
+            "not an emoji"
+        );
+    }
+
+    #[test]
+    fn search_matches_names() {
+        let hearts: Vec<&str> = search("heart").map(|&(emoji, _, _)| emoji).collect();
+        assert!(hearts.contains(&"❤"));
+        assert!(hearts.contains(&"💔"));
+        assert!(hearts.contains(&"😍"), "'heart eyes'");
+        assert!(!hearts.contains(&"🐶"));
+
+        assert_eq!(search("no such emoji").count(), 0);
+    }
+
+    #[test]
+    fn recent_list() {
+        let ctx = Context::default();
+        let id = Id::new("picker");
+        let recent = || EmojiPickerMemory::load(&ctx, id).recent;
+
+        assert_eq!(EmojiPickerMemory::pick(&ctx, id, "🐶", 3), "🐶");
+        EmojiPickerMemory::pick(&ctx, id, "🐱", 3);
+        EmojiPickerMemory::pick(&ctx, id, "🐭", 3);
+        assert_eq!(recent(), ["🐭", "🐱", "🐶"]);
+
+        // Picking again moves it to the front:
+        EmojiPickerMemory::pick(&ctx, id, "🐶", 3);
+        assert_eq!(recent(), ["🐶", "🐭", "🐱"]);
+
+        // The oldest is forgotten:
+        EmojiPickerMemory::pick(&ctx, id, "🐰", 3);
+        assert_eq!(recent(), ["🐰", "🐶", "🐭"]);
+    }
+
+    #[test]
+    fn pick_applies_skin_tone_but_remembers_plain_emoji() {
+        let ctx = Context::default();
+        let id = Id::new("picker");
+        let mut memory = EmojiPickerMemory::load(&ctx, id);
+        memory.skin_tone = SkinTone::Dark;
+        memory.store(&ctx, id);
+
+        assert_eq!(EmojiPickerMemory::pick(&ctx, id, "👍", 8), "👍\u{1F3FF}");
+        assert_eq!(EmojiPickerMemory::load(&ctx, id).recent, ["👍"]);
+    }
+}
@@ -0,0 +1,520 @@
+use egui::{
+    emath::Rot2, load::TexturePoll, pos2, vec2, Color32, ColorImage, Id, Image, ImageOptions, Key,
+    Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Vec2, Widget,
+};
+
+/// The persisted pan/zoom/rotation of an [`ImageViewer`].
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ImageViewerState {
+    /// Screen points per image texel.
+    ///
+    /// `None` means "zoom to fit", which is recalculated each frame.
+    pub zoom: Option<f32>,
+
+    /// How far the center of the image is from the center of the view, in points.
+    pub pan: Vec2,
+
+    /// Clockwise rotation of the image, in radians.
+    pub rotation: f32,
+}
+
+impl Default for ImageViewerState {
+    fn default() -> Self {
+        Self {
+            zoom: None,
+            pan: Vec2::ZERO,
+            rotation: 0.0,
+        }
+    }
+}
+
+impl ImageViewerState {
+    pub fn load(ctx: &egui::Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    pub fn store(self, ctx: &egui::Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+
+    /// Fit the whole image in the view, and center it.
+    pub fn fit(&mut self) {
+        self.zoom = None;
+        self.pan = Vec2::ZERO;
+    }
+
+    /// Rotate the image by a number of 90° steps (positive is clockwise).
+    pub fn rotate_quarter_turns(&mut self, quarter_turns: i32) {
+        let quarter = std::f32::consts::FRAC_PI_2;
+        self.rotation =
+            (self.rotation + quarter_turns as f32 * quarter).rem_euclid(std::f32::consts::TAU);
+    }
+}
+
+/// What [`ImageViewer::show`] returns.
+pub struct ImageViewerResponse {
+    /// The response of the whole view.
+    pub response: Response,
+
+    /// The state after this frame.
+    pub state: ImageViewerState,
+
+    /// The texel coordinate under the mouse pointer, if any.
+    ///
+    /// `(0, 0)` is the top left corner of the image.
+    pub hovered_texel: Option<[usize; 2]>,
+}
+
+/// A view of an image that can be zoomed, panned and rotated.
+///
+/// * Scroll (or pinch) to zoom around the mouse cursor
+/// * Drag to pan
+/// * Double-click to toggle between "fit" and 100%
+/// * `R` / `Shift+R` rotates the hovered image 90° clockwise / counter-clockwise
+///
+/// At high zoom levels a pixel grid is shown, and hovering a texel shows its
+/// coordinate, and its color if [`Self::pixels`] was given.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.add(egui_extras::ImageViewer::new(
+///     "viewer",
+///     egui::Image::new(egui::include_image!("../../egui/assets/ferris.png"))
+///         .texture_options(egui::TextureOptions::NEAREST),
+/// ));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ImageViewer<'a> {
+    id_source: Id,
+    image: Image<'a>,
+    pixels: Option<&'a ColorImage>,
+    size: Option<Vec2>,
+    min_zoom: f32,
+    max_zoom: f32,
+    pixel_grid_zoom: Option<f32>,
+    rotate_with_keys: bool,
+}
+
+impl<'a> ImageViewer<'a> {
+    pub fn new(id_source: impl std::hash::Hash, image: Image<'a>) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            image,
+            pixels: None,
+            size: None,
+            min_zoom: 0.01,
+            max_zoom: 256.0,
+            pixel_grid_zoom: Some(12.0),
+            rotate_with_keys: true,
+        }
+    }
+
+    /// The CPU-side pixels of the image, used for showing the color of the hovered texel.
+    ///
+    /// Must have the same size as the texture.
+    #[inline]
+    pub fn pixels(mut self, pixels: &'a ColorImage) -> Self {
+        self.pixels = Some(pixels);
+        self
+    }
+
+    /// Size of the view. Defaults to all available space.
+    #[inline]
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Limits of the zoom, in screen points per texel.
+    ///
+    /// Default: `0.01..=256.0`.
+    #[inline]
+    pub fn zoom_range(mut self, zoom_range: std::ops::RangeInclusive<f32>) -> Self {
+        self.min_zoom = *zoom_range.start();
+        self.max_zoom = *zoom_range.end();
+        self
+    }
+
+    /// Show a grid between texels, and a texel readout on hover,
+    /// when there are at least this many points per texel.
+    ///
+    /// `None` turns it off. Default: `Some(12.0)`.
+    #[inline]
+    pub fn pixel_grid_zoom(mut self, pixel_grid_zoom: Option<f32>) -> Self {
+        self.pixel_grid_zoom = pixel_grid_zoom;
+        self
+    }
+
+    /// Rotate the image with `R` and `Shift+R` while hovered.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn rotate_with_keys(mut self, rotate_with_keys: bool) -> Self {
+        self.rotate_with_keys = rotate_with_keys;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> ImageViewerResponse {
+        let Self {
+            id_source,
+            image,
+            pixels,
+            size,
+            min_zoom,
+            max_zoom,
+            pixel_grid_zoom,
+            rotate_with_keys,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let mut state = ImageViewerState::load(ui.ctx(), id).unwrap_or_default();
+
+        let size = size.unwrap_or_else(|| ui.available_size());
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+
+        let texture = match image.load_for_size(ui.ctx(), size) {
+            Ok(TexturePoll::Ready { texture }) => texture,
+            Ok(TexturePoll::Pending { .. }) => {
+                if ui.is_rect_visible(rect) {
+                    egui::Spinner::new().paint_at(
+                        ui,
+                        Rect::from_center_size(
+                            rect.center(),
+                            Vec2::splat(ui.spacing().interact_size.y),
+                        ),
+                    );
+                }
+                return ImageViewerResponse {
+                    response,
+                    state,
+                    hovered_texel: None,
+                };
+            }
+            Err(err) => {
+                let response = response.on_hover_text(err.to_string());
+                ui.painter().text(
+                    rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    "⚠",
+                    egui::TextStyle::Body.resolve(ui.style()),
+                    ui.visuals().error_fg_color,
+                );
+                return ImageViewerResponse {
+                    response,
+                    state,
+                    hovered_texel: None,
+                };
+            }
+        };
+        let texture_size = texture.size;
+
+        let fit_zoom = {
+            let (sin, cos) = state.rotation.sin_cos();
+            let rotated_size = vec2(
+                cos.abs() * texture_size.x + sin.abs() * texture_size.y,
+                sin.abs() * texture_size.x + cos.abs() * texture_size.y,
+            );
+            (rect.width() / rotated_size.x).min(rect.height() / rotated_size.y)
+        };
+        let mut zoom = state.zoom.unwrap_or(fit_zoom);
+
+        let hover_pos = response.hover_pos();
+
+        if let Some(hover_pos) = hover_pos {
+            let (scroll_delta, zoom_delta) = ui.input(|i| (i.smooth_scroll_delta, i.zoom_delta()));
+            let zoom_factor = zoom_factor(zoom_delta, scroll_delta.y);
+            if zoom_factor != 1.0 {
+                // Don't let any parent scroll area scroll:
+                ui.input_mut(|i| i.smooth_scroll_delta = Vec2::ZERO);
+
+                let new_zoom = clamped_zoom(zoom * zoom_factor, min_zoom, max_zoom);
+                zoom_around(&mut state, rect, hover_pos, zoom, new_zoom);
+                zoom = new_zoom;
+            }
+
+            if rotate_with_keys {
+                let (rotate_cw, rotate_ccw) = ui.input_mut(|i| {
+                    let shift = i.modifiers.shift;
+                    let pressed = i.key_pressed(Key::R);
+                    (pressed && !shift, pressed && shift)
+                });
+                if rotate_cw {
+                    state.rotate_quarter_turns(1);
+                }
+                if rotate_ccw {
+                    state.rotate_quarter_turns(-1);
+                }
+            }
+        }
+
+        if response.dragged() {
+            state.pan += response.drag_delta();
+            state.zoom = Some(zoom);
+        }
+
+        if response.double_clicked() {
+            if state.zoom.is_none() {
+                let actual_size = 1.0 / ui.ctx().pixels_per_point();
+                let pivot = hover_pos.unwrap_or_else(|| rect.center());
+                zoom_around(&mut state, rect, pivot, zoom, actual_size);
+                zoom = actual_size;
+            } else {
+                state.fit();
+                zoom = fit_zoom;
+            }
+        }
+
+        let rot = Rot2::from_angle(state.rotation);
+        let image_center = rect.center() + state.pan;
+        let mapping = TexelMapping {
+            image_center,
+            rot,
+            zoom,
+            texture_size,
+        };
+        let texel_to_screen = |texel: Pos2| mapping.texel_to_screen(texel);
+        let screen_to_texel = |pos: Pos2| mapping.screen_to_texel(pos);
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter_at(rect);
+            let image_rect = Rect::from_center_size(image_center, texture_size * zoom);
+            let options = ImageOptions {
+                rotation: (state.rotation != 0.0).then_some((rot, Vec2::splat(0.5))),
+                ..image.image_options().clone()
+            };
+            egui::paint_texture_at(&painter, image_rect, &options, &texture);
+
+            if pixel_grid_zoom.map_or(false, |grid_zoom| grid_zoom <= zoom) {
+                // Only draw the grid lines that are visible:
+                let texel_bounds = Rect::from_points(
+                    &[
+                        rect.left_top(),
+                        rect.right_top(),
+                        rect.left_bottom(),
+                        rect.right_bottom(),
+                    ]
+                    .map(screen_to_texel),
+                );
+                let stroke = Stroke::new(1.0, ui.visuals().weak_text_color().gamma_multiply(0.5));
+                let x_range = texel_bounds.min.x.floor().max(0.0) as usize
+                    ..=texel_bounds.max.x.ceil().min(texture_size.x) as usize;
+                let y_range = texel_bounds.min.y.floor().max(0.0) as usize
+                    ..=texel_bounds.max.y.ceil().min(texture_size.y) as usize;
+                for x in x_range.clone() {
+                    let x = x as f32;
+                    painter.add(Shape::line_segment(
+                        [
+                            texel_to_screen(pos2(x, 0.0)),
+                            texel_to_screen(pos2(x, texture_size.y)),
+                        ],
+                        stroke,
+                    ));
+                }
+                for y in y_range {
+                    let y = y as f32;
+                    painter.add(Shape::line_segment(
+                        [
+                            texel_to_screen(pos2(0.0, y)),
+                            texel_to_screen(pos2(texture_size.x, y)),
+                        ],
+                        stroke,
+                    ));
+                }
+            }
+        }
+
+        let hovered_texel = hover_pos.and_then(|pos| mapping.texel_at(pos));
+
+        let mut response = response;
+        if let Some([x, y]) = hovered_texel {
+            if pixel_grid_zoom.map_or(false, |grid_zoom| grid_zoom <= zoom) {
+                let color = pixels
+                    .filter(|pixels| x < pixels.width() && y < pixels.height())
+                    .map(|pixels| pixels[(x, y)]);
+                response = response.on_hover_ui_at_pointer(|ui| {
+                    ui.label(format!("x: {x}, y: {y}"));
+                    if let Some(color) = color {
+                        texel_color_readout(ui, color);
+                    }
+                });
+            }
+        }
+
+        state.store(ui.ctx(), id);
+
+        ImageViewerResponse {
+            response,
+            state,
+            hovered_texel,
+        }
+    }
+}
+
+impl<'a> Widget for ImageViewer<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+/// Maps between screen points and texel coordinates of the viewed image.
+#[derive(Clone, Copy, Debug)]
+struct TexelMapping {
+    /// Where the center of the image is on screen.
+    image_center: Pos2,
+    rot: Rot2,
+
+    /// Screen points per texel.
+    zoom: f32,
+    texture_size: Vec2,
+}
+
+impl TexelMapping {
+    fn texel_to_screen(&self, texel: Pos2) -> Pos2 {
+        self.image_center + self.rot * ((texel.to_vec2() - self.texture_size / 2.0) * self.zoom)
+    }
+
+    fn screen_to_texel(&self, pos: Pos2) -> Pos2 {
+        pos2(0.0, 0.0)
+            + self.rot.inverse() * (pos - self.image_center) / self.zoom
+            + self.texture_size / 2.0
+    }
+
+    /// The texel under the screen position, if it is inside the image.
+    fn texel_at(&self, pos: Pos2) -> Option<[usize; 2]> {
+        let texel = self.screen_to_texel(pos);
+        let inside = 0.0 <= texel.x
+            && texel.x < self.texture_size.x
+            && 0.0 <= texel.y
+            && texel.y < self.texture_size.y;
+        inside.then_some([texel.x as usize, texel.y as usize])
+    }
+}
+
+/// How much to zoom in from a pinch (`zoom_delta`) and a vertical scroll.
+fn zoom_factor(zoom_delta: f32, scroll_delta_y: f32) -> f32 {
+    zoom_delta * (scroll_delta_y / 200.0).exp()
+}
+
+fn clamped_zoom(zoom: f32, min_zoom: f32, max_zoom: f32) -> f32 {
+    zoom.clamp(min_zoom, max_zoom)
+}
+
+/// Change the zoom while keeping the point under `pivot` in place.
+fn zoom_around(
+    state: &mut ImageViewerState,
+    rect: Rect,
+    pivot: Pos2,
+    old_zoom: f32,
+    new_zoom: f32,
+) {
+    let pivot_from_center = pivot - rect.center();
+    state.pan = pivot_from_center - (pivot_from_center - state.pan) * (new_zoom / old_zoom);
+    state.zoom = Some(new_zoom);
+}
+
+fn texel_color_readout(ui: &mut Ui, color: Color32) {
+    ui.horizontal(|ui| {
+        let (swatch_rect, _) =
+            ui.allocate_exact_size(Vec2::splat(ui.spacing().interact_size.y), Sense::hover());
+        ui.painter().rect_filled(swatch_rect, 2.0, color);
+        let [r, g, b, a] = color.to_srgba_unmultiplied();
+        ui.label(format!("rgba({r}, {g}, {b}, {a})"));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping(rotation: f32, zoom: f32) -> TexelMapping {
+        TexelMapping {
+            image_center: pos2(100.0, 100.0),
+            rot: Rot2::from_angle(rotation),
+            zoom,
+            texture_size: vec2(4.0, 2.0),
+        }
+    }
+
+    #[test]
+    fn texel_mapping_round_trips() {
+        for rotation in [0.0, std::f32::consts::FRAC_PI_2, 1.0] {
+            let mapping = mapping(rotation, 10.0);
+            let texel = pos2(1.5, 0.25);
+            let back = mapping.screen_to_texel(mapping.texel_to_screen(texel));
+            assert!((back - texel).length() < 1e-4, "{back:?} != {texel:?}");
+        }
+    }
+
+    #[test]
+    fn texel_at_unrotated() {
+        let mapping = mapping(0.0, 10.0);
+        // The image spans 80..120 in x and 90..110 in y:
+        assert_eq!(mapping.texel_at(pos2(81.0, 91.0)), Some([0, 0]));
+        assert_eq!(mapping.texel_at(pos2(119.0, 109.0)), Some([3, 1]));
+        assert_eq!(mapping.texel_at(pos2(105.0, 95.0)), Some([2, 0]));
+        assert_eq!(mapping.texel_at(pos2(79.0, 100.0)), None);
+        assert_eq!(mapping.texel_at(pos2(120.0, 100.0)), None);
+        assert_eq!(mapping.texel_at(pos2(100.0, 110.0)), None);
+    }
+
+    #[test]
+    fn texel_at_rotated_quarter_turn() {
+        // Rotated clockwise, the top left texel ends up in the top right corner:
+        let mapping = mapping(std::f32::consts::FRAC_PI_2, 10.0);
+        assert_eq!(mapping.texel_at(pos2(109.0, 81.0)), Some([0, 0]));
+        assert_eq!(mapping.texel_at(pos2(91.0, 119.0)), Some([3, 1]));
+        assert_eq!(mapping.texel_at(pos2(81.0, 100.0)), None);
+    }
+
+    #[test]
+    fn zoom_is_clamped() {
+        assert_eq!(zoom_factor(1.0, 0.0), 1.0);
+        assert!(zoom_factor(1.0, 50.0) > 1.0);
+        assert!(zoom_factor(1.0, -50.0) < 1.0);
+
+        // Scrolling in and out by the same amount gets back to the same zoom:
+        let zoom = 2.0 * zoom_factor(1.0, 50.0) * zoom_factor(1.0, -50.0);
+        assert!((zoom - 2.0).abs() < 1e-5);
+
+        assert_eq!(clamped_zoom(2.0 * zoom_factor(1.5, 0.0), 0.5, 4.0), 3.0);
+        assert_eq!(
+            clamped_zoom(2.0 * zoom_factor(1.0, 10_000.0), 0.5, 4.0),
+            4.0
+        );
+        assert_eq!(
+            clamped_zoom(2.0 * zoom_factor(1.0, -10_000.0), 0.5, 4.0),
+            0.5
+        );
+    }
+
+    #[test]
+    fn zoom_around_keeps_pivot_in_place() {
+        let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(200.0, 200.0));
+        let mut state = ImageViewerState {
+            zoom: Some(2.0),
+            pan: vec2(10.0, -5.0),
+            rotation: 0.0,
+        };
+        let pivot = pos2(150.0, 60.0);
+
+        let before = TexelMapping {
+            image_center: rect.center() + state.pan,
+            rot: Rot2::IDENTITY,
+            zoom: 2.0,
+            texture_size: vec2(64.0, 64.0),
+        };
+        let texel_under_pivot = before.screen_to_texel(pivot);
+
+        zoom_around(&mut state, rect, pivot, 2.0, 8.0);
+        assert_eq!(state.zoom, Some(8.0));
+
+        let after = TexelMapping {
+            image_center: rect.center() + state.pan,
+            zoom: 8.0,
+            ..before
+        };
+        assert!((after.screen_to_texel(pivot) - texel_under_pivot).length() < 1e-3);
+    }
+}
@@ -0,0 +1,603 @@
+use egui::{
+    emath::RectTransform, epaint::CubicBezierShape, lerp, vec2, Color32, ColorImage, Event, Key,
+    KeyboardShortcut, Modifiers, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Vec2, Widget,
+};
+
+/// A sample of a stroke in an [`Ink`] drawing.
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct InkPoint {
+    /// Position relative to the top left corner of the canvas, in points.
+    pub pos: Pos2,
+
+    /// How hard the pen was pressed, in `0.0..=1.0`.
+    ///
+    /// This is `1.0` for devices without pressure sensitivity, e.g. a mouse.
+    pub pressure: f32,
+}
+
+/// One continuous stroke of an [`Ink`] drawing.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct InkStroke {
+    pub points: Vec<InkPoint>,
+    pub color: Color32,
+
+    /// Width at full pressure, in points.
+    pub width: f32,
+}
+
+impl InkStroke {
+    /// The stroke smoothed into cubic bezier segments (`[start, control, control, end]`),
+    /// relative to the top left corner of the canvas.
+    ///
+    /// The curve passes through all the sampled points (Catmull-Rom spline).
+    pub fn bezier_segments(&self) -> Vec<[Pos2; 4]> {
+        let points = &self.points;
+        if points.len() < 2 {
+            return vec![];
+        }
+        let at = |i: usize| points[i.min(points.len() - 1)].pos;
+        (0..points.len() - 1)
+            .map(|i| {
+                let prev = at(i.saturating_sub(1));
+                let start = at(i);
+                let end = at(i + 1);
+                let next = at(i + 2);
+                [
+                    start,
+                    start + (end - prev) / 6.0,
+                    end - (next - start) / 6.0,
+                    end,
+                ]
+            })
+            .collect()
+    }
+
+    /// The shapes to paint this stroke, using `to_screen` to go from canvas to screen coordinates.
+    pub fn shapes(&self, to_screen: &RectTransform) -> Vec<Shape> {
+        let scale = to_screen.scale().x;
+        match self.points.as_slice() {
+            [] => vec![],
+            [point] => vec![Shape::circle_filled(
+                to_screen * point.pos,
+                0.5 * self.width * point.pressure * scale,
+                self.color,
+            )],
+            points => self
+                .bezier_segments()
+                .into_iter()
+                .zip(points.windows(2))
+                .map(|(segment, ends)| {
+                    let pressure = 0.5 * (ends[0].pressure + ends[1].pressure);
+                    Shape::CubicBezier(CubicBezierShape::from_points_stroke(
+                        segment.map(|p| to_screen * p),
+                        false,
+                        Color32::TRANSPARENT,
+                        Stroke::new(self.width * pressure * scale, self.color),
+                    ))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A freehand drawing made with an [`InkCanvas`].
+///
+/// Can be serialized to save or export the strokes.
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Ink {
+    pub strokes: Vec<InkStroke>,
+
+    /// Strokes that were undone, most recently undone last.
+    #[serde(skip)]
+    redo_stack: Vec<InkStroke>,
+}
+
+impl Ink {
+    pub fn is_empty(&self) -> bool {
+        self.strokes.is_empty()
+    }
+
+    /// Remove all strokes. This can not be undone.
+    pub fn clear(&mut self) {
+        self.strokes.clear();
+        self.redo_stack.clear();
+    }
+
+    /// Add a finished stroke. This clears the redo stack.
+    pub fn push(&mut self, stroke: InkStroke) {
+        self.strokes.push(stroke);
+        self.redo_stack.clear();
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.strokes.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Remove the last stroke. Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        if let Some(stroke) = self.strokes.pop() {
+            self.redo_stack.push(stroke);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Bring back the last undone stroke. Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        if let Some(stroke) = self.redo_stack.pop() {
+            self.strokes.push(stroke);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// All strokes as smoothed cubic bezier paths, relative to the top left corner of the canvas.
+    ///
+    /// See [`InkStroke::bezier_segments`].
+    pub fn bezier_paths(&self) -> Vec<Vec<[Pos2; 4]>> {
+        self.strokes
+            .iter()
+            .map(InkStroke::bezier_segments)
+            .collect()
+    }
+
+    /// The smallest rectangle containing all the sampled points, if any.
+    pub fn bounding_rect(&self) -> Option<Rect> {
+        let mut points = self.strokes.iter().flat_map(|s| s.points.iter());
+        let first = points.next()?;
+        Some(
+            points.fold(Rect::from_min_max(first.pos, first.pos), |rect, p| {
+                rect.union(Rect::from_min_max(p.pos, p.pos))
+            }),
+        )
+    }
+
+    /// Paint all strokes, using `to_screen` to go from canvas to screen coordinates.
+    pub fn paint(&self, painter: &egui::Painter, to_screen: &RectTransform) {
+        for stroke in &self.strokes {
+            painter.extend(stroke.shapes(to_screen));
+        }
+    }
+
+    /// Rasterize the drawing on the CPU.
+    ///
+    /// `canvas_size` is the size of the canvas in points,
+    /// and `pixels_per_point` decides the resolution of the resulting image.
+    pub fn to_color_image(
+        &self,
+        canvas_size: Vec2,
+        pixels_per_point: f32,
+        background: Color32,
+    ) -> ColorImage {
+        let width = (canvas_size.x * pixels_per_point).round().max(1.0) as usize;
+        let height = (canvas_size.y * pixels_per_point).round().max(1.0) as usize;
+        let mut image = ColorImage::new([width, height], background);
+        let to_pixels = RectTransform::from_to(
+            Rect::from_min_size(Pos2::ZERO, canvas_size),
+            Rect::from_min_size(Pos2::ZERO, vec2(width as f32, height as f32)),
+        );
+
+        for stroke in &self.strokes {
+            // Use the max coverage within a stroke, so overlapping segments don't darken the joints:
+            let mut coverage = vec![0.0_f32; width * height];
+            for shape in stroke.shapes(&to_pixels) {
+                match shape {
+                    Shape::Circle(circle) => {
+                        rasterize_segment(
+                            &mut coverage,
+                            [width, height],
+                            [circle.center, circle.center],
+                            2.0 * circle.radius,
+                        );
+                    }
+                    Shape::CubicBezier(bezier) => {
+                        for line in bezier.flatten(Some(0.1)).windows(2) {
+                            rasterize_segment(
+                                &mut coverage,
+                                [width, height],
+                                [line[0], line[1]],
+                                bezier.stroke.width,
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            for (pixel, coverage) in image.pixels.iter_mut().zip(coverage) {
+                if coverage > 0.0 {
+                    let src = stroke.color.gamma_multiply(coverage);
+                    let dst = *pixel;
+                    let blend = |s: u8, d: u8| {
+                        s.saturating_add(((d as u16 * (255 - src.a()) as u16) / 255) as u8)
+                    };
+                    *pixel = Color32::from_rgba_premultiplied(
+                        blend(src.r(), dst.r()),
+                        blend(src.g(), dst.g()),
+                        blend(src.b(), dst.b()),
+                        blend(src.a(), dst.a()),
+                    );
+                }
+            }
+        }
+
+        image
+    }
+}
+
+/// Set the anti-aliased coverage of a thick line segment, keeping the max coverage of each pixel.
+fn rasterize_segment(
+    coverage: &mut [f32],
+    [width, height]: [usize; 2],
+    [a, b]: [Pos2; 2],
+    thickness: f32,
+) {
+    let radius = 0.5 * thickness.max(1.0);
+    let bounds = Rect::from_two_pos(a, b).expand(radius + 1.0);
+    let x_range =
+        bounds.min.x.floor().max(0.0) as usize..(bounds.max.x.ceil().max(0.0) as usize).min(width);
+    let y_range =
+        bounds.min.y.floor().max(0.0) as usize..(bounds.max.y.ceil().max(0.0) as usize).min(height);
+
+    let ab = b - a;
+    let length_sq = ab.length_sq();
+    for y in y_range {
+        for x in x_range.clone() {
+            let p = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
+            let t = if length_sq > 0.0 {
+                ((p - a).dot(ab) / length_sq).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let distance = p.distance(a + t * ab);
+            let pixel_coverage = (radius - distance + 0.5).clamp(0.0, 1.0);
+            let c = &mut coverage[y * width + x];
+            *c = c.max(pixel_coverage);
+        }
+    }
+}
+
+/// A canvas for freehand drawing with the mouse, a finger or a stylus.
+///
+/// Pointer samples are smoothed and painted as bezier curves.
+/// If the device reports pressure (see [`egui::Event::Touch`]), the stroke width follows it.
+///
+/// Use `Ctrl+Z` / `Ctrl+Shift+Z` (`Cmd` on Mac) while hovering the canvas to undo/redo strokes,
+/// or call [`Ink::undo`] and [`Ink::redo`] directly.
+///
+/// The returned [`Response`] is marked as changed when a stroke is finished or undone.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut ink = egui_extras::Ink::default();
+/// ui.add(egui_extras::InkCanvas::new(&mut ink).size([300.0, 200.0]));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct InkCanvas<'a> {
+    ink: &'a mut Ink,
+    stroke: Stroke,
+    size: Option<Vec2>,
+    smoothing: f32,
+    min_distance: f32,
+    undo_shortcuts: bool,
+}
+
+impl<'a> InkCanvas<'a> {
+    pub fn new(ink: &'a mut Ink) -> Self {
+        Self {
+            ink,
+            stroke: Stroke::new(2.0, Color32::from_gray(200)),
+            size: None,
+            smoothing: 0.5,
+            min_distance: 1.0,
+            undo_shortcuts: true,
+        }
+    }
+
+    /// Color and width (at full pressure) of new strokes.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Size of the canvas. Defaults to all available space.
+    #[inline]
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// How much to smooth the pointer samples, in `0.0..1.0`.
+    ///
+    /// `0.0` uses the raw samples. Default: `0.5`.
+    #[inline]
+    pub fn smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = smoothing.clamp(0.0, 0.99);
+        self
+    }
+
+    /// Skip samples closer than this to the previous one, in points.
+    ///
+    /// Default: `1.0`.
+    #[inline]
+    pub fn min_distance(mut self, min_distance: f32) -> Self {
+        self.min_distance = min_distance;
+        self
+    }
+
+    /// Undo/redo with the standard keyboard shortcuts while hovered.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn undo_shortcuts(mut self, undo_shortcuts: bool) -> Self {
+        self.undo_shortcuts = undo_shortcuts;
+        self
+    }
+}
+
+impl<'a> Widget for InkCanvas<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            ink,
+            stroke,
+            size,
+            smoothing,
+            min_distance,
+            undo_shortcuts,
+        } = self;
+
+        let size = size.unwrap_or_else(|| ui.available_size_before_wrap());
+        let (mut response, painter) = ui.allocate_painter(size, Sense::drag());
+        let id = response.id;
+
+        let to_screen = RectTransform::from_to(
+            Rect::from_min_size(Pos2::ZERO, response.rect.size()),
+            response.rect,
+        );
+        let from_screen = to_screen.inverse();
+
+        let raw_point = response.interact_pointer_pos().map(|pointer_pos| {
+            let pressure = ui.input(|i| {
+                i.events.iter().rev().find_map(|event| match event {
+                    Event::Touch { force, .. } => *force,
+                    _ => None,
+                })
+            });
+            InkPoint {
+                pos: from_screen * pointer_pos,
+                pressure: pressure.unwrap_or(1.0).clamp(0.0, 1.0),
+            }
+        });
+
+        // The stroke being drawn can get long, so update it in place instead of copying it every frame:
+        let (finished, current_shapes) = ui.data_mut(|d| {
+            let current = d.get_temp_mut_or_default::<Option<InkStroke>>(id);
+
+            if response.drag_started() {
+                *current = Some(InkStroke {
+                    points: vec![],
+                    color: stroke.color,
+                    width: stroke.width,
+                });
+            }
+
+            if let (Some(current), Some(raw)) = (current.as_mut(), raw_point) {
+                let point = match current.points.last() {
+                    Some(last) => InkPoint {
+                        pos: last.pos.lerp(raw.pos, 1.0 - smoothing),
+                        pressure: lerp(last.pressure..=raw.pressure, 1.0 - smoothing),
+                    },
+                    None => raw,
+                };
+                let is_new = current
+                    .points
+                    .last()
+                    .map_or(true, |last| last.pos.distance(point.pos) >= min_distance);
+                if is_new {
+                    current.points.push(point);
+                }
+            }
+
+            if response.drag_stopped() {
+                (current.take(), None)
+            } else {
+                (
+                    None,
+                    current.as_ref().map(|current| current.shapes(&to_screen)),
+                )
+            }
+        });
+        let is_drawing = current_shapes.is_some();
+
+        if let Some(finished) = finished {
+            if !finished.points.is_empty() {
+                ink.push(finished);
+                response.mark_changed();
+            }
+        }
+
+        if undo_shortcuts && response.hovered() && !is_drawing {
+            let (undo, redo) = ui.input_mut(|i| {
+                let redo = i.consume_shortcut(&KeyboardShortcut::new(
+                    Modifiers::COMMAND | Modifiers::SHIFT,
+                    Key::Z,
+                ));
+                let undo = i.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::Z));
+                (undo, redo)
+            });
+            if (undo && ink.undo()) || (redo && ink.redo()) {
+                response.mark_changed();
+            }
+        }
+
+        if ui.is_rect_visible(response.rect) {
+            ink.paint(&painter, &to_screen);
+            if let Some(current_shapes) = current_shapes {
+                painter.extend(current_shapes);
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::pos2;
+
+    fn stroke(points: &[Pos2]) -> InkStroke {
+        InkStroke {
+            points: points
+                .iter()
+                .map(|&pos| InkPoint { pos, pressure: 1.0 })
+                .collect(),
+            color: Color32::WHITE,
+            width: 2.0,
+        }
+    }
+
+    #[test]
+    fn bezier_segments_need_two_points() {
+        assert!(stroke(&[]).bezier_segments().is_empty());
+        assert!(stroke(&[pos2(1.0, 2.0)]).bezier_segments().is_empty());
+    }
+
+    #[test]
+    fn bezier_segments_pass_through_points() {
+        let points = [
+            pos2(0.0, 0.0),
+            pos2(10.0, 5.0),
+            pos2(20.0, 0.0),
+            pos2(30.0, 5.0),
+        ];
+        let segments = stroke(&points).bezier_segments();
+        assert_eq!(segments.len(), points.len() - 1);
+        for (segment, ends) in segments.iter().zip(points.windows(2)) {
+            assert_eq!(segment[0], ends[0]);
+            assert_eq!(segment[3], ends[1]);
+        }
+    }
+
+    #[test]
+    fn bezier_segments_of_straight_line_are_straight() {
+        let segments = stroke(&[pos2(0.0, 0.0), pos2(6.0, 0.0), pos2(12.0, 0.0)]).bezier_segments();
+        // The tangent at the middle point is along the line, so the controls are at 1/3 and 2/3:
+        assert_eq!(segments[0][2], pos2(4.0, 0.0));
+        assert_eq!(segments[1][1], pos2(8.0, 0.0));
+        for segment in &segments {
+            assert!(segment.iter().all(|p| p.y == 0.0));
+        }
+    }
+
+    #[test]
+    fn undo_redo() {
+        let mut ink = Ink::default();
+        assert!(!ink.can_undo());
+        assert!(!ink.undo());
+        assert!(!ink.redo());
+
+        ink.push(stroke(&[pos2(0.0, 0.0)]));
+        ink.push(stroke(&[pos2(1.0, 1.0)]));
+        assert!(ink.undo());
+        assert_eq!(ink.strokes.len(), 1);
+        assert!(ink.can_redo());
+
+        assert!(ink.redo());
+        assert_eq!(ink.strokes.len(), 2);
+        assert_eq!(ink.strokes[1], stroke(&[pos2(1.0, 1.0)]));
+        assert!(!ink.can_redo());
+
+        // A new stroke clears the redo stack:
+        assert!(ink.undo());
+        ink.push(stroke(&[pos2(2.0, 2.0)]));
+        assert!(!ink.can_redo());
+        assert!(!ink.redo());
+
+        ink.clear();
+        assert!(ink.is_empty());
+        assert!(!ink.can_undo());
+        assert!(!ink.can_redo());
+    }
+
+    #[test]
+    fn rasterize_horizontal_segment() {
+        let size = [10, 10];
+        let mut coverage = vec![0.0; 100];
+        rasterize_segment(&mut coverage, size, [pos2(3.0, 5.0), pos2(7.0, 5.0)], 3.0);
+        let at = |x: usize, y: usize| coverage[y * 10 + x];
+
+        // Pixel centers at y=4.5 and y=5.5 are within the line:
+        assert_eq!(at(5, 4), 1.0);
+        assert_eq!(at(5, 5), 1.0);
+        // Anti-aliased edge, where the pixel center is right at the edge:
+        assert_eq!(at(5, 3), 0.5);
+        assert_eq!(at(5, 6), 0.5);
+        // Far away:
+        assert_eq!(at(5, 2), 0.0);
+        assert_eq!(at(5, 9), 0.0);
+        // Round caps don't reach far past the ends:
+        assert_eq!(at(0, 5), 0.0);
+    }
+
+    #[test]
+    fn rasterize_keeps_max_coverage_and_clips() {
+        let size = [4, 4];
+        let mut coverage = vec![0.0; 16];
+        // A dot at the center of pixel (2, 2):
+        let dot = [pos2(2.5, 2.5), pos2(2.5, 2.5)];
+        rasterize_segment(&mut coverage, size, dot, 2.0);
+        assert_eq!(coverage[2 * 4 + 2], 1.0);
+        let neighbor = coverage[2 * 4 + 1];
+        assert!(0.0 < neighbor && neighbor < 1.0);
+
+        // A thinner overlapping segment doesn't reduce the coverage:
+        rasterize_segment(&mut coverage, size, dot, 0.1);
+        assert_eq!(coverage[2 * 4 + 2], 1.0);
+        assert_eq!(coverage[2 * 4 + 1], neighbor);
+
+        // Segments outside the image are clipped, not panicking:
+        rasterize_segment(
+            &mut coverage,
+            size,
+            [pos2(-10.0, -10.0), pos2(20.0, 20.0)],
+            3.0,
+        );
+        rasterize_segment(
+            &mut coverage,
+            size,
+            [pos2(50.0, 50.0), pos2(60.0, 60.0)],
+            3.0,
+        );
+    }
+
+    #[test]
+    fn to_color_image_size_and_pixels() {
+        let background = Color32::BLACK;
+        let image = Ink::default().to_color_image(vec2(10.0, 5.0), 2.0, background);
+        assert_eq!(image.size, [20, 10]);
+        assert!(image.pixels.iter().all(|&p| p == background));
+
+        let mut ink = Ink::default();
+        ink.push(InkStroke {
+            color: Color32::RED,
+            ..stroke(&[pos2(1.0, 2.5), pos2(9.0, 2.5)])
+        });
+        let image = ink.to_color_image(vec2(10.0, 5.0), 2.0, background);
+        // On the stroke:
+        assert_eq!(image[(10, 4)], Color32::RED);
+        assert_eq!(image[(10, 5)], Color32::RED);
+        // Off the stroke:
+        assert_eq!(image[(10, 0)], background);
+        assert_eq!(image[(0, 9)], background);
+    }
+}
@@ -16,24 +16,34 @@ mod datepicker;
 
 pub mod syntax_highlighting;
 
+mod emoji_picker;
 #[doc(hidden)]
 pub mod image;
+mod image_viewer;
+mod ink_canvas;
 mod layout;
 mod loaders;
+mod signature_field;
 mod sizing;
 mod strip;
 mod table;
+mod tag_editor;
 
 #[cfg(feature = "chrono")]
 pub use crate::datepicker::DatePickerButton;
 
+pub use crate::emoji_picker::{EmojiPicker, SkinTone};
 #[doc(hidden)]
 #[allow(deprecated)]
 pub use crate::image::RetainedImage;
+pub use crate::image_viewer::{ImageViewer, ImageViewerResponse, ImageViewerState};
+pub use crate::ink_canvas::{Ink, InkCanvas, InkPoint, InkStroke};
 pub(crate) use crate::layout::StripLayout;
+pub use crate::signature_field::{Signature, SignatureField};
 pub use crate::sizing::Size;
 pub use crate::strip::*;
 pub use crate::table::*;
+pub use crate::tag_editor::TagEditor;
 
 pub use loaders::install_image_loaders;
 
@@ -0,0 +1,237 @@
+use egui::{Color32, ColorImage, Pos2, Response, Stroke, Ui, Vec2, Widget};
+
+use crate::{Ink, InkCanvas};
+
+/// The state of a [`SignatureField`].
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Signature {
+    /// The strokes of the signature, relative to the top left corner of the signing box.
+    pub ink: Ink,
+
+    /// Has the user pressed "Accept"?
+    ///
+    /// An accepted signature can't be drawn on until it is cleared.
+    pub accepted: bool,
+
+    /// The size of the signing box the signature was drawn in, in points.
+    pub canvas_size: Vec2,
+}
+
+impl Signature {
+    pub fn is_empty(&self) -> bool {
+        self.ink.is_empty()
+    }
+
+    /// Remove all strokes and un-accept the signature.
+    pub fn clear(&mut self) {
+        self.ink.clear();
+        self.accepted = false;
+    }
+
+    /// The signature as smoothed cubic bezier paths, relative to the top left corner of the signing box.
+    pub fn paths(&self) -> Vec<Vec<[Pos2; 4]>> {
+        self.ink.bezier_paths()
+    }
+
+    /// Rasterize the signature on a transparent background.
+    ///
+    /// `pixels_per_point` decides the resolution of the resulting image.
+    pub fn to_color_image(&self, pixels_per_point: f32) -> ColorImage {
+        self.ink
+            .to_color_image(self.canvas_size, pixels_per_point, Color32::TRANSPARENT)
+    }
+}
+
+/// A compact draw-to-sign box, with "Clear" and "Accept" buttons.
+///
+/// The returned [`Response`] is marked as changed when the signature is drawn on, cleared, or accepted.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut signature = egui_extras::Signature::default();
+/// if ui.add(egui_extras::SignatureField::new(&mut signature)).changed() && signature.accepted {
+///     let image: egui::ColorImage = signature.to_color_image(2.0);
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct SignatureField<'a> {
+    signature: &'a mut Signature,
+    size: Vec2,
+    stroke: Option<Stroke>,
+    hint_text: &'a str,
+}
+
+impl<'a> SignatureField<'a> {
+    pub fn new(signature: &'a mut Signature) -> Self {
+        Self {
+            signature,
+            size: Vec2::new(240.0, 80.0),
+            stroke: None,
+            hint_text: "Sign here",
+        }
+    }
+
+    /// Size of the signing box, excluding the buttons.
+    ///
+    /// Default: `240 x 80`. Use a smaller box for initials.
+    #[inline]
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// Pen color and width.
+    ///
+    /// Default: the text color of the current style, at width `2.0`.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// The text shown next to the signing line while empty.
+    ///
+    /// Default: "Sign here".
+    #[inline]
+    pub fn hint_text(mut self, hint_text: &'a str) -> Self {
+        self.hint_text = hint_text;
+        self
+    }
+}
+
+impl<'a> Widget for SignatureField<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            signature,
+            size,
+            stroke,
+            hint_text,
+        } = self;
+
+        let stroke = stroke.unwrap_or_else(|| Stroke::new(2.0, ui.visuals().text_color()));
+        signature.canvas_size = size;
+
+        let inner = ui.vertical(|ui| {
+            let frame = egui::Frame::canvas(ui.style()).inner_margin(0.0);
+            let mut response = frame
+                .show(ui, |ui| {
+                    ui.add_enabled(
+                        !signature.accepted,
+                        InkCanvas::new(&mut signature.ink).stroke(stroke).size(size),
+                    )
+                })
+                .inner;
+
+            let rect = response.rect;
+            let visuals = ui.visuals();
+            let line_y = rect.bottom() - 0.25 * rect.height();
+            let margin = 0.05 * rect.width();
+            ui.painter().hline(
+                rect.left() + margin..=rect.right() - margin,
+                line_y,
+                visuals.widgets.noninteractive.bg_stroke,
+            );
+            let font_id = egui::TextStyle::Small.resolve(ui.style());
+            ui.painter().text(
+                egui::pos2(rect.left() + margin, line_y),
+                egui::Align2::LEFT_BOTTOM,
+                if signature.is_empty() {
+                    format!("✕ {hint_text}")
+                } else {
+                    "✕".to_owned()
+                },
+                font_id,
+                visuals.weak_text_color(),
+            );
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(!signature.is_empty(), egui::Button::new("Clear"))
+                    .clicked()
+                {
+                    signature.clear();
+                    response.mark_changed();
+                }
+                let can_accept = !signature.is_empty() && !signature.accepted;
+                if ui
+                    .add_enabled(can_accept, egui::Button::new("Accept"))
+                    .clicked()
+                {
+                    signature.accepted = true;
+                    response.mark_changed();
+                }
+                if signature.accepted {
+                    ui.weak("✔ Accepted");
+                }
+            });
+
+            response
+        });
+
+        inner.inner | inner.response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InkPoint, InkStroke};
+
+    fn signed() -> Signature {
+        let mut signature = Signature {
+            canvas_size: Vec2::new(20.0, 10.0),
+            ..Default::default()
+        };
+        signature.ink.push(InkStroke {
+            points: [Pos2::new(2.0, 5.0), Pos2::new(18.0, 5.0)]
+                .map(|pos| InkPoint { pos, pressure: 1.0 })
+                .to_vec(),
+            color: Color32::BLACK,
+            width: 2.0,
+        });
+        signature
+    }
+
+    #[test]
+    fn clear_empties_and_unaccepts() {
+        assert!(Signature::default().is_empty());
+
+        let mut signature = signed();
+        signature.accepted = true;
+        assert!(!signature.is_empty());
+        assert_eq!(signature.paths().len(), 1);
+
+        signature.clear();
+        assert!(signature.is_empty());
+        assert!(!signature.accepted);
+        assert!(signature.paths().is_empty());
+        assert!(!signature.ink.can_redo(), "Clearing can not be undone");
+    }
+
+    #[test]
+    fn export_image() {
+        let image = Signature {
+            canvas_size: Vec2::new(20.0, 10.0),
+            ..Default::default()
+        }
+        .to_color_image(2.0);
+        assert_eq!(image.size, [40, 20]);
+        assert!(image.pixels.iter().all(|&p| p == Color32::TRANSPARENT));
+
+        let image = signed().to_color_image(1.0);
+        assert_eq!(image.size, [20, 10]);
+        assert_eq!(image[(10, 4)], Color32::BLACK);
+        assert_eq!(image[(10, 0)], Color32::TRANSPARENT);
+    }
+
+    #[test]
+    fn field_sets_canvas_size() {
+        let mut signature = Signature::default();
+        egui::__run_test_ui(|ui| {
+            ui.add(SignatureField::new(&mut signature).size([100.0, 30.0]));
+        });
+        assert_eq!(signature.canvas_size, Vec2::new(100.0, 30.0));
+    }
+}
@@ -0,0 +1,382 @@
+use egui::{AutoComplete, Frame, Id, Key, Margin, Response, RichText, TextEdit, Ui, Widget};
+
+/// What is being dragged when reordering the tags of a [`TagEditor`].
+struct TagDragPayload {
+    editor_id: Id,
+    index: usize,
+}
+
+/// A text field where each entered word becomes a removable "chip".
+///
+/// * Type and press `Enter` to add a tag
+/// * `Backspace` in an empty field removes the last tag
+/// * Click the `✕` on a chip to remove it
+/// * Drag chips to reorder them
+/// * If [`Self::suggestions`] are given, matching ones are shown in an [`AutoComplete`] popup;
+///   pick one with the arrow keys + `Enter`/`Tab`, or by clicking it
+///
+/// The returned [`Response`] is marked as changed when a tag is added, removed or moved.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut tags: Vec<String> = vec!["rust".to_owned()];
+/// ui.add(
+///     egui_extras::TagEditor::new("my_tags", &mut tags)
+///         .suggestions(["egui", "eframe", "rust", "wasm"]),
+/// );
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct TagEditor<'a> {
+    id_source: Id,
+    tags: &'a mut Vec<String>,
+    suggestions: Vec<String>,
+    max_suggestions: usize,
+    hint_text: String,
+    allow_duplicates: bool,
+    desired_width: Option<f32>,
+}
+
+impl<'a> TagEditor<'a> {
+    pub fn new(id_source: impl std::hash::Hash, tags: &'a mut Vec<String>) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            tags,
+            suggestions: vec![],
+            max_suggestions: 8,
+            hint_text: "Add tag…".to_owned(),
+            allow_duplicates: false,
+            desired_width: None,
+        }
+    }
+
+    /// Tags to suggest while typing. Tags that are already added are not suggested.
+    #[inline]
+    pub fn suggestions(mut self, suggestions: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.suggestions = suggestions.into_iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Show at most this many suggestions. Default: 8.
+    #[inline]
+    pub fn max_suggestions(mut self, max_suggestions: usize) -> Self {
+        self.max_suggestions = max_suggestions;
+        self
+    }
+
+    /// Text shown in the input part while it is empty.
+    #[inline]
+    pub fn hint_text(mut self, hint_text: impl ToString) -> Self {
+        self.hint_text = hint_text.to_string();
+        self
+    }
+
+    /// Allow the same tag to be added more than once. Default: `false`.
+    #[inline]
+    pub fn allow_duplicates(mut self, allow_duplicates: bool) -> Self {
+        self.allow_duplicates = allow_duplicates;
+        self
+    }
+
+    /// Width of the whole field. Defaults to all available width.
+    #[inline]
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+}
+
+impl<'a> Widget for TagEditor<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            id_source,
+            tags,
+            suggestions,
+            max_suggestions,
+            hint_text,
+            allow_duplicates,
+            desired_width,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let text_edit_id = id.with("text_edit");
+        let mut text: String = ui.data(|d| d.get_temp(id)).unwrap_or_default();
+
+        // We must handle the key before the `TextEdit` does:
+        let has_focus = ui.memory(|m| m.has_focus(text_edit_id));
+        let remove_last =
+            has_focus && text.is_empty() && ui.input(|i| i.key_pressed(Key::Backspace));
+
+        let mut changed = false;
+
+        let frame = Frame::none()
+            .fill(ui.visuals().extreme_bg_color)
+            .stroke(if has_focus {
+                ui.visuals().selection.stroke
+            } else {
+                ui.visuals().widgets.inactive.bg_stroke
+            })
+            .rounding(ui.visuals().widgets.inactive.rounding)
+            .inner_margin(Margin::same(4.0));
+
+        let width = desired_width.unwrap_or_else(|| ui.available_width());
+        let frame_output = frame.show(ui, |ui| {
+            ui.set_width(width - 8.0);
+            ui.horizontal_wrapped(|ui| {
+                ui.spacing_mut().item_spacing = egui::vec2(4.0, 4.0);
+
+                let mut remove = None;
+                let mut move_from_to = None;
+                for (index, tag) in tags.iter().enumerate() {
+                    let chip_id = id.with(("chip", index));
+                    let payload = TagDragPayload {
+                        editor_id: id,
+                        index,
+                    };
+                    let chip_response = ui
+                        .dnd_drag_source(chip_id, payload, |ui| {
+                            if chip_ui(ui, tag) {
+                                remove = Some(index);
+                            }
+                        })
+                        .response;
+
+                    if let Some(payload) = chip_response.dnd_hover_payload::<TagDragPayload>() {
+                        if payload.editor_id == id && payload.index != index {
+                            // Show where the chip will be dropped:
+                            let rect = chip_response.rect;
+                            let x = if payload.index < index {
+                                rect.right() + 2.0
+                            } else {
+                                rect.left() - 2.0
+                            };
+                            ui.painter()
+                                .vline(x, rect.y_range(), ui.visuals().selection.stroke);
+                        }
+                    }
+                    if let Some(payload) = chip_response.dnd_release_payload::<TagDragPayload>() {
+                        if payload.editor_id == id {
+                            move_from_to = Some((payload.index, index));
+                        }
+                    }
+                }
+
+                if let Some(index) = remove {
+                    tags.remove(index);
+                    changed = true;
+                }
+                if let Some((from, to)) = move_from_to {
+                    changed |= move_tag(tags, from, to);
+                }
+
+                let candidates = suggestions
+                    .iter()
+                    .filter(|suggestion| allow_duplicates || !tags.contains(suggestion));
+                AutoComplete::candidates(candidates)
+                    .max_suggestions(max_suggestions)
+                    .show(
+                        ui,
+                        TextEdit::singleline(&mut text)
+                            .id(text_edit_id)
+                            .frame(false)
+                            .hint_text(hint_text)
+                            .desired_width(ui.available_width().max(80.0)),
+                    )
+            })
+            .inner
+        });
+        let frame_response = frame_output.response;
+        let autocomplete = frame_output.inner;
+        let text_response = autocomplete.text_edit.response;
+
+        let entered = text_response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+        if autocomplete.accepted.is_some() || entered {
+            // `text` is the picked suggestion, or what was typed:
+            changed |= add_tag(tags, &text, allow_duplicates);
+            text.clear();
+            ui.memory_mut(|m| m.request_focus(text_edit_id));
+        }
+        if remove_last && tags.pop().is_some() {
+            changed = true;
+        }
+
+        // Clicking anywhere in the field focuses the text input:
+        if !has_focus
+            && frame_response.contains_pointer()
+            && ui.input(|i| i.pointer.primary_clicked())
+        {
+            ui.memory_mut(|m| m.request_focus(text_edit_id));
+        }
+
+        ui.data_mut(|d| d.insert_temp(id, text));
+
+        let mut response = text_response | frame_response;
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Add `tag` (trimmed) to the end of `tags`, unless it is empty or (without `allow_duplicates`) already there.
+///
+/// Returns `true` if it was added.
+fn add_tag(tags: &mut Vec<String>, tag: &str, allow_duplicates: bool) -> bool {
+    let tag = tag.trim();
+    if tag.is_empty() || (!allow_duplicates && tags.iter().any(|t| t == tag)) {
+        return false;
+    }
+    tags.push(tag.to_owned());
+    true
+}
+
+/// Move the tag at `from` so that it ends up at `to`.
+///
+/// Returns `true` if anything moved.
+fn move_tag(tags: &mut Vec<String>, from: usize, to: usize) -> bool {
+    if from == to || tags.len() <= from || tags.len() <= to {
+        return false;
+    }
+    let tag = tags.remove(from);
+    tags.insert(to, tag);
+    true
+}
+
+/// Shows a tag chip. Returns `true` if the remove button was clicked.
+fn chip_ui(ui: &mut Ui, tag: &str) -> bool {
+    let visuals = ui.visuals().widgets.inactive;
+    Frame::none()
+        .fill(visuals.weak_bg_fill)
+        .rounding(ui.visuals().widgets.inactive.rounding)
+        .inner_margin(Margin::symmetric(4.0, 1.0))
+        .show(ui, |ui| {
+            ui.spacing_mut().item_spacing.x = 2.0;
+            ui.label(RichText::new(tag).color(visuals.text_color()));
+            ui.add(egui::Button::new(RichText::new("✕").small()).frame(false))
+                .on_hover_text("Remove")
+                .clicked()
+        })
+        .inner
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(tags: &[&str]) -> Vec<String> {
+        tags.iter().map(|&tag| tag.to_owned()).collect()
+    }
+
+    #[test]
+    fn test_add_tag() {
+        let mut t = tags(&["rust"]);
+        assert!(add_tag(&mut t, " egui ", false));
+        assert_eq!(t, tags(&["rust", "egui"]));
+
+        assert!(!add_tag(&mut t, "   ", false));
+        assert_eq!(t, tags(&["rust", "egui"]));
+    }
+
+    #[test]
+    fn test_add_tag_dedupe() {
+        let mut t = tags(&["rust"]);
+        assert!(!add_tag(&mut t, "rust", false));
+        assert!(!add_tag(&mut t, " rust", false));
+        assert_eq!(t, tags(&["rust"]));
+
+        assert!(add_tag(&mut t, "rust", true));
+        assert_eq!(t, tags(&["rust", "rust"]));
+    }
+
+    #[test]
+    fn test_move_tag() {
+        let mut t = tags(&["a", "b", "c"]);
+        assert!(move_tag(&mut t, 0, 2));
+        assert_eq!(t, tags(&["b", "c", "a"]));
+        assert!(move_tag(&mut t, 2, 0));
+        assert_eq!(t, tags(&["a", "b", "c"]));
+
+        assert!(!move_tag(&mut t, 1, 1));
+        assert!(!move_tag(&mut t, 3, 0));
+        assert!(!move_tag(&mut t, 0, 3));
+        assert_eq!(t, tags(&["a", "b", "c"]));
+    }
+
+    fn run_tag_editor(
+        ctx: &egui::Context,
+        events: Vec<egui::Event>,
+        t: &mut Vec<String>,
+    ) -> Response {
+        let mut response = None;
+        let _ = ctx.run(
+            egui::RawInput {
+                events,
+                ..Default::default()
+            },
+            |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    response = Some(
+                        ui.add(TagEditor::new("tags", t).suggestions(["egui", "eframe", "rust"])),
+                    );
+                });
+            },
+        );
+        response.unwrap()
+    }
+
+    fn key(key: Key) -> egui::Event {
+        egui::Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn type_enter_and_backspace() {
+        let ctx = egui::Context::default();
+        let mut t = tags(&["rust"]);
+        let response = run_tag_editor(&ctx, vec![], &mut t);
+        ctx.memory_mut(|m| m.request_focus(response.id));
+        run_tag_editor(&ctx, vec![], &mut t);
+
+        // Add a tag:
+        run_tag_editor(&ctx, vec![egui::Event::Text("egui".to_owned())], &mut t);
+        let response = run_tag_editor(&ctx, vec![key(Key::Enter)], &mut t);
+        assert!(response.changed());
+        assert_eq!(t, tags(&["rust", "egui"]));
+
+        // Duplicates are not added:
+        run_tag_editor(&ctx, vec![egui::Event::Text("rust".to_owned())], &mut t);
+        run_tag_editor(&ctx, vec![key(Key::Enter)], &mut t);
+        assert_eq!(t, tags(&["rust", "egui"]));
+
+        // Backspace in the empty field removes the last tag:
+        let response = run_tag_editor(&ctx, vec![key(Key::Backspace)], &mut t);
+        assert!(response.changed());
+        assert_eq!(t, tags(&["rust"]));
+    }
+
+    #[test]
+    fn pick_suggestion() {
+        let ctx = egui::Context::default();
+        let mut t = tags(&["rust"]);
+        let response = run_tag_editor(&ctx, vec![], &mut t);
+        ctx.memory_mut(|m| m.request_focus(response.id));
+        run_tag_editor(&ctx, vec![], &mut t);
+
+        // Tab picks the first suggestion, once the popup is shown:
+        run_tag_editor(&ctx, vec![egui::Event::Text("fr".to_owned())], &mut t);
+        run_tag_editor(&ctx, vec![], &mut t);
+        let response = run_tag_editor(&ctx, vec![key(Key::Tab)], &mut t);
+        assert!(response.changed());
+        assert_eq!(t, tags(&["rust", "eframe"]));
+
+        // Tags that are already added are not suggested, so this adds what was typed:
+        run_tag_editor(&ctx, vec![egui::Event::Text("rus".to_owned())], &mut t);
+        run_tag_editor(&ctx, vec![key(Key::Enter)], &mut t);
+        assert_eq!(t, tags(&["rust", "eframe", "rus"]));
+    }
+}
@@ -107,18 +107,62 @@ pub struct Painter {
 /// The callback is passed, the [`egui::PaintCallbackInfo`] and the [`Painter`] which can be used to
 /// access the OpenGL context.
 ///
+/// Each frame, [`Painter::paint_primitives`] calls the hooks of all callbacks in three steps:
+/// * [`Self::with_prepare`]: before anything is painted, e.g. to render to an offscreen framebuffer.
+/// * [`Self::new`]: when the callback is reached, with the viewport and clip rectangle set.
+/// * [`Self::with_cleanup`]: after everything is painted, e.g. to free per-frame resources.
+///
+/// To support other backends too, see [`egui::PaintCallback::with_backend`].
+///
 /// # Example
 ///
 /// See the [`custom3d_glow`](https://github.com/emilk/egui/blob/master/crates/egui_demo_app/src/apps/custom3d_wgpu.rs) demo source for a detailed usage example.
 pub struct CallbackFn {
     f: Box<dyn Fn(PaintCallbackInfo, &Painter) + Sync + Send>,
+    prepare: Option<Box<dyn Fn(&Painter) + Sync + Send>>,
+    cleanup: Option<Box<dyn Fn(&Painter) + Sync + Send>>,
 }
 
 impl CallbackFn {
     pub fn new<F: Fn(PaintCallbackInfo, &Painter) + Sync + Send + 'static>(callback: F) -> Self {
         let f = Box::new(callback);
-        Self { f }
+        Self {
+            f,
+            prepare: None,
+            cleanup: None,
+        }
+    }
+
+    /// Called before anything is painted this frame.
+    #[inline]
+    pub fn with_prepare(mut self, prepare: impl Fn(&Painter) + Sync + Send + 'static) -> Self {
+        self.prepare = Some(Box::new(prepare));
+        self
     }
+
+    /// Called after everything is painted this frame.
+    #[inline]
+    pub fn with_cleanup(mut self, cleanup: impl Fn(&Painter) + Sync + Send + 'static) -> Self {
+        self.cleanup = Some(Box::new(cleanup));
+        self
+    }
+
+    /// Turn this into a [`egui::PaintCallback`] that paints in `rect`.
+    pub fn into_paint_callback(self, rect: egui::Rect) -> egui::PaintCallback {
+        egui::PaintCallback::new(rect, self)
+    }
+}
+
+/// All the [`CallbackFn`]s in these primitives.
+fn callback_fns(
+    clipped_primitives: &[egui::ClippedPrimitive],
+) -> impl Iterator<Item = &CallbackFn> {
+    clipped_primitives
+        .iter()
+        .filter_map(|clipped| match &clipped.primitive {
+            Primitive::Callback(callback) => callback.downcast_ref::<CallbackFn>(),
+            Primitive::Mesh(_) => None,
+        })
 }
 
 impl Painter {
@@ -402,6 +446,14 @@ impl Painter {
         crate::profile_function!();
         self.assert_not_destroyed();
 
+        for callback in callback_fns(clipped_primitives) {
+            if let Some(prepare) = &callback.prepare {
+                crate::profile_scope!("prepare callback");
+                prepare(self);
+                check_for_gl_error!(&self.gl, "prepare callback");
+            }
+        }
+
         unsafe { self.prepare_painting(screen_size_px, pixels_per_point) };
 
         for egui::ClippedPrimitive {
@@ -436,7 +488,7 @@ impl Painter {
                             );
                         }
 
-                        if let Some(callback) = callback.callback.downcast_ref::<CallbackFn>() {
+                        if let Some(callback) = callback.downcast_ref::<CallbackFn>() {
                             (callback.f)(info, self);
                         } else {
                             log::warn!("Warning: Unsupported render callback. Expected egui_glow::CallbackFn");
@@ -459,6 +511,14 @@ impl Painter {
 
             check_for_gl_error!(&self.gl, "painting");
         }
+
+        for callback in callback_fns(clipped_primitives) {
+            if let Some(cleanup) = &callback.cleanup {
+                crate::profile_scope!("cleanup callback");
+                cleanup(self);
+                check_for_gl_error!(&self.gl, "cleanup callback");
+            }
+        }
     }
 
     #[inline(never)] // Easier profiling
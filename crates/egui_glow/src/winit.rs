@@ -73,6 +73,7 @@ impl EguiGlow {
             shapes,
             pixels_per_point,
             viewport_output,
+            stats: _,
         } = self.egui_ctx.run(raw_input, run_ui);
 
         if viewport_output.len() > 1 {
@@ -82,6 +83,7 @@ impl EguiGlow {
             let mut screenshot_requested = false;
             egui_winit::process_viewport_commands(
                 &self.egui_ctx,
+                ViewportId::ROOT,
                 &mut self.viewport_info,
                 commands,
                 window,
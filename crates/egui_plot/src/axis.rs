@@ -3,12 +3,16 @@ use std::{fmt::Debug, ops::RangeInclusive, sync::Arc};
 use egui::{
     emath::{remap_clamp, round_to_decimals, Rot2},
     epaint::TextShape,
-    Pos2, Rangef, Rect, Response, Sense, TextStyle, Ui, Vec2, WidgetText,
+    Color32, FontId, Pos2, Rangef, Rect, Response, Sense, Style, TextStyle, Ui, Vec2, WidgetText,
 };
 
-use super::{transform::PlotTransform, GridMark};
+use super::{
+    transform::{AxisScale, PlotTransform},
+    GridMark,
+};
 
 pub(super) type AxisFormatterFn = dyn Fn(GridMark, usize, &RangeInclusive<f64>) -> String;
+pub(super) type TickGeneratorFn = dyn Fn(&RangeInclusive<f64>) -> Vec<GridMark>;
 
 /// X or Y axis.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -94,21 +98,189 @@ impl From<Placement> for VPlacement {
     }
 }
 
+/// Where to put the ticks of an axis, see [`AxisHints::ticks`].
+#[derive(Clone)]
+pub enum TickSpec {
+    /// Ticks at exactly these values, e.g. musical note frequencies or category boundaries.
+    Fixed(Vec<f64>),
+
+    /// Exactly these ticks.
+    ///
+    /// Unlike [`Self::Fixed`], this lets you choose the [`GridMark::step_size`] of each tick,
+    /// which decides which labels are hidden first when zooming out: give the important ticks a bigger step size.
+    Marks(Vec<GridMark>),
+
+    /// Ticks generated for the visible range, e.g. to only show thresholds when zoomed in.
+    ///
+    /// Ticks outside of the range are ignored. See also [`Self::generator`].
+    Generator(Arc<TickGeneratorFn>),
+
+    /// This many evenly spaced ticks across the visible range, including both ends.
+    Count(usize),
+
+    /// Ticks at every multiple of this step.
+    Step(f64),
+}
+
+impl Debug for TickSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fixed(values) => f.debug_tuple("Fixed").field(values).finish(),
+            Self::Marks(marks) => f.debug_tuple("Marks").field(marks).finish(),
+            Self::Generator(_) => f.write_str("Generator(..)"),
+            Self::Count(count) => f.debug_tuple("Count").field(count).finish(),
+            Self::Step(step) => f.debug_tuple("Step").field(step).finish(),
+        }
+    }
+}
+
+impl PartialEq for TickSpec {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Fixed(a), Self::Fixed(b)) => a == b,
+            (Self::Marks(a), Self::Marks(b)) => a == b,
+            (Self::Generator(a), Self::Generator(b)) => Arc::ptr_eq(a, b),
+            (Self::Count(a), Self::Count(b)) => a == b,
+            (Self::Step(a), Self::Step(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl From<Vec<f64>> for TickSpec {
+    fn from(values: Vec<f64>) -> Self {
+        Self::Fixed(values)
+    }
+}
+
+impl From<Vec<GridMark>> for TickSpec {
+    fn from(marks: Vec<GridMark>) -> Self {
+        Self::Marks(marks)
+    }
+}
+
+impl TickSpec {
+    /// Ticks generated by `generator` for the visible range, see [`Self::Generator`].
+    ///
+    /// ```
+    /// use egui_plot::{GridMark, TickSpec};
+    ///
+    /// // A tick at every whole hour, and more important ones every day:
+    /// let ticks = TickSpec::generator(|range| {
+    ///     let hour = 3600.0;
+    ///     let first = (range.start() / hour).ceil() as i64;
+    ///     let last = (range.end() / hour).floor() as i64;
+    ///     (first..=last)
+    ///         .map(|i| GridMark {
+    ///             value: i as f64 * hour,
+    ///             step_size: if i % 24 == 0 { 24.0 * hour } else { hour },
+    ///         })
+    ///         .collect()
+    /// });
+    /// ```
+    pub fn generator(generator: impl Fn(&RangeInclusive<f64>) -> Vec<GridMark> + 'static) -> Self {
+        Self::Generator(Arc::new(generator))
+    }
+
+    /// The ticks within the given visible range.
+    pub(super) fn marks(&self, range: &RangeInclusive<f64>) -> Vec<GridMark> {
+        let (min, max) = (*range.start(), *range.end());
+        let width = max - min;
+
+        match self {
+            Self::Marks(marks) => {
+                let mut marks: Vec<GridMark> = marks
+                    .iter()
+                    .copied()
+                    .filter(|mark| range.contains(&mark.value))
+                    .collect();
+                marks.sort_by(|a, b| a.value.total_cmp(&b.value));
+                marks
+            }
+            Self::Generator(generator) => generator(range)
+                .into_iter()
+                .filter(|mark| range.contains(&mark.value))
+                .collect(),
+            Self::Fixed(values) => {
+                let mut values: Vec<f64> = values
+                    .iter()
+                    .copied()
+                    .filter(|value| range.contains(value))
+                    .collect();
+                values.sort_by(f64::total_cmp);
+
+                // Use the distance to the closest neighbor, so that labels that don't fit are hidden:
+                (0..values.len())
+                    .map(|i| {
+                        let before = i.checked_sub(1).map(|j| values[i] - values[j]);
+                        let after = values.get(i + 1).map(|next| next - values[i]);
+                        let step_size = match (before, after) {
+                            (Some(before), Some(after)) => before.min(after),
+                            (Some(gap), None) | (None, Some(gap)) => gap,
+                            (None, None) => width,
+                        };
+                        GridMark {
+                            value: values[i],
+                            step_size,
+                        }
+                    })
+                    .collect()
+            }
+            Self::Count(count) => {
+                if *count == 0 {
+                    return Vec::new();
+                }
+                if *count == 1 {
+                    return vec![GridMark {
+                        value: min + 0.5 * width,
+                        step_size: width,
+                    }];
+                }
+                let step_size = width / (*count - 1) as f64;
+                (0..*count)
+                    .map(|i| GridMark {
+                        value: min + i as f64 * step_size,
+                        step_size,
+                    })
+                    .collect()
+            }
+            Self::Step(step_size) => {
+                let step_size = step_size.abs();
+                if step_size <= 0.0 || width / step_size > 10_000.0 {
+                    return Vec::new(); // Too many ticks to be useful
+                }
+                let first = (min / step_size).ceil() as i64;
+                let last = (max / step_size).floor() as i64;
+                (first..=last)
+                    .map(|i| GridMark {
+                        value: i as f64 * step_size,
+                        step_size,
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
 /// Axis configuration.
 ///
 /// Used to configure axis label and ticks.
 #[derive(Clone)]
 pub struct AxisHints {
     pub(super) label: WidgetText,
-    pub(super) formatter: Arc<AxisFormatterFn>,
+    /// `None` means the default formatter for the scale of the axis.
+    pub(super) formatter: Option<Arc<AxisFormatterFn>>,
     pub(super) digits: usize,
     pub(super) placement: Placement,
     pub(super) label_spacing: Rangef,
+    pub(super) ticks: Option<TickSpec>,
+    pub(super) label_font: Option<FontId>,
+    pub(super) tick_font: Option<FontId>,
+    pub(super) label_color: Option<Color32>,
+    pub(super) tick_color: Option<Color32>,
+    pub(super) tick_angle: f32,
 }
 
-// TODO(JohannesProgrammiert): this just a guess. It might cease to work if a user changes font size.
-const LINE_HEIGHT: f32 = 12.0;
-
 impl AxisHints {
     /// Initializes a default axis configuration for the X axis.
     pub fn new_x() -> Self {
@@ -123,14 +295,21 @@ impl AxisHints {
     /// Initializes a default axis configuration for the specified axis.
     ///
     /// `label` is empty.
-    /// `formatter` is default float to string formatter.
+    /// `formatter` is default float to string formatter,
+    /// which uses scientific notation for large and small numbers on logarithmic axes.
     /// maximum `digits` on tick label is 5.
     pub fn new(axis: Axis) -> Self {
         Self {
             label: Default::default(),
-            formatter: Arc::new(Self::default_formatter),
+            formatter: None,
             digits: 5,
             placement: Placement::LeftBottom,
+            ticks: None,
+            label_font: None,
+            tick_font: None,
+            label_color: None,
+            tick_color: None,
+            tick_angle: 0.0,
             label_spacing: match axis {
                 Axis::X => Rangef::new(60.0, 80.0), // labels can get pretty wide
                 Axis::Y => Rangef::new(20.0, 30.0), // text isn't very high
@@ -140,17 +319,61 @@ impl AxisHints {
 
     /// Specify custom formatter for ticks.
     ///
-    /// The first parameter of `formatter` is the raw tick value as `f64`.
-    /// The second parameter is the maximum number of characters that fit into y-labels.
-    /// The second parameter of `formatter` is the currently shown range on this axis.
+    /// Arguments of `fmt`:
+    /// * the grid mark to format
+    /// * maximum requested number of characters per tick label.
+    /// * currently shown range on this axis.
+    ///
+    /// The formatter is a closure, so it can capture settings such as units or an epoch:
+    ///
+    /// ```
+    /// use egui_plot::AxisHints;
+    ///
+    /// let epoch_seconds = 1_700_000_000.0;
+    /// let unit = String::from("s");
+    /// let x_axis = AxisHints::new_x().formatter(move |mark, _max_chars, _range| {
+    ///     format!("{:.0}{unit}", mark.value - epoch_seconds)
+    /// });
+    /// ```
     pub fn formatter(
         mut self,
         fmt: impl Fn(GridMark, usize, &RangeInclusive<f64>) -> String + 'static,
     ) -> Self {
-        self.formatter = Arc::new(fmt);
+        self.formatter = Some(Arc::new(fmt));
         self
     }
 
+    /// Format a tick with the custom formatter, or the default one for the given scale.
+    pub(super) fn format_tick(
+        &self,
+        mark: GridMark,
+        range: &RangeInclusive<f64>,
+        scale: AxisScale,
+    ) -> String {
+        match (&self.formatter, scale) {
+            (Some(formatter), _) => formatter(mark, self.digits, range),
+            (None, AxisScale::Linear) => Self::default_formatter(mark, self.digits, range),
+            (None, AxisScale::Log10) => Self::log10_formatter(mark, self.digits, range),
+        }
+    }
+
+    /// Like [`Self::default_formatter`], but switches to scientific notation
+    /// outside of a few decades, e.g. `1e-6` or `2e9`.
+    fn log10_formatter(mark: GridMark, max_digits: usize, range: &RangeInclusive<f64>) -> String {
+        let tick = mark.value;
+        if tick <= 0.0 {
+            return String::new();
+        }
+
+        // Nudge it, so that e.g. 1000 doesn't end up as 9.999…e2:
+        let exponent = (tick.log10() + 1e-9).floor();
+        if (-3.0..max_digits as f64).contains(&exponent) {
+            return Self::default_formatter(mark, max_digits, range);
+        }
+        let mantissa = round_to_decimals(tick / 10.0_f64.powf(exponent), 2);
+        format!("{mantissa}e{exponent}")
+    }
+
     fn default_formatter(
         mark: GridMark,
         max_digits: usize,
@@ -209,23 +432,126 @@ impl AxisHints {
         self
     }
 
-    pub(super) fn thickness(&self, axis: Axis) -> f32 {
-        match axis {
-            Axis::X => {
-                if self.label.is_empty() {
-                    1.0 * LINE_HEIGHT
-                } else {
-                    3.0 * LINE_HEIGHT
-                }
-            }
-            Axis::Y => {
-                if self.label.is_empty() {
-                    (self.digits as f32) * LINE_HEIGHT
-                } else {
-                    (self.digits as f32 + 1.0) * LINE_HEIGHT
-                }
+    /// Put the tick labels at the given positions, instead of where the grid lines are.
+    ///
+    /// By default the ticks follow the grid spacer of the plot (see [`crate::Plot::x_grid_spacer`]),
+    /// which is a [`crate::log_grid_spacer`] unless you change it.
+    /// Labels of ticks that are too close together are still hidden, see [`Self::label_spacing`].
+    ///
+    /// This only affects the labels; use a custom grid spacer to move the grid lines too.
+    ///
+    /// A `Vec<f64>` is turned into [`TickSpec::Fixed`], and a `Vec<GridMark>` into [`TickSpec::Marks`].
+    ///
+    /// ```
+    /// use egui_plot::{AxisHints, GridMark, TickSpec};
+    ///
+    /// let notes = AxisHints::new_x().ticks(vec![261.63, 293.66, 329.63, 349.23]);
+    /// let percent = AxisHints::new_y().ticks(TickSpec::Step(25.0));
+    ///
+    /// // The limit is more important than the warning level, so it stays visible longest:
+    /// let thresholds = AxisHints::new_y().ticks(vec![
+    ///     GridMark { value: 80.0, step_size: 10.0 },
+    ///     GridMark { value: 100.0, step_size: 100.0 },
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn ticks(mut self, ticks: impl Into<TickSpec>) -> Self {
+        self.ticks = Some(ticks.into());
+        self
+    }
+
+    /// The font of the axis label.
+    ///
+    /// Default: [`TextStyle::Body`].
+    #[inline]
+    pub fn label_font(mut self, font_id: FontId) -> Self {
+        self.label_font = Some(font_id);
+        self
+    }
+
+    /// The font of the tick labels.
+    ///
+    /// Default: [`TextStyle::Body`].
+    #[inline]
+    pub fn tick_font(mut self, font_id: FontId) -> Self {
+        self.tick_font = Some(font_id);
+        self
+    }
+
+    /// The color of the axis label.
+    ///
+    /// Default: the text color of the [`egui::Visuals`].
+    #[inline]
+    pub fn label_color(mut self, color: impl Into<Color32>) -> Self {
+        self.label_color = Some(color.into());
+        self
+    }
+
+    /// The color of the tick labels.
+    ///
+    /// The tick labels still fade in as they get further apart, see [`Self::label_spacing`].
+    ///
+    /// Default: the text color of the [`egui::Visuals`].
+    #[inline]
+    pub fn tick_color(mut self, color: impl Into<Color32>) -> Self {
+        self.tick_color = Some(color.into());
+        self
+    }
+
+    /// Rotate the tick labels by this angle, in radians, e.g. to fit long dates or category names.
+    ///
+    /// Positive angles rotate clockwise.
+    /// The end of each label closest to the plot is put next to its tick,
+    /// and the axis grows to fit the rotated labels.
+    ///
+    /// ```
+    /// use egui_plot::AxisHints;
+    ///
+    /// // Reading upwards, from the bottom left:
+    /// let x_axis = AxisHints::new_x().tick_angle(-std::f32::consts::FRAC_PI_4);
+    /// ```
+    ///
+    /// Default: `0.0`.
+    #[inline]
+    pub fn tick_angle(mut self, angle: f32) -> Self {
+        self.tick_angle = angle;
+        self
+    }
+
+    fn label_font_id(&self, style: &Style) -> FontId {
+        self.label_font
+            .clone()
+            .unwrap_or_else(|| TextStyle::Body.resolve(style))
+    }
+
+    fn tick_font_id(&self, style: &Style) -> FontId {
+        self.tick_font
+            .clone()
+            .unwrap_or_else(|| TextStyle::Body.resolve(style))
+    }
+
+    /// How much space to reserve for the axis, measured with the fonts in use.
+    ///
+    /// If the tick labels turn out to need more space, the plot uses that the next frame.
+    pub(super) fn thickness(&self, ui: &Ui, axis: Axis) -> f32 {
+        let label_font = self.label_font_id(ui.style());
+        let tick_font = self.tick_font_id(ui.style());
+        ui.fonts(|fonts| {
+            let label_height = if self.label.is_empty() {
+                0.0
+            } else {
+                fonts.row_height(&label_font)
+            };
+            let digit_width = fonts.glyph_width(&tick_font, '0');
+            // Room for a sign and a decimal point too:
+            let tick_width = (self.digits as f32 + 2.0) * digit_width;
+            let tick_size = Vec2::new(tick_width, fonts.row_height(&tick_font));
+            let (_, tick_thickness) = rotated_extents(tick_size, self.tick_angle, axis);
+            match axis {
+                Axis::X => tick_thickness + 2.0 * label_height,
+                Axis::Y => tick_thickness + label_height,
             }
-        }
+        })
     }
 }
 
@@ -263,11 +589,18 @@ impl AxisWidget {
         let visuals = ui.style().visuals.clone();
 
         {
-            let text = self.hints.label;
-            let galley = text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Body);
-            let text_color = visuals
-                .override_text_color
-                .unwrap_or_else(|| ui.visuals().text_color());
+            let text = self.hints.label.clone();
+            let galley = text.into_galley(
+                ui,
+                Some(false),
+                f32::INFINITY,
+                self.hints.label_font_id(ui.style()),
+            );
+            let text_color = self.hints.label_color.unwrap_or_else(|| {
+                visuals
+                    .override_text_color
+                    .unwrap_or_else(|| ui.visuals().text_color())
+            });
             let angle: f32 = match axis {
                 Axis::X => 0.0,
                 Axis::Y => -std::f32::consts::TAU * 0.25,
@@ -312,18 +645,33 @@ impl AxisWidget {
                 .add(TextShape::new(text_pos, galley, text_color).with_angle(angle));
         }
 
-        let font_id = TextStyle::Body.resolve(ui.style());
+        let font_id = self.hints.tick_font_id(ui.style());
         let Some(transform) = self.transform else {
             return (response, 0.0);
         };
 
         let label_spacing = self.hints.label_spacing;
+        let scale = transform.scales()[usize::from(axis)];
 
         let mut thickness: f32 = 0.0;
 
+        let steps = match &self.hints.ticks {
+            Some(ticks) => {
+                let mut marks = ticks.marks(&self.range);
+                if scale == AxisScale::Log10 {
+                    // The spacing of the labels is measured in decades:
+                    for mark in &mut marks {
+                        mark.step_size = super::step_size_in_decades(mark.value, mark.step_size);
+                    }
+                }
+                Arc::new(marks)
+            }
+            None => self.steps.clone(),
+        };
+
         // Add tick labels:
-        for step in self.steps.iter() {
-            let text = (self.hints.formatter)(*step, self.hints.digits, &self.range);
+        for step in steps.iter() {
+            let text = self.hints.format_tick(*step, &self.range, scale);
             if !text.is_empty() {
                 let spacing_in_points =
                     (transform.dpos_dvalue()[usize::from(axis)] * step.step_size).abs() as f32;
@@ -336,38 +684,66 @@ impl AxisWidget {
                 // Fade in labels as they get further apart:
                 let strength = remap_clamp(spacing_in_points, label_spacing, 0.0..=1.0);
 
-                let text_color = super::color_from_strength(ui, strength);
+                let text_color = match self.hints.tick_color {
+                    Some(color) => color.gamma_multiply(strength.sqrt()),
+                    None => super::color_from_strength(ui, strength),
+                };
                 let galley = ui
                     .painter()
                     .layout_no_wrap(text, font_id.clone(), text_color);
 
-                if spacing_in_points < galley.size()[axis as usize] {
+                let angle = self.hints.tick_angle;
+                let (along, across) = rotated_extents(galley.size(), angle, axis);
+                if spacing_in_points < along {
                     continue; // the galley won't fit (likely too wide on the X axis).
                 }
 
+                thickness = thickness.max(across);
+
                 match axis {
                     Axis::X => {
-                        thickness = thickness.max(galley.size().y);
-
                         let projected_point = super::PlotPoint::new(step.value, 0.0);
                         let center_x = transform.position_from_point(&projected_point).x;
-                        let y = match VPlacement::from(self.hints.placement) {
-                            VPlacement::Bottom => self.rect.min.y,
-                            VPlacement::Top => self.rect.max.y - galley.size().y,
-                        };
-                        let pos = Pos2::new(center_x - galley.size().x / 2.0, y);
-                        ui.painter().add(TextShape::new(pos, galley, text_color));
+                        let placement = VPlacement::from(self.hints.placement);
+
+                        if angle == 0.0 {
+                            let y = match placement {
+                                VPlacement::Bottom => self.rect.min.y,
+                                VPlacement::Top => self.rect.max.y - galley.size().y,
+                            };
+                            let pos = Pos2::new(center_x - galley.size().x / 2.0, y);
+                            ui.painter().add(TextShape::new(pos, galley, text_color));
+                        } else {
+                            let rot = Rot2::from_angle(angle);
+                            let reading_dir = rot * Vec2::X;
+                            let half_height = galley.size().y / 2.0;
+
+                            // Keep the rotated label from reaching into the plot:
+                            let inset = half_height * (rot * Vec2::Y).y.abs();
+                            let (edge_y, reads_away_from_plot) = match placement {
+                                VPlacement::Bottom => {
+                                    (self.rect.min.y + inset, 0.0 < reading_dir.y)
+                                }
+                                VPlacement::Top => (self.rect.max.y - inset, reading_dir.y < 0.0),
+                            };
+
+                            // Put the end of the label closest to the plot at the tick:
+                            let anchor = if reads_away_from_plot {
+                                Vec2::new(0.0, half_height)
+                            } else {
+                                Vec2::new(galley.size().x, half_height)
+                            };
+                            let pos = Pos2::new(center_x, edge_y) - rot * anchor;
+                            ui.painter()
+                                .add(TextShape::new(pos, galley, text_color).with_angle(angle));
+                        }
                     }
                     Axis::Y => {
-                        thickness = thickness.max(galley.size().x);
-
                         let projected_point = super::PlotPoint::new(0.0, step.value);
                         let center_y = transform.position_from_point(&projected_point).y;
 
                         match HPlacement::from(self.hints.placement) {
                             HPlacement::Left => {
-                                let angle = 0.0; // TODO(emilk): allow users to rotate text
-
                                 if angle == 0.0 {
                                     let x = self.rect.max.x - galley.size().x;
                                     let pos = Pos2::new(x, center_y - galley.size().y / 2.0);
@@ -389,7 +765,8 @@ impl AxisWidget {
                             HPlacement::Right => {
                                 let x = self.rect.min.x;
                                 let pos = Pos2::new(x, center_y - galley.size().y / 2.0);
-                                ui.painter().add(TextShape::new(pos, galley, text_color));
+                                ui.painter()
+                                    .add(TextShape::new(pos, galley, text_color).with_angle(angle));
                             }
                         };
                     }
@@ -400,3 +777,89 @@ impl AxisWidget {
         (response, thickness)
     }
 }
+
+/// The extents of a tick label of the given size, rotated by the given angle,
+/// along the axis and across it.
+///
+/// The extent along the axis is how far apart ticks need to be for their labels not to overlap.
+/// The extent across the axis is the thickness the axis needs.
+fn rotated_extents(size: Vec2, angle: f32, axis: Axis) -> (f32, f32) {
+    let rot = Rot2::from_angle(angle);
+    let reading_dir = rot * Vec2::X;
+    let up_dir = rot * Vec2::Y;
+    let i = usize::from(axis);
+
+    let along_box = (size.x * reading_dir[i]).abs() + (size.y * up_dir[i]).abs();
+    // Labels that are side by side, like slanted lines, only need to be their height apart:
+    let along_stacked = size.y / up_dir[i].abs();
+    let across = (size.x * reading_dir[1 - i]).abs() + (size.y * up_dir[1 - i]).abs();
+
+    (along_box.min(along_stacked), across)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mark(value: f64, step_size: f64) -> GridMark {
+        GridMark { value, step_size }
+    }
+
+    #[test]
+    fn fixed_ticks_are_sorted_and_spaced_by_closest_neighbor() {
+        let ticks = TickSpec::Fixed(vec![5.0, -1.0, 1.0, 2.0, 20.0]);
+        assert_eq!(
+            ticks.marks(&(0.0..=10.0)),
+            vec![mark(1.0, 1.0), mark(2.0, 1.0), mark(5.0, 3.0)]
+        );
+        assert_eq!(
+            TickSpec::Fixed(vec![4.0]).marks(&(0.0..=10.0)),
+            vec![mark(4.0, 10.0)]
+        );
+    }
+
+    #[test]
+    fn marks_outside_of_range_are_ignored() {
+        let ticks = TickSpec::Marks(vec![mark(3.0, 1.0), mark(-1.0, 1.0), mark(1.0, 2.0)]);
+        assert_eq!(
+            ticks.marks(&(0.0..=10.0)),
+            vec![mark(1.0, 2.0), mark(3.0, 1.0)]
+        );
+
+        let ticks = TickSpec::generator(|range| {
+            vec![mark(*range.start() - 1.0, 1.0), mark(*range.end(), 1.0)]
+        });
+        assert_eq!(ticks.marks(&(0.0..=10.0)), vec![mark(10.0, 1.0)]);
+    }
+
+    #[test]
+    fn count_ticks_include_both_ends() {
+        assert_eq!(
+            TickSpec::Count(3).marks(&(0.0..=10.0)),
+            vec![mark(0.0, 5.0), mark(5.0, 5.0), mark(10.0, 5.0)]
+        );
+        assert_eq!(
+            TickSpec::Count(1).marks(&(0.0..=10.0)),
+            vec![mark(5.0, 10.0)]
+        );
+        assert!(TickSpec::Count(0).marks(&(0.0..=10.0)).is_empty());
+    }
+
+    #[test]
+    fn step_ticks_at_multiples_of_step() {
+        assert_eq!(
+            TickSpec::Step(4.0).marks(&(-1.0..=10.0)),
+            vec![mark(0.0, 4.0), mark(4.0, 4.0), mark(8.0, 4.0)]
+        );
+        assert!(TickSpec::Step(0.0).marks(&(0.0..=10.0)).is_empty());
+        assert!(TickSpec::Step(1e-6).marks(&(0.0..=10.0)).is_empty());
+    }
+
+    #[test]
+    fn generator_debug_and_eq() {
+        let ticks = TickSpec::generator(|_| Vec::new());
+        assert_eq!(format!("{ticks:?}"), "Generator(..)");
+        assert_eq!(ticks, ticks.clone());
+        assert_ne!(ticks, TickSpec::generator(|_| Vec::new()));
+    }
+}
@@ -0,0 +1,491 @@
+use egui::{
+    emath::lerp, epaint::PathShape, Color32, Id, Key, Pos2, Rect, Response, Sense, Shape, Stroke,
+    Ui, Vec2, Widget,
+};
+
+use super::{PlotBounds, PlotPoint, PlotTransform};
+
+/// How a [`Curve`] goes from one [`Keyframe`] to the next.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CurveInterpolation {
+    /// Keep the value of the keyframe until the next one.
+    Constant,
+
+    /// A straight line to the next keyframe.
+    Linear,
+
+    /// A smooth curve, following the slopes of the keyframes.
+    #[default]
+    Cubic,
+}
+
+impl CurveInterpolation {
+    pub const ALL: [Self; 3] = [Self::Constant, Self::Linear, Self::Cubic];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Constant => "Constant",
+            Self::Linear => "Linear",
+            Self::Cubic => "Cubic",
+        }
+    }
+}
+
+/// A point that a [`Curve`] passes through.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Keyframe {
+    pub x: f64,
+    pub y: f64,
+
+    /// The slope (`dy/dx`) of the curve arriving at this keyframe, for [`CurveInterpolation::Cubic`].
+    pub in_slope: f64,
+
+    /// The slope (`dy/dx`) of the curve leaving this keyframe, for [`CurveInterpolation::Cubic`].
+    pub out_slope: f64,
+
+    /// How to get from this keyframe to the next one.
+    pub interpolation: CurveInterpolation,
+}
+
+impl Keyframe {
+    /// A keyframe with a flat slope and cubic interpolation.
+    pub fn new(x: f64, y: f64) -> Self {
+        Self {
+            x,
+            y,
+            in_slope: 0.0,
+            out_slope: 0.0,
+            interpolation: CurveInterpolation::default(),
+        }
+    }
+
+    /// Set both the incoming and the outgoing slope.
+    #[inline]
+    pub fn slope(mut self, slope: f64) -> Self {
+        self.in_slope = slope;
+        self.out_slope = slope;
+        self
+    }
+
+    #[inline]
+    pub fn interpolation(mut self, interpolation: CurveInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+}
+
+/// A 1D function defined by keyframes, e.g. an animation curve, an audio envelope or a transfer function.
+///
+/// Edit it with a [`CurveEditor`].
+///
+/// ```
+/// use egui_plot::{Curve, CurveInterpolation, Keyframe};
+///
+/// let envelope = Curve::new(vec![
+///     Keyframe::new(0.0, 0.0).interpolation(CurveInterpolation::Linear),
+///     Keyframe::new(0.2, 1.0),
+///     Keyframe::new(1.0, 0.0),
+/// ]);
+/// assert_eq!(envelope.evaluate(0.1), 0.5);
+/// assert_eq!(envelope.evaluate(2.0), 0.0);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Curve {
+    /// The keyframes, sorted by [`Keyframe::x`].
+    pub keyframes: Vec<Keyframe>,
+}
+
+impl Curve {
+    /// The keyframes are sorted by `x`.
+    pub fn new(mut keyframes: Vec<Keyframe>) -> Self {
+        keyframes.sort_by(|a, b| a.x.total_cmp(&b.x));
+        Self { keyframes }
+    }
+
+    /// The value of the curve at `x`.
+    ///
+    /// Before the first keyframe and after the last one, the curve is flat.
+    /// A curve without keyframes is zero everywhere.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let next = self.keyframes.partition_point(|k| k.x <= x);
+        let (Some(k0), Some(k1)) = (
+            next.checked_sub(1).and_then(|i| self.keyframes.get(i)),
+            self.keyframes.get(next),
+        ) else {
+            return self
+                .keyframes
+                .get(next.min(self.keyframes.len().saturating_sub(1)))
+                .map_or(0.0, |k| k.y);
+        };
+
+        let h = k1.x - k0.x;
+        let t = if 0.0 < h { (x - k0.x) / h } else { 0.0 };
+        match k0.interpolation {
+            CurveInterpolation::Constant => k0.y,
+            CurveInterpolation::Linear => lerp(k0.y..=k1.y, t),
+            CurveInterpolation::Cubic => {
+                // Cubic Hermite spline:
+                let t2 = t * t;
+                let t3 = t2 * t;
+                let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+                let h10 = t3 - 2.0 * t2 + t;
+                let h01 = -2.0 * t3 + 3.0 * t2;
+                let h11 = t3 - t2;
+                h00 * k0.y + h10 * h * k0.out_slope + h01 * k1.y + h11 * h * k1.in_slope
+            }
+        }
+    }
+
+    /// Add a keyframe, keeping the keyframes sorted. Returns its index.
+    pub fn insert(&mut self, keyframe: Keyframe) -> usize {
+        let index = self.keyframes.partition_point(|k| k.x <= keyframe.x);
+        self.keyframes.insert(index, keyframe);
+        index
+    }
+
+    /// The bounds of the keyframes.
+    pub fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for k in &self.keyframes {
+            bounds.extend_with(&PlotPoint::new(k.x, k.y));
+        }
+        bounds
+    }
+}
+
+/// State of a [`CurveEditor`] between frames.
+#[derive(Clone)]
+struct CurveEditorState {
+    bounds: PlotBounds,
+    selected: Option<usize>,
+}
+
+/// Edit a [`Curve`] by dragging its keyframes around.
+///
+/// * Double-click to add a keyframe.
+/// * Drag the handles of the selected keyframe to change the slopes of a cubic curve.
+/// * Right-click a keyframe to change its interpolation or to delete it.
+///   `Delete` removes the selected keyframe too.
+/// * Scroll to pan, and zoom with ctrl+scroll or a pinch gesture.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Curve, CurveEditor, Keyframe};
+///
+/// # let mut curve = Curve::new(vec![Keyframe::new(0.0, 0.0), Keyframe::new(1.0, 1.0)]);
+/// let response = ui.add(CurveEditor::new("transfer_function", &mut curve).snap(0.05, 0.05));
+/// if response.changed() {
+///     let value = curve.evaluate(0.5);
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct CurveEditor<'a> {
+    id_source: Id,
+    curve: &'a mut Curve,
+    default_bounds: Option<PlotBounds>,
+    height: f32,
+    snap: [Option<f64>; 2],
+    allow_zoom: bool,
+    color: Option<Color32>,
+}
+
+impl<'a> CurveEditor<'a> {
+    /// Give a unique id for each editor within the same [`Ui`].
+    pub fn new(id_source: impl std::hash::Hash, curve: &'a mut Curve) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            curve,
+            default_bounds: None,
+            height: 160.0,
+            snap: [None, None],
+            allow_zoom: true,
+            color: None,
+        }
+    }
+
+    /// The region to show at first.
+    ///
+    /// Default: the keyframes of the curve, with some margin.
+    #[inline]
+    pub fn default_bounds(mut self, bounds: PlotBounds) -> Self {
+        self.default_bounds = Some(bounds);
+        self
+    }
+
+    /// Height of the editor. It takes up all the available width. Default: `160.0`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Round the keyframe positions to multiples of these steps while dragging.
+    #[inline]
+    pub fn snap(mut self, x_step: f64, y_step: f64) -> Self {
+        self.snap = [Some(x_step), Some(y_step)];
+        self
+    }
+
+    /// Allow panning and zooming with the scroll wheel. Default: `true`.
+    #[inline]
+    pub fn allow_zoom(mut self, allow_zoom: bool) -> Self {
+        self.allow_zoom = allow_zoom;
+        self
+    }
+
+    /// The color of the curve. Default: the strong text color of the [`egui::Visuals`].
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl<'a> Widget for CurveEditor<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            id_source,
+            curve,
+            default_bounds,
+            height,
+            snap,
+            allow_zoom,
+            color,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let size = Vec2::new(ui.available_width(), height);
+        let (rect, mut response) = ui.allocate_exact_size(size, Sense::click());
+
+        let mut state = ui
+            .data(|d| d.get_temp::<CurveEditorState>(id))
+            .unwrap_or_else(|| {
+                let mut bounds = default_bounds.unwrap_or_else(|| curve.bounds());
+                if !bounds.is_valid() {
+                    bounds = PlotBounds::from_min_max([0.0, 0.0], [1.0, 1.0]);
+                } else if default_bounds.is_none() {
+                    bounds.add_relative_margin_x(Vec2::splat(0.1));
+                    bounds.add_relative_margin_y(Vec2::splat(0.1));
+                }
+                CurveEditorState {
+                    bounds,
+                    selected: None,
+                }
+            });
+        state.selected = state.selected.filter(|i| *i < curve.keyframes.len());
+
+        let mut transform = PlotTransform::new(rect, state.bounds, false, false);
+
+        if allow_zoom && response.hovered() {
+            if let Some(hover_pos) = response.hover_pos() {
+                let zoom_factor = ui.input(|i| i.zoom_delta_2d());
+                if zoom_factor != Vec2::splat(1.0) {
+                    transform.zoom(zoom_factor, hover_pos);
+                }
+                let scroll_delta = ui.input(|i| i.smooth_scroll_delta);
+                if scroll_delta != Vec2::ZERO {
+                    transform.translate_bounds(-scroll_delta);
+                }
+            }
+        }
+
+        let snapped = |value: PlotPoint| {
+            let round = |v: f64, step: Option<f64>| match step {
+                Some(step) if 0.0 < step => (v / step).round() * step,
+                _ => v,
+            };
+            PlotPoint::new(round(value.x, snap[0]), round(value.y, snap[1]))
+        };
+
+        let mut changed = false;
+
+        // Double-click to add a keyframe:
+        if response.double_clicked() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                let value = snapped(transform.value_from_position(pos));
+                // Follow the slope of the curve, so adding a keyframe on it keeps its shape:
+                let dx = 1e-6 * transform.bounds().width();
+                let slope =
+                    (curve.evaluate(value.x + dx) - curve.evaluate(value.x - dx)) / (2.0 * dx);
+                let index = curve.insert(Keyframe::new(value.x, value.y).slope(slope));
+                state.selected = Some(index);
+                changed = true;
+            }
+        }
+
+        let visuals = ui.visuals().clone();
+        let curve_color = color.unwrap_or_else(|| visuals.strong_text_color());
+        let radius = 0.25 * ui.spacing().interact_size.y;
+        let handle_length = 3.0 * ui.spacing().interact_size.y;
+
+        // The slope handles of the selected keyframe:
+        let mut handle_shapes = Vec::new();
+        if let Some(i) = state.selected {
+            let key = curve.keyframes[i];
+            if key.interpolation == CurveInterpolation::Cubic
+                || i.checked_sub(1).map_or(false, |j| {
+                    curve.keyframes[j].interpolation == CurveInterpolation::Cubic
+                })
+            {
+                let key_pos = transform.position_from_point(&PlotPoint::new(key.x, key.y));
+                let dpos_dvalue = transform.dpos_dvalue();
+                for (side, slope) in [(-1.0_f32, key.in_slope), (1.0, key.out_slope)] {
+                    let dir = Vec2::new(dpos_dvalue[0] as f32, (slope * dpos_dvalue[1]) as f32)
+                        .normalized();
+                    let handle_pos = key_pos + side * handle_length * dir;
+                    let handle_rect = Rect::from_center_size(handle_pos, Vec2::splat(2.0 * radius));
+                    let handle_response = ui.interact(
+                        handle_rect.expand(radius),
+                        id.with(("slope", i, side < 0.0)),
+                        Sense::drag(),
+                    );
+                    if handle_response.dragged() {
+                        if let Some(pointer) = handle_response.interact_pointer_pos() {
+                            let value = transform.value_from_position(pointer);
+                            let dx = (value.x - key.x) * side as f64;
+                            if 0.0 < dx {
+                                let slope = (value.y - key.y) * side as f64 / dx;
+                                let key = &mut curve.keyframes[i];
+                                if side < 0.0 {
+                                    key.in_slope = slope;
+                                } else {
+                                    key.out_slope = slope;
+                                }
+                                changed = true;
+                            }
+                        }
+                    }
+                    let stroke = ui.style().interact(&handle_response).fg_stroke;
+                    handle_shapes.push(Shape::line_segment([key_pos, handle_pos], stroke));
+                    handle_shapes.push(Shape::circle_stroke(handle_pos, radius, stroke));
+                }
+            }
+        }
+
+        // The keyframes:
+        let mut remove = None;
+        let mut key_shapes = Vec::new();
+        for i in 0..curve.keyframes.len() {
+            let key = curve.keyframes[i];
+            let pos = transform.position_from_point(&PlotPoint::new(key.x, key.y));
+            let key_rect = Rect::from_center_size(pos, Vec2::splat(4.0 * radius));
+            let key_response = ui.interact(key_rect, id.with(("key", i)), Sense::click_and_drag());
+
+            if key_response.clicked() || key_response.drag_started() {
+                state.selected = Some(i);
+            }
+            if key_response.dragged() {
+                if let Some(pointer) = key_response.interact_pointer_pos() {
+                    let value = snapped(transform.value_from_position(pointer));
+                    // Keep the keyframes sorted:
+                    let min_x = i
+                        .checked_sub(1)
+                        .map_or(f64::NEG_INFINITY, |j| curve.keyframes[j].x);
+                    let max_x = curve.keyframes.get(i + 1).map_or(f64::INFINITY, |k| k.x);
+                    let key = &mut curve.keyframes[i];
+                    key.x = value.x.clamp(min_x, max_x);
+                    key.y = value.y;
+                    changed = true;
+                }
+            }
+            key_response.context_menu(|ui| {
+                for interpolation in CurveInterpolation::ALL {
+                    if ui
+                        .selectable_value(
+                            &mut curve.keyframes[i].interpolation,
+                            interpolation,
+                            interpolation.name(),
+                        )
+                        .clicked()
+                    {
+                        changed = true;
+                        ui.close_menu();
+                    }
+                }
+                ui.separator();
+                if ui.button("Delete").clicked() {
+                    remove = Some(i);
+                    ui.close_menu();
+                }
+            });
+
+            let selected = state.selected == Some(i);
+            let stroke = if selected {
+                visuals.selection.stroke
+            } else {
+                ui.style().interact(&key_response).fg_stroke
+            };
+            let fill = if selected {
+                visuals.selection.bg_fill
+            } else {
+                curve_color
+            };
+            key_shapes.push(Shape::circle_filled(
+                pos,
+                radius + stroke.width,
+                stroke.color,
+            ));
+            key_shapes.push(Shape::circle_filled(pos, radius, fill));
+            response |= key_response;
+        }
+
+        if (response.has_focus() || response.hovered()) && ui.input(|i| i.key_pressed(Key::Delete))
+        {
+            remove = remove.or(state.selected);
+        }
+        if let Some(i) = remove {
+            curve.keyframes.remove(i);
+            state.selected = None;
+            changed = true;
+        }
+
+        if ui.is_rect_visible(rect) {
+            let painter = ui.painter_at(rect);
+            painter.rect(
+                rect,
+                2.0,
+                visuals.extreme_bg_color,
+                visuals.widgets.noninteractive.bg_stroke,
+            );
+
+            // The axes, if visible:
+            let origin = transform.position_from_point(&PlotPoint::new(0.0, 0.0));
+            let axis_stroke = Stroke::new(1.0, visuals.weak_text_color());
+            if rect.x_range().contains(origin.x) {
+                painter.vline(origin.x, rect.y_range(), axis_stroke);
+            }
+            if rect.y_range().contains(origin.y) {
+                painter.hline(rect.x_range(), origin.y, axis_stroke);
+            }
+
+            // The curve, one point per pixel:
+            let pixels_per_point = ui.ctx().pixels_per_point();
+            let num_points = (rect.width() * pixels_per_point).ceil().max(2.0) as usize;
+            let points: Vec<Pos2> = (0..num_points)
+                .map(|i| {
+                    let x = lerp(rect.x_range(), i as f32 / (num_points - 1) as f32);
+                    let value_x = transform.value_from_position(Pos2::new(x, rect.top())).x;
+                    transform.position_from_point(&PlotPoint::new(value_x, curve.evaluate(value_x)))
+                })
+                .collect();
+            if !curve.keyframes.is_empty() {
+                painter.add(PathShape::line(points, Stroke::new(2.0, curve_color)));
+            }
+
+            painter.extend(handle_shapes);
+            painter.extend(key_shapes);
+        }
+
+        state.bounds = *transform.bounds();
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
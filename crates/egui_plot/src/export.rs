@@ -0,0 +1,494 @@
+use std::fmt::Write as _;
+
+use egui::{
+    epaint::{ClippedShape, Primitive, TextShape, Vertex},
+    pos2, vec2, ClippedPrimitive, Color32, ColorImage, Context, FontFamily, Pos2, Rect, Shape,
+    Stroke, TextureId,
+};
+
+use crate::PlotTransform;
+
+/// The shapes of the items in a [`crate::Plot`], returned by [`crate::PlotUi::export`].
+///
+/// Use [`Self::to_color_image`] to rasterize it, e.g. to save it as a PNG,
+/// or [`Self::to_svg`] for a vector image that can be scaled for print.
+///
+/// Only the items are included, not the grid, the axes, the legend or any hover labels.
+/// All coordinates are screen coordinates in points.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Line, Plot, PlotPoints};
+///
+/// let mut svg = None;
+/// Plot::new("export_demo").show(ui, |plot_ui| {
+///     plot_ui.line(Line::new(PlotPoints::from_explicit_callback(f64::sin, .., 100)));
+///     if plot_ui.response().double_clicked() {
+///         svg = Some(plot_ui.export().to_svg(egui::Color32::WHITE));
+///     }
+/// });
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct PlotExport {
+    /// The transform the shapes were painted with.
+    ///
+    /// Its [`PlotTransform::frame`] is the exported area.
+    pub transform: PlotTransform,
+
+    /// The shapes of the items, in the order they are painted.
+    pub shapes: Vec<Shape>,
+}
+
+impl PlotExport {
+    /// The exported area, in points.
+    #[inline]
+    pub fn rect(&self) -> Rect {
+        *self.transform.frame()
+    }
+
+    /// Rasterize the shapes on top of the `background` color.
+    ///
+    /// The image has one pixel per physical pixel of `ctx`, and should be created
+    /// with the same context (and zoom factor) as the plot, so that the text matches the font atlas.
+    ///
+    /// Only the font texture is available on the CPU,
+    /// so meshes using other textures (e.g. [`crate::PlotImage`]) are left out.
+    pub fn to_color_image(&self, ctx: &Context, background: Color32) -> ColorImage {
+        let rect = self.rect();
+        let pixels_per_point = ctx.pixels_per_point();
+        let size = [
+            (rect.width() * pixels_per_point).round() as usize,
+            (rect.height() * pixels_per_point).round() as usize,
+        ];
+        let mut image = ColorImage::new(size, background);
+        let image_rect = Rect::from_min_size(Pos2::ZERO, vec2(size[0] as f32, size[1] as f32));
+        let to_pixels = |pos: Pos2| ((pos - rect.min) * pixels_per_point).to_pos2();
+
+        let font_image = ctx.fonts(|fonts| fonts.image());
+        let font_texture = FontTexture {
+            size: font_image.size,
+            pixels: font_image.srgba_pixels(None).collect(),
+        };
+
+        let shapes = vec![ClippedShape {
+            clip_rect: rect,
+            shape: Shape::Vec(self.shapes.clone()),
+        }];
+        for ClippedPrimitive {
+            clip_rect,
+            primitive,
+        } in ctx.tessellate(shapes, pixels_per_point)
+        {
+            let Primitive::Mesh(mesh) = primitive else {
+                continue;
+            };
+            if mesh.texture_id != TextureId::default() {
+                continue;
+            }
+            let clip_rect = Rect::from_min_max(to_pixels(clip_rect.min), to_pixels(clip_rect.max))
+                .intersect(image_rect);
+            for triangle in mesh.indices.chunks_exact(3) {
+                let vertex = |i: usize| {
+                    let vertex = &mesh.vertices[triangle[i] as usize];
+                    Vertex {
+                        pos: to_pixels(vertex.pos),
+                        ..*vertex
+                    }
+                };
+                paint_triangle(
+                    &mut image,
+                    clip_rect,
+                    [vertex(0), vertex(1), vertex(2)],
+                    &font_texture,
+                );
+            }
+        }
+
+        image
+    }
+
+    /// Serialize the shapes to an SVG document, on top of the `background` color.
+    ///
+    /// The SVG is as large as [`Self::rect`], with one user unit per point.
+    /// Text is written as `<text>` elements using a generic font family, and so may look slightly
+    /// different from the plot. Images and paint callbacks are left out.
+    pub fn to_svg(&self, background: Color32) -> String {
+        let rect = self.rect();
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="{x} {y} {w} {h}">"#,
+            x = rect.min.x,
+            y = rect.min.y,
+            w = rect.width(),
+            h = rect.height(),
+        )
+        .ok();
+        if background != Color32::TRANSPARENT {
+            writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" {}/>"#,
+                rect.min.x,
+                rect.min.y,
+                rect.width(),
+                rect.height(),
+                fill_attributes(background),
+            )
+            .ok();
+        }
+        for shape in &self.shapes {
+            write_svg_shape(&mut svg, shape);
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+struct FontTexture {
+    size: [usize; 2],
+
+    /// Premultiplied white, with the coverage as alpha.
+    pixels: Vec<Color32>,
+}
+
+impl FontTexture {
+    fn sample(&self, uv: Pos2) -> Color32 {
+        let [w, h] = self.size;
+        let x = ((uv.x * w as f32) as usize).min(w.saturating_sub(1));
+        let y = ((uv.y * h as f32) as usize).min(h.saturating_sub(1));
+        self.pixels
+            .get(y * w + x)
+            .copied()
+            .unwrap_or(Color32::WHITE)
+    }
+}
+
+/// Twice the signed area of the triangle `a b p`.
+fn edge_function(a: Pos2, b: Pos2, p: Pos2) -> f32 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+/// Pixels exactly on an edge shared by two triangles should only be painted once.
+fn is_top_left_edge(a: Pos2, b: Pos2) -> bool {
+    let d = b - a;
+    d.y < 0.0 || (d.y == 0.0 && d.x > 0.0)
+}
+
+/// Paint a triangle with pixel coordinates onto the image, sampling pixel centers.
+///
+/// There is no multisampling: anti-aliasing comes from the feathering of the tessellator.
+fn paint_triangle(
+    image: &mut ColorImage,
+    clip_rect: Rect,
+    mut vertices: [Vertex; 3],
+    font_texture: &FontTexture,
+) {
+    let mut area = edge_function(vertices[0].pos, vertices[1].pos, vertices[2].pos);
+    if area == 0.0 || !area.is_finite() {
+        return;
+    }
+    if area < 0.0 {
+        vertices.swap(1, 2);
+        area = -area;
+    }
+    let [v0, v1, v2] = vertices;
+
+    let bounds = Rect::from_points(&[v0.pos, v1.pos, v2.pos]).intersect(clip_rect);
+    if !bounds.is_positive() {
+        return;
+    }
+
+    let edges = [(v1.pos, v2.pos), (v2.pos, v0.pos), (v0.pos, v1.pos)];
+    let width = image.size[0];
+    for y in (bounds.min.y.floor() as usize)..(bounds.max.y.ceil() as usize) {
+        for x in (bounds.min.x.floor() as usize)..(bounds.max.x.ceil() as usize) {
+            let p = pos2(x as f32 + 0.5, y as f32 + 0.5);
+            let mut weights = [0.0; 3];
+            let mut inside = true;
+            for (weight, &(a, b)) in weights.iter_mut().zip(&edges) {
+                let e = edge_function(a, b, p);
+                inside &= e > 0.0 || (e == 0.0 && is_top_left_edge(a, b));
+                *weight = e / area;
+            }
+            if !inside {
+                continue;
+            }
+
+            let uv = pos2(
+                weights[0] * v0.uv.x + weights[1] * v1.uv.x + weights[2] * v2.uv.x,
+                weights[0] * v0.uv.y + weights[1] * v1.uv.y + weights[2] * v2.uv.y,
+            );
+            let texel = font_texture.sample(uv).to_array();
+            let [c0, c1, c2] = [v0.color, v1.color, v2.color].map(|c| c.to_array());
+            let src: [f32; 4] = std::array::from_fn(|i| {
+                let color = weights[0] * c0[i] as f32
+                    + weights[1] * c1[i] as f32
+                    + weights[2] * c2[i] as f32;
+                color * texel[i] as f32 / 255.0
+            });
+
+            // Premultiplied alpha blending:
+            if let Some(pixel) = image.pixels.get_mut(y * width + x) {
+                let dst = pixel.to_array();
+                let keep = 1.0 - src[3] / 255.0;
+                let [r, g, b, a]: [u8; 4] = std::array::from_fn(|i| {
+                    (src[i] + dst[i] as f32 * keep).round().clamp(0.0, 255.0) as u8
+                });
+                *pixel = Color32::from_rgba_premultiplied(r, g, b, a);
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+fn color_attributes(name: &str, color: Color32) -> String {
+    if color.a() == 0 {
+        return format!(r#"{name}="none""#);
+    }
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    if a == 255 {
+        format!(r#"{name}="rgb({r},{g},{b})""#)
+    } else {
+        format!(
+            r#"{name}="rgb({r},{g},{b})" {name}-opacity="{:.3}""#,
+            a as f32 / 255.0
+        )
+    }
+}
+
+fn fill_attributes(fill: Color32) -> String {
+    color_attributes("fill", fill)
+}
+
+fn stroke_attributes(stroke: Stroke) -> String {
+    if stroke.is_empty() {
+        r#"stroke="none""#.to_owned()
+    } else {
+        format!(
+            r#"{} stroke-width="{}""#,
+            color_attributes("stroke", stroke.color),
+            stroke.width
+        )
+    }
+}
+
+fn svg_points(points: &[Pos2]) -> String {
+    let mut s = String::new();
+    for p in points {
+        write!(s, "{:.2},{:.2} ", p.x, p.y).ok();
+    }
+    s.trim_end().to_owned()
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn write_svg_shape(svg: &mut String, shape: &Shape) {
+    match shape {
+        Shape::Noop | Shape::Callback(_) => {}
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                write_svg_shape(svg, shape);
+            }
+        }
+        Shape::Circle(circle) => {
+            writeln!(
+                svg,
+                r#"<circle cx="{:.2}" cy="{:.2}" r="{:.2}" {} {}/>"#,
+                circle.center.x,
+                circle.center.y,
+                circle.radius,
+                fill_attributes(circle.fill),
+                stroke_attributes(circle.stroke),
+            )
+            .ok();
+        }
+        Shape::Ellipse(ellipse) => {
+            writeln!(
+                svg,
+                r#"<ellipse cx="{:.2}" cy="{:.2}" rx="{:.2}" ry="{:.2}" {} {}/>"#,
+                ellipse.center.x,
+                ellipse.center.y,
+                ellipse.radius.x,
+                ellipse.radius.y,
+                fill_attributes(ellipse.fill),
+                stroke_attributes(ellipse.stroke),
+            )
+            .ok();
+        }
+        Shape::LineSegment { points, stroke } => {
+            writeln!(
+                svg,
+                r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" {}/>"#,
+                points[0].x,
+                points[0].y,
+                points[1].x,
+                points[1].y,
+                stroke_attributes(*stroke),
+            )
+            .ok();
+        }
+        Shape::Path(path) => {
+            let element = if path.closed { "polygon" } else { "polyline" };
+            let fill = if path.closed {
+                path.fill
+            } else {
+                Color32::TRANSPARENT
+            };
+            writeln!(
+                svg,
+                r#"<{element} points="{}" {} {} stroke-linejoin="round"/>"#,
+                svg_points(&path.points),
+                fill_attributes(fill),
+                stroke_attributes(path.stroke),
+            )
+            .ok();
+        }
+        Shape::Rect(rect) => {
+            writeln!(
+                svg,
+                r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" rx="{:.2}" {} {}/>"#,
+                rect.rect.min.x,
+                rect.rect.min.y,
+                rect.rect.width(),
+                rect.rect.height(),
+                rect.rounding.nw,
+                fill_attributes(rect.fill),
+                stroke_attributes(rect.stroke),
+            )
+            .ok();
+        }
+        Shape::Text(text) => write_svg_text(svg, text),
+        Shape::Mesh(mesh) => {
+            if mesh.texture_id != TextureId::default() {
+                return;
+            }
+            for triangle in mesh.indices.chunks_exact(3) {
+                let points: Vec<Pos2> = triangle
+                    .iter()
+                    .map(|&i| mesh.vertices[i as usize].pos)
+                    .collect();
+                writeln!(
+                    svg,
+                    r#"<polygon points="{}" {}/>"#,
+                    svg_points(&points),
+                    fill_attributes(mesh.vertices[triangle[0] as usize].color),
+                )
+                .ok();
+            }
+        }
+        Shape::QuadraticBezier(bezier) => {
+            let [p0, p1, p2] = bezier.points;
+            let fill = if bezier.closed {
+                bezier.fill
+            } else {
+                Color32::TRANSPARENT
+            };
+            writeln!(
+                svg,
+                r#"<path d="M {:.2} {:.2} Q {:.2} {:.2} {:.2} {:.2}{}" {} {}/>"#,
+                p0.x,
+                p0.y,
+                p1.x,
+                p1.y,
+                p2.x,
+                p2.y,
+                if bezier.closed { " Z" } else { "" },
+                fill_attributes(fill),
+                stroke_attributes(bezier.stroke),
+            )
+            .ok();
+        }
+        Shape::CubicBezier(bezier) => {
+            let [p0, p1, p2, p3] = bezier.points;
+            let fill = if bezier.closed {
+                bezier.fill
+            } else {
+                Color32::TRANSPARENT
+            };
+            writeln!(
+                svg,
+                r#"<path d="M {:.2} {:.2} C {:.2} {:.2} {:.2} {:.2} {:.2} {:.2}{}" {} {}/>"#,
+                p0.x,
+                p0.y,
+                p1.x,
+                p1.y,
+                p2.x,
+                p2.y,
+                p3.x,
+                p3.y,
+                if bezier.closed { " Z" } else { "" },
+                fill_attributes(fill),
+                stroke_attributes(bezier.stroke),
+            )
+            .ok();
+        }
+    }
+}
+
+/// One `<text>` element per row, positioned at the baseline of its first glyph.
+fn write_svg_text(svg: &mut String, text: &TextShape) {
+    let rotate = if text.angle == 0.0 {
+        String::new()
+    } else {
+        format!(
+            r#" transform="rotate({:.2} {:.2} {:.2})""#,
+            text.angle.to_degrees(),
+            text.pos.x,
+            text.pos.y
+        )
+    };
+
+    for row in &text.galley.rows {
+        let Some(first_glyph) = row.glyphs.first() else {
+            continue;
+        };
+        let Some(section) = text
+            .galley
+            .job
+            .sections
+            .get(first_glyph.section_index as usize)
+        else {
+            continue;
+        };
+        let format = &section.format;
+
+        let mut color = text.override_text_color.unwrap_or(format.color);
+        if color == Color32::PLACEHOLDER {
+            color = text.fallback_color;
+        }
+        color = color.gamma_multiply(text.opacity_factor);
+
+        let family = match format.font_id.family {
+            FontFamily::Monospace => "monospace",
+            _ => "sans-serif",
+        };
+        let content: String = row.glyphs.iter().map(|glyph| glyph.chr).collect();
+
+        writeln!(
+            svg,
+            r#"<text x="{:.2}" y="{:.2}" font-family="{family}" font-size="{}" {}{rotate} xml:space="preserve">{}</text>"#,
+            text.pos.x + first_glyph.pos.x,
+            text.pos.y + first_glyph.pos.y,
+            format.font_id.size,
+            fill_attributes(color),
+            escape_xml(&content),
+        )
+        .ok();
+    }
+}
@@ -0,0 +1,304 @@
+//! A filled region between two curves, see [`Band`].
+
+use std::ops::RangeInclusive;
+
+use egui::epaint::{util::FloatOrd, Mesh};
+use egui::{Color32, Id, Pos2, Shape, Stroke, Ui};
+
+use super::{
+    rulers_at_value, values::ClosestElem, Cursor, LabelFormatter, LineStyle, PlotBounds,
+    PlotConfig, PlotGeometry, PlotItem, PlotPoint, PlotPoints, PlotTransform, DEFAULT_FILL_ALPHA,
+};
+
+/// The region between two curves, filled with a color.
+///
+/// Useful for confidence intervals and min/max envelopes.
+/// The two curves may have a different number of points, but both should be sorted by x.
+///
+/// The boundaries are not drawn unless you give them a width with [`Self::width`] or [`Self::stroke`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Band, Line, Plot, PlotPoints};
+///
+/// let mean = |x: f64| x.sin();
+/// Plot::new("confidence").show(ui, |plot_ui| {
+///     plot_ui.band(
+///         Band::new(
+///             PlotPoints::from_explicit_callback(move |x| mean(x) - 0.2, .., 100),
+///             PlotPoints::from_explicit_callback(move |x| mean(x) + 0.2, .., 100),
+///         )
+///         .name("95% interval"),
+///     );
+///     plot_ui.line(Line::new(PlotPoints::from_explicit_callback(mean, .., 100)).name("mean"));
+/// });
+/// # });
+/// ```
+pub struct Band {
+    pub(crate) lower: PlotPoints,
+    pub(crate) upper: PlotPoints,
+    pub(crate) stroke: Stroke,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) allow_hover: bool,
+    pub(super) fill_color: Option<Color32>,
+    pub(super) style: LineStyle,
+    id: Option<Id>,
+}
+
+impl Band {
+    /// The region between the `lower` and `upper` curves.
+    ///
+    /// It doesn't matter if the curves cross, or which one is actually on top.
+    pub fn new(lower: impl Into<PlotPoints>, upper: impl Into<PlotPoints>) -> Self {
+        Self {
+            lower: lower.into(),
+            upper: upper.into(),
+            stroke: Stroke::new(0.0, Color32::TRANSPARENT),
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            fill_color: None,
+            style: LineStyle::Solid,
+            id: None,
+        }
+    }
+
+    /// Highlight this band in the plot by scaling up the boundaries and reducing the fill
+    /// transparency.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Stroke of the two boundary curves.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Width of the two boundary curves. Default: `0.0`, i.e. they are not drawn.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Color of the boundaries, and of the fill unless [`Self::fill_color`] is set.
+    ///
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Fill color. Defaults to the stroke color with added transparency.
+    #[inline]
+    pub fn fill_color(mut self, color: impl Into<Color32>) -> Self {
+        self.fill_color = Some(color.into());
+        self
+    }
+
+    /// Set the style of the boundaries. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Name of this band.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the band's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The point with the given index, counting the points of `lower` first.
+    fn point(&self, index: usize) -> Option<PlotPoint> {
+        let lower = self.lower.points();
+        match index.checked_sub(lower.len()) {
+            None => lower.get(index).copied(),
+            Some(index) => self.upper.points().get(index).copied(),
+        }
+    }
+}
+
+/// Triangulate the strip between two polylines that are sorted by x.
+///
+/// We walk along both polylines at the same time, always advancing the one that lags behind,
+/// so the curves may have different numbers of points.
+fn fill_between(lower: &[Pos2], upper: &[Pos2], color: Color32) -> Mesh {
+    let mut mesh = Mesh::default();
+    if lower.is_empty() || upper.is_empty() || lower.len() + upper.len() < 3 {
+        return mesh;
+    }
+    mesh.reserve_vertices(lower.len() + upper.len());
+    mesh.reserve_triangles(lower.len() + upper.len() - 2);
+    for &pos in lower.iter().chain(upper) {
+        mesh.colored_vertex(pos, color);
+    }
+
+    let offset = lower.len() as u32;
+    let (mut i, mut j) = (0, 0);
+    while i + 1 < lower.len() || j + 1 < upper.len() {
+        let advance_lower = if i + 1 == lower.len() {
+            false
+        } else if j + 1 == upper.len() {
+            true
+        } else {
+            lower[i + 1].x <= upper[j + 1].x
+        };
+        if advance_lower {
+            mesh.add_triangle(i as u32, i as u32 + 1, offset + j as u32);
+            i += 1;
+        } else {
+            mesh.add_triangle(i as u32, offset + j as u32, offset + j as u32 + 1);
+            j += 1;
+        }
+    }
+    mesh
+}
+
+impl PlotItem for Band {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let Self {
+            lower,
+            upper,
+            stroke,
+            highlight,
+            fill_color,
+            style,
+            ..
+        } = self;
+
+        let to_screen = |series: &PlotPoints| -> Vec<Pos2> {
+            series
+                .points()
+                .iter()
+                .map(|v| transform.position_from_point(v))
+                .collect()
+        };
+        let lower_tf = to_screen(lower);
+        let upper_tf = to_screen(upper);
+
+        let mut fill_alpha = DEFAULT_FILL_ALPHA;
+        if *highlight {
+            fill_alpha = (2.0 * fill_alpha).min(1.0);
+        }
+        let fill_color = fill_color.unwrap_or(stroke.color.linear_multiply(fill_alpha));
+        shapes.push(Shape::Mesh(fill_between(&lower_tf, &upper_tf, fill_color)));
+
+        if stroke.width > 0.0 {
+            style.style_line(lower_tf, *stroke, *highlight, shapes);
+            style.style_line(upper_tf, *stroke, *highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.lower.generate_points(x_range.clone());
+        self.upper.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        // The points come from two series, so we implement `find_closest` and `on_hover` instead.
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = self.lower.bounds();
+        bounds.merge(&self.upper.bounds());
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.lower
+            .points()
+            .iter()
+            .chain(self.upper.points())
+            .enumerate()
+            .map(|(index, value)| {
+                let pos = transform.position_from_point(value);
+                let dist_sq = point.distance_sq(pos);
+                ClosestElem { index, dist_sq }
+            })
+            .min_by_key(|e| e.dist_sq.ord())
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        let Some(value) = self.point(elem.index) else {
+            return;
+        };
+
+        let line_color = if plot.ui.visuals().dark_mode {
+            Color32::from_gray(100).additive()
+        } else {
+            Color32::from_black_alpha(180)
+        };
+        let pointer = plot.transform.position_from_point(&value);
+        shapes.push(Shape::circle_filled(pointer, 3.0, line_color));
+
+        rulers_at_value(
+            pointer,
+            value,
+            self.name(),
+            plot,
+            shapes,
+            cursors,
+            label_formatter,
+        );
+    }
+}
@@ -149,12 +149,41 @@ impl Bar {
         let text: Option<String> = parent
             .element_formatter
             .as_ref()
-            .map(|fmt| fmt(self, parent));
+            .map(|fmt| fmt(self, parent))
+            .or_else(|| parent.stacked.then(|| self.stacked_values_format(plot)));
 
         add_rulers_and_text(self, plot, text, shapes, cursors);
     }
 }
 
+impl Bar {
+    /// The number of decimals that make sense for values at the current zoom level.
+    fn value_decimals(&self, transform: &PlotTransform) -> usize {
+        let scale = transform.dvalue_dpos();
+        let scale = match self.orientation {
+            Orientation::Horizontal => scale[0],
+            Orientation::Vertical => scale[1],
+        };
+        ((-scale.abs().log10()).ceil().at_least(0.0) as usize).at_most(6)
+    }
+
+    /// The value of this bar, and the total of the stack up to and including it.
+    fn stacked_values_format(&self, plot: &PlotConfig<'_>) -> String {
+        let decimals = self.value_decimals(plot.transform);
+        let total = self.base_offset.unwrap_or(0.0) + self.value;
+        let values = format!(
+            "{}\nTotal: {}",
+            crate::format_number(self.value, decimals),
+            crate::format_number(total, decimals)
+        );
+        if self.name.is_empty() {
+            values
+        } else {
+            format!("{}\n{values}", self.name)
+        }
+    }
+}
+
 impl RectElement for Bar {
     fn name(&self) -> &str {
         self.name.as_str()
@@ -186,12 +215,6 @@ impl RectElement for Bar {
     }
 
     fn default_values_format(&self, transform: &PlotTransform) -> String {
-        let scale = transform.dvalue_dpos();
-        let scale = match self.orientation {
-            Orientation::Horizontal => scale[0],
-            Orientation::Vertical => scale[1],
-        };
-        let decimals = ((-scale.abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
-        crate::format_number(self.value, decimals)
+        crate::format_number(self.value, self.value_decimals(transform))
     }
 }
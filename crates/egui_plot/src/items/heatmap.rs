@@ -0,0 +1,567 @@
+//! A grid of values painted as colors, see [`Heatmap`].
+
+use std::hash::{Hash, Hasher};
+use std::ops::RangeInclusive;
+
+use egui::{
+    emath::format_with_decimals_in_range,
+    epaint::{ColorImage, Mesh, TextureHandle},
+    lerp, pos2, vec2, Align2, Color32, Id, Pos2, Rect, Response, Sense, Shape, Stroke, TextStyle,
+    TextureOptions, Ui, Vec2, Widget,
+};
+
+use super::{
+    ClosestElem, Cursor, LabelFormatter, PlotBounds, PlotConfig, PlotGeometry, PlotItem, PlotPoint,
+    PlotTransform,
+};
+
+/// Maps values in the `[0, 1]` range to colors, by interpolating between evenly spaced colors.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColorMap {
+    colors: Vec<Color32>,
+}
+
+impl Default for ColorMap {
+    fn default() -> Self {
+        Self::viridis()
+    }
+}
+
+impl ColorMap {
+    /// The colors for `0`, `1` and evenly spaced in between.
+    ///
+    /// # Panics
+    /// If `colors` is empty.
+    pub fn new(colors: Vec<Color32>) -> Self {
+        assert!(!colors.is_empty(), "A ColorMap needs at least one color");
+        Self { colors }
+    }
+
+    /// Sample a [`egui::Gradient`], e.g. one made with a [`egui::GradientEditor`].
+    pub fn from_gradient(gradient: &egui::Gradient) -> Self {
+        const SAMPLES: usize = 256;
+        Self::new(
+            (0..SAMPLES)
+                .map(|i| gradient.color_at(i as f32 / (SAMPLES - 1) as f32))
+                .collect(),
+        )
+    }
+
+    /// From dark purple over blue and green to yellow.
+    ///
+    /// Perceptually uniform and readable for people with color vision deficiencies.
+    pub fn viridis() -> Self {
+        Self::new(vec![
+            Color32::from_rgb(0x44, 0x01, 0x54),
+            Color32::from_rgb(0x47, 0x2D, 0x7B),
+            Color32::from_rgb(0x3B, 0x52, 0x8B),
+            Color32::from_rgb(0x2C, 0x72, 0x8E),
+            Color32::from_rgb(0x21, 0x91, 0x8C),
+            Color32::from_rgb(0x28, 0xAE, 0x80),
+            Color32::from_rgb(0x5E, 0xC9, 0x62),
+            Color32::from_rgb(0xAD, 0xDC, 0x30),
+            Color32::from_rgb(0xFD, 0xE7, 0x25),
+        ])
+    }
+
+    /// From black to white.
+    pub fn grayscale() -> Self {
+        Self::new(vec![Color32::BLACK, Color32::WHITE])
+    }
+
+    /// The color at `t`, which is clamped to `[0, 1]`.
+    pub fn color_at(&self, t: f64) -> Color32 {
+        let last = self.colors.len() - 1;
+        let t = t.clamp(0.0, 1.0) * last as f64;
+        let index = (t.floor() as usize).min(last.saturating_sub(1));
+        let (a, b) = (self.colors[index], self.colors[(index + 1).min(last)]);
+        let t = (t - index as f64) as f32;
+        let channel = |a: u8, b: u8| lerp(a as f32..=b as f32, t).round() as u8;
+        Color32::from_rgba_premultiplied(
+            channel(a.r(), b.r()),
+            channel(a.g(), b.g()),
+            channel(a.b(), b.b()),
+            channel(a.a(), b.a()),
+        )
+    }
+
+    fn color_of(&self, value: f64, range: &RangeInclusive<f64>) -> Color32 {
+        if value.is_nan() {
+            Color32::TRANSPARENT
+        } else {
+            self.color_at(normalize(value, range))
+        }
+    }
+}
+
+fn normalize(value: f64, range: &RangeInclusive<f64>) -> f64 {
+    let width = range.end() - range.start();
+    if width > 0.0 {
+        (value - range.start()) / width
+    } else {
+        0.5
+    }
+}
+
+/// A grid of values, each painted as a rectangle with a color from a [`ColorMap`].
+///
+/// The values are given row by row, with the first row at the top.
+/// By default each cell is one unit wide and high, with the grid starting at the origin;
+/// use [`Self::position`] to place it elsewhere.
+///
+/// Hovering a cell shows its value. Use [`Self::color_bar`] to show which color means what.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Heatmap, Plot};
+///
+/// let values: Vec<f64> = (0..100).map(|i| ((i % 10) * (i / 10)) as f64).collect();
+/// let heatmap = Heatmap::new(values, 10).name("product");
+/// ui.horizontal(|ui| {
+///     ui.add(heatmap.color_bar());
+///     Plot::new("heatmap")
+///         .data_aspect(1.0)
+///         .show(ui, |plot_ui| plot_ui.heatmap(heatmap));
+/// });
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Heatmap {
+    pub(crate) values: Vec<f64>,
+    pub(crate) cols: usize,
+    pub(crate) rows: usize,
+
+    /// The plot coordinates of the grid corners.
+    pub(crate) bounds: PlotBounds,
+
+    pub(crate) color_map: ColorMap,
+
+    /// `None` means the range of the values.
+    pub(crate) range: Option<RangeInclusive<f64>>,
+
+    pub(crate) name: String,
+    pub(crate) highlight: bool,
+    pub(crate) allow_hover: bool,
+    id: Option<Id>,
+}
+
+impl Heatmap {
+    /// `values` are the cells row by row, `cols` per row.
+    ///
+    /// Values that don't make up a whole row at the end are ignored.
+    /// `NaN` values are left transparent.
+    pub fn new(values: impl Into<Vec<f64>>, cols: usize) -> Self {
+        let values = values.into();
+        let cols = cols.max(1);
+        let rows = values.len() / cols;
+        Self {
+            values,
+            cols,
+            rows,
+            bounds: PlotBounds::from_min_max([0.0, 0.0], [cols as f64, rows as f64]),
+            color_map: ColorMap::default(),
+            range: None,
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            id: None,
+        }
+    }
+
+    /// Place the grid between these plot coordinates, stretching the cells to fit.
+    #[inline]
+    pub fn position(mut self, min: impl Into<PlotPoint>, max: impl Into<PlotPoint>) -> Self {
+        let (min, max) = (min.into(), max.into());
+        self.bounds = PlotBounds::from_min_max([min.x, min.y], [max.x, max.y]);
+        self
+    }
+
+    /// Default: [`ColorMap::viridis`].
+    #[inline]
+    pub fn color_map(mut self, color_map: ColorMap) -> Self {
+        self.color_map = color_map;
+        self
+    }
+
+    /// The values mapped to the first and last color of the color map. Values outside are clamped.
+    ///
+    /// Default: the smallest and largest of the values.
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Highlight this heatmap in the plot by outlining it.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Name of this heatmap.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on,
+    /// and when hovering the heatmap.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the heatmap's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// A vertical bar showing the color map with the range of this heatmap, to put next to the plot.
+    pub fn color_bar(&self) -> ColorBar {
+        ColorBar::new(self.color_map.clone(), self.value_range())
+    }
+
+    /// The range that is mapped to the color map.
+    pub fn value_range(&self) -> RangeInclusive<f64> {
+        self.range.clone().unwrap_or_else(|| {
+            let (min, max) = self
+                .values
+                .iter()
+                .filter(|v| v.is_finite())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+                    (min.min(v), max.max(v))
+                });
+            if min <= max {
+                min..=max
+            } else {
+                0.0..=1.0
+            }
+        })
+    }
+
+    fn cell_size(&self) -> [f64; 2] {
+        [
+            self.bounds.width() / self.cols as f64,
+            self.bounds.height() / self.rows as f64,
+        ]
+    }
+
+    /// The plot bounds of the cell with the given index.
+    fn cell_bounds(&self, index: usize) -> PlotBounds {
+        let [width, height] = self.cell_size();
+        let (row, col) = (index / self.cols, index % self.cols);
+        let min = [
+            self.bounds.min()[0] + col as f64 * width,
+            self.bounds.max()[1] - (row + 1) as f64 * height,
+        ];
+        PlotBounds::from_min_max(min, [min[0] + width, min[1] + height])
+    }
+
+    fn screen_rect(&self, bounds: &PlotBounds, transform: &PlotTransform) -> Rect {
+        transform.rect_from_values(&bounds.min().into(), &bounds.max().into())
+    }
+
+    /// The values painted into a texture, cached in the temporary data of the [`egui::Context`]
+    /// until the heatmap changes.
+    fn texture(&self, ui: &Ui) -> TextureHandle {
+        let range = self.value_range();
+        let mut hasher = egui::ahash::AHasher::default();
+        for value in &self.values {
+            value.to_bits().hash(&mut hasher);
+        }
+        (self.cols, &self.color_map).hash(&mut hasher);
+        (range.start().to_bits(), range.end().to_bits()).hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let id = ui
+            .id()
+            .with("egui_plot_heatmap")
+            .with(self.id.unwrap_or_else(|| Id::new(&self.name)));
+        if let Some((cached_hash, texture)) = ui.data(|d| d.get_temp::<(u64, TextureHandle)>(id)) {
+            if cached_hash == hash {
+                return texture;
+            }
+        }
+
+        let pixels = self.values[..self.cols * self.rows]
+            .iter()
+            .map(|&value| self.color_map.color_of(value, &range))
+            .collect();
+        let image = ColorImage {
+            size: [self.cols, self.rows],
+            pixels,
+        };
+        let texture = ui
+            .ctx()
+            .load_texture("egui_plot_heatmap", image, TextureOptions::NEAREST);
+        ui.data_mut(|d| d.insert_temp(id, (hash, texture.clone())));
+        texture
+    }
+}
+
+impl PlotItem for Heatmap {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.rows == 0 {
+            return;
+        }
+
+        // Map the corners separately, so that the texture is flipped along inverted axes:
+        let top_left = transform
+            .position_from_point(&PlotPoint::new(self.bounds.min()[0], self.bounds.max()[1]));
+        let bottom_right = transform
+            .position_from_point(&PlotPoint::new(self.bounds.max()[0], self.bounds.min()[1]));
+        let rect = Rect::from_two_pos(top_left, bottom_right);
+        let uv = Rect::from_min_max(
+            pos2(
+                if top_left.x <= bottom_right.x {
+                    0.0
+                } else {
+                    1.0
+                },
+                if top_left.y <= bottom_right.y {
+                    0.0
+                } else {
+                    1.0
+                },
+            ),
+            pos2(
+                if top_left.x <= bottom_right.x {
+                    1.0
+                } else {
+                    0.0
+                },
+                if top_left.y <= bottom_right.y {
+                    1.0
+                } else {
+                    0.0
+                },
+            ),
+        );
+
+        let mut mesh = Mesh::with_texture(self.texture(ui).id());
+        mesh.add_rect_with_uv(rect, uv, Color32::WHITE);
+        shapes.push(Shape::mesh(mesh));
+
+        if self.highlight {
+            shapes.push(Shape::rect_stroke(
+                rect,
+                0.0,
+                Stroke::new(1.0, ui.visuals().strong_text_color()),
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.color_map.color_at(0.5)
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        if self.rows == 0 {
+            return None;
+        }
+        let value = transform.value_from_position(point);
+        let [width, height] = self.cell_size();
+        let col = ((value.x - self.bounds.min()[0]) / width).floor();
+        let row = ((self.bounds.max()[1] - value.y) / height).floor();
+        if !(0.0..self.cols as f64).contains(&col) || !(0.0..self.rows as f64).contains(&row) {
+            return None;
+        }
+
+        // Inside the heatmap every cell is hoverable, but items on top of it that are
+        // closer to the pointer (e.g. points) should win:
+        let index = row as usize * self.cols + col as usize;
+        let center = self
+            .screen_rect(&self.cell_bounds(index), transform)
+            .center();
+        Some(ClosestElem {
+            index,
+            dist_sq: point.distance_sq(center).min(HOVER_DIST_SQ),
+        })
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter,
+    ) {
+        let cell = self.screen_rect(&self.cell_bounds(elem.index), plot.transform);
+        shapes.push(Shape::rect_stroke(
+            cell,
+            0.0,
+            Stroke::new(1.0, plot.ui.visuals().strong_text_color()),
+        ));
+
+        if !plot.show_label {
+            return;
+        }
+
+        let value = format_with_decimals_in_range(self.values[elem.index], 0..=6);
+        let text = if self.name.is_empty() {
+            value
+        } else {
+            format!("{}\n{value}", self.name)
+        };
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                cell.right_top() + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+}
+
+/// Just below the squared hover radius of the plot, so that cells are always hoverable.
+const HOVER_DIST_SQ: f32 = 15.0 * 15.0;
+
+/// A vertical bar showing the colors of a [`ColorMap`], labeled with the values they stand for.
+///
+/// See [`Heatmap::color_bar`].
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct ColorBar {
+    color_map: ColorMap,
+    range: RangeInclusive<f64>,
+    size: Vec2,
+}
+
+impl ColorBar {
+    pub fn new(color_map: ColorMap, range: RangeInclusive<f64>) -> Self {
+        Self {
+            color_map,
+            range,
+            size: vec2(16.0, 200.0),
+        }
+    }
+
+    /// The size of the colored bar, without the labels. Default: `16 x 200`.
+    #[inline]
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Widget for ColorBar {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            color_map,
+            range,
+            size,
+        } = self;
+
+        let labels = [
+            *range.end(),
+            0.5 * (range.start() + range.end()),
+            *range.start(),
+        ]
+        .map(|value| format_with_decimals_in_range(value, 0..=3));
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let label_width = ui.fonts(|f| {
+            labels
+                .iter()
+                .map(|label| {
+                    f.layout_no_wrap(label.clone(), font_id.clone(), Color32::PLACEHOLDER)
+                        .size()
+                        .x
+                })
+                .fold(0.0, f32::max)
+        });
+        let spacing = ui.spacing().item_spacing.x;
+        let (rect, response) =
+            ui.allocate_exact_size(size + vec2(spacing + label_width, 0.0), Sense::hover());
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        // One band per color of the map, from the end of the range at the top:
+        let bar = Rect::from_min_size(rect.min, size);
+        let bands = color_map.colors.len().max(2) - 1;
+        let mut mesh = Mesh::default();
+        for band in 0..bands {
+            let (t_top, t_bottom) = (
+                1.0 - band as f32 / bands as f32,
+                1.0 - (band + 1) as f32 / bands as f32,
+            );
+            let (top, bottom) = (
+                lerp(bar.bottom()..=bar.top(), t_top),
+                lerp(bar.bottom()..=bar.top(), t_bottom),
+            );
+            let (top_color, bottom_color) = (
+                color_map.color_at(t_top as f64),
+                color_map.color_at(t_bottom as f64),
+            );
+            let first = mesh.vertices.len() as u32;
+            mesh.colored_vertex(pos2(bar.left(), top), top_color);
+            mesh.colored_vertex(pos2(bar.right(), top), top_color);
+            mesh.colored_vertex(pos2(bar.left(), bottom), bottom_color);
+            mesh.colored_vertex(pos2(bar.right(), bottom), bottom_color);
+            mesh.add_triangle(first, first + 1, first + 2);
+            mesh.add_triangle(first + 2, first + 1, first + 3);
+        }
+        let painter = ui.painter();
+        painter.add(Shape::mesh(mesh));
+        painter.rect_stroke(bar, 0.0, ui.visuals().widgets.noninteractive.bg_stroke);
+
+        let text_color = ui.visuals().text_color();
+        for (label, (y, anchor)) in labels.into_iter().zip([
+            (bar.top(), Align2::LEFT_TOP),
+            (bar.center().y, Align2::LEFT_CENTER),
+            (bar.bottom(), Align2::LEFT_BOTTOM),
+        ]) {
+            painter.text(
+                pos2(bar.right() + spacing, y),
+                anchor,
+                label,
+                font_id.clone(),
+                text_color,
+            );
+        }
+
+        response
+    }
+}
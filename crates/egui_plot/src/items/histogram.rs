@@ -0,0 +1,357 @@
+//! Binning raw samples into a [`BarChart`], see [`Histogram`].
+
+use super::{Bar, BarChart};
+
+/// [`HistogramBins::Width`] and [`HistogramBins::FreedmanDiaconis`] fall back to
+/// [`HistogramBins::Sturges`] if they would give more bins than this.
+const MAX_NUM_BINS: usize = 10_000;
+
+/// How a [`Histogram`] divides its samples into bins.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum HistogramBins {
+    /// Bins of this width, aligned to multiples of it.
+    ///
+    /// Falls back to [`Self::Sturges`] if that would give more than 10 000 bins.
+    Width(f64),
+
+    /// `log2(n) + 1` bins of the same width (Sturges' rule).
+    ///
+    /// Works well for small and normally distributed samples.
+    #[default]
+    Sturges,
+
+    /// Bins of width `2 * IQR / cbrt(n)`, where IQR is the interquartile range (the Freedman–Diaconis rule).
+    ///
+    /// Less sensitive to outliers, and gives more bins for large samples.
+    /// Falls back to [`Self::Sturges`] if the IQR is zero, or if that would give more than 10 000 bins.
+    FreedmanDiaconis,
+
+    /// Explicit bin edges, in increasing order: `n + 1` edges give `n` bins.
+    ///
+    /// The bins may have different widths. Samples outside of the edges are ignored.
+    Edges(Vec<f64>),
+}
+
+/// Builds a [`BarChart`] counting how many samples fall into each bin.
+///
+/// Each bin includes its left edge, except the last one, which includes both edges.
+/// Samples that are NaN or infinite are ignored.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Histogram, HistogramBins, Plot};
+///
+/// let samples = (0..1000).map(|i| (i as f64 * 0.1).sin() + (i as f64 * 0.37).cos());
+/// Plot::new("histogram").show(ui, |plot_ui| {
+///     plot_ui.histogram(
+///         Histogram::new(samples)
+///             .bins(HistogramBins::FreedmanDiaconis)
+///             .density(true),
+///     );
+/// });
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    samples: Vec<f64>,
+    bins: HistogramBins,
+    density: bool,
+    cumulative: bool,
+    name: String,
+}
+
+impl Histogram {
+    pub fn new(samples: impl IntoIterator<Item = f64>) -> Self {
+        Self {
+            samples: samples.into_iter().filter(|x| x.is_finite()).collect(),
+            bins: HistogramBins::default(),
+            density: false,
+            cumulative: false,
+            name: String::new(),
+        }
+    }
+
+    /// How to choose the bins. Default: [`HistogramBins::Sturges`].
+    ///
+    /// # Panics
+    /// If a [`HistogramBins::Width`] is not positive,
+    /// or if [`HistogramBins::Edges`] are fewer than two or not increasing.
+    #[inline]
+    pub fn bins(mut self, bins: HistogramBins) -> Self {
+        match &bins {
+            HistogramBins::Width(width) => {
+                assert!(*width > 0.0, "Histogram bin width must be positive");
+            }
+            HistogramBins::Edges(edges) => {
+                assert!(edges.len() >= 2, "A histogram needs at least two bin edges");
+                assert!(
+                    edges.windows(2).all(|w| w[0] < w[1]),
+                    "Histogram bin edges must be increasing"
+                );
+            }
+            HistogramBins::Sturges | HistogramBins::FreedmanDiaconis => {}
+        }
+        self.bins = bins;
+        self
+    }
+
+    /// Normalize the bars so that their total area is one,
+    /// i.e. show a probability density instead of counts. Default: `false`.
+    ///
+    /// Only the samples within the bins count towards the total.
+    ///
+    /// Together with [`Self::cumulative`], the last bar is one.
+    #[inline]
+    pub fn density(mut self, density: bool) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Let each bar include all the samples in the bins before it. Default: `false`.
+    #[inline]
+    pub fn cumulative(mut self, cumulative: bool) -> Self {
+        self.cumulative = cumulative;
+        self
+    }
+
+    /// Name of the resulting chart.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// The edges of the bins: `n + 1` values for `n` bins.
+    ///
+    /// Empty if there are no samples, unless the edges were given explicitly.
+    pub fn bin_edges(&self) -> Vec<f64> {
+        if let HistogramBins::Edges(edges) = &self.bins {
+            return edges.clone();
+        }
+
+        let Some((min, max)) = min_max(&self.samples) else {
+            return vec![];
+        };
+        if min == max {
+            // All samples are the same, so there is no range to divide:
+            return vec![min - 0.5, max + 0.5];
+        }
+
+        let num_bins = match self.bins {
+            HistogramBins::Width(width) => {
+                let first = (min / width).floor() * width;
+                let num_bins = ((max - first) / width).floor() + 1.0;
+                if num_bins <= MAX_NUM_BINS as f64 {
+                    let num_bins = num_bins as usize;
+                    return (0..=num_bins).map(|i| first + i as f64 * width).collect();
+                }
+                sturges_num_bins(self.samples.len())
+            }
+            HistogramBins::FreedmanDiaconis => {
+                let width = freedman_diaconis_width(&self.samples);
+                let num_bins = ((max - min) / width).ceil();
+                if width > 0.0 && num_bins <= MAX_NUM_BINS as f64 {
+                    num_bins as usize
+                } else {
+                    sturges_num_bins(self.samples.len())
+                }
+            }
+            HistogramBins::Sturges | HistogramBins::Edges(_) => {
+                sturges_num_bins(self.samples.len())
+            }
+        };
+
+        // Evenly spaced, ending exactly at `max` so that it isn't left out by rounding errors:
+        (0..=num_bins)
+            .map(|i| {
+                if i == num_bins {
+                    max
+                } else {
+                    min + (max - min) * (i as f64 / num_bins as f64)
+                }
+            })
+            .collect()
+    }
+
+    /// The height of each bin, i.e. the count (or density) of the samples in it.
+    pub fn bin_values(&self) -> Vec<f64> {
+        self.values_for_edges(&self.bin_edges())
+    }
+
+    fn values_for_edges(&self, edges: &[f64]) -> Vec<f64> {
+        let num_bins = edges.len().saturating_sub(1);
+        let mut counts = vec![0.0; num_bins];
+        if num_bins == 0 {
+            return counts;
+        }
+
+        let (first, last) = (edges[0], edges[num_bins]);
+        for &x in &self.samples {
+            if x < first || last < x {
+                continue;
+            }
+            // The number of edges at or below `x`, so the last edge falls into the last bin:
+            let bin = edges.partition_point(|&edge| edge <= x).clamp(1, num_bins) - 1;
+            counts[bin] += 1.0;
+        }
+        let total: f64 = counts.iter().sum();
+
+        if self.cumulative {
+            let mut sum = 0.0;
+            for count in &mut counts {
+                sum += *count;
+                *count = sum;
+            }
+        }
+
+        if self.density && total > 0.0 {
+            for (count, edge) in counts.iter_mut().zip(edges.windows(2)) {
+                // A cumulative density is a fraction of the samples, not divided by the bin width:
+                let area = if self.cumulative {
+                    total
+                } else {
+                    total * (edge[1] - edge[0])
+                };
+                *count /= area;
+            }
+        }
+
+        counts
+    }
+
+    /// A chart with one bar per bin, filling the bin.
+    pub fn into_bar_chart(self) -> BarChart {
+        let edges = self.bin_edges();
+        let values = self.values_for_edges(&edges);
+        let bars = edges
+            .windows(2)
+            .zip(values)
+            .map(|(edge, value)| {
+                Bar::new(0.5 * (edge[0] + edge[1]), value).width(edge[1] - edge[0])
+            })
+            .collect();
+        BarChart::new(bars).name(self.name)
+    }
+}
+
+impl From<Histogram> for BarChart {
+    fn from(histogram: Histogram) -> Self {
+        histogram.into_bar_chart()
+    }
+}
+
+fn min_max(samples: &[f64]) -> Option<(f64, f64)> {
+    let min = samples.iter().copied().reduce(f64::min)?;
+    let max = samples.iter().copied().reduce(f64::max)?;
+    Some((min, max))
+}
+
+fn sturges_num_bins(num_samples: usize) -> usize {
+    (num_samples as f64).log2().ceil() as usize + 1
+}
+
+fn freedman_diaconis_width(samples: &[f64]) -> f64 {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let iqr = quantile(&sorted, 0.75) - quantile(&sorted, 0.25);
+    2.0 * iqr / (samples.len() as f64).cbrt()
+}
+
+/// Linearly interpolated quantile of sorted, non-empty samples.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lo] + (pos - lo as f64) * (sorted[hi] - sorted[lo])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_per_bin() {
+        let histogram = Histogram::new([0.5, 1.0, 1.5, 2.9, f64::NAN, f64::INFINITY])
+            .bins(HistogramBins::Width(1.0));
+        assert_eq!(histogram.bin_edges(), vec![0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(histogram.bin_values(), vec![1.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn last_edge_is_in_last_bin() {
+        let histogram = Histogram::new([0.0, 1.0, 2.0, 3.0, 4.0, 5.0])
+            .bins(HistogramBins::Edges(vec![0.0, 2.0, 4.0]));
+        // 2.0 is in the second bin, 4.0 on the last edge is too, and 5.0 is outside:
+        assert_eq!(histogram.bin_values(), vec![2.0, 3.0]);
+
+        // Evenly spaced bins end exactly at the largest sample:
+        let histogram = Histogram::new([0.1, 0.2, 0.7]);
+        let edges = histogram.bin_edges();
+        assert_eq!(edges.first(), Some(&0.1));
+        assert_eq!(edges.last(), Some(&0.7));
+        assert_eq!(histogram.bin_values().iter().sum::<f64>(), 3.0);
+    }
+
+    #[test]
+    fn same_samples() {
+        let histogram = Histogram::new([2.0, 2.0, 2.0]);
+        assert_eq!(histogram.bin_edges(), vec![1.5, 2.5]);
+        assert_eq!(histogram.bin_values(), vec![3.0]);
+        assert!(Histogram::new([]).bin_edges().is_empty());
+    }
+
+    #[test]
+    fn sturges() {
+        let histogram = Histogram::new((0..16).map(f64::from));
+        assert_eq!(histogram.bin_edges().len(), 6); // log2(16) + 1 = 5 bins
+    }
+
+    #[test]
+    fn density_has_unit_area() {
+        let histogram = Histogram::new([0.5, 1.5, 1.5, 3.5])
+            .bins(HistogramBins::Edges(vec![0.0, 1.0, 3.0, 4.0]))
+            .density(true);
+        let edges = histogram.bin_edges();
+        let values = histogram.bin_values();
+        assert_eq!(values, vec![0.25, 0.25, 0.25]);
+        let area: f64 = values
+            .iter()
+            .zip(edges.windows(2))
+            .map(|(value, edge)| value * (edge[1] - edge[0]))
+            .sum();
+        assert_eq!(area, 1.0);
+    }
+
+    #[test]
+    fn cumulative() {
+        let histogram = Histogram::new([0.5, 1.5, 1.5, 2.5]).bins(HistogramBins::Width(1.0));
+        assert_eq!(
+            histogram.clone().cumulative(true).bin_values(),
+            vec![1.0, 3.0, 4.0]
+        );
+        assert_eq!(
+            histogram.cumulative(true).density(true).bin_values(),
+            vec![0.25, 0.75, 1.0]
+        );
+    }
+
+    #[test]
+    fn too_many_bins_falls_back_to_sturges() {
+        let samples = [0.0, 1.0, 2.0, 1e9];
+        let histogram = Histogram::new(samples).bins(HistogramBins::Width(1.0));
+        assert_eq!(
+            histogram.bin_edges().len(),
+            sturges_num_bins(samples.len()) + 1
+        );
+
+        // A tiny interquartile range, with one far outlier:
+        let samples = [0.0, 0.0, 1e-9, 1e-9, 1e9];
+        let histogram = Histogram::new(samples).bins(HistogramBins::FreedmanDiaconis);
+        assert_eq!(
+            histogram.bin_edges().len(),
+            sturges_num_bins(samples.len()) + 1
+        );
+    }
+}
@@ -1,7 +1,7 @@
 //! Contains items that can be added to a plot.
 #![allow(clippy::type_complexity)] // TODO(emilk): simplify some of the callback types with type aliases
 
-use std::ops::RangeInclusive;
+use std::{borrow::Cow, ops::RangeInclusive};
 
 use epaint::{emath::Rot2, util::FloatOrd, Mesh};
 
@@ -11,13 +11,24 @@ use super::{Cursor, LabelFormatter, PlotBounds, PlotTransform};
 use rect_elem::*;
 use values::ClosestElem;
 
+pub use band::Band;
 pub use bar::Bar;
 pub use box_elem::{BoxElem, BoxSpread};
-pub use values::{LineStyle, MarkerShape, Orientation, PlotGeometry, PlotPoint, PlotPoints};
-
+pub use heatmap::{ColorBar, ColorMap, Heatmap};
+pub use histogram::{Histogram, HistogramBins};
+pub use streaming::{PlotRingBuffer, StreamingLine};
+pub use values::{
+    LineStyle, MarkerShape, Orientation, PlotGeometry, PlotPoint, PlotPoints, StepKind,
+};
+
+mod band;
 mod bar;
 mod box_elem;
+mod heatmap;
+mod histogram;
+mod point_sprite;
 mod rect_elem;
+mod streaming;
 mod values;
 
 const DEFAULT_FILL_ALPHA: f32 = 0.05;
@@ -28,6 +39,11 @@ pub struct PlotConfig<'a> {
     pub transform: &'a PlotTransform,
     pub show_x: bool,
     pub show_y: bool,
+
+    /// Show a text label next to the hovered element.
+    ///
+    /// `false` when a [`crate::Plot::tooltip_ui`] is shown instead.
+    pub show_label: bool,
 }
 
 /// Trait shared by things that can be drawn in the plot.
@@ -413,6 +429,184 @@ impl PlotItem for VLine {
     }
 }
 
+/// A shaded range of values along one axis, filling the full extent of the plot along the other.
+///
+/// E.g. `Span::new(Axis::X, 2.0..=3.0)` shades everything between x=2 and x=3, across the full height of the plot.
+///
+/// Useful for e.g. marking an interval of interest. Spans are never hovered.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Span {
+    pub(super) axis: Axis,
+    pub(super) range: RangeInclusive<f64>,
+    pub(super) stroke: Stroke,
+    pub(super) fill_color: Option<Color32>,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) include_in_bounds: bool,
+    id: Option<Id>,
+}
+
+impl Span {
+    /// A span of `range` along `axis`.
+    pub fn new(axis: Axis, range: impl Into<RangeInclusive<f64>>) -> Self {
+        Self {
+            axis,
+            range: range.into(),
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            fill_color: None,
+            name: String::default(),
+            highlight: false,
+            include_in_bounds: true,
+            id: None,
+        }
+    }
+
+    /// Highlight this span in the plot by scaling up the border and reducing the fill transparency.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Stroke of the two borders of the span.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Border color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Fill color. Defaults to the border color with added transparency.
+    #[inline]
+    pub fn fill_color(mut self, color: impl Into<Color32>) -> Self {
+        self.fill_color = Some(color.into());
+        self
+    }
+
+    /// Should the automatic bounds of the plot include the range? Default: `true`.
+    #[inline]
+    pub fn include_in_bounds(mut self, include_in_bounds: bool) -> Self {
+        self.include_in_bounds = include_in_bounds;
+        self
+    }
+
+    /// Name of this span.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the span's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl PlotItem for Span {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let Self {
+            axis,
+            range,
+            stroke,
+            fill_color,
+            highlight,
+            ..
+        } = self;
+
+        // Span the range along our axis, and the full plot along the other:
+        let bounds = transform.bounds();
+        let (mut min, mut max) = (bounds.min, bounds.max);
+        min[usize::from(*axis)] = *range.start();
+        max[usize::from(*axis)] = *range.end();
+        let corners = [min, max].map(|[x, y]| {
+            ui.painter()
+                .round_pos_to_pixels(transform.position_from_point(&PlotPoint::new(x, y)))
+        });
+        let rect = Rect::from_two_pos(corners[0], corners[1]);
+
+        let fill_alpha = if *highlight {
+            2.0 * DEFAULT_FILL_ALPHA
+        } else {
+            DEFAULT_FILL_ALPHA
+        };
+        let fill_color = fill_color.unwrap_or(stroke.color.linear_multiply(fill_alpha));
+        shapes.push(Shape::rect_filled(rect, 0.0, fill_color));
+
+        let stroke = if *highlight {
+            Stroke::new(2.0 * stroke.width, stroke.color)
+        } else {
+            *stroke
+        };
+        let edges = match axis {
+            Axis::X => [
+                [rect.left_top(), rect.left_bottom()],
+                [rect.right_top(), rect.right_bottom()],
+            ],
+            Axis::Y => [
+                [rect.left_top(), rect.right_top()],
+                [rect.left_bottom(), rect.right_bottom()],
+            ],
+        };
+        for edge in edges {
+            shapes.push(Shape::line_segment(edge, stroke));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        false
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        if self.include_in_bounds {
+            bounds.min[usize::from(self.axis)] = *self.range.start();
+            bounds.max[usize::from(self.axis)] = *self.range.end();
+        }
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
 /// A series of values forming a path.
 pub struct Line {
     pub(super) series: PlotPoints,
@@ -422,6 +616,7 @@ pub struct Line {
     pub(super) allow_hover: bool,
     pub(super) fill: Option<f32>,
     pub(super) style: LineStyle,
+    pub(super) downsample: bool,
     id: Option<Id>,
 }
 
@@ -435,6 +630,7 @@ impl Line {
             allow_hover: true,
             fill: None,
             style: LineStyle::Solid,
+            downsample: false,
             id: None,
         }
     }
@@ -507,6 +703,55 @@ impl Line {
         self.id = Some(id);
         self
     }
+
+    /// Reduce the number of points drawn when there are many more of them than pixels.
+    ///
+    /// Consecutive points that fall on the same pixel column are replaced by
+    /// the first, lowest, highest and last of them, which looks the same but is much faster
+    /// to paint. Use this for lines with hundreds of thousands of points or more.
+    ///
+    /// Only runs of consecutive points are merged, so this assumes the x values are sorted.
+    /// Unsorted points are still painted correctly, but may not be reduced much.
+    ///
+    /// Hovering still uses all points. Default: `false`.
+    #[inline]
+    pub fn downsample(mut self, downsample: bool) -> Self {
+        self.downsample = downsample;
+        self
+    }
+}
+
+/// Min-max decimation: replace each run of consecutive points on the same pixel column
+/// with the first, lowest, highest and last point of the run, in their original order.
+///
+/// Assumes `values` are sorted by x. Otherwise the result is still correct, as only
+/// neighboring points are merged, but the runs are short and little is gained.
+fn downsample_min_max(values: &[Pos2], pixels_per_point: f32) -> Vec<Pos2> {
+    let column = |pos: Pos2| (pos.x * pixels_per_point).floor();
+    let mut result = Vec::new();
+    let mut start = 0;
+    while start < values.len() {
+        let (mut min, mut max) = (start, start);
+        let mut end = start + 1;
+        while end < values.len() && column(values[end]) == column(values[start]) {
+            if values[end].y < values[min].y {
+                min = end;
+            }
+            if values[end].y > values[max].y {
+                max = end;
+            }
+            end += 1;
+        }
+        let mut picks = [start, min, max, end - 1];
+        picks.sort_unstable();
+        for (i, &pick) in picks.iter().enumerate() {
+            if i == 0 || pick != picks[i - 1] {
+                result.push(values[pick]);
+            }
+        }
+        start = end;
+    }
+    result
 }
 
 /// Returns the x-coordinate of a possible intersection between a line segment from `p1` to `p2` and
@@ -517,28 +762,36 @@ fn y_intersection(p1: &Pos2, p2: &Pos2, y: f32) -> Option<f32> {
 }
 
 impl PlotItem for Line {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let Self {
             series,
             stroke,
             highlight,
             mut fill,
             style,
+            downsample,
             ..
         } = self;
 
-        let values_tf: Vec<_> = series
+        let mut values_tf: Vec<_> = series
             .points()
             .iter()
             .map(|v| transform.position_from_point(v))
             .collect();
-        let n_values = values_tf.len();
-
+        if *downsample {
+            values_tf = downsample_min_max(&values_tf, ui.ctx().pixels_per_point());
+        }
         // Fill the area between the line and a reference line, if required.
-        if n_values < 2 {
+        if values_tf.len() < 2 {
             fill = None;
         }
         if let Some(y_reference) = fill {
+            // The fill follows the steps of a step line:
+            let values_tf: Cow<'_, [Pos2]> = match style {
+                LineStyle::Step(kind) => Cow::Owned(kind.steps(&values_tf)),
+                _ => Cow::Borrowed(&values_tf),
+            };
+            let n_values = values_tf.len();
             let mut fill_alpha = DEFAULT_FILL_ALPHA;
             if *highlight {
                 fill_alpha = (2.0 * fill_alpha).at_most(1.0);
@@ -613,6 +866,158 @@ impl PlotItem for Line {
     }
 }
 
+/// A vertical line from a baseline to each point, e.g. for discrete signals.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Impulses, Plot, PlotPoints};
+///
+/// let samples: PlotPoints = (0..20).map(|i| [i as f64, (i as f64 * 0.5).sin()]).collect();
+/// Plot::new("impulses").show(ui, |plot_ui| {
+///     plot_ui.impulses(Impulses::new(samples).name("samples"));
+/// });
+/// # });
+/// ```
+pub struct Impulses {
+    pub(super) series: PlotPoints,
+    pub(super) stroke: Stroke,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) allow_hover: bool,
+    pub(super) baseline: f64,
+    id: Option<Id>,
+}
+
+impl Impulses {
+    pub fn new(series: impl Into<PlotPoints>) -> Self {
+        Self {
+            series: series.into(),
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            baseline: 0.0,
+            id: None,
+        }
+    }
+
+    /// Highlight these impulses in the plot by scaling up the lines.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// The y value the lines start from. Default: `0.0`.
+    #[inline]
+    pub fn baseline(mut self, baseline: impl Into<f64>) -> Self {
+        self.baseline = baseline.into();
+        self
+    }
+
+    /// Name of these impulses.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the id which is used to identify these impulses in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl PlotItem for Impulses {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut stroke = self.stroke;
+        if self.highlight {
+            stroke.width *= 2.0;
+        }
+        for point in self.series.points() {
+            let base = transform.position_from_point(&PlotPoint::new(point.x, self.baseline));
+            let tip = transform.position_from_point(point);
+            shapes.push(Shape::line_segment([base, tip], stroke));
+        }
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.series.points())
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = self.series.bounds();
+        if bounds.is_valid() {
+            bounds.extend_with_y(self.baseline);
+        }
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
 /// A convex polygon.
 pub struct Polygon {
     pub(super) series: PlotPoints,
@@ -775,6 +1180,7 @@ pub struct Text {
     pub(super) allow_hover: bool,
     pub(super) color: Color32,
     pub(super) anchor: Align2,
+    pub(super) include_in_bounds: bool,
     id: Option<Id>,
 }
 
@@ -788,6 +1194,7 @@ impl Text {
             allow_hover: true,
             color: Color32::TRANSPARENT,
             anchor: Align2::CENTER_CENTER,
+            include_in_bounds: true,
             id: None,
         }
     }
@@ -799,28 +1206,193 @@ impl Text {
         self
     }
 
-    /// Allowed hovering this item in the plot. Default: `true`.
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Text color.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Anchor position of the text. Default is `Align2::CENTER_CENTER`.
+    #[inline]
+    pub fn anchor(mut self, anchor: Align2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Should the automatic bounds of the plot include the position of the text? Default: `true`.
+    #[inline]
+    pub fn include_in_bounds(mut self, include_in_bounds: bool) -> Self {
+        self.include_in_bounds = include_in_bounds;
+        self
+    }
+
+    /// Name of this text.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the text's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl PlotItem for Text {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let color = if self.color == Color32::TRANSPARENT {
+            ui.style().visuals.text_color()
+        } else {
+            self.color
+        };
+
+        let galley =
+            self.text
+                .clone()
+                .into_galley(ui, Some(false), f32::INFINITY, TextStyle::Small);
+
+        let pos = transform.position_from_point(&self.position);
+        let rect = self.anchor.anchor_size(pos, galley.size());
+
+        shapes.push(epaint::TextShape::new(rect.min, galley, color).into());
+
+        if self.highlight {
+            shapes.push(Shape::rect_stroke(
+                rect.expand(2.0),
+                1.0,
+                Stroke::new(0.5, color),
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        if self.include_in_bounds {
+            bounds.extend_with(&self.position);
+        }
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
+/// A text box pointing at a position in the plot with a leader line, e.g. to point out a peak.
+///
+/// The box is placed at a fixed offset (in points) from the position, so it keeps its size when zooming.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Callout, Plot, PlotPoint};
+///
+/// Plot::new("callout").show(ui, |plot_ui| {
+///     plot_ui.callout(
+///         Callout::new(PlotPoint::new(2.0, 4.0), "Peak").offset(egui::vec2(40.0, -30.0)),
+///     );
+/// });
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct Callout {
+    pub(super) text: WidgetText,
+    pub(super) target: PlotPoint,
+    pub(super) offset: Vec2,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) color: Color32,
+    pub(super) include_in_bounds: bool,
+    id: Option<Id>,
+}
+
+impl Callout {
+    pub fn new(target: PlotPoint, text: impl Into<WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            target,
+            offset: vec2(30.0, -30.0),
+            name: Default::default(),
+            highlight: false,
+            color: Color32::TRANSPARENT,
+            include_in_bounds: true,
+            id: None,
+        }
+    }
+
+    /// Where to put the center of the text box, relative to the target, in points.
+    ///
+    /// Default: up and to the right of the target.
+    #[inline]
+    pub fn offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Highlight this callout in the plot by drawing a thicker outline.
     #[inline]
-    pub fn allow_hover(mut self, hovering: bool) -> Self {
-        self.allow_hover = hovering;
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
         self
     }
 
-    /// Text color.
+    /// Color of the text, box outline and leader line. Defaults to the text color.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
         self.color = color.into();
         self
     }
 
-    /// Anchor position of the text. Default is `Align2::CENTER_CENTER`.
+    /// Should the automatic bounds of the plot include the target? Default: `true`.
     #[inline]
-    pub fn anchor(mut self, anchor: Align2) -> Self {
-        self.anchor = anchor;
+    pub fn include_in_bounds(mut self, include_in_bounds: bool) -> Self {
+        self.include_in_bounds = include_in_bounds;
         self
     }
 
-    /// Name of this text.
+    /// Name of this callout.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
     ///
@@ -833,7 +1405,7 @@ impl Text {
         self
     }
 
-    /// Set the text's id which is used to identify it in the plot's response.
+    /// Set the callout's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
@@ -841,31 +1413,37 @@ impl Text {
     }
 }
 
-impl PlotItem for Text {
+impl PlotItem for Callout {
     fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let color = if self.color == Color32::TRANSPARENT {
             ui.style().visuals.text_color()
         } else {
             self.color
         };
+        let stroke = Stroke::new(if self.highlight { 2.0 } else { 1.0 }, color);
 
         let galley =
             self.text
                 .clone()
                 .into_galley(ui, Some(false), f32::INFINITY, TextStyle::Small);
 
-        let pos = transform.position_from_point(&self.position);
-        let rect = self.anchor.anchor_size(pos, galley.size());
-
-        shapes.push(epaint::TextShape::new(rect.min, galley, color).into());
+        let target = transform.position_from_point(&self.target);
+        let text_rect = Rect::from_center_size(target + self.offset, galley.size());
+        let box_rect = text_rect.expand(4.0);
 
-        if self.highlight {
-            shapes.push(Shape::rect_stroke(
-                rect.expand(2.0),
-                1.0,
-                Stroke::new(0.5, color),
-            ));
+        // The leader line goes from the target to the closest point of the box:
+        let attachment = box_rect.clamp(target);
+        if attachment != target {
+            shapes.push(Shape::line_segment([target, attachment], stroke));
         }
+        shapes.push(Shape::circle_filled(target, 2.0, color));
+        shapes.push(Shape::rect_filled(
+            box_rect,
+            2.0,
+            ui.visuals().extreme_bg_color,
+        ));
+        shapes.push(Shape::rect_stroke(box_rect, 2.0, stroke));
+        shapes.push(epaint::TextShape::new(text_rect.min, galley, color).into());
     }
 
     fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
@@ -887,7 +1465,7 @@ impl PlotItem for Text {
     }
 
     fn allow_hover(&self) -> bool {
-        self.allow_hover
+        false
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
@@ -896,7 +1474,9 @@ impl PlotItem for Text {
 
     fn bounds(&self) -> PlotBounds {
         let mut bounds = PlotBounds::NOTHING;
-        bounds.extend_with(&self.position);
+        if self.include_in_bounds {
+            bounds.extend_with(&self.target);
+        }
         bounds
     }
 
@@ -1017,7 +1597,7 @@ impl Points {
 }
 
 impl PlotItem for Points {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let sqrt_3 = 3_f32.sqrt();
         let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
         let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
@@ -1050,8 +1630,26 @@ impl PlotItem for Points {
 
         let y_reference = stems.map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
 
-        series
-            .points()
+        let points = series.points();
+        if points.len() >= point_sprite::POINT_SPRITE_THRESHOLD {
+            // Tessellating each marker would be slow, so paint copies of one rasterized marker:
+            let centers = points
+                .iter()
+                .map(|value| transform.position_from_point(value));
+            if let Some(y) = y_reference {
+                shapes.extend(
+                    centers.clone().map(|center| {
+                        Shape::line_segment([center, pos2(center.x, y)], stem_stroke)
+                    }),
+                );
+            }
+            shapes.push(point_sprite::point_sprites(
+                ui, *shape, *filled, radius, *color, centers,
+            ));
+            return;
+        }
+
+        points
             .iter()
             .map(|value| transform.position_from_point(value))
             .for_each(|center| {
@@ -1182,11 +1780,27 @@ impl PlotItem for Points {
     }
 }
 
+/// The shape of the tips of [`Arrows`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ArrowHead {
+    /// Two lines.
+    #[default]
+    Open,
+
+    /// A filled triangle.
+    Filled,
+
+    /// No head, just a line.
+    None,
+}
+
 /// A set of arrows.
 pub struct Arrows {
     pub(super) origins: PlotPoints,
     pub(super) tips: PlotPoints,
     pub(super) tip_length: Option<f32>,
+    pub(super) head: ArrowHead,
+    pub(super) include_in_bounds: bool,
     pub(super) color: Color32,
     pub(super) name: String,
     pub(super) highlight: bool,
@@ -1200,6 +1814,8 @@ impl Arrows {
             origins: origins.into(),
             tips: tips.into(),
             tip_length: None,
+            head: ArrowHead::default(),
+            include_in_bounds: true,
             color: Color32::TRANSPARENT,
             name: Default::default(),
             highlight: false,
@@ -1229,6 +1845,13 @@ impl Arrows {
         self
     }
 
+    /// Set the shape of the arrow tips. Default is [`ArrowHead::Open`].
+    #[inline]
+    pub fn head(mut self, head: ArrowHead) -> Self {
+        self.head = head;
+        self
+    }
+
     /// Set the arrows' color.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
@@ -1236,6 +1859,13 @@ impl Arrows {
         self
     }
 
+    /// Should the automatic bounds of the plot include the origins of the arrows? Default: `true`.
+    #[inline]
+    pub fn include_in_bounds(mut self, include_in_bounds: bool) -> Self {
+        self.include_in_bounds = include_in_bounds;
+        self
+    }
+
     /// Name of this set of arrows.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -1264,6 +1894,7 @@ impl PlotItem for Arrows {
             origins,
             tips,
             tip_length,
+            head,
             color,
             highlight,
             ..
@@ -1289,15 +1920,26 @@ impl PlotItem for Arrows {
                 };
                 let tip = origin + vector;
                 let dir = vector.normalized();
-                shapes.push(Shape::line_segment([origin, tip], stroke));
-                shapes.push(Shape::line(
-                    vec![
-                        tip - tip_length * (rot.inverse() * dir),
-                        tip,
-                        tip - tip_length * (rot * dir),
-                    ],
-                    stroke,
-                ));
+                let head_points = vec![
+                    tip - tip_length * (rot.inverse() * dir),
+                    tip,
+                    tip - tip_length * (rot * dir),
+                ];
+                match head {
+                    ArrowHead::Open => {
+                        shapes.push(Shape::line_segment([origin, tip], stroke));
+                        shapes.push(Shape::line(head_points, stroke));
+                    }
+                    ArrowHead::Filled => {
+                        // Stop the line at the base of the head, so it doesn't poke through the tip:
+                        let base = tip - tip_length * rot.angle().cos() * dir;
+                        shapes.push(Shape::line_segment([origin, base], stroke));
+                        shapes.push(Shape::convex_polygon(head_points, *color, stroke));
+                    }
+                    ArrowHead::None => {
+                        shapes.push(Shape::line_segment([origin, tip], stroke));
+                    }
+                }
             });
     }
 
@@ -1332,7 +1974,268 @@ impl PlotItem for Arrows {
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.origins.bounds()
+        if self.include_in_bounds {
+            self.origins.bounds()
+        } else {
+            PlotBounds::NOTHING
+        }
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
+/// Error bars around a set of points, e.g. showing the uncertainty of measurements.
+///
+/// Each point gets a vertical whisker for its y error and/or a horizontal whisker for its x error,
+/// with caps at the ends. The errors are given per point, in the same order as the points.
+///
+/// To show the points themselves too, add [`Points`] with the same name and color:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{ErrorBars, Plot, Points};
+///
+/// let measurements = vec![[1.0, 2.0], [2.0, 2.5], [3.0, 2.2]];
+/// Plot::new("measurements").show(ui, |plot_ui| {
+///     plot_ui.error_bars(
+///         ErrorBars::new(measurements.clone())
+///             .y_errors([0.3, 0.2, 0.4])
+///             .name("measured")
+///             .color(egui::Color32::RED),
+///     );
+///     plot_ui.points(Points::new(measurements).name("measured").color(egui::Color32::RED));
+/// });
+/// # });
+/// ```
+pub struct ErrorBars {
+    pub(super) points: PlotPoints,
+
+    /// `(below, above)` for each point, as positive distances.
+    pub(super) y_errors: Vec<(f64, f64)>,
+
+    /// `(left, right)` for each point, as positive distances.
+    pub(super) x_errors: Vec<(f64, f64)>,
+
+    pub(super) cap_width: f32,
+    pub(super) stroke: Stroke,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) allow_hover: bool,
+    id: Option<Id>,
+}
+
+impl ErrorBars {
+    pub fn new(points: impl Into<PlotPoints>) -> Self {
+        Self {
+            points: points.into(),
+            y_errors: Vec::new(),
+            x_errors: Vec::new(),
+            cap_width: 6.0,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            id: None,
+        }
+    }
+
+    /// The same error below and above each point.
+    #[inline]
+    pub fn y_errors(mut self, errors: impl IntoIterator<Item = f64>) -> Self {
+        self.y_errors = errors.into_iter().map(|e| (e.abs(), e.abs())).collect();
+        self
+    }
+
+    /// Different errors `(below, above)` each point.
+    #[inline]
+    pub fn y_errors_asymmetric(mut self, errors: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        self.y_errors = errors
+            .into_iter()
+            .map(|(below, above)| (below.abs(), above.abs()))
+            .collect();
+        self
+    }
+
+    /// The same error left and right of each point.
+    #[inline]
+    pub fn x_errors(mut self, errors: impl IntoIterator<Item = f64>) -> Self {
+        self.x_errors = errors.into_iter().map(|e| (e.abs(), e.abs())).collect();
+        self
+    }
+
+    /// Different errors `(left, right)` of each point.
+    #[inline]
+    pub fn x_errors_asymmetric(mut self, errors: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        self.x_errors = errors
+            .into_iter()
+            .map(|(left, right)| (left.abs(), right.abs()))
+            .collect();
+        self
+    }
+
+    /// Width of the caps at the ends of the whiskers, in UI points. `0.0` for no caps. Default: `6.0`.
+    #[inline]
+    pub fn cap_width(mut self, cap_width: impl Into<f32>) -> Self {
+        self.cap_width = cap_width.into();
+        self
+    }
+
+    /// Highlight these error bars in the plot by making them thicker.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Name of this set of error bars.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the error bars' id which is used to identify them in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The ends of the whiskers of the point with the given index, as `[start, end]` pairs.
+    fn whiskers(&self, index: usize, point: &PlotPoint) -> [Option<[PlotPoint; 2]>; 2] {
+        let y = self.y_errors.get(index).map(|&(below, above)| {
+            [
+                PlotPoint::new(point.x, point.y - below),
+                PlotPoint::new(point.x, point.y + above),
+            ]
+        });
+        let x = self.x_errors.get(index).map(|&(left, right)| {
+            [
+                PlotPoint::new(point.x - left, point.y),
+                PlotPoint::new(point.x + right, point.y),
+            ]
+        });
+        [x, y]
+    }
+}
+
+impl PlotItem for ErrorBars {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut stroke = self.stroke;
+        if self.highlight {
+            stroke.width *= 2.0;
+        }
+        let half_cap = 0.5 * self.cap_width;
+
+        for (index, point) in self.points.points().iter().enumerate() {
+            let [x, y] = self.whiskers(index, point);
+            if let Some([start, end]) = x {
+                let (start, end) = (
+                    transform.position_from_point(&start),
+                    transform.position_from_point(&end),
+                );
+                shapes.push(Shape::line_segment([start, end], stroke));
+                if half_cap > 0.0 {
+                    for end in [start, end] {
+                        shapes.push(Shape::line_segment(
+                            [end - vec2(0.0, half_cap), end + vec2(0.0, half_cap)],
+                            stroke,
+                        ));
+                    }
+                }
+            }
+            if let Some([start, end]) = y {
+                let (start, end) = (
+                    transform.position_from_point(&start),
+                    transform.position_from_point(&end),
+                );
+                shapes.push(Shape::line_segment([start, end], stroke));
+                if half_cap > 0.0 {
+                    for end in [start, end] {
+                        shapes.push(Shape::line_segment(
+                            [end - vec2(half_cap, 0.0), end + vec2(half_cap, 0.0)],
+                            stroke,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.points.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.points.points())
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for (index, point) in self.points.points().iter().enumerate() {
+            bounds.extend_with(point);
+            for [start, end] in self.whiskers(index, point).into_iter().flatten() {
+                bounds.extend_with(&start);
+                bounds.extend_with(&end);
+            }
+        }
+        bounds
     }
 
     fn id(&self) -> Option<Id> {
@@ -1550,6 +2453,9 @@ pub struct BarChart {
     /// A custom element formatter
     pub(super) element_formatter: Option<Box<dyn Fn(&Bar, &BarChart) -> String>>,
 
+    /// Set by [`Self::stack_on`], to show the totals when hovering.
+    pub(super) stacked: bool,
+
     highlight: bool,
     allow_hover: bool,
     id: Option<Id>,
@@ -1563,6 +2469,7 @@ impl BarChart {
             default_color: Color32::TRANSPARENT,
             name: String::new(),
             element_formatter: None,
+            stacked: false,
             highlight: false,
             allow_hover: true,
             id: None,
@@ -1651,25 +2558,39 @@ impl BarChart {
     /// Stacks the bars on top of another chart.
     /// Positive values are stacked on top of other positive values.
     /// Negative values are stacked below other negative values.
+    ///
+    /// Each bar is stacked on the bars of the other charts at the same argument,
+    /// so the charts don't need to have the same number of bars.
+    /// When hovering a stacked bar, both its own value and the total of the stack are shown.
+    ///
+    /// To stack several charts, stack each one on all of the previous ones.
     #[inline]
     pub fn stack_on(mut self, others: &[&Self]) -> Self {
         for (index, bar) in self.bars.iter_mut().enumerate() {
+            let below = others.iter().filter_map(|other_chart| {
+                // Usually the charts have bars at the same arguments, in the same order:
+                other_chart
+                    .bars
+                    .get(index)
+                    .filter(|other| other.argument == bar.argument)
+                    .or_else(|| {
+                        other_chart
+                            .bars
+                            .iter()
+                            .find(|other| other.argument == bar.argument)
+                    })
+            });
             let new_base_offset = if bar.value.is_sign_positive() {
-                others
-                    .iter()
-                    .filter_map(|other_chart| other_chart.bars.get(index).map(|bar| bar.upper()))
-                    .max_by_key(|value| value.ord())
+                below.map(|bar| bar.upper()).max_by_key(|value| value.ord())
             } else {
-                others
-                    .iter()
-                    .filter_map(|other_chart| other_chart.bars.get(index).map(|bar| bar.lower()))
-                    .min_by_key(|value| value.ord())
+                below.map(|bar| bar.lower()).min_by_key(|value| value.ord())
             };
 
             if let Some(value) = new_base_offset {
                 bar.base_offset = Some(value);
             }
         }
+        self.stacked |= !others.is_empty();
         self
     }
 
@@ -1747,6 +2668,129 @@ impl PlotItem for BarChart {
     }
 }
 
+/// Bar charts placed side by side, e.g. to compare several series in each category.
+///
+/// Each chart (or stack of charts) gets its own slot within the width of the group,
+/// so bars at the same argument are shown next to each other instead of on top of each other.
+/// The charts keep their own names and colors, so they each get one legend entry.
+///
+/// Add it with [`crate::PlotUi::bar_group`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Bar, BarChart, BarGroup, Plot};
+///
+/// let series = |name: &str, values: [f64; 3]| {
+///     let bars = values
+///         .iter()
+///         .enumerate()
+///         .map(|(category, &value)| Bar::new(category as f64, value))
+///         .collect();
+///     BarChart::new(bars).name(name)
+/// };
+///
+/// let group = BarGroup::new()
+///     .chart(series("2022", [3.0, 5.0, 2.0]))
+///     .chart(series("2023", [4.0, 4.5, 3.0]))
+///     .stack(vec![
+///         series("2024 (confirmed)", [4.0, 5.0, 2.5]),
+///         series("2024 (expected)", [1.0, 0.5, 1.0]),
+///     ]);
+///
+/// Plot::new("sales").show(ui, |plot_ui| plot_ui.bar_group(group));
+/// # });
+/// ```
+#[derive(Default)]
+pub struct BarGroup {
+    /// The charts of each slot, stacked on each other in order.
+    pub(super) slots: Vec<Vec<BarChart>>,
+
+    width: Option<f64>,
+    gap: f64,
+}
+
+impl BarGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chart in the next slot.
+    #[inline]
+    pub fn chart(mut self, chart: BarChart) -> Self {
+        self.slots.push(vec![chart]);
+        self
+    }
+
+    /// Add charts stacked on each other (first chart at the bottom) in the next slot,
+    /// see [`BarChart::stack_on`].
+    #[inline]
+    pub fn stack(mut self, charts: Vec<BarChart>) -> Self {
+        if !charts.is_empty() {
+            self.slots.push(charts);
+        }
+        self
+    }
+
+    /// The width of a whole group of bars, along the argument axis.
+    ///
+    /// Default: `0.8` times the distance between the arguments of the bars, so that
+    /// neighboring groups don't touch.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// The fraction of each slot left empty between neighboring bars, in `[0, 1)`.
+    ///
+    /// Default: `0.0`.
+    #[inline]
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = gap.clamp(0.0, 0.99);
+        self
+    }
+
+    /// The charts with the bars moved to their slots and stacked.
+    pub(crate) fn into_charts(self) -> Vec<BarChart> {
+        let Self { slots, width, gap } = self;
+
+        let width = width.unwrap_or_else(|| 0.8 * smallest_argument_distance(&slots));
+        let slot_width = width / slots.len().max(1) as f64;
+
+        let mut result = Vec::new();
+        for (index, slot) in slots.into_iter().enumerate() {
+            let offset = (index as f64 + 0.5) * slot_width - 0.5 * width;
+            let mut stack: Vec<BarChart> = Vec::new();
+            for mut chart in slot {
+                for bar in &mut chart.bars {
+                    bar.argument += offset;
+                    bar.bar_width = slot_width * (1.0 - gap);
+                }
+                let chart = chart.stack_on(&stack.iter().collect::<Vec<_>>());
+                stack.push(chart);
+            }
+            result.extend(stack);
+        }
+        result
+    }
+}
+
+/// The smallest distance between two different arguments of the bars, or `1.0` if there is only one.
+fn smallest_argument_distance(slots: &[Vec<BarChart>]) -> f64 {
+    let mut arguments: Vec<f64> = slots
+        .iter()
+        .flatten()
+        .flat_map(|chart| chart.bars.iter().map(|bar| bar.argument))
+        .collect();
+    arguments.sort_by_key(|argument| argument.ord());
+    arguments
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|distance| *distance > 0.0)
+        .min_by_key(|distance| distance.ord())
+        .unwrap_or(1.0)
+}
+
 /// A diagram containing a series of [`BoxElem`] elements.
 pub struct BoxPlot {
     pub(super) boxes: Vec<BoxElem>,
@@ -1995,6 +3039,10 @@ fn add_rulers_and_text(
         }
     }
 
+    if !plot.show_label {
+        return;
+    }
+
     // Text
     let text = text.unwrap_or({
         let mut text = elem.name().to_owned(); // could be empty
@@ -2041,6 +3089,10 @@ pub(super) fn rulers_at_value(
         cursors.push(Cursor::Horizontal { y: value.y });
     }
 
+    if !plot.show_label {
+        return;
+    }
+
     let prefix = if name.is_empty() {
         String::new()
     } else {
@@ -2099,3 +3151,75 @@ where
         })
         .min_by_key(|e| e.dist_sq.ord())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn downsample_keeps_first_min_max_last_of_each_column() {
+        let values = [
+            // Column 0:
+            pos2(0.1, 5.0),
+            pos2(0.2, 9.0),
+            pos2(0.3, 1.0),
+            pos2(0.4, 7.0),
+            pos2(0.5, 6.0),
+            // Column 1:
+            pos2(1.2, 3.0),
+            pos2(1.6, 4.0),
+        ];
+        assert_eq!(
+            downsample_min_max(&values, 1.0),
+            vec![
+                pos2(0.1, 5.0),
+                pos2(0.2, 9.0),
+                pos2(0.3, 1.0),
+                pos2(0.5, 6.0),
+                pos2(1.2, 3.0),
+                pos2(1.6, 4.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn downsample_uses_physical_pixels() {
+        let values = [
+            pos2(0.1, 1.0),
+            pos2(0.3, 2.0),
+            pos2(0.6, 3.0),
+            pos2(0.9, 4.0),
+        ];
+        assert_eq!(downsample_min_max(&values, 1.0), vec![values[0], values[3]]);
+        assert_eq!(downsample_min_max(&values, 2.0), values.to_vec());
+    }
+
+    #[test]
+    fn downsample_unsorted_keeps_all_points() {
+        let values = [
+            pos2(0.5, 1.0),
+            pos2(3.5, 2.0),
+            pos2(0.5, 3.0),
+            pos2(3.5, 4.0),
+        ];
+        assert_eq!(downsample_min_max(&values, 1.0), values.to_vec());
+    }
+
+    #[test]
+    fn span_bounds_follow_axis() {
+        let x_span = Span::new(Axis::X, 2.0..=3.0);
+        let bounds = x_span.bounds();
+        assert_eq!(bounds.range_x(), 2.0..=3.0);
+        assert!(!bounds.is_valid_y());
+
+        let y_span = Span::new(Axis::Y, -1.0..=4.0);
+        let bounds = y_span.bounds();
+        assert_eq!(bounds.range_y(), -1.0..=4.0);
+        assert!(!bounds.is_valid_x());
+
+        let bounds = Span::new(Axis::X, 2.0..=3.0)
+            .include_in_bounds(false)
+            .bounds();
+        assert!(!bounds.is_valid_x());
+    }
+}
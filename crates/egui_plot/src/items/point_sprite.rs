@@ -0,0 +1,256 @@
+//! Painting big point clouds as textured quads.
+//!
+//! Tessellating every marker of a [`super::Points`] separately produces a lot of vertices,
+//! especially for circles. Above [`POINT_SPRITE_THRESHOLD`] points we instead rasterize the marker
+//! once into a small texture, and paint each point as a quad (four vertices) using that texture.
+
+use egui::{
+    epaint::{ColorImage, Mesh, TextureHandle},
+    pos2, Color32, Context, Id, Pos2, Rect, Shape, TextureOptions, Ui, Vec2,
+};
+
+use super::MarkerShape;
+
+/// [`super::Points`] with at least this many points are painted as sprites.
+pub(super) const POINT_SPRITE_THRESHOLD: usize = 10_000;
+
+/// Number of samples along each side of a pixel when rasterizing a marker.
+const SAMPLES_PER_PIXEL_SIDE: usize = 4;
+
+/// Paint a marker at each of the given centers, as copies of one cached rasterized marker.
+///
+/// The marker looks the same as when painted by [`super::Points`] with the same settings.
+pub(super) fn point_sprites(
+    ui: &Ui,
+    shape: MarkerShape,
+    filled: bool,
+    radius: f32,
+    color: Color32,
+    centers: impl ExactSizeIterator<Item = Pos2>,
+) -> Shape {
+    let pixels_per_point = ui.ctx().pixels_per_point();
+    let texture = sprite_texture(ui.ctx(), shape, filled, radius, pixels_per_point);
+    let size = Vec2::splat(texture.size()[0] as f32 / pixels_per_point);
+    let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+
+    let mut mesh = Mesh::with_texture(texture.id());
+    mesh.reserve_vertices(4 * centers.len());
+    mesh.reserve_triangles(2 * centers.len());
+    for center in centers {
+        mesh.add_rect_with_uv(Rect::from_center_size(center, size), uv, color);
+    }
+    Shape::mesh(mesh)
+}
+
+/// The rasterized marker, in white so that it can be tinted with the vertex colors.
+///
+/// Cached in the temporary data of the [`Context`], since the same few markers are used every frame.
+fn sprite_texture(
+    ctx: &Context,
+    shape: MarkerShape,
+    filled: bool,
+    radius: f32,
+    pixels_per_point: f32,
+) -> TextureHandle {
+    let id = Id::new((
+        "egui_plot_point_sprite",
+        shape,
+        filled,
+        radius.to_bits(),
+        pixels_per_point.to_bits(),
+    ));
+    if let Some(texture) = ctx.data(|d| d.get_temp::<TextureHandle>(id)) {
+        return texture;
+    }
+
+    let image = rasterize(
+        &MarkerGeometry::new(shape, filled, radius),
+        pixels_per_point,
+    );
+    let texture = ctx.load_texture(
+        format!("egui_plot_point_sprite_{shape:?}"),
+        image,
+        TextureOptions::LINEAR,
+    );
+    ctx.data_mut(|d| d.insert_temp(id, texture.clone()));
+    texture
+}
+
+fn rasterize(geometry: &MarkerGeometry, pixels_per_point: f32) -> ColorImage {
+    // One pixel of margin on each side, so that the edges are anti-aliased:
+    let side = (2.0 * geometry.extent() * pixels_per_point).ceil() as usize + 2;
+    let center = 0.5 * side as f32;
+    let samples = SAMPLES_PER_PIXEL_SIDE * SAMPLES_PER_PIXEL_SIDE;
+
+    let mut pixels = Vec::with_capacity(side * side);
+    for y in 0..side {
+        for x in 0..side {
+            let mut covered = 0;
+            for sy in 0..SAMPLES_PER_PIXEL_SIDE {
+                for sx in 0..SAMPLES_PER_PIXEL_SIDE {
+                    let sample = Vec2::new(
+                        x as f32 + (sx as f32 + 0.5) / SAMPLES_PER_PIXEL_SIDE as f32 - center,
+                        y as f32 + (sy as f32 + 0.5) / SAMPLES_PER_PIXEL_SIDE as f32 - center,
+                    );
+                    if geometry.contains(sample / pixels_per_point) {
+                        covered += 1;
+                    }
+                }
+            }
+            let alpha = (255 * covered + samples / 2) / samples;
+            pixels.push(Color32::from_white_alpha(alpha as u8));
+        }
+    }
+
+    ColorImage {
+        size: [side, side],
+        pixels,
+    }
+}
+
+/// The shape of a marker, relative to its center, in points.
+///
+/// Mirrors the shapes painted by [`super::Points`].
+struct MarkerGeometry {
+    kind: GeometryKind,
+
+    /// Half the width of the outlines and lines.
+    half_width: f32,
+}
+
+enum GeometryKind {
+    Disc(f32),
+    Circle(f32),
+    Polygon(Vec<Vec2>),
+    Outline(Vec<Vec2>),
+    Lines(Vec<[Vec2; 2]>),
+}
+
+impl MarkerGeometry {
+    fn new(shape: MarkerShape, filled: bool, radius: f32) -> Self {
+        let sqrt_3 = 3_f32.sqrt();
+        let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
+        let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
+
+        let v = |dx: f32, dy: f32| radius * Vec2::new(dx, dy);
+        let polygon = |points: Vec<Vec2>| {
+            if filled {
+                GeometryKind::Polygon(points)
+            } else {
+                GeometryKind::Outline(points)
+            }
+        };
+
+        let kind = match shape {
+            MarkerShape::Circle => {
+                if filled {
+                    GeometryKind::Disc(radius)
+                } else {
+                    GeometryKind::Circle(radius)
+                }
+            }
+            MarkerShape::Diamond => {
+                polygon(vec![v(0.0, 1.0), v(-1.0, 0.0), v(0.0, -1.0), v(1.0, 0.0)])
+            }
+            MarkerShape::Square => polygon(vec![
+                v(-frac_1_sqrt_2, frac_1_sqrt_2),
+                v(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                v(frac_1_sqrt_2, -frac_1_sqrt_2),
+                v(frac_1_sqrt_2, frac_1_sqrt_2),
+            ]),
+            MarkerShape::Cross => GeometryKind::Lines(vec![
+                [
+                    v(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                    v(frac_1_sqrt_2, frac_1_sqrt_2),
+                ],
+                [
+                    v(frac_1_sqrt_2, -frac_1_sqrt_2),
+                    v(-frac_1_sqrt_2, frac_1_sqrt_2),
+                ],
+            ]),
+            MarkerShape::Plus => GeometryKind::Lines(vec![
+                [v(-1.0, 0.0), v(1.0, 0.0)],
+                [v(0.0, -1.0), v(0.0, 1.0)],
+            ]),
+            MarkerShape::Up => polygon(vec![
+                v(0.0, -1.0),
+                v(0.5 * sqrt_3, 0.5),
+                v(-0.5 * sqrt_3, 0.5),
+            ]),
+            MarkerShape::Down => polygon(vec![
+                v(0.0, 1.0),
+                v(-0.5 * sqrt_3, -0.5),
+                v(0.5 * sqrt_3, -0.5),
+            ]),
+            MarkerShape::Left => polygon(vec![
+                v(-1.0, 0.0),
+                v(0.5, -0.5 * sqrt_3),
+                v(0.5, 0.5 * sqrt_3),
+            ]),
+            MarkerShape::Right => polygon(vec![
+                v(1.0, 0.0),
+                v(-0.5, 0.5 * sqrt_3),
+                v(-0.5, -0.5 * sqrt_3),
+            ]),
+            MarkerShape::Asterisk => GeometryKind::Lines(vec![
+                [v(0.0, -1.0), v(0.0, 1.0)],
+                [v(-frac_sqrt_3_2, 0.5), v(frac_sqrt_3_2, -0.5)],
+                [v(-frac_sqrt_3_2, -0.5), v(frac_sqrt_3_2, 0.5)],
+            ]),
+        };
+
+        Self {
+            kind,
+            half_width: 0.5 * radius / 5.0,
+        }
+    }
+
+    /// How far the marker reaches from its center.
+    fn extent(&self) -> f32 {
+        let radius = match &self.kind {
+            GeometryKind::Disc(radius) | GeometryKind::Circle(radius) => *radius,
+            GeometryKind::Polygon(points) | GeometryKind::Outline(points) => {
+                points.iter().map(|p| p.length()).fold(0.0, f32::max)
+            }
+            GeometryKind::Lines(lines) => lines
+                .iter()
+                .flatten()
+                .map(|p| p.length())
+                .fold(0.0, f32::max),
+        };
+        radius + self.half_width
+    }
+
+    fn contains(&self, p: Vec2) -> bool {
+        match &self.kind {
+            GeometryKind::Disc(radius) => p.length() <= *radius,
+            GeometryKind::Circle(radius) => (p.length() - radius).abs() <= self.half_width,
+            GeometryKind::Polygon(points) => {
+                let mut sides =
+                    edges(points).map(|[a, b]| (b - a).x * (p - a).y - (b - a).y * (p - a).x);
+                let first = sides.next().unwrap_or_default();
+                sides.all(|side| side * first >= 0.0)
+            }
+            GeometryKind::Outline(points) => {
+                edges(points).any(|segment| distance_to_segment(p, segment) <= self.half_width)
+            }
+            GeometryKind::Lines(lines) => lines
+                .iter()
+                .any(|segment| distance_to_segment(p, *segment) <= self.half_width),
+        }
+    }
+}
+
+/// The edges of a closed polygon.
+fn edges(points: &[Vec2]) -> impl Iterator<Item = [Vec2; 2]> + '_ {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| [*a, *b])
+}
+
+fn distance_to_segment(p: Vec2, [a, b]: [Vec2; 2]) -> f32 {
+    let ab = b - a;
+    let t = ((p - a).dot(ab) / ab.length_sq()).clamp(0.0, 1.0);
+    (a + t * ab - p).length()
+}
@@ -0,0 +1,414 @@
+//! Lines for realtime data, where new samples arrive every frame.
+
+use std::{collections::VecDeque, ops::RangeInclusive, sync::Arc};
+
+use egui::epaint::util::FloatOrd;
+use egui::{mutex::Mutex, Color32, Id, Pos2, Shape, Stroke, Ui};
+
+use super::{
+    rulers_at_value, values::ClosestElem, Cursor, LabelFormatter, LineStyle, PlotBounds,
+    PlotConfig, PlotGeometry, PlotItem, PlotPoint, PlotTransform,
+};
+
+/// A fixed-capacity buffer of samples, for plotting data that streams in with [`StreamingLine`].
+///
+/// Pushing a sample is O(1) and never allocates: once the buffer is full, the oldest sample is dropped.
+/// Cloning a [`PlotRingBuffer`] is cheap and gives another handle to the same samples,
+/// so you can keep one handle in your app (or on another thread) and give clones to the plot each frame.
+///
+/// The x values are expected to increase, e.g. a timestamp.
+#[derive(Clone)]
+pub struct PlotRingBuffer {
+    points: Arc<Mutex<VecDeque<PlotPoint>>>,
+    capacity: usize,
+}
+
+impl PlotRingBuffer {
+    /// A buffer keeping the latest `capacity` samples.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            points: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// The maximum number of samples kept.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of samples currently in the buffer.
+    pub fn len(&self) -> usize {
+        self.points.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.lock().is_empty()
+    }
+
+    /// Add a sample, dropping the oldest one if the buffer is full.
+    pub fn push(&self, point: impl Into<PlotPoint>) {
+        let mut points = self.points.lock();
+        if points.len() == self.capacity {
+            points.pop_front();
+        }
+        points.push_back(point.into());
+    }
+
+    /// Add many samples, dropping the oldest ones as needed.
+    pub fn extend(&self, new_points: impl IntoIterator<Item = impl Into<PlotPoint>>) {
+        let mut points = self.points.lock();
+        for point in new_points {
+            if points.len() == self.capacity {
+                points.pop_front();
+            }
+            points.push_back(point.into());
+        }
+    }
+
+    /// Remove all samples.
+    pub fn clear(&self) {
+        self.points.lock().clear();
+    }
+
+    /// The newest sample, if any.
+    pub fn latest(&self) -> Option<PlotPoint> {
+        self.points.lock().back().copied()
+    }
+
+    /// An id that is the same for all clones of this buffer.
+    fn id(&self) -> Id {
+        Id::new(("egui_plot_ring_buffer", Arc::as_ptr(&self.points) as usize))
+    }
+
+    fn with_points<R>(&self, f: impl FnOnce(&VecDeque<PlotPoint>) -> R) -> R {
+        f(&self.points.lock())
+    }
+}
+
+/// The index of the oldest sample within `window` of the newest one.
+fn window_start(window: Option<f64>, points: &VecDeque<PlotPoint>) -> usize {
+    match (window, points.back()) {
+        (Some(window), Some(latest)) => points.partition_point(|p| p.x < latest.x - window),
+        _ => 0,
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A line showing the samples of a [`PlotRingBuffer`], for realtime telemetry.
+///
+/// Unlike [`crate::Line`], the samples are not copied into the plot every frame.
+/// With [`Self::window`], the x axis follows the newest sample (as long as the plot is auto-bounded).
+///
+/// The id of the item is the same every frame (unless set with [`Self::id`]),
+/// so it can be used to e.g. detect hovering with [`crate::PlotResponse::hovered_plot_item`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, PlotRingBuffer, StreamingLine};
+///
+/// // Keep this in your app state, and push new samples as they arrive:
+/// let buffer = PlotRingBuffer::new(10_000);
+/// let time = ui.input(|i| i.time);
+/// buffer.push([time, time.sin()]);
+///
+/// Plot::new("telemetry").show(ui, |plot_ui| {
+///     plot_ui.streaming_line(StreamingLine::new(buffer.clone()).name("signal").window(10.0));
+/// });
+/// # });
+/// ```
+pub struct StreamingLine {
+    buffer: PlotRingBuffer,
+    pub(crate) stroke: Stroke,
+    name: String,
+    highlight: bool,
+    allow_hover: bool,
+    style: LineStyle,
+    window: Option<f64>,
+    id: Option<Id>,
+}
+
+impl StreamingLine {
+    pub fn new(buffer: PlotRingBuffer) -> Self {
+        Self {
+            buffer,
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            style: LineStyle::Solid,
+            window: None,
+            id: None,
+        }
+    }
+
+    /// Only show the samples that are at most this far (along x) behind the newest one.
+    ///
+    /// The bounds of the item always span the whole window,
+    /// so an auto-bounded plot scrolls along with the data at a steady pace.
+    #[inline]
+    pub fn window(mut self, width: f64) -> Self {
+        self.window = Some(width.abs());
+        self
+    }
+
+    /// Highlight this line in the plot by scaling up the line.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Set the line's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Name of this line.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the line's id which is used to identify it in the plot's response.
+    ///
+    /// By default, all clones of the same [`PlotRingBuffer`] give the same id.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl PlotItem for StreamingLine {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let bounds = transform.bounds();
+        let (min_x, max_x) = (bounds.min()[0], bounds.max()[0]);
+        let values_tf: Vec<Pos2> = self.buffer.with_points(|points| {
+            // Include the samples just outside of the plot, so the line reaches its edges:
+            let start = points
+                .partition_point(|p| p.x < min_x)
+                .saturating_sub(1)
+                .max(window_start(self.window, points));
+            let end = (points.partition_point(|p| p.x <= max_x) + 1)
+                .min(points.len())
+                .max(start);
+            points
+                .range(start..end)
+                .map(|p| transform.position_from_point(p))
+                .collect()
+        });
+        self.style
+            .style_line(values_tf, self.stroke, self.highlight, shapes);
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        // The samples are behind a lock, so we implement `find_closest` and `on_hover` instead.
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        self.buffer.with_points(|points| {
+            for point in points.range(window_start(self.window, points)..) {
+                bounds.extend_with(point);
+            }
+            if let (Some(window), Some(latest)) = (self.window, points.back()) {
+                bounds.extend_with_x(latest.x - window);
+            }
+        });
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id.unwrap_or_else(|| self.buffer.id()))
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.buffer.with_points(|points| {
+            let start = window_start(self.window, points);
+            points
+                .range(start..)
+                .enumerate()
+                .map(|(index, value)| {
+                    let pos = transform.position_from_point(value);
+                    let dist_sq = point.distance_sq(pos);
+                    ClosestElem {
+                        index: start + index,
+                        dist_sq,
+                    }
+                })
+                .min_by_key(|e| e.dist_sq.ord())
+        })
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        // More samples may have been pushed since `find_closest`, so the index may be off by a bit.
+        let Some(value) = self
+            .buffer
+            .with_points(|points| points.get(elem.index).copied())
+        else {
+            return;
+        };
+
+        let line_color = if plot.ui.visuals().dark_mode {
+            Color32::from_gray(100).additive()
+        } else {
+            Color32::from_black_alpha(180)
+        };
+        let pointer = plot.transform.position_from_point(&value);
+        shapes.push(Shape::circle_filled(pointer, 3.0, line_color));
+
+        rulers_at_value(
+            pointer,
+            value,
+            self.name(),
+            plot,
+            shapes,
+            cursors,
+            label_formatter,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xs(buffer: &PlotRingBuffer) -> Vec<f64> {
+        buffer.with_points(|points| points.iter().map(|p| p.x).collect())
+    }
+
+    #[test]
+    fn push_up_to_capacity() {
+        let buffer = PlotRingBuffer::new(3);
+        assert!(buffer.is_empty());
+        for x in 0..3 {
+            buffer.push([x as f64, 0.0]);
+        }
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(xs(&buffer), vec![0.0, 1.0, 2.0]);
+        assert_eq!(buffer.latest(), Some(PlotPoint::new(2.0, 0.0)));
+    }
+
+    #[test]
+    fn push_beyond_capacity_drops_oldest() {
+        let buffer = PlotRingBuffer::new(3);
+        for x in 0..5 {
+            buffer.push([x as f64, 0.0]);
+        }
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(xs(&buffer), vec![2.0, 3.0, 4.0]);
+
+        buffer.extend((5..12).map(|x| [x as f64, 0.0]));
+        assert_eq!(xs(&buffer), vec![9.0, 10.0, 11.0]);
+    }
+
+    #[test]
+    fn order_after_wrapping_around() {
+        // Enough pushes for the samples to wrap around the end of the storage several times:
+        let buffer = PlotRingBuffer::new(4);
+        for x in 0_u32..10 {
+            buffer.push([x as f64, 0.0]);
+            let expected: Vec<f64> = (x.saturating_sub(3)..=x).map(|x| x as f64).collect();
+            assert_eq!(xs(&buffer), expected);
+        }
+        assert_eq!(buffer.latest(), Some(PlotPoint::new(9.0, 0.0)));
+    }
+
+    #[test]
+    fn clones_share_samples() {
+        let buffer = PlotRingBuffer::new(0);
+        assert_eq!(buffer.capacity(), 1);
+
+        let clone = buffer.clone();
+        clone.push([1.0, 0.0]);
+        clone.push([2.0, 0.0]);
+        assert_eq!(xs(&buffer), vec![2.0]);
+        assert_eq!(buffer.id(), clone.id());
+
+        buffer.clear();
+        assert!(clone.is_empty());
+        assert_ne!(buffer.id(), PlotRingBuffer::new(1).id());
+    }
+
+    #[test]
+    fn window() {
+        let buffer = PlotRingBuffer::new(10);
+        buffer.extend((0..10).map(|x| [x as f64, 0.0]));
+        buffer.with_points(|points| {
+            assert_eq!(window_start(None, points), 0);
+            assert_eq!(window_start(Some(3.0), points), 6);
+            assert_eq!(window_start(Some(100.0), points), 0);
+        });
+    }
+}
@@ -1,6 +1,6 @@
 use std::ops::{Bound, RangeBounds, RangeInclusive};
 
-use egui::{Pos2, Shape, Stroke, Vec2};
+use egui::{pos2, Pos2, Shape, Stroke, Vec2};
 
 use crate::transform::PlotBounds;
 
@@ -51,8 +51,15 @@ impl PlotPoint {
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum LineStyle {
     Solid,
-    Dotted { spacing: f32 },
-    Dashed { length: f32 },
+    Dotted {
+        spacing: f32,
+    },
+    Dashed {
+        length: f32,
+    },
+
+    /// A solid line that only moves horizontally and vertically, for discrete signals.
+    Step(StepKind),
 }
 
 impl LineStyle {
@@ -96,6 +103,12 @@ impl LineStyle {
                         }
                         shapes.push(Shape::line(line, stroke));
                     }
+                    Self::Step(kind) => {
+                        if highlight {
+                            stroke.width *= 2.0;
+                        }
+                        shapes.push(Shape::line(kind.steps(&line), stroke));
+                    }
                     Self::Dotted { spacing } => {
                         // Take the stroke width for the radius even though it's not "correct", otherwise
                         // the dots would become too small.
@@ -129,7 +142,45 @@ impl ToString for LineStyle {
             Self::Solid => "Solid".into(),
             Self::Dotted { spacing } => format!("Dotted{spacing}Px"),
             Self::Dashed { length } => format!("Dashed{length}Px"),
+            Self::Step(kind) => format!("Step{kind:?}"),
+        }
+    }
+}
+
+/// Where the steps of a [`LineStyle::Step`] go.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StepKind {
+    /// Each value holds from the previous point up to its own point,
+    /// i.e. the step is at the start of the interval.
+    Pre,
+
+    /// Each value holds from its own point up to the next point,
+    /// i.e. the step is at the end of the interval. This is how a sample-and-hold signal looks.
+    Post,
+
+    /// The step is halfway between the points.
+    Mid,
+}
+
+impl StepKind {
+    /// Add the corners of the steps between the (screen space) points of a line.
+    pub(super) fn steps(self, line: &[Pos2]) -> Vec<Pos2> {
+        let mut steps = Vec::with_capacity(3 * line.len());
+        for (i, &point) in line.iter().enumerate() {
+            if let Some(&prev) = i.checked_sub(1).and_then(|i| line.get(i)) {
+                match self {
+                    Self::Pre => steps.push(pos2(prev.x, point.y)),
+                    Self::Post => steps.push(pos2(point.x, prev.y)),
+                    Self::Mid => {
+                        let mid_x = 0.5 * (prev.x + point.x);
+                        steps.push(pos2(mid_x, prev.y));
+                        steps.push(pos2(mid_x, point.y));
+                    }
+                }
+            }
+            steps.push(point);
         }
+        steps
     }
 }
 
@@ -320,7 +371,7 @@ impl PlotPoints {
 // ----------------------------------------------------------------------------
 
 /// Circle, Diamond, Square, Cross, …
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum MarkerShape {
     Circle,
     Diamond,
@@ -26,12 +26,28 @@ impl Corner {
     }
 }
 
+/// Where to place the plot legend, relative to the plot area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegendPlacement {
+    /// In a corner of the plot area, on top of the data. See [`Legend::position`].
+    Inside,
+
+    /// In a strip to the right of the plot area and its axes.
+    OutsideRight,
+
+    /// In a strip below the plot area and its axes.
+    OutsideBottom,
+}
+
 /// The configuration for a plot legend.
 #[derive(Clone, PartialEq)]
 pub struct Legend {
     pub text_style: TextStyle,
     pub background_alpha: f32,
     pub position: Corner,
+    pub placement: LegendPlacement,
+    pub columns: usize,
+    pub max_height: Option<f32>,
 
     /// Used for overriding the `hidden_items` set in [`LegendWidget`].
     hidden_items: Option<ahash::HashSet<String>>,
@@ -43,6 +59,9 @@ impl Default for Legend {
             text_style: TextStyle::Body,
             background_alpha: 0.75,
             position: Corner::RightTop,
+            placement: LegendPlacement::Inside,
+            columns: 1,
+            max_height: None,
 
             hidden_items: None,
         }
@@ -65,12 +84,42 @@ impl Legend {
     }
 
     /// In which corner to place the legend. Default: `Corner::RightTop`.
+    ///
+    /// Only used with [`LegendPlacement::Inside`].
     #[inline]
     pub fn position(mut self, corner: Corner) -> Self {
         self.position = corner;
         self
     }
 
+    /// Whether to place the legend on top of the plot area, or next to it.
+    ///
+    /// When placed outside, the plot area shrinks to make room for the legend, so it doesn't cover the data.
+    ///
+    /// Default: [`LegendPlacement::Inside`].
+    #[inline]
+    pub fn placement(mut self, placement: LegendPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// In how many columns to lay out the entries. Default: `1`.
+    #[inline]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Scroll the entries when the legend gets taller than this.
+    ///
+    /// Default: the height of the plot area, or a third of the height of the plot
+    /// for [`LegendPlacement::OutsideBottom`].
+    #[inline]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
     /// Specifies hidden items in the legend configuration to override the existing ones. This
     /// allows the legend traces' visibility to be controlled from the application code.
     #[inline]
@@ -179,10 +228,10 @@ pub(super) struct LegendWidget {
 impl LegendWidget {
     /// Create a new legend from items, the names of items that are hidden and the style of the
     /// text. Returns `None` if the legend has no entries.
-    pub(super) fn try_new(
+    pub(super) fn try_new<'a>(
         rect: Rect,
         config: Legend,
-        items: &[Box<dyn PlotItem>],
+        items: impl Iterator<Item = &'a dyn PlotItem>,
         hidden_items: &ahash::HashSet<String>, // Existing hiddent items in the plot memory.
     ) -> Option<Self> {
         // If `config.hidden_items` is not `None`, it is used.
@@ -192,7 +241,6 @@ impl LegendWidget {
         // checkbox. If their colors don't match, we pick a neutral color for the checkbox.
         let mut entries: BTreeMap<String, LegendEntry> = BTreeMap::new();
         items
-            .iter()
             .filter(|item| !item.name().is_empty())
             .for_each(|item| {
                 entries
@@ -242,13 +290,21 @@ impl Widget for &mut LegendWidget {
             config,
         } = self;
 
-        let main_dir = match config.position {
-            Corner::LeftTop | Corner::RightTop => Direction::TopDown,
-            Corner::LeftBottom | Corner::RightBottom => Direction::BottomUp,
-        };
-        let cross_align = match config.position {
-            Corner::LeftTop | Corner::LeftBottom => Align::LEFT,
-            Corner::RightTop | Corner::RightBottom => Align::RIGHT,
+        let (main_dir, cross_align) = match config.placement {
+            LegendPlacement::Inside => {
+                let main_dir = match config.position {
+                    Corner::LeftTop | Corner::RightTop => Direction::TopDown,
+                    Corner::LeftBottom | Corner::RightBottom => Direction::BottomUp,
+                };
+                let cross_align = match config.position {
+                    Corner::LeftTop | Corner::LeftBottom => Align::LEFT,
+                    Corner::RightTop | Corner::RightBottom => Align::RIGHT,
+                };
+                (main_dir, cross_align)
+            }
+            LegendPlacement::OutsideRight | LegendPlacement::OutsideBottom => {
+                (Direction::TopDown, Align::LEFT)
+            }
         };
         let layout = Layout::from_main_dir_and_cross_align(main_dir, cross_align);
         let legend_pad = 4.0;
@@ -265,16 +321,52 @@ impl Widget for &mut LegendWidget {
                     ..Default::default()
                 }
                 .multiply_with_opacity(config.background_alpha);
-                background_frame
-                    .show(ui, |ui| {
-                        entries
-                            .iter_mut()
-                            .map(|(name, entry)| entry.ui(ui, name.clone(), &config.text_style))
-                            .reduce(|r1, r2| r1.union(r2))
-                            .unwrap()
-                    })
-                    .inner
+                let frame_response = background_frame.show(ui, |ui| {
+                    let max_height = config
+                        .max_height
+                        .unwrap_or(f32::INFINITY)
+                        .min(ui.available_height());
+                    ScrollArea::vertical()
+                        .id_source("legend")
+                        .max_height(max_height)
+                        .show(ui, |ui| {
+                            let mut entries: Vec<_> = entries.iter_mut().collect();
+                            if config.columns <= 1 {
+                                return entries_ui(ui, &mut entries, &config.text_style);
+                            }
+
+                            let rows = (entries.len() + config.columns - 1) / config.columns;
+                            ui.horizontal_top(|ui| {
+                                entries
+                                    .chunks_mut(rows)
+                                    .map(|column| {
+                                        ui.with_layout(Layout::top_down(cross_align), |ui| {
+                                            entries_ui(ui, column, &config.text_style)
+                                        })
+                                        .inner
+                                    })
+                                    .reduce(|r1, r2| r1.union(r2))
+                                    .unwrap()
+                            })
+                            .inner
+                        })
+                        .inner
+                });
+                // The rect of the whole legend, so the plot can make room for it:
+                frame_response.inner.union(frame_response.response)
             })
             .inner
     }
 }
+
+fn entries_ui(
+    ui: &mut Ui,
+    entries: &mut [(&String, &mut LegendEntry)],
+    text_style: &TextStyle,
+) -> Response {
+    entries
+        .iter_mut()
+        .map(|(name, entry)| entry.ui(ui, (*name).clone(), text_style))
+        .reduce(|r1, r2| r1.union(r2))
+        .unwrap()
+}
@@ -7,29 +7,38 @@
 //!
 
 mod axis;
+mod curve_editor;
+mod export;
 mod items;
 mod legend;
 mod memory;
 mod plot_ui;
+mod ruler;
+mod time_axis;
 mod transform;
 
 use std::{cmp::Ordering, ops::RangeInclusive, sync::Arc};
 
 use egui::ahash::HashMap;
 use egui::*;
-use epaint::{util::FloatOrd, Hsva};
+use epaint::util::FloatOrd;
 
 pub use crate::{
-    axis::{Axis, AxisHints, HPlacement, Placement, VPlacement},
+    axis::{Axis, AxisHints, HPlacement, Placement, TickSpec, VPlacement},
+    curve_editor::{Curve, CurveEditor, CurveInterpolation, Keyframe},
+    export::PlotExport,
     items::{
-        Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, HLine, Line, LineStyle, MarkerShape,
-        Orientation, PlotGeometry, PlotImage, PlotItem, PlotPoint, PlotPoints, Points, Polygon,
-        Text, VLine,
+        ArrowHead, Arrows, Band, Bar, BarChart, BarGroup, BoxElem, BoxPlot, BoxSpread, Callout,
+        ColorBar, ColorMap, ErrorBars, HLine, Heatmap, Histogram, HistogramBins, Impulses, Line,
+        LineStyle, MarkerShape, Orientation, PlotGeometry, PlotImage, PlotItem, PlotPoint,
+        PlotPoints, PlotRingBuffer, Points, Polygon, Span, StepKind, StreamingLine, Text, VLine,
     },
-    legend::{Corner, Legend},
+    legend::{Corner, Legend, LegendPlacement},
     memory::PlotMemory,
     plot_ui::PlotUi,
-    transform::{PlotBounds, PlotTransform},
+    ruler::Ruler,
+    time_axis::{time_formatter, time_grid_spacer},
+    transform::{AxisScale, PlotBounds, PlotTransform},
 };
 
 use axis::AxisWidget;
@@ -37,7 +46,9 @@ use items::{horizontal_line, rulers_color, vertical_line};
 use legend::LegendWidget;
 
 type LabelFormatterFn = dyn Fn(&str, &PlotPoint) -> String;
-pub type LabelFormatter = Option<Box<LabelFormatterFn>>;
+pub type LabelFormatter = Option<Arc<LabelFormatterFn>>;
+
+type TooltipUiFn = dyn Fn(&mut Ui, &dyn PlotItem, &PlotPoint);
 
 type GridSpacerFn = dyn Fn(GridInput) -> Vec<GridMark>;
 type GridSpacer = Box<GridSpacerFn>;
@@ -147,6 +158,7 @@ pub struct Plot {
     id: Option<Id>,
 
     center_axis: Vec2b,
+    invert_axis: Vec2b,
     allow_zoom: Vec2b,
     allow_drag: Vec2b,
     allow_scroll: Vec2b,
@@ -156,20 +168,27 @@ pub struct Plot {
     min_auto_bounds: PlotBounds,
     margin_fraction: Vec2,
     boxed_zoom_pointer_button: PointerButton,
+    boxed_zoom_modifiers: Option<Modifiers>,
+    zoom_back_shortcut: Option<KeyboardShortcut>,
     linked_axes: Option<(Id, Vec2b)>,
+    link_zoom_only: bool,
     linked_cursors: Option<(Id, Vec2b)>,
+    crosshair: bool,
+    crosshair_formatter: LabelFormatter,
 
     min_size: Vec2,
     width: Option<f32>,
     height: Option<f32>,
     data_aspect: Option<f32>,
     view_aspect: Option<f32>,
+    letterbox: bool,
 
     reset: bool,
 
     show_x: bool,
     show_y: bool,
     label_formatter: LabelFormatter,
+    tooltip_ui: Option<Arc<TooltipUiFn>>,
     coordinates_formatter: Option<(Corner, CoordinatesFormatter)>,
     x_axes: Vec<AxisHints>, // default x axes
     y_axes: Vec<AxisHints>, // default y axes
@@ -178,10 +197,11 @@ pub struct Plot {
     show_axes: Vec2b,
 
     show_grid: Vec2b,
-    grid_spacing: Rangef,
+    grid_style: GridStyle,
     grid_spacers: [GridSpacer; 2],
     sharp_grid_lines: bool,
     clamp_grid: bool,
+    axis_scales: [AxisScale; 2],
 
     sense: Sense,
 }
@@ -194,6 +214,7 @@ impl Plot {
             id: None,
 
             center_axis: false.into(),
+            invert_axis: false.into(),
             allow_zoom: true.into(),
             allow_drag: true.into(),
             allow_scroll: true.into(),
@@ -203,20 +224,27 @@ impl Plot {
             min_auto_bounds: PlotBounds::NOTHING,
             margin_fraction: Vec2::splat(0.05),
             boxed_zoom_pointer_button: PointerButton::Secondary,
+            boxed_zoom_modifiers: None,
+            zoom_back_shortcut: Some(KeyboardShortcut::new(Modifiers::NONE, Key::Backspace)),
             linked_axes: None,
+            link_zoom_only: false,
             linked_cursors: None,
+            crosshair: false,
+            crosshair_formatter: None,
 
             min_size: Vec2::splat(64.0),
             width: None,
             height: None,
             data_aspect: None,
             view_aspect: None,
+            letterbox: false,
 
             reset: false,
 
             show_x: true,
             show_y: true,
             label_formatter: None,
+            tooltip_ui: None,
             coordinates_formatter: None,
             x_axes: vec![AxisHints::new(Axis::X)],
             y_axes: vec![AxisHints::new(Axis::Y)],
@@ -225,10 +253,11 @@ impl Plot {
             show_axes: true.into(),
 
             show_grid: true.into(),
-            grid_spacing: Rangef::new(8.0, 300.0),
+            grid_style: GridStyle::default(),
             grid_spacers: [log_grid_spacer(10), log_grid_spacer(10)],
             sharp_grid_lines: true,
             clamp_grid: false,
+            axis_scales: Default::default(),
 
             sense: egui::Sense::click_and_drag(),
         }
@@ -255,6 +284,17 @@ impl Plot {
         self
     }
 
+    /// Keep the [`Self::data_aspect`] by shrinking the plot area instead of changing the bounds.
+    ///
+    /// The plot area is then centered in the space of the plot, with empty bars on the sides,
+    /// so resizing the plot never changes which part of the data is shown.
+    /// Default: `false`.
+    #[inline]
+    pub fn letterbox(mut self, on: bool) -> Self {
+        self.letterbox = on;
+        self
+    }
+
     /// width / height ratio of the plot region.
     /// By default no fixed aspect ratio is set (and width/height will fill the ui it is in).
     #[inline]
@@ -316,6 +356,21 @@ impl Plot {
         self
     }
 
+    /// Let the values on the X axis increase to the left. Default: `false`.
+    #[inline]
+    pub fn invert_x(mut self, on: bool) -> Self {
+        self.invert_axis.x = on;
+        self
+    }
+
+    /// Let the values on the Y axis increase downwards, e.g. for pixel coordinates of images.
+    /// Default: `false`.
+    #[inline]
+    pub fn invert_y(mut self, on: bool) -> Self {
+        self.invert_axis.y = on;
+        self
+    }
+
     /// Whether to allow zooming in the plot. Default: `true`.
     ///
     /// Note: Allowing zoom in one axis but not the other may lead to unexpected results if used in combination with `data_aspect`.
@@ -357,6 +412,11 @@ impl Plot {
 
     /// Whether to allow zooming in the plot by dragging out a box with the secondary mouse button.
     ///
+    /// The bounds before each boxed zoom are remembered, and the user can go back to them by right-clicking
+    /// the plot or pressing the [`Self::zoom_back_shortcut`].
+    /// Double-clicking resets the bounds and forgets this history.
+    /// See also [`PlotUi::zoom_back`] and [`PlotMemory::zoom_back`].
+    ///
     /// Default: `true`.
     #[inline]
     pub fn allow_boxed_zoom(mut self, on: bool) -> Self {
@@ -371,6 +431,25 @@ impl Plot {
         self
     }
 
+    /// Also do a boxed zoom when dragging with the primary mouse button while these modifiers are held,
+    /// e.g. [`Modifiers::SHIFT`], instead of moving the bounds.
+    ///
+    /// Default: `None`.
+    #[inline]
+    pub fn boxed_zoom_modifiers(mut self, modifiers: impl Into<Option<Modifiers>>) -> Self {
+        self.boxed_zoom_modifiers = modifiers.into();
+        self
+    }
+
+    /// The shortcut for going back to the bounds before the last boxed zoom, while the plot is hovered.
+    ///
+    /// Default: `Backspace`.
+    #[inline]
+    pub fn zoom_back_shortcut(mut self, shortcut: impl Into<Option<KeyboardShortcut>>) -> Self {
+        self.zoom_back_shortcut = shortcut.into();
+        self
+    }
+
     /// Whether to allow dragging in the plot to move the bounds. Default: `true`.
     #[inline]
     pub fn allow_drag<T>(mut self, on: T) -> Self
@@ -383,6 +462,8 @@ impl Plot {
 
     /// Provide a function to customize the on-hover label for the x and y axis
     ///
+    /// The formatter is a closure, so it can capture e.g. units or app state.
+    ///
     /// ```
     /// # egui::__run_test_ui(|ui| {
     /// use egui_plot::{Line, Plot, PlotPoints};
@@ -406,7 +487,46 @@ impl Plot {
         mut self,
         label_formatter: impl Fn(&str, &PlotPoint) -> String + 'static,
     ) -> Self {
-        self.label_formatter = Some(Box::new(label_formatter));
+        self.label_formatter = Some(Arc::new(label_formatter));
+        self
+    }
+
+    /// Show arbitrary content in a tooltip when hovering a plot item,
+    /// instead of the text of [`Self::label_formatter`].
+    ///
+    /// The function gets the hovered item and the hovered value:
+    /// the hovered point for lines and points, otherwise the plot coordinates of the pointer.
+    /// The marker and rulers of the hovered item are still shown.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Line, Plot, PlotPoints};
+    /// Plot::new("my_plot")
+    ///     .tooltip_ui(|ui, item, point| {
+    ///         ui.horizontal(|ui| {
+    ///             let (rect, _) = ui.allocate_exact_size(egui::vec2(12.0, 12.0), egui::Sense::hover());
+    ///             ui.painter().rect_filled(rect, 2.0, item.color());
+    ///             ui.strong(item.name());
+    ///         });
+    ///         egui::Grid::new("values").show(ui, |ui| {
+    ///             ui.label("time");
+    ///             ui.label(format!("{:.2} s", point.x));
+    ///             ui.end_row();
+    ///             ui.label("value");
+    ///             ui.label(format!("{:.3}", point.y));
+    ///             ui.end_row();
+    ///         });
+    ///     })
+    ///     .show(ui, |plot_ui| {
+    ///         plot_ui.line(Line::new(PlotPoints::from_explicit_callback(f64::sin, .., 100)).name("sin"));
+    ///     });
+    /// # });
+    /// ```
+    pub fn tooltip_ui(
+        mut self,
+        tooltip_ui: impl Fn(&mut Ui, &dyn PlotItem, &PlotPoint) + 'static,
+    ) -> Self {
+        self.tooltip_ui = Some(Arc::new(tooltip_ui));
         self
     }
 
@@ -465,14 +585,71 @@ impl Plot {
         self
     }
 
+    /// How the values along the X axis are spread out, e.g. [`AxisScale::Log10`] for a logarithmic axis.
+    ///
+    /// Switching to [`AxisScale::Log10`] also switches to the [`log10_grid_spacer`],
+    /// so call [`Self::x_grid_spacer`] afterwards if you want another one.
+    /// The default tick labels use scientific notation for very large and small values,
+    /// unless you set your own [`Self::x_axis_formatter`].
+    ///
+    /// Only positive values can be shown on a logarithmic axis.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{AxisScale, Line, Plot, PlotPoints};
+    ///
+    /// let response: PlotPoints = (0..=60)
+    ///     .map(|i| {
+    ///         let frequency = 10.0_f64.powf(i as f64 / 10.0);
+    ///         [frequency, 1.0 / (1.0 + frequency / 100.0)]
+    ///     })
+    ///     .collect();
+    /// Plot::new("bode_plot")
+    ///     .x_axis_scale(AxisScale::Log10)
+    ///     .y_axis_scale(AxisScale::Log10)
+    ///     .show(ui, |plot_ui| plot_ui.line(Line::new(response)));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn x_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[0] = scale;
+        if scale == AxisScale::Log10 {
+            self.grid_spacers[0] = log10_grid_spacer();
+        }
+        self
+    }
+
+    /// How the values along the Y axis are spread out, e.g. [`AxisScale::Log10`] for a logarithmic axis.
+    ///
+    /// See [`Self::x_axis_scale`] for details.
+    #[inline]
+    pub fn y_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[1] = scale;
+        if scale == AxisScale::Log10 {
+            self.grid_spacers[1] = log10_grid_spacer();
+        }
+        self
+    }
+
     /// Set when the grid starts showing.
     ///
     /// When grid lines are closer than the given minimum, they will be hidden.
     /// When they get further apart they will fade in, until the reaches the given maximum,
     /// at which point they are fully opaque.
+    ///
+    /// Shorthand for setting [`GridStyle::spacing`].
     #[inline]
     pub fn grid_spacing(mut self, grid_spacing: impl Into<Rangef>) -> Self {
-        self.grid_spacing = grid_spacing.into();
+        self.grid_style.spacing = grid_spacing.into();
+        self
+    }
+
+    /// How the grid lines are painted.
+    ///
+    /// Default: [`GridStyle::default`], which fades in the lines with the text color of the current visuals.
+    #[inline]
+    pub fn grid_style(mut self, grid_style: GridStyle) -> Self {
+        self.grid_style = grid_style;
         self
     }
 
@@ -572,6 +749,10 @@ impl Plot {
 
     /// Add this plot to an axis link group so that this plot will share the bounds with other plots in the
     /// same group. A plot cannot belong to more than one axis group.
+    ///
+    /// Each plot in the group chooses which of its axes to link, so e.g. stacked time series panels can all
+    /// link only their x axis (time) while keeping their own y range.
+    /// See also [`Self::link_zoom_only`].
     #[inline]
     pub fn link_axis(mut self, group_id: impl Into<Id>, link_x: bool, link_y: bool) -> Self {
         self.linked_axes = Some((
@@ -584,6 +765,18 @@ impl Plot {
         self
     }
 
+    /// Only share the zoom level of the linked axes with the link group, not the offset.
+    ///
+    /// The linked axes of this plot will have the same width/height as the other plots in the group,
+    /// but can be panned independently. Only has an effect together with [`Self::link_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn link_zoom_only(mut self, link_zoom_only: bool) -> Self {
+        self.link_zoom_only = link_zoom_only;
+        self
+    }
+
     /// Add this plot to a cursor link group so that this plot will share the cursor position with other plots
     /// in the same group. A plot cannot belong to more than one cursor group.
     #[inline]
@@ -598,6 +791,50 @@ impl Plot {
         self
     }
 
+    /// Show a crosshair with the values of all items at the x position of the pointer.
+    ///
+    /// Every line and series of points gets a marker where it crosses the vertical rule,
+    /// and a readout with its value is shown next to the rule.
+    /// Together with [`Self::link_cursor`], the crosshair and readouts are shown in all plots of the group
+    /// whenever one of them is hovered, which is the usual interaction for dashboards of stacked time series.
+    ///
+    /// Default: `false`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Line, Plot, PlotPoints};
+    /// for (name, f) in [("sin", f64::sin as fn(f64) -> f64), ("cos", f64::cos)] {
+    ///     Plot::new(name)
+    ///         .height(100.0)
+    ///         .link_axis("dashboard", true, false)
+    ///         .link_cursor("dashboard", true, false)
+    ///         .crosshair(true)
+    ///         .show(ui, |plot_ui| {
+    ///             plot_ui.line(Line::new(PlotPoints::from_explicit_callback(f, .., 100)).name(name));
+    ///         });
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn crosshair(mut self, crosshair: bool) -> Self {
+        self.crosshair = crosshair;
+        self
+    }
+
+    /// Provide a function to format the readouts of the crosshair, see [`Self::crosshair`].
+    ///
+    /// The function gets the name of the item and its value at the crosshair.
+    /// Return an empty string to show no readout for the item.
+    ///
+    /// By default the readout is the name of the item followed by the y value.
+    pub fn crosshair_formatter(
+        mut self,
+        crosshair_formatter: impl Fn(&str, &PlotPoint) -> String + 'static,
+    ) -> Self {
+        self.crosshair_formatter = Some(Arc::new(crosshair_formatter));
+        self
+    }
+
     /// Round grid positions to full pixels to avoid aliasing. Improves plot appearance but might have an
     /// undesired effect when shifting the plot bounds. Enabled by default.
     #[inline]
@@ -659,12 +896,14 @@ impl Plot {
     /// * the grid mark to format
     /// * maximum requested number of characters per tick label.
     /// * currently shown range on this axis.
+    ///
+    /// The formatter can capture state, see [`AxisHints::formatter`].
     pub fn x_axis_formatter(
         mut self,
         fmt: impl Fn(GridMark, usize, &RangeInclusive<f64>) -> String + 'static,
     ) -> Self {
         if let Some(main) = self.x_axes.first_mut() {
-            main.formatter = Arc::new(fmt);
+            main.formatter = Some(Arc::new(fmt));
         }
         self
     }
@@ -675,12 +914,43 @@ impl Plot {
     /// * the grid mark to format
     /// * maximum requested number of characters per tick label.
     /// * currently shown range on this axis.
+    ///
+    /// The formatter can capture state, see [`AxisHints::formatter`].
     pub fn y_axis_formatter(
         mut self,
         fmt: impl Fn(GridMark, usize, &RangeInclusive<f64>) -> String + 'static,
     ) -> Self {
         if let Some(main) = self.y_axes.first_mut() {
-            main.formatter = Arc::new(fmt);
+            main.formatter = Some(Arc::new(fmt));
+        }
+        self
+    }
+
+    /// Show time on the X axis, for values that are seconds since the unix epoch.
+    ///
+    /// The grid lines snap to whole seconds, minutes, hours, days, months and years (see [`time_grid_spacer`]),
+    /// and the ticks are labeled with dates or times (see [`time_formatter`]).
+    /// `utc_offset_seconds` is the time zone to show them in, e.g. `0` for UTC or `3600` for UTC+1.
+    ///
+    /// Call [`Self::x_axis_formatter`] afterwards for your own labels on the same grid.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Line, Plot, PlotPoints};
+    ///
+    /// let start = 1_700_000_000.0;
+    /// let temperature: PlotPoints = (0..48)
+    ///     .map(|hour| [start + hour as f64 * 3600.0, 20.0 + (hour as f64 * 0.25).sin()])
+    ///     .collect();
+    /// Plot::new("temperature")
+    ///     .x_axis_time(0)
+    ///     .show(ui, |plot_ui| plot_ui.line(Line::new(temperature)));
+    /// # });
+    /// ```
+    pub fn x_axis_time(mut self, utc_offset_seconds: i64) -> Self {
+        self.grid_spacers[0] = time_grid_spacer(utc_offset_seconds);
+        if let Some(main) = self.x_axes.first_mut() {
+            main.formatter = Some(Arc::new(time_formatter(utc_offset_seconds)));
         }
         self
     }
@@ -710,6 +980,8 @@ impl Plot {
     /// Set custom configuration for left Y-axis
     ///
     /// More than one axis may be specified. The first specified axis is considered the main axis.
+    /// Items can be plotted against the other axes with [`PlotUi::set_y_axis`],
+    /// which gives them their own range.
     #[inline]
     pub fn custom_y_axes(mut self, hints: Vec<AxisHints>) -> Self {
         self.y_axes = hints;
@@ -730,12 +1002,15 @@ impl Plot {
             id_source,
             id,
             center_axis,
+            invert_axis,
             allow_zoom,
             allow_drag,
             allow_scroll,
             allow_double_click_reset,
             allow_boxed_zoom,
             boxed_zoom_pointer_button,
+            boxed_zoom_modifiers,
+            zoom_back_shortcut,
             default_auto_bounds,
             min_auto_bounds,
             margin_fraction,
@@ -744,9 +1019,11 @@ impl Plot {
             min_size,
             data_aspect,
             view_aspect,
+            letterbox,
             mut show_x,
             mut show_y,
             label_formatter,
+            tooltip_ui,
             coordinates_formatter,
             x_axes,
             y_axes,
@@ -755,13 +1032,17 @@ impl Plot {
             show_background,
             show_axes,
             show_grid,
-            grid_spacing,
+            grid_style,
             linked_axes,
+            link_zoom_only,
             linked_cursors,
+            crosshair,
+            crosshair_formatter,
 
             clamp_grid,
             grid_spacers,
             sharp_grid_lines,
+            axis_scales,
             sense,
         } = self;
 
@@ -798,11 +1079,50 @@ impl Plot {
         };
 
         let plot_id = id.unwrap_or_else(|| ui.make_persistent_id(id_source));
+        let prev_mem = PlotMemory::load(ui.ctx(), plot_id); // TODO(emilk): avoid loading plot memory twice
+
+        // Make room for a legend outside of the plot area, as big as it was last frame.
+        // The legend may use up to `legend_max_rect`, and is measured again this frame.
+        let prev_legend_size = prev_mem.as_ref().map_or(Vec2::ZERO, |mem| mem.legend_size);
+        let (axes_rect, legend_strip, legend_max_rect) =
+            match legend_config.as_ref().map(|config| config.placement) {
+                Some(LegendPlacement::OutsideRight) => {
+                    let max_width = complete_rect.width() / 2.0;
+                    let width = prev_legend_size.x.min(max_width);
+                    let mut axes_rect = complete_rect;
+                    axes_rect.max.x -= width;
+                    let strip = Rect::from_x_y_ranges(
+                        axes_rect.right()..=complete_rect.right(),
+                        complete_rect.y_range(),
+                    );
+                    let max_rect =
+                        Rect::from_min_size(strip.min, vec2(max_width, complete_rect.height()));
+                    (axes_rect, Some(strip), Some(max_rect))
+                }
+                Some(LegendPlacement::OutsideBottom) => {
+                    let max_height = legend_config
+                        .as_ref()
+                        .and_then(|config| config.max_height)
+                        .unwrap_or(complete_rect.height() / 3.0);
+                    let height = prev_legend_size.y.min(max_height);
+                    let mut axes_rect = complete_rect;
+                    axes_rect.max.y -= height;
+                    let strip = Rect::from_x_y_ranges(
+                        complete_rect.x_range(),
+                        axes_rect.bottom()..=complete_rect.bottom(),
+                    );
+                    let max_rect =
+                        Rect::from_min_size(strip.min, vec2(complete_rect.width(), max_height));
+                    (axes_rect, Some(strip), Some(max_rect))
+                }
+                Some(LegendPlacement::Inside) | None => (complete_rect, None, None),
+            };
 
         let ([x_axis_widgets, y_axis_widgets], plot_rect) = axis_widgets(
-            PlotMemory::load(ui.ctx(), plot_id).as_ref(), // TODO(emilk): avoid loading plot memory twice
+            ui,
+            prev_mem.as_ref(),
             show_axes,
-            complete_rect,
+            axes_rect,
             [&x_axes, &y_axes],
         );
 
@@ -827,21 +1147,35 @@ impl Plot {
             auto_bounds: default_auto_bounds,
             hovered_legend_item: None,
             hidden_items: Default::default(),
-            transform: PlotTransform::new(plot_rect, min_auto_bounds, center_axis.x, center_axis.y),
+            cursor: None,
+            transform: PlotTransform::new(plot_rect, min_auto_bounds, center_axis.x, center_axis.y)
+                .with_scales(axis_scales)
+                .with_inverted_axis(invert_axis),
+            secondary_transforms: Vec::new(),
             last_click_pos_for_zoom: None,
+            zoom_history: Vec::new(),
             x_axis_thickness: Default::default(),
+            legend_size: Vec2::ZERO,
             y_axis_thickness: Default::default(),
         });
 
         let last_plot_transform = mem.transform;
 
+        let mut plot_area_ui = ui.child_ui(plot_rect, Layout::default());
+        plot_area_ui.set_clip_rect(plot_rect.intersect(ui.clip_rect()));
+
         // Call the plot build function.
         let mut plot_ui = PlotUi {
             ctx: ui.ctx().clone(),
             items: Vec::new(),
+            y_axis: 0,
             next_auto_color_idx: 0,
             last_plot_transform,
+            last_secondary_transforms: mem.secondary_transforms.clone(),
+            hidden_items: mem.hidden_items.clone(),
+            plot_area_ui,
             last_auto_bounds: mem.auto_bounds,
+            zoom_history: mem.zoom_history.clone(),
             response,
             bounds_modifications: Vec::new(),
         };
@@ -850,10 +1184,17 @@ impl Plot {
             mut items,
             mut response,
             last_plot_transform,
+            plot_area_ui,
             bounds_modifications,
             ..
         } = plot_ui;
 
+        for (y_axis, _) in &mut items {
+            if y_axes.len() <= *y_axis {
+                *y_axis = 0;
+            }
+        }
+
         // Background
         if show_background {
             ui.painter()
@@ -867,24 +1208,30 @@ impl Plot {
         }
 
         // --- Legend ---
-        let legend = legend_config
-            .and_then(|config| LegendWidget::try_new(plot_rect, config, &items, &mem.hidden_items));
+        let legend = legend_config.and_then(|config| {
+            LegendWidget::try_new(
+                legend_max_rect.unwrap_or(plot_rect),
+                config,
+                items.iter().map(|(_, item)| item.as_ref()),
+                &mem.hidden_items,
+            )
+        });
         // Don't show hover cursor when hovering over legend.
         if mem.hovered_legend_item.is_some() {
             show_x = false;
             show_y = false;
         }
         // Remove the deselected items.
-        items.retain(|item| !mem.hidden_items.contains(item.name()));
+        items.retain(|(_, item)| !mem.hidden_items.contains(item.name()));
         // Highlight the hovered items.
         if let Some(hovered_name) = &mem.hovered_legend_item {
             items
                 .iter_mut()
-                .filter(|entry| entry.name() == hovered_name)
-                .for_each(|entry| entry.highlight());
+                .filter(|(_, entry)| entry.name() == hovered_name)
+                .for_each(|(_, entry)| entry.highlight());
         }
         // Move highlighted items to front.
-        items.sort_by_key(|item| item.highlighted());
+        items.sort_by_key(|(_, item)| item.highlighted());
 
         // --- Bound computation ---
         let mut bounds = *last_plot_transform.bounds();
@@ -923,11 +1270,19 @@ impl Plot {
                 let link_groups: &mut BoundsLinkGroups = data.get_temp_mut_or_default(Id::NULL);
                 if let Some(linked_bounds) = link_groups.0.get(id) {
                     if axes.x {
-                        bounds.set_x(&linked_bounds.bounds);
+                        if link_zoom_only {
+                            bounds.set_width(linked_bounds.bounds.width());
+                        } else {
+                            bounds.set_x(&linked_bounds.bounds);
+                        }
                         mem.auto_bounds.x = linked_bounds.auto_bounds.x;
                     }
                     if axes.y {
-                        bounds.set_y(&linked_bounds.bounds);
+                        if link_zoom_only {
+                            bounds.set_height(linked_bounds.bounds.height());
+                        } else {
+                            bounds.set_y(&linked_bounds.bounds);
+                        }
                         mem.auto_bounds.y = linked_bounds.auto_bounds.y;
                     }
                 };
@@ -936,7 +1291,22 @@ impl Plot {
 
         // Allow double-clicking to reset to the initial bounds.
         if allow_double_click_reset && response.double_clicked() {
-            mem.auto_bounds = true.into();
+            mem.reset_bounds();
+        }
+
+        // Go back to the bounds before the last boxed zoom.
+        let zoom_back = allow_boxed_zoom
+            && !mem.zoom_history.is_empty()
+            && (response.secondary_clicked()
+                || (response.hovered()
+                    && zoom_back_shortcut.map_or(false, |shortcut| {
+                        ui.input_mut(|i| i.consume_shortcut(&shortcut))
+                    })));
+        if zoom_back {
+            if let Some(previous_bounds) = mem.zoom_history.pop() {
+                bounds = previous_bounds;
+                mem.auto_bounds = false.into();
+            }
         }
 
         // Apply bounds modifications.
@@ -957,6 +1327,12 @@ impl Plot {
                     bounds.zoom(zoom_factor, center);
                     mem.auto_bounds = false.into();
                 }
+                BoundsModification::ZoomBack => {
+                    if let Some(previous_bounds) = mem.zoom_history.pop() {
+                        bounds = previous_bounds;
+                        mem.auto_bounds = false.into();
+                    }
+                }
             }
         }
 
@@ -973,30 +1349,41 @@ impl Plot {
 
         // Set bounds automatically based on content.
         if auto_x || auto_y {
-            for item in &items {
+            for (y_axis, item) in &items {
                 let item_bounds = item.bounds();
                 if auto_x {
                     bounds.merge_x(&item_bounds);
                 }
-                if auto_y {
+                if auto_y && *y_axis == 0 {
                     bounds.merge_y(&item_bounds);
                 }
             }
 
+            // Add the margins where the axes are laid out, so that they look the same on logarithmic axes:
+            bounds.clamp_to_scales(axis_scales);
+            let mut linear_bounds = bounds.to_linear(axis_scales);
+
             if auto_x {
-                bounds.add_relative_margin_x(margin_fraction);
+                linear_bounds.add_relative_margin_x(margin_fraction);
             }
 
             if auto_y {
-                bounds.add_relative_margin_y(margin_fraction);
+                linear_bounds.add_relative_margin_y(margin_fraction);
             }
+
+            bounds = linear_bounds.values_from_linear(axis_scales);
         }
 
-        mem.transform = PlotTransform::new(plot_rect, bounds, center_axis.x, center_axis.y);
+        mem.transform = PlotTransform::new(plot_rect, bounds, center_axis.x, center_axis.y)
+            .with_scales(axis_scales)
+            .with_inverted_axis(invert_axis);
 
         // Enforce aspect ratio
         if let Some(data_aspect) = data_aspect {
-            if let Some((_, linked_axes)) = &linked_axes {
+            if letterbox {
+                mem.transform
+                    .set_aspect_by_shrinking_frame(data_aspect as f64);
+            } else if let Some((_, linked_axes)) = &linked_axes {
                 let change_x = linked_axes.y && !linked_axes.x;
                 mem.transform.set_aspect_by_changing_axis(
                     data_aspect as f64,
@@ -1010,8 +1397,45 @@ impl Plot {
             }
         }
 
+        // The other y axes share the x axis, but have their own y bounds:
+        let last_secondary_transforms = std::mem::take(&mut mem.secondary_transforms);
+        mem.secondary_transforms = (1..y_axes.len())
+            .map(|y_axis| {
+                let mut bounds = *mem.transform.bounds();
+                let last_bounds = last_secondary_transforms
+                    .get(y_axis - 1)
+                    .map(|transform| *transform.bounds())
+                    .filter(|bounds| bounds.is_valid_y());
+                match last_bounds {
+                    Some(last_bounds) if !auto_y => bounds.set_y(&last_bounds),
+                    _ => {
+                        let mut item_bounds = PlotBounds::NOTHING;
+                        for (item_y_axis, item) in &items {
+                            if *item_y_axis == y_axis {
+                                item_bounds.merge_y(&item.bounds());
+                            }
+                        }
+                        item_bounds.clamp_to_scales(axis_scales);
+                        let mut linear_bounds = item_bounds.to_linear(axis_scales);
+                        linear_bounds.add_relative_margin_y(margin_fraction);
+                        bounds.set_y(&linear_bounds.values_from_linear(axis_scales));
+                    }
+                }
+                PlotTransform::new(*mem.transform.frame(), bounds, false, center_axis.y)
+                    .with_scales(axis_scales)
+                    .with_inverted_axis(invert_axis)
+            })
+            .collect();
+
+        let modifier_box_zoom = allow_boxed_zoom
+            && boxed_zoom_modifiers.map_or(false, |modifiers| {
+                ui.input(|i| i.modifiers.matches_logically(modifiers))
+            });
+        let box_zoom_dragged = response.dragged_by(boxed_zoom_pointer_button)
+            || (modifier_box_zoom && response.dragged_by(PointerButton::Primary));
+
         // Dragging
-        if allow_drag.any() && response.dragged_by(PointerButton::Primary) {
+        if allow_drag.any() && response.dragged_by(PointerButton::Primary) && !modifier_box_zoom {
             response = response.on_hover_cursor(CursorIcon::Grabbing);
             let mut delta = -response.drag_delta();
             if !allow_drag.x {
@@ -1021,6 +1445,9 @@ impl Plot {
                 delta.y = 0.0;
             }
             mem.transform.translate_bounds(delta);
+            for transform in &mut mem.secondary_transforms {
+                transform.translate_bounds(delta);
+            }
             mem.auto_bounds = mem.auto_bounds.and(!allow_drag);
         }
 
@@ -1028,7 +1455,7 @@ impl Plot {
         let mut boxed_zoom_rect = None;
         if allow_boxed_zoom {
             // Save last click to allow boxed zooming
-            if response.drag_started() && response.dragged_by(boxed_zoom_pointer_button) {
+            if response.drag_started() && box_zoom_dragged {
                 // it would be best for egui that input has a memory of the last click pos because it's a common pattern
                 mem.last_click_pos_for_zoom = response.hover_pos();
             }
@@ -1036,7 +1463,7 @@ impl Plot {
             let box_end_pos = response.hover_pos();
             if let (Some(box_start_pos), Some(box_end_pos)) = (box_start_pos, box_end_pos) {
                 // while dragging prepare a Shape and draw it later on top of the plot
-                if response.dragged_by(boxed_zoom_pointer_button) {
+                if box_zoom_dragged {
                     response = response.on_hover_cursor(CursorIcon::ZoomIn);
                     let rect = epaint::Rect::from_two_pos(box_start_pos, box_end_pos);
                     boxed_zoom_rect = Some((
@@ -1054,20 +1481,16 @@ impl Plot {
                 }
                 // when the click is release perform the zoom
                 if response.drag_stopped() {
-                    let box_start_pos = mem.transform.value_from_position(box_start_pos);
-                    let box_end_pos = mem.transform.value_from_position(box_end_pos);
-                    let new_bounds = PlotBounds {
-                        min: [
-                            box_start_pos.x.min(box_end_pos.x),
-                            box_start_pos.y.min(box_end_pos.y),
-                        ],
-                        max: [
-                            box_start_pos.x.max(box_end_pos.x),
-                            box_start_pos.y.max(box_end_pos.y),
-                        ],
-                    };
+                    let new_bounds =
+                        bounds_between_positions(&mem.transform, box_start_pos, box_end_pos);
                     if new_bounds.is_valid() {
+                        mem.zoom_history.push(*mem.transform.bounds());
                         mem.transform.set_bounds(new_bounds);
+                        for transform in &mut mem.secondary_transforms {
+                            let new_bounds =
+                                bounds_between_positions(transform, box_start_pos, box_end_pos);
+                            transform.set_bounds(new_bounds);
+                        }
                         mem.auto_bounds = false.into();
                     }
                     // reset the boxed zoom state
@@ -1097,6 +1520,9 @@ impl Plot {
                 }
                 if zoom_factor != Vec2::splat(1.0) {
                     mem.transform.zoom(zoom_factor, hover_pos);
+                    for transform in &mut mem.secondary_transforms {
+                        transform.zoom(zoom_factor, hover_pos);
+                    }
                     mem.auto_bounds = mem.auto_bounds.and(!allow_zoom);
                 }
             }
@@ -1110,6 +1536,9 @@ impl Plot {
                 }
                 if scroll_delta != Vec2::ZERO {
                     mem.transform.translate_bounds(-scroll_delta);
+                    for transform in &mut mem.secondary_transforms {
+                        transform.translate_bounds(-scroll_delta);
+                    }
                     mem.auto_bounds = false.into();
                 }
             }
@@ -1123,18 +1552,11 @@ impl Plot {
         let x_steps = Arc::new({
             let input = GridInput {
                 bounds: (bounds.min[0], bounds.max[0]),
-                base_step_size: mem.transform.dvalue_dpos()[0].abs() * grid_spacing.min as f64,
+                base_step_size: mem.transform.dvalue_dpos()[0].abs()
+                    * grid_style.spacing.min as f64,
             };
             (grid_spacers[0])(input)
         });
-        let y_axis_range = bounds.range_y();
-        let y_steps = Arc::new({
-            let input = GridInput {
-                bounds: (bounds.min[1], bounds.max[1]),
-                base_step_size: mem.transform.dvalue_dpos()[1].abs() * grid_spacing.min as f64,
-            };
-            (grid_spacers[1])(input)
-        });
         for (i, mut widget) in x_axis_widgets.into_iter().enumerate() {
             widget.range = x_axis_range.clone();
             widget.transform = Some(mem.transform);
@@ -1143,15 +1565,27 @@ impl Plot {
             mem.x_axis_thickness.insert(i, thickness);
         }
         for (i, mut widget) in y_axis_widgets.into_iter().enumerate() {
-            widget.range = y_axis_range.clone();
-            widget.transform = Some(mem.transform);
-            widget.steps = y_steps.clone();
+            let transform = i
+                .checked_sub(1)
+                .and_then(|i| mem.secondary_transforms.get(i))
+                .unwrap_or(&mem.transform);
+            let bounds = transform.bounds();
+            widget.range = bounds.range_y();
+            widget.transform = Some(*transform);
+            widget.steps = Arc::new({
+                let input = GridInput {
+                    bounds: (bounds.min[1], bounds.max[1]),
+                    base_step_size: transform.dvalue_dpos()[1].abs()
+                        * grid_style.spacing.min as f64,
+                };
+                (grid_spacers[1])(input)
+            });
             let (_response, thickness) = widget.ui(ui, Axis::Y);
             mem.y_axis_thickness.insert(i, thickness);
         }
 
         // Initialize values from functions.
-        for item in &mut items {
+        for (_, item) in &mut items {
             item.initialize(mem.transform.bounds().range_x());
         }
 
@@ -1160,19 +1594,24 @@ impl Plot {
             show_x,
             show_y,
             label_formatter,
+            tooltip_ui,
             coordinates_formatter,
             show_grid,
-            grid_spacing,
+            grid_style,
             transform: mem.transform,
+            secondary_transforms: mem.secondary_transforms.clone(),
             draw_cursor_x: linked_cursors.as_ref().map_or(false, |group| group.1.x),
             draw_cursor_y: linked_cursors.as_ref().map_or(false, |group| group.1.y),
             draw_cursors,
+            crosshair,
+            crosshair_formatter,
+            cursor: mem.cursor,
             grid_spacers,
             sharp_grid_lines,
             clamp_grid,
         };
 
-        let (plot_cursors, hovered_plot_item) = prepared.ui(ui, &response);
+        let (plot_cursors, hovered_plot_item) = prepared.ui(ui, &plot_area_ui, &response);
 
         if let Some(boxed_zoom_rect) = boxed_zoom_rect {
             ui.painter()
@@ -1184,9 +1623,22 @@ impl Plot {
         }
 
         if let Some(mut legend) = legend {
-            ui.add(&mut legend);
+            let legend_response = ui.add(&mut legend);
             mem.hidden_items = legend.hidden_items();
             mem.hovered_legend_item = legend.hovered_item_name();
+
+            if let Some(strip) = legend_strip {
+                ui.advance_cursor_after_rect(strip);
+
+                // Leave the same margin around the legend as inside the plot:
+                let legend_size = legend_response.rect.size() + Vec2::splat(2.0 * 4.0);
+                if (legend_size - mem.legend_size).length() > 0.5 {
+                    mem.legend_size = legend_size;
+                    ui.ctx().request_repaint();
+                }
+            }
+        } else {
+            mem.legend_size = Vec2::ZERO;
         }
 
         if let Some((id, _)) = linked_cursors.as_ref() {
@@ -1237,6 +1689,7 @@ impl Plot {
 
 /// Returns the rect left after adding axes.
 fn axis_widgets(
+    ui: &Ui,
     mem: Option<&PlotMemory>,
     show_axes: Vec2b,
     complete_rect: Rect,
@@ -1276,7 +1729,7 @@ fn axis_widgets(
         let initial_x_range = complete_rect.x_range();
 
         for (i, cfg) in x_axes.iter().enumerate().rev() {
-            let mut height = cfg.thickness(Axis::X);
+            let mut height = cfg.thickness(ui, Axis::X);
             if let Some(mem) = mem {
                 // If the labels took up too much space the previous frame, give them more space now:
                 height = height.max(mem.x_axis_thickness.get(&i).copied().unwrap_or_default());
@@ -1304,7 +1757,7 @@ fn axis_widgets(
         let plot_y_range = rect_left.y_range();
 
         for (i, cfg) in y_axes.iter().enumerate().rev() {
-            let mut width = cfg.thickness(Axis::Y);
+            let mut width = cfg.thickness(ui, Axis::Y);
             if let Some(mem) = mem {
                 // If the labels took up too much space the previous frame, give them more space now:
                 width = width.max(mem.y_axis_thickness.get(&i).copied().unwrap_or_default());
@@ -1353,6 +1806,7 @@ enum BoundsModification {
     Translate(Vec2),
     AutoBounds(Vec2b),
     Zoom(Vec2, PlotPoint),
+    ZoomBack,
 }
 
 // ----------------------------------------------------------------------------
@@ -1376,7 +1830,7 @@ pub struct GridInput {
 }
 
 /// One mark (horizontal or vertical line) in the background grid of a plot.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GridMark {
     /// X or Y value in the plot.
     pub value: f64,
@@ -1390,6 +1844,102 @@ pub struct GridMark {
     pub step_size: f64,
 }
 
+/// How the background grid of a [`Plot`] is painted.
+///
+/// The grid spacer (see [`Plot::x_grid_spacer`]) produces marks with different step sizes.
+/// The marks with the smallest step size are the _minor_ lines, the rest are _major_ lines.
+///
+/// The spacing of the tick labels is configured separately, with [`AxisHints::label_spacing`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{GridLineStyle, GridStyle, Plot};
+///
+/// let grid_style = GridStyle {
+///     major: GridLineStyle {
+///         width: 1.5,
+///         ..Default::default()
+///     },
+///     show_minor: false,
+///     ..Default::default()
+/// };
+/// Plot::new("my_plot")
+///     .grid_style(grid_style)
+///     .show(ui, |plot_ui| {});
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridStyle {
+    /// Grid lines closer together than the minimum (in points) are hidden.
+    /// Further apart they fade in, until they reach full contrast at the maximum.
+    ///
+    /// Default: `8.0..=300.0`.
+    pub spacing: Rangef,
+
+    /// Style of the major grid lines.
+    pub major: GridLineStyle,
+
+    /// Style of the minor grid lines, i.e. those of the finest subdivision.
+    pub minor: GridLineStyle,
+
+    /// Paint the minor grid lines?
+    ///
+    /// Default: `true`.
+    pub show_minor: bool,
+}
+
+impl Default for GridStyle {
+    fn default() -> Self {
+        Self {
+            spacing: Rangef::new(8.0, 300.0),
+            major: GridLineStyle::default(),
+            minor: GridLineStyle::default(),
+            show_minor: true,
+        }
+    }
+}
+
+/// How one kind of grid line is painted. See [`GridStyle`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridLineStyle {
+    /// Width of the lines, in points.
+    ///
+    /// Default: `1.0`.
+    pub width: f32,
+
+    /// Color of the lines at full contrast.
+    ///
+    /// Default: `None`, which uses the text color of the current visuals.
+    pub color: Option<Color32>,
+
+    /// Shape of the contrast curve, as an exponent of the fade-in strength.
+    ///
+    /// The opacity of a line is `strength.powf(contrast_exponent)`,
+    /// where `strength` goes from 0 to 1 over [`GridStyle::spacing`].
+    /// Smaller values make lines reach full contrast sooner.
+    ///
+    /// Default: `0.5`.
+    pub contrast_exponent: f32,
+}
+
+impl Default for GridLineStyle {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            color: None,
+            contrast_exponent: 0.5,
+        }
+    }
+}
+
+impl GridLineStyle {
+    /// The color of a line with the given 0-1 strength.
+    pub fn color_from_strength(&self, ui: &Ui, strength: f32) -> Color32 {
+        let base_color = self.color.unwrap_or_else(|| ui.visuals().text_color());
+        base_color.gamma_multiply(strength.powf(self.contrast_exponent))
+    }
+}
+
 /// Recursively splits the grid into `base` subdivisions (e.g. 100, 10, 1).
 ///
 /// The logarithmic base, expressing how many times each grid unit is subdivided.
@@ -1418,6 +1968,81 @@ pub fn log_grid_spacer(log_base: i64) -> GridSpacer {
     Box::new(step_sizes)
 }
 
+/// Grid marks for a logarithmic axis, see [`Plot::x_axis_scale`].
+///
+/// The thickest lines are at powers of ten, with thinner lines at 2, 3, … 9 times a power of ten.
+/// When zoomed out over many decades, only every 10th or 100th decade gets a line,
+/// and when zoomed in to less than a decade, the marks are spread out like in [`log_grid_spacer`].
+///
+/// The step sizes of the returned marks are measured in decades,
+/// just like [`GridInput::base_step_size`] on a logarithmic axis.
+pub fn log10_grid_spacer() -> GridSpacer {
+    let linear_spacer = log_grid_spacer(10);
+    let step_sizes = move |input: GridInput| -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+
+        // handle degenerate cases
+        if input.base_step_size.abs() < f64::EPSILON || min <= 0.0 || max <= min {
+            return Vec::new();
+        }
+
+        let (log_min, log_max) = (min.log10(), max.log10());
+
+        if log_max - log_min < 1.0 {
+            // Within a decade the axis is almost linear, so use linear marks,
+            // as fine as they need to be at the low end:
+            let mut marks = linear_spacer(GridInput {
+                bounds: input.bounds,
+                base_step_size: input.base_step_size * min * std::f64::consts::LN_10,
+            });
+            for mark in &mut marks {
+                mark.step_size = step_size_in_decades(mark.value, mark.step_size);
+            }
+            return marks;
+        }
+
+        // Powers of ten, with step sizes in decades:
+        let smallest_visible_unit = next_power(input.base_step_size, 10.0).max(1.0);
+        let mut marks = generate_marks(
+            [
+                smallest_visible_unit,
+                smallest_visible_unit * 10.0,
+                smallest_visible_unit * 100.0,
+            ],
+            (log_min, log_max),
+        );
+        for mark in &mut marks {
+            mark.value = 10.0_f64.powi(mark.value.round() as i32);
+        }
+
+        if smallest_visible_unit == 1.0 {
+            // Thinner lines in between, which fade in as the gap to the next one grows:
+            for decade in (log_min.floor() as i32)..=(log_max.floor() as i32) {
+                let power = 10.0_f64.powi(decade);
+                for multiple in 2..=9 {
+                    let value = multiple as f64 * power;
+                    if min <= value && value <= max {
+                        marks.push(GridMark {
+                            value,
+                            step_size: ((multiple + 1) as f64 / multiple as f64).log10(),
+                        });
+                    }
+                }
+            }
+            marks.sort_by(|a, b| cmp_f64(a.value, b.value));
+        }
+
+        marks
+    };
+
+    Box::new(step_sizes)
+}
+
+/// The size of a step at the given value, measured in decades (see [`AxisScale::Log10`]).
+fn step_size_in_decades(value: f64, step_size: f64) -> f64 {
+    step_size / (value.abs() * std::f64::consts::LN_10)
+}
+
 /// Splits the grid into uniform-sized spacings (e.g. 100, 25, 1).
 ///
 /// This function should return 3 positive step sizes, designating where the lines in the grid are drawn.
@@ -1438,33 +2063,51 @@ pub fn uniform_grid_spacer(spacer: impl Fn(GridInput) -> [f64; 3] + 'static) ->
 // ----------------------------------------------------------------------------
 
 struct PreparedPlot {
-    items: Vec<Box<dyn PlotItem>>,
+    /// The items, and the index of the y axis they are plotted against.
+    items: Vec<(usize, Box<dyn PlotItem>)>,
     show_x: bool,
     show_y: bool,
     label_formatter: LabelFormatter,
+    tooltip_ui: Option<Arc<TooltipUiFn>>,
     coordinates_formatter: Option<(Corner, CoordinatesFormatter)>,
     // axis_formatters: [AxisFormatter; 2],
     transform: PlotTransform,
+
+    /// See [`PlotMemory::secondary_transforms`].
+    secondary_transforms: Vec<PlotTransform>,
     show_grid: Vec2b,
-    grid_spacing: Rangef,
+    grid_style: GridStyle,
     grid_spacers: [GridSpacer; 2],
     draw_cursor_x: bool,
     draw_cursor_y: bool,
     draw_cursors: Vec<Cursor>,
+    crosshair: bool,
+    crosshair_formatter: LabelFormatter,
+
+    /// See [`PlotMemory::cursor`].
+    cursor: Option<PlotPoint>,
 
     sharp_grid_lines: bool,
     clamp_grid: bool,
 }
 
 impl PreparedPlot {
-    fn ui(self, ui: &mut Ui, response: &Response) -> (Vec<Cursor>, Option<Id>) {
+    /// The transform of the given y axis.
+    fn transform_for(&self, y_axis: usize) -> &PlotTransform {
+        y_axis
+            .checked_sub(1)
+            .and_then(|i| self.secondary_transforms.get(i))
+            .unwrap_or(&self.transform)
+    }
+
+    fn ui(self, ui: &mut Ui, plot_area_ui: &Ui, response: &Response) -> (Vec<Cursor>, Option<Id>) {
         let mut axes_shapes = Vec::new();
 
         if self.show_grid.x {
-            self.paint_grid(ui, &mut axes_shapes, Axis::X, self.grid_spacing);
+            self.paint_grid(ui, &mut axes_shapes, Axis::X);
         }
         if self.show_grid.y {
-            self.paint_grid(ui, &mut axes_shapes, Axis::Y, self.grid_spacing);
+            self.paint_grid(ui, &mut axes_shapes, Axis::Y);
         }
 
         // Sort the axes by strength so that those with higher strength are drawn in front.
@@ -1474,15 +2117,15 @@ impl PreparedPlot {
 
         let transform = &self.transform;
 
-        let mut plot_ui = ui.child_ui(*transform.frame(), Layout::default());
-        plot_ui.set_clip_rect(transform.frame().intersect(ui.clip_rect()));
-        for item in &self.items {
-            item.shapes(&plot_ui, transform, &mut shapes);
+        for (y_axis, item) in &self.items {
+            item.shapes(plot_area_ui, self.transform_for(*y_axis), &mut shapes);
         }
 
         let hover_pos = response.hover_pos();
         let (cursors, hovered_item_id) = if let Some(pointer) = hover_pos {
-            self.hover(ui, pointer, &mut shapes)
+            self.hover(ui, pointer, response.id, &mut shapes)
+        } else if let Some(PlotPoint { x, y }) = self.cursor {
+            (vec![Cursor::Vertical { x }, Cursor::Horizontal { y }], None)
         } else {
             (Vec::new(), None)
         };
@@ -1518,6 +2161,31 @@ impl PreparedPlot {
         draw_cursor(&self.draw_cursors, false);
         draw_cursor(&cursors, true);
 
+        if self.crosshair {
+            // The pointer is either in this plot, or in another plot of the cursor link group:
+            let crosshair_x = hover_pos
+                .map(|pointer| transform.value_from_position(pointer).x)
+                .or_else(|| {
+                    self.draw_cursors
+                        .iter()
+                        .rev()
+                        .find_map(|cursor| match cursor {
+                            Cursor::Vertical { x } => Some(*x),
+                            Cursor::Horizontal { .. } => None,
+                        })
+                });
+            if let Some(x) = crosshair_x {
+                if hover_pos.is_none() && !self.draw_cursor_x {
+                    shapes.push(vertical_line(
+                        transform.position_from_point(&PlotPoint::new(x, 0.0)),
+                        transform,
+                        line_color,
+                    ));
+                }
+                self.crosshair_readouts(ui, x, &mut shapes);
+            }
+        }
+
         let painter = ui.painter().with_clip_rect(*transform.frame());
         painter.extend(shapes);
 
@@ -1541,15 +2209,76 @@ impl PreparedPlot {
         (cursors, hovered_item_id)
     }
 
-    fn paint_grid(&self, ui: &Ui, shapes: &mut Vec<(Shape, f32)>, axis: Axis, fade_range: Rangef) {
+    /// A marker on every item where it crosses the crosshair at `x`, and a readout of its value.
+    fn crosshair_readouts(&self, ui: &Ui, x: f64, shapes: &mut Vec<Shape>) {
+        let frame = self.transform.frame();
+        let font_id = TextStyle::Body.resolve(ui.style());
+
+        // Put the readouts on the side of the rule with the most room:
+        let rule_x = self.transform.position_from_point_x(x);
+        let (align, mut text_pos) = if rule_x < frame.center().x {
+            (Align2::LEFT_TOP, pos2(rule_x + 4.0, frame.top() + 4.0))
+        } else {
+            (Align2::RIGHT_TOP, pos2(rule_x - 4.0, frame.top() + 4.0))
+        };
+
+        for (y_axis, item) in &self.items {
+            let PlotGeometry::Points(points) = item.geometry() else {
+                continue;
+            };
+            let bounds = item.bounds();
+            if x < bounds.min()[0] || bounds.max()[0] < x {
+                continue;
+            }
+            let Some(value) = points
+                .iter()
+                .filter(|point| point.y.is_finite())
+                .min_by_key(|point| (point.x - x).abs().ord())
+            else {
+                continue;
+            };
+
+            let transform = self.transform_for(*y_axis);
+            let color = item.color();
+            shapes.push(Shape::circle_filled(
+                transform.position_from_point(value),
+                3.0,
+                color,
+            ));
+
+            let text = if let Some(formatter) = &self.crosshair_formatter {
+                formatter(item.name(), value)
+            } else {
+                let scale = transform.dvalue_dpos();
+                let y_decimals =
+                    ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
+                if item.name().is_empty() {
+                    format!("{:.*}", y_decimals, value.y)
+                } else {
+                    format!("{}: {:.*}", item.name(), y_decimals, value.y)
+                }
+            };
+            if text.is_empty() {
+                continue;
+            }
+            let galley = ui.fonts(|f| f.layout_no_wrap(text, font_id.clone(), color));
+            let rect = align.anchor_size(text_pos, galley.size());
+            text_pos.y = rect.bottom();
+            shapes.push(Shape::galley(rect.min, galley, color));
+        }
+    }
+
+    fn paint_grid(&self, ui: &Ui, shapes: &mut Vec<(Shape, f32)>, axis: Axis) {
         #![allow(clippy::collapsible_else_if)]
         let Self {
             transform,
             // axis_formatters,
             grid_spacers,
+            grid_style,
             clamp_grid,
             ..
         } = self;
+        let fade_range = grid_style.spacing;
 
         let iaxis = usize::from(axis);
 
@@ -1563,12 +2292,21 @@ impl PreparedPlot {
         };
         let steps = (grid_spacers[iaxis])(input);
 
+        // The finest subdivision of the grid is painted with the minor style.
+        // If there is only one step size, all lines are major.
+        let minor_step_size = steps.iter().map(|step| step.step_size).reduce(f64::min);
+        let has_major_steps = steps
+            .iter()
+            .any(|step| Some(step.step_size) != minor_step_size);
+
         let clamp_range = clamp_grid.then(|| {
             let mut tight_bounds = PlotBounds::NOTHING;
-            for item in &self.items {
+            for (y_axis, item) in &self.items {
                 let item_bounds = item.bounds();
                 tight_bounds.merge_x(&item_bounds);
-                tight_bounds.merge_y(&item_bounds);
+                if *y_axis == 0 {
+                    tight_bounds.merge_y(&item_bounds);
+                }
             }
             tight_bounds
         });
@@ -1603,9 +2341,19 @@ impl PreparedPlot {
                 continue; // Too close together
             }
 
+            let is_minor = has_major_steps && Some(step.step_size) == minor_step_size;
+            if is_minor && !grid_style.show_minor {
+                continue;
+            }
+            let line_style = if is_minor {
+                &grid_style.minor
+            } else {
+                &grid_style.major
+            };
+
             let line_strength = remap_clamp(spacing_in_points, fade_range, 0.0..=1.0);
 
-            let line_color = color_from_strength(ui, line_strength);
+            let line_color = line_style.color_from_strength(ui, line_strength);
 
             let mut p0 = pos_in_gui;
             let mut p1 = pos_in_gui;
@@ -1632,13 +2380,19 @@ impl PreparedPlot {
             }
 
             shapes.push((
-                Shape::line_segment([p0, p1], Stroke::new(1.0, line_color)),
+                Shape::line_segment([p0, p1], Stroke::new(line_style.width, line_color)),
                 line_strength,
             ));
         }
     }
 
-    fn hover(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) -> (Vec<Cursor>, Option<Id>) {
+    fn hover(
+        &self,
+        ui: &Ui,
+        pointer: Pos2,
+        plot_id: Id,
+        shapes: &mut Vec<Shape>,
+    ) -> (Vec<Cursor>, Option<Id>) {
         let Self {
             transform,
             show_x,
@@ -1656,12 +2410,13 @@ impl PreparedPlot {
 
         let candidates = items
             .iter()
-            .filter(|entry| entry.allow_hover())
-            .filter_map(|item| {
+            .filter(|(_, entry)| entry.allow_hover())
+            .filter_map(|(y_axis, item)| {
                 let item = &**item;
+                let transform = self.transform_for(*y_axis);
                 let closest = item.find_closest(pointer, transform);
 
-                Some(item).zip(closest)
+                Some((item, transform)).zip(closest)
             });
 
         let closest = candidates
@@ -1673,12 +2428,31 @@ impl PreparedPlot {
             transform,
             show_x: *show_x,
             show_y: *show_y,
+            show_label: true,
         };
 
         let mut cursors = Vec::new();
 
-        let hovered_plot_item_id = if let Some((item, elem)) = closest {
+        let hovered_plot_item_id = if let Some(((item, item_transform), elem)) = closest {
+            let plot = items::PlotConfig {
+                transform: item_transform,
+                // Keep the marker and rulers, but show the tooltip instead of the text label:
+                show_label: self.tooltip_ui.is_none(),
+                ..plot
+            };
+            let index = elem.index;
             item.on_hover(elem, shapes, &mut cursors, &plot, label_formatter);
+
+            if let Some(tooltip_ui) = &self.tooltip_ui {
+                let point = match item.geometry() {
+                    PlotGeometry::Points(points) => points.get(index).copied(),
+                    PlotGeometry::None | PlotGeometry::Rects => None,
+                }
+                .unwrap_or_else(|| item_transform.value_from_position(pointer));
+                egui::show_tooltip_at_pointer(ui.ctx(), plot_id.with("tooltip"), |ui| {
+                    tooltip_ui(ui, item, &point);
+                });
+            }
             item.id()
         } else {
             let value = transform.value_from_position(pointer);
@@ -1698,6 +2472,16 @@ impl PreparedPlot {
     }
 }
 
+/// The plot bounds of the box between two screen positions.
+fn bounds_between_positions(transform: &PlotTransform, a: Pos2, b: Pos2) -> PlotBounds {
+    let a = transform.value_from_position(a);
+    let b = transform.value_from_position(b);
+    PlotBounds {
+        min: [a.x.min(b.x), a.y.min(b.y)],
+        max: [a.x.max(b.x), a.y.max(b.y)],
+    }
+}
+
 /// Returns next bigger power in given base
 /// e.g.
 /// ```ignore
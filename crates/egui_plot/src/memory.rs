@@ -1,8 +1,8 @@
 use std::collections::BTreeMap;
 
-use egui::{ahash, Context, Id, Pos2, Vec2b};
+use egui::{ahash, Context, Id, Pos2, Vec2, Vec2b};
 
-use crate::{PlotBounds, PlotTransform};
+use crate::{PlotBounds, PlotPoint, PlotTransform};
 
 /// Information about the plot that has to persist between frames.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -20,18 +20,37 @@ pub struct PlotMemory {
     /// Which items _not_ to show?
     pub hidden_items: ahash::HashSet<String>,
 
+    /// A cursor to show while the plot is not hovered, e.g. to point out a value from outside the plot.
+    ///
+    /// Shown as a vertical and a horizontal line, and shared with linked plots
+    /// just like the cursor of a hovering pointer (see [`crate::Plot::link_cursor`]).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub cursor: Option<PlotPoint>,
+
     /// The transform from last frame.
     pub(crate) transform: PlotTransform,
 
+    /// The transforms of the other y axes from last frame, see [`crate::PlotUi::set_y_axis`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) secondary_transforms: Vec<PlotTransform>,
+
     /// Allows to remember the first click position when performing a boxed zoom
     pub(crate) last_click_pos_for_zoom: Option<Pos2>,
 
+    /// The bounds before each boxed zoom, most recent last.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) zoom_history: Vec<PlotBounds>,
+
     /// The thickness of each of the axes the previous frame.
     ///
     /// This is used in the next frame to make the axes thicker
     /// in order to fit the labels, if necessary.
     pub(crate) x_axis_thickness: BTreeMap<usize, f32>,
     pub(crate) y_axis_thickness: BTreeMap<usize, f32>,
+
+    /// The size of the legend the previous frame, when placed outside of the plot area.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) legend_size: Vec2,
 }
 
 impl PlotMemory {
@@ -52,10 +71,99 @@ impl PlotMemory {
     }
 
     /// Plot-space bounds.
+    ///
+    /// These are only kept if [`Self::auto_bounds`] is turned off for the axes you want to set,
+    /// so you usually want [`Self::set_bounds_and_stop_auto`] instead.
     #[inline]
     pub fn set_bounds(&mut self, bounds: PlotBounds) {
         self.transform.set_bounds(bounds);
     }
+
+    /// Set the plot-space bounds, and turn off [`Self::auto_bounds`] so that they are kept.
+    ///
+    /// This is what happens when the user pans or zooms the plot.
+    #[inline]
+    pub fn set_bounds_and_stop_auto(&mut self, bounds: PlotBounds) {
+        self.set_bounds(bounds);
+        self.auto_bounds = false.into();
+    }
+
+    /// Go back to the automatic bounds, e.g. for a "reset zoom" button.
+    ///
+    /// This is what happens when the user double-clicks the plot.
+    ///
+    /// This also clears the [`Self::zoom_history`].
+    #[inline]
+    pub fn reset_bounds(&mut self) {
+        self.auto_bounds = true.into();
+        self.zoom_history.clear();
+    }
+
+    /// The bounds before each boxed zoom, most recent last.
+    #[inline]
+    pub fn zoom_history(&self) -> &[PlotBounds] {
+        &self.zoom_history
+    }
+
+    /// Go back to the bounds before the last boxed zoom, e.g. for an "undo zoom" button.
+    ///
+    /// This is what happens when the user right-clicks the plot.
+    /// Returns `false` if there is no zoom to undo.
+    pub fn zoom_back(&mut self) -> bool {
+        if let Some(bounds) = self.zoom_history.pop() {
+            self.set_bounds_and_stop_auto(bounds);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Is the item with the given name shown, i.e. not hidden in the legend?
+    #[inline]
+    pub fn is_item_visible(&self, name: &str) -> bool {
+        !self.hidden_items.contains(name)
+    }
+
+    /// Show or hide the item with the given name, like clicking it in the legend.
+    pub fn set_item_visible(&mut self, name: impl Into<String>, visible: bool) {
+        let name = name.into();
+        if visible {
+            self.hidden_items.remove(&name);
+        } else {
+            self.hidden_items.insert(name);
+        }
+    }
+
+    /// Load the memory of the plot with the given id, change it, and store it again.
+    ///
+    /// This lets toolbars and other code outside of [`crate::Plot::show`] control the plot,
+    /// with the changes taking effect the next time the plot is shown.
+    /// Use [`crate::Plot::id`] to give the plot an id that is easy to get at.
+    ///
+    /// Returns `None` if the plot has not been shown yet.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Plot, PlotMemory};
+    ///
+    /// let plot_id = egui::Id::new("my_plot");
+    /// if ui.button("Reset zoom").clicked() {
+    ///     PlotMemory::modify(ui.ctx(), plot_id, |mem| mem.reset_bounds());
+    /// }
+    /// if ui.button("Hide sine").clicked() {
+    ///     PlotMemory::modify(ui.ctx(), plot_id, |mem| mem.set_item_visible("sine", false));
+    /// }
+    /// Plot::new("my_plot").id(plot_id).show(ui, |plot_ui| {
+    ///     // …
+    /// });
+    /// # });
+    /// ```
+    pub fn modify<R>(ctx: &Context, id: Id, modify: impl FnOnce(&mut Self) -> R) -> Option<R> {
+        let mut mem = Self::load(ctx, id)?;
+        let result = modify(&mut mem);
+        mem.store(ctx, id);
+        Some(result)
+    }
 }
 
 #[cfg(feature = "serde")]
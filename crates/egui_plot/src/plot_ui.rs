@@ -4,10 +4,27 @@ use crate::*;
 /// provided to [`Plot::show`]. See [`Plot`] for an example of how to use it.
 pub struct PlotUi {
     pub(crate) ctx: Context,
-    pub(crate) items: Vec<Box<dyn PlotItem>>,
+
+    /// The items, and the index of the y axis they are plotted against.
+    pub(crate) items: Vec<(usize, Box<dyn PlotItem>)>,
+
+    /// See [`Self::set_y_axis`].
+    pub(crate) y_axis: usize,
+
     pub(crate) next_auto_color_idx: usize,
     pub(crate) last_plot_transform: PlotTransform,
+
+    /// See [`PlotMemory::secondary_transforms`].
+    pub(crate) last_secondary_transforms: Vec<PlotTransform>,
+
+    /// See [`PlotMemory::hidden_items`].
+    pub(crate) hidden_items: ahash::HashSet<String>,
+
+    /// A child ui covering the plot area, which the items are painted with.
+    pub(crate) plot_area_ui: Ui,
+
     pub(crate) last_auto_bounds: Vec2b,
+    pub(crate) zoom_history: Vec<PlotBounds>,
     pub(crate) response: Response,
     pub(crate) bounds_modifications: Vec<BoundsModification>,
 }
@@ -16,9 +33,7 @@ impl PlotUi {
     fn auto_color(&mut self) -> Color32 {
         let i = self.next_auto_color_idx;
         self.next_auto_color_idx += 1;
-        let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
-        let h = i as f32 * golden_ratio;
-        Hsva::new(h, 0.85, 0.5, 1.0).into() // TODO(emilk): OkLab or some other perspective color space
+        self.ctx.style().visuals.series_color(i)
     }
 
     pub fn ctx(&self) -> &Context {
@@ -56,6 +71,20 @@ impl PlotUi {
             .push(BoundsModification::AutoBounds(auto_bounds));
     }
 
+    /// The bounds before each boxed zoom, most recent last, as they were in the last frame.
+    ///
+    /// Can be used to e.g. only enable an "undo zoom" button when there is something to undo.
+    pub fn zoom_history(&self) -> &[PlotBounds] {
+        &self.zoom_history
+    }
+
+    /// Go back to the bounds before the last boxed zoom, if any.
+    ///
+    /// This is what happens when the user right-clicks the plot.
+    pub fn zoom_back(&mut self) {
+        self.bounds_modifications.push(BoundsModification::ZoomBack);
+    }
+
     /// Can be used to check if the plot was hovered or clicked.
     pub fn response(&self) -> &Response {
         &self.response
@@ -116,9 +145,66 @@ impl PlotUi {
         self.last_plot_transform.value_from_position(position)
     }
 
+    /// The shapes of the items added so far, for saving the plot as an image.
+    ///
+    /// The items are painted with the transforms of the last frame, like [`Self::screen_from_plot`].
+    /// Items hidden in the legend are left out,
+    /// and points given by a function are sampled over the x range of the last frame.
+    ///
+    /// See [`PlotExport`] for how to turn the result into a PNG or an SVG.
+    pub fn export(&mut self) -> PlotExport {
+        let transform = self.last_plot_transform;
+        let mut shapes = Vec::new();
+        for (y_axis, item) in &mut self.items {
+            if self.hidden_items.contains(item.name()) {
+                continue;
+            }
+            let item_transform = y_axis
+                .checked_sub(1)
+                .and_then(|i| self.last_secondary_transforms.get(i))
+                .unwrap_or(&transform);
+            item.initialize(item_transform.bounds().range_x());
+            item.shapes(&self.plot_area_ui, item_transform, &mut shapes);
+        }
+        PlotExport { transform, shapes }
+    }
+
+    /// Plot the items added after this against another y axis.
+    ///
+    /// The index is into the axes given to [`Plot::custom_y_axes`], where `0` is the main axis.
+    /// Each y axis has its own range, which is fit to its own items,
+    /// so you can plot quantities with different units in the same plot.
+    /// Dragging and zooming moves all y axes together.
+    ///
+    /// Items for a y axis that doesn't exist are plotted against the main axis.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{AxisHints, HPlacement, Line, Plot, PlotPoints};
+    ///
+    /// let temperature: PlotPoints = (0..24).map(|h| [h as f64, 15.0 + h as f64 * 0.5]).collect();
+    /// let pressure: PlotPoints = (0..24).map(|h| [h as f64, 1013.0 - h as f64 * 0.2]).collect();
+    ///
+    /// Plot::new("weather")
+    ///     .custom_y_axes(vec![
+    ///         AxisHints::new_y().label("°C"),
+    ///         AxisHints::new_y().label("hPa").placement(HPlacement::Right),
+    ///     ])
+    ///     .show(ui, |plot_ui| {
+    ///         plot_ui.line(Line::new(temperature).name("Temperature"));
+    ///         plot_ui.set_y_axis(1);
+    ///         plot_ui.line(Line::new(pressure).name("Pressure"));
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn set_y_axis(&mut self, y_axis: usize) {
+        self.y_axis = y_axis;
+    }
+
     /// Add an arbitrary item.
     pub fn add(&mut self, item: impl PlotItem + 'static) {
-        self.items.push(Box::new(item));
+        self.items.push((self.y_axis, Box::new(item)));
     }
 
     /// Add a data line.
@@ -131,7 +217,29 @@ impl PlotUi {
         if line.stroke.color == Color32::TRANSPARENT {
             line.stroke.color = self.auto_color();
         }
-        self.items.push(Box::new(line));
+        self.items.push((self.y_axis, Box::new(line)));
+    }
+
+    /// Add vertical lines from a baseline to each point.
+    pub fn impulses(&mut self, mut impulses: Impulses) {
+        if impulses.series.is_empty() {
+            return;
+        };
+
+        // Give the stroke an automatic color if no color has been assigned.
+        if impulses.stroke.color == Color32::TRANSPARENT {
+            impulses.stroke.color = self.auto_color();
+        }
+        self.items.push((self.y_axis, Box::new(impulses)));
+    }
+
+    /// Add a line showing the samples of a [`PlotRingBuffer`].
+    pub fn streaming_line(&mut self, mut line: StreamingLine) {
+        // Give the stroke an automatic color if no color has been assigned.
+        if line.stroke.color == Color32::TRANSPARENT {
+            line.stroke.color = self.auto_color();
+        }
+        self.items.push((self.y_axis, Box::new(line)));
     }
 
     /// Add a polygon. The polygon has to be convex.
@@ -144,7 +252,20 @@ impl PlotUi {
         if polygon.stroke.color == Color32::TRANSPARENT {
             polygon.stroke.color = self.auto_color();
         }
-        self.items.push(Box::new(polygon));
+        self.items.push((self.y_axis, Box::new(polygon)));
+    }
+
+    /// Add a filled band between two curves.
+    pub fn band(&mut self, mut band: Band) {
+        if band.lower.is_empty() && band.upper.is_empty() {
+            return;
+        };
+
+        // Give the band an automatic color if no color has been assigned.
+        if band.stroke.color == Color32::TRANSPARENT {
+            band.stroke.color = self.auto_color();
+        }
+        self.items.push((self.y_axis, Box::new(band)));
     }
 
     /// Add a text.
@@ -153,7 +274,16 @@ impl PlotUi {
             return;
         };
 
-        self.items.push(Box::new(text));
+        self.items.push((self.y_axis, Box::new(text)));
+    }
+
+    /// Add a text box pointing at a position in the plot.
+    pub fn callout(&mut self, callout: Callout) {
+        if callout.text.is_empty() {
+            return;
+        };
+
+        self.items.push((self.y_axis, Box::new(callout)));
     }
 
     /// Add data points.
@@ -166,7 +296,7 @@ impl PlotUi {
         if points.color == Color32::TRANSPARENT {
             points.color = self.auto_color();
         }
-        self.items.push(Box::new(points));
+        self.items.push((self.y_axis, Box::new(points)));
     }
 
     /// Add arrows.
@@ -179,12 +309,33 @@ impl PlotUi {
         if arrows.color == Color32::TRANSPARENT {
             arrows.color = self.auto_color();
         }
-        self.items.push(Box::new(arrows));
+        self.items.push((self.y_axis, Box::new(arrows)));
+    }
+
+    /// Add error bars.
+    pub fn error_bars(&mut self, mut error_bars: ErrorBars) {
+        if error_bars.points.is_empty() {
+            return;
+        };
+
+        // Give the error bars an automatic color if no color has been assigned.
+        if error_bars.stroke.color == Color32::TRANSPARENT {
+            error_bars.stroke.color = self.auto_color();
+        }
+        self.items.push((self.y_axis, Box::new(error_bars)));
     }
 
     /// Add an image.
     pub fn image(&mut self, image: PlotImage) {
-        self.items.push(Box::new(image));
+        self.items.push((self.y_axis, Box::new(image)));
+    }
+
+    /// Add a heatmap.
+    pub fn heatmap(&mut self, heatmap: Heatmap) {
+        if heatmap.rows == 0 {
+            return;
+        }
+        self.items.push((self.y_axis, Box::new(heatmap)));
     }
 
     /// Add a horizontal line.
@@ -194,7 +345,7 @@ impl PlotUi {
         if hline.stroke.color == Color32::TRANSPARENT {
             hline.stroke.color = self.auto_color();
         }
-        self.items.push(Box::new(hline));
+        self.items.push((self.y_axis, Box::new(hline)));
     }
 
     /// Add a vertical line.
@@ -204,7 +355,16 @@ impl PlotUi {
         if vline.stroke.color == Color32::TRANSPARENT {
             vline.stroke.color = self.auto_color();
         }
-        self.items.push(Box::new(vline));
+        self.items.push((self.y_axis, Box::new(vline)));
+    }
+
+    /// Add a shaded range of values along one axis.
+    /// Always fills the full extent of the plot along the other axis.
+    pub fn span(&mut self, mut span: Span) {
+        if span.stroke.color == Color32::TRANSPARENT {
+            span.stroke.color = self.auto_color();
+        }
+        self.items.push((self.y_axis, Box::new(span)));
     }
 
     /// Add a box plot diagram.
@@ -217,7 +377,7 @@ impl PlotUi {
         if box_plot.default_color == Color32::TRANSPARENT {
             box_plot = box_plot.color(self.auto_color());
         }
-        self.items.push(Box::new(box_plot));
+        self.items.push((self.y_axis, Box::new(box_plot)));
     }
 
     /// Add a bar chart.
@@ -230,6 +390,18 @@ impl PlotUi {
         if chart.default_color == Color32::TRANSPARENT {
             chart = chart.color(self.auto_color());
         }
-        self.items.push(Box::new(chart));
+        self.items.push((self.y_axis, Box::new(chart)));
+    }
+
+    /// Add a bar chart of the distribution of some samples, see [`Histogram`].
+    pub fn histogram(&mut self, histogram: Histogram) {
+        self.bar_chart(histogram.into_bar_chart());
+    }
+
+    /// Add bar charts side by side, see [`BarGroup`].
+    pub fn bar_group(&mut self, group: BarGroup) {
+        for chart in group.into_charts() {
+            self.bar_chart(chart);
+        }
     }
 }
@@ -0,0 +1,243 @@
+use std::{ops::RangeInclusive, sync::Arc};
+
+use egui::{
+    emath::remap_clamp, Pos2, Rangef, Response, Sense, Stroke, Ui, Vec2, Widget, WidgetText,
+};
+
+use super::{
+    axis::AxisWidget, color_from_strength, log_grid_spacer, Axis, AxisHints, GridInput, GridMark,
+    GridSpacer, HPlacement, Placement, PlotBounds, PlotTransform, VPlacement,
+};
+
+/// The length of the longest tick marks.
+const TICK_LENGTH: f32 = 8.0;
+
+/// A standalone axis, with tick marks and labels, to put next to custom canvases
+/// such as timelines or waveform views.
+///
+/// It uses the same tick logic as the axes of a [`crate::Plot`],
+/// so it can be configured with the same grid spacers and formatters.
+///
+/// Use [`Self::horizontal`] for a ruler along the x axis, and [`Self::vertical`] for one along the y axis.
+/// As in a plot, values increase to the right and upwards.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Ruler, VPlacement};
+///
+/// let visible_seconds = 0.0..=60.0;
+/// ui.add(
+///     Ruler::horizontal(visible_seconds)
+///         .placement(VPlacement::Top)
+///         .formatter(|mark, _digits, _range| format!("{}s", mark.value)),
+/// );
+/// // … paint the timeline below it, using the same range.
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Ruler {
+    axis: Axis,
+    range: RangeInclusive<f64>,
+    hints: AxisHints,
+    grid_spacer: GridSpacer,
+    grid_spacing: Rangef,
+    length: Option<f32>,
+}
+
+impl Ruler {
+    /// A ruler along the x axis, showing the given range from left to right.
+    pub fn horizontal(range: RangeInclusive<f64>) -> Self {
+        Self::new(Axis::X, range)
+    }
+
+    /// A ruler along the y axis, showing the given range from bottom to top.
+    pub fn vertical(range: RangeInclusive<f64>) -> Self {
+        Self::new(Axis::Y, range)
+    }
+
+    /// A ruler along the given axis.
+    pub fn new(axis: Axis, range: RangeInclusive<f64>) -> Self {
+        Self {
+            axis,
+            range,
+            hints: AxisHints::new(axis),
+            grid_spacer: log_grid_spacer(10),
+            grid_spacing: Rangef::new(8.0, 300.0),
+            length: None,
+        }
+    }
+
+    /// Specify custom formatter for the tick labels.
+    ///
+    /// See [`AxisHints::formatter`].
+    #[inline]
+    pub fn formatter(
+        mut self,
+        fmt: impl Fn(GridMark, usize, &RangeInclusive<f64>) -> String + 'static,
+    ) -> Self {
+        self.hints = self.hints.formatter(fmt);
+        self
+    }
+
+    /// Specify a label for the whole ruler.
+    #[inline]
+    pub fn label(mut self, label: impl Into<WidgetText>) -> Self {
+        self.hints = self.hints.label(label);
+        self
+    }
+
+    /// Specify maximum number of digits for the tick labels.
+    ///
+    /// See [`AxisHints::max_digits`].
+    #[inline]
+    pub fn max_digits(mut self, digits: usize) -> Self {
+        self.hints = self.hints.max_digits(digits);
+        self
+    }
+
+    /// On which side of the canvas the ruler is.
+    ///
+    /// For horizontal rulers, use [`VPlacement`]. For vertical rulers, use [`HPlacement`].
+    /// The tick marks are on the side facing the canvas.
+    ///
+    /// Default: below or to the left of the canvas.
+    #[inline]
+    pub fn placement(mut self, placement: impl Into<Placement>) -> Self {
+        self.hints = self.hints.placement(placement);
+        self
+    }
+
+    /// Set the minimum spacing between tick labels.
+    ///
+    /// See [`AxisHints::label_spacing`].
+    #[inline]
+    pub fn label_spacing(mut self, range: impl Into<Rangef>) -> Self {
+        self.hints = self.hints.label_spacing(range);
+        self
+    }
+
+    /// Where to put the tick marks.
+    ///
+    /// See [`crate::Plot::x_grid_spacer`].
+    /// Default: [`log_grid_spacer`] with base 10.
+    #[inline]
+    pub fn grid_spacer(mut self, spacer: impl Fn(GridInput) -> Vec<GridMark> + 'static) -> Self {
+        self.grid_spacer = Box::new(spacer);
+        self
+    }
+
+    /// The density of the tick marks.
+    ///
+    /// Tick marks closer together than the minimum are hidden,
+    /// and fade in until they are the maximum apart.
+    ///
+    /// See [`crate::Plot::grid_spacing`].
+    /// Default: `8.0..=300.0`.
+    #[inline]
+    pub fn grid_spacing(mut self, grid_spacing: impl Into<Rangef>) -> Self {
+        self.grid_spacing = grid_spacing.into();
+        self
+    }
+
+    /// The length of the ruler in points, i.e. the width of a horizontal ruler.
+    ///
+    /// Default: all the available width or height.
+    #[inline]
+    pub fn length(mut self, length: f32) -> Self {
+        self.length = Some(length);
+        self
+    }
+}
+
+impl Widget for Ruler {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            axis,
+            range,
+            hints,
+            grid_spacer,
+            grid_spacing,
+            length,
+        } = self;
+
+        let iaxis = usize::from(axis);
+        let thickness = hints.thickness(ui, axis) + TICK_LENGTH;
+        let mut size = Vec2::ZERO;
+        size[iaxis] = length.unwrap_or_else(|| ui.available_size_before_wrap()[iaxis]);
+        size[1 - iaxis] = thickness;
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let mut min = [0.0; 2];
+        let mut max = [1.0; 2];
+        min[iaxis] = *range.start();
+        max[iaxis] = *range.end();
+        let transform = PlotTransform::new(rect, PlotBounds::from_min_max(min, max), false, false);
+
+        let steps = grid_spacer(GridInput {
+            bounds: (min[iaxis], max[iaxis]),
+            base_step_size: transform.dvalue_dpos()[iaxis].abs() * grid_spacing.min as f64,
+        });
+
+        // The tick marks are on the side facing the canvas, and the labels beyond them:
+        let (canvas_edge, direction) = match axis {
+            Axis::X => match VPlacement::from(hints.placement) {
+                VPlacement::Bottom => (rect.top(), 1.0),
+                VPlacement::Top => (rect.bottom(), -1.0),
+            },
+            Axis::Y => match HPlacement::from(hints.placement) {
+                HPlacement::Left => (rect.right(), -1.0),
+                HPlacement::Right => (rect.left(), 1.0),
+            },
+        };
+
+        let painter = ui.painter_at(rect);
+        for step in &steps {
+            let spacing_in_points = (transform.dpos_dvalue()[iaxis] * step.step_size).abs() as f32;
+            if spacing_in_points <= grid_spacing.min {
+                continue; // Too close together
+            }
+            let strength = remap_clamp(spacing_in_points, grid_spacing, 0.0..=1.0);
+
+            let mut value = [0.0; 2];
+            value[iaxis] = step.value;
+            let along = transform.position_from_point(&value.into())[iaxis];
+
+            let mut from = Pos2::ZERO;
+            from[iaxis] = along;
+            from[1 - iaxis] = canvas_edge;
+            let mut to = from;
+            to[1 - iaxis] += direction * TICK_LENGTH * strength.sqrt();
+            painter.line_segment(
+                [from, to],
+                Stroke::new(1.0, color_from_strength(ui, strength)),
+            );
+        }
+
+        let mut label_rect = rect;
+        if direction > 0.0 {
+            label_rect.min[1 - iaxis] += TICK_LENGTH;
+        } else {
+            label_rect.max[1 - iaxis] -= TICK_LENGTH;
+        }
+
+        let mut axis_widget = AxisWidget::new(hints, label_rect);
+        axis_widget.range = range;
+        axis_widget.transform = Some(PlotTransform::new(
+            label_rect,
+            PlotBounds::from_min_max(min, max),
+            false,
+            false,
+        ));
+        axis_widget.steps = Arc::new(steps);
+
+        // Paint the labels in a child ui, since we have already allocated the space:
+        let mut child_ui = ui.child_ui(label_rect, *ui.layout());
+        axis_widget.ui(&mut child_ui, axis);
+
+        response
+    }
+}
@@ -0,0 +1,341 @@
+//! Grid marks and tick labels for axes showing time, as seconds since the unix epoch.
+//!
+//! See [`crate::Plot::x_axis_time`].
+
+use std::ops::RangeInclusive;
+
+use super::{cmp_f64, log_grid_spacer, GridInput, GridMark, GridSpacer};
+
+const MINUTE: i64 = 60;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+/// The average length of a month in the gregorian calendar, in seconds.
+const AVERAGE_MONTH: f64 = 365.2425 * DAY as f64 / 12.0;
+
+/// A step between two grid marks on a time axis.
+#[derive(Clone, Copy)]
+enum TimeStep {
+    Seconds(i64),
+    Months(i64),
+}
+
+/// The possible steps, from fine to coarse. Each step is a multiple of the previous one,
+/// so that the marks of coarser steps coincide with marks of the finer steps.
+const TIME_STEPS: [TimeStep; 16] = [
+    TimeStep::Seconds(1),
+    TimeStep::Seconds(5),
+    TimeStep::Seconds(15),
+    TimeStep::Seconds(MINUTE),
+    TimeStep::Seconds(5 * MINUTE),
+    TimeStep::Seconds(15 * MINUTE),
+    TimeStep::Seconds(HOUR),
+    TimeStep::Seconds(3 * HOUR),
+    TimeStep::Seconds(DAY),
+    TimeStep::Months(1),
+    TimeStep::Months(3),
+    TimeStep::Months(12),
+    TimeStep::Months(5 * 12),
+    TimeStep::Months(10 * 12),
+    TimeStep::Months(50 * 12),
+    TimeStep::Months(100 * 12),
+];
+
+impl TimeStep {
+    /// The (approximate) length of the step in seconds, used as the step size of the grid marks.
+    fn seconds(self) -> f64 {
+        match self {
+            Self::Seconds(seconds) => seconds as f64,
+            Self::Months(months) => months as f64 * AVERAGE_MONTH,
+        }
+    }
+
+    /// Add the marks between `min` and `max`, aligned to the local time.
+    fn fill_marks(self, out: &mut Vec<GridMark>, (min, max): (f64, f64), utc_offset_seconds: i64) {
+        let offset = utc_offset_seconds as f64;
+        let (min, max) = (min + offset, max + offset);
+        let step_size = self.seconds();
+
+        match self {
+            Self::Seconds(seconds) => {
+                let first = (min / seconds as f64).ceil() as i64;
+                let last = (max / seconds as f64).floor() as i64;
+                out.extend((first..=last).map(|i| GridMark {
+                    value: (i * seconds) as f64 - offset,
+                    step_size,
+                }));
+            }
+            Self::Months(months) => {
+                let (year, month, _) = civil_from_days((min / DAY as f64).floor() as i64);
+                let mut index = (year * 12 + month - 1).div_euclid(months) * months;
+                loop {
+                    let start = month_start(index) as f64;
+                    if start > max {
+                        break;
+                    }
+                    if start >= min {
+                        out.push(GridMark {
+                            value: start - offset,
+                            step_size,
+                        });
+                    }
+                    index += months;
+                }
+            }
+        }
+    }
+}
+
+/// Grid marks for an axis showing time, as seconds since the unix epoch.
+///
+/// The marks are at whole seconds, minutes, hours, days, months or years,
+/// depending on how far zoomed in the plot is.
+/// Days start at midnight in the time zone `utc_offset_seconds` away from UTC,
+/// e.g. `3600` for UTC+1.
+///
+/// Below a second, the marks are spread out like in [`log_grid_spacer`].
+pub fn time_grid_spacer(utc_offset_seconds: i64) -> GridSpacer {
+    let sub_second_spacer = log_grid_spacer(10);
+    let step_sizes = move |input: GridInput| -> Vec<GridMark> {
+        // handle degenerate cases
+        if input.base_step_size.abs() < f64::EPSILON || input.bounds.1 <= input.bounds.0 {
+            return Vec::new();
+        }
+
+        if input.base_step_size < 1.0 {
+            return sub_second_spacer(input);
+        }
+
+        // The thinnest lines, and the next two coarser steps for thicker lines:
+        let first = TIME_STEPS
+            .iter()
+            .position(|step| step.seconds() >= input.base_step_size)
+            .unwrap_or(TIME_STEPS.len())
+            .min(TIME_STEPS.len() - 3);
+
+        let mut marks = Vec::new();
+        for step in &TIME_STEPS[first..first + 3] {
+            step.fill_marks(&mut marks, input.bounds, utc_offset_seconds);
+        }
+
+        // Remove the duplicates of the coarser steps, as in `generate_marks`:
+        marks.sort_by(|a, b| match cmp_f64(a.value, b.value) {
+            std::cmp::Ordering::Equal => cmp_f64(b.step_size, a.step_size),
+            ord => ord,
+        });
+        marks.dedup_by(|a, b| a.value == b.value);
+
+        marks
+    };
+
+    Box::new(step_sizes)
+}
+
+/// Tick labels for an axis showing time, as seconds since the unix epoch.
+///
+/// Shows as much of the date and time as the step size of each mark calls for,
+/// e.g. `2024` for years, `2024-03-15` for days and `14:30` for minutes,
+/// in the time zone `utc_offset_seconds` away from UTC.
+///
+/// Pair it with [`time_grid_spacer`], or use [`crate::Plot::x_axis_time`] for both.
+pub fn time_formatter(
+    utc_offset_seconds: i64,
+) -> impl Fn(GridMark, usize, &RangeInclusive<f64>) -> String {
+    move |mark, _max_digits, _range| format_time(mark.value, mark.step_size, utc_offset_seconds)
+}
+
+fn format_time(timestamp: f64, step_size: f64, utc_offset_seconds: i64) -> String {
+    let local = timestamp + utc_offset_seconds as f64;
+    let seconds = local.floor() as i64;
+    let (year, month, day) = civil_from_days(seconds.div_euclid(DAY));
+    let second_of_day = seconds.rem_euclid(DAY);
+    let (hour, minute) = (second_of_day / HOUR, second_of_day % HOUR / MINUTE);
+
+    if step_size >= 365.0 * DAY as f64 {
+        format!("{year}")
+    } else if step_size >= 28.0 * DAY as f64 {
+        format!("{year}-{month:02}")
+    } else if step_size >= DAY as f64 {
+        format!("{year}-{month:02}-{day:02}")
+    } else if step_size >= MINUTE as f64 {
+        format!("{hour:02}:{minute:02}")
+    } else if step_size >= 1.0 {
+        format!("{hour:02}:{minute:02}:{:02}", second_of_day % MINUTE)
+    } else {
+        let decimals = (-step_size.log10()).ceil().clamp(1.0, 9.0) as usize;
+        let second = (second_of_day % MINUTE) as f64 + (local - seconds as f64);
+        format!(
+            "{hour:02}:{minute:02}:{second:0width$.decimals$}",
+            width = decimals + 3
+        )
+    }
+}
+
+/// The start of the month with the given index (`year * 12 + month - 1`), in seconds since the epoch.
+fn month_start(index: i64) -> i64 {
+    days_from_civil(index.div_euclid(12), index.rem_euclid(12) + 1, 1) * DAY
+}
+
+/// Days since 1970-01-01 of the given date in the proleptic gregorian calendar.
+///
+/// From <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The `(year, month, day)` of the given number of days since 1970-01-01.
+///
+/// From <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timestamp(year: i64, month: i64, day: i64, second_of_day: i64) -> f64 {
+        (days_from_civil(year, month, day) * DAY + second_of_day) as f64
+    }
+
+    fn marks(bounds: (f64, f64), base_step_size: f64, utc_offset_seconds: i64) -> Vec<GridMark> {
+        time_grid_spacer(utc_offset_seconds)(GridInput {
+            bounds,
+            base_step_size,
+        })
+    }
+
+    #[test]
+    fn seconds_and_minutes() {
+        let marks = marks((0.0, 60.0), 2.0, 0);
+        let values: Vec<f64> = marks.iter().map(|mark| mark.value).collect();
+        assert_eq!(values, (0..=12).map(|i| 5.0 * i as f64).collect::<Vec<_>>());
+
+        let step_size = |value: f64| {
+            marks
+                .iter()
+                .find(|mark| mark.value == value)
+                .unwrap()
+                .step_size
+        };
+        assert_eq!(step_size(0.0), 60.0);
+        assert_eq!(step_size(15.0), 15.0);
+        assert_eq!(step_size(20.0), 5.0);
+        assert_eq!(step_size(60.0), 60.0);
+    }
+
+    #[test]
+    fn days_and_months() {
+        // 2024 is a leap year:
+        let (start, end) = (timestamp(2024, 1, 30, 0), timestamp(2024, 3, 2, 0));
+        let marks = marks((start, end), 40_000.0, 0);
+        assert_eq!(marks.len(), 2 + 29 + 2);
+        assert!(marks
+            .iter()
+            .all(|mark| (mark.value - start) % DAY as f64 == 0.0));
+
+        let month_starts: Vec<f64> = marks
+            .iter()
+            .filter(|mark| mark.step_size == AVERAGE_MONTH)
+            .map(|mark| mark.value)
+            .collect();
+        assert_eq!(
+            month_starts,
+            vec![timestamp(2024, 2, 1, 0), timestamp(2024, 3, 1, 0)]
+        );
+    }
+
+    #[test]
+    fn years_across_new_year() {
+        let (start, end) = (timestamp(2019, 6, 1, 0), timestamp(2031, 6, 1, 0));
+        let marks = marks((start, end), 60.0 * DAY as f64, 0);
+        // Quarters, years and five years:
+        assert_eq!(marks[0].value, timestamp(2019, 7, 1, 0));
+        assert!(marks.contains(&GridMark {
+            value: timestamp(2020, 1, 1, 0),
+            step_size: 5.0 * 12.0 * AVERAGE_MONTH,
+        }));
+        assert!(marks.contains(&GridMark {
+            value: timestamp(2021, 1, 1, 0),
+            step_size: 12.0 * AVERAGE_MONTH,
+        }));
+    }
+
+    #[test]
+    fn local_midnight() {
+        let (start, end) = (timestamp(2024, 1, 1, 0), timestamp(2024, 1, 3, 0));
+        let values: Vec<f64> = marks((start, end), 20.0 * HOUR as f64, HOUR)
+            .iter()
+            .filter(|mark| mark.step_size == DAY as f64)
+            .map(|mark| mark.value)
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                timestamp(2024, 1, 1, 23 * HOUR),
+                timestamp(2024, 1, 2, 23 * HOUR)
+            ]
+        );
+    }
+
+    #[test]
+    fn labels() {
+        let t = timestamp(2024, 3, 15, 14 * HOUR + 30 * MINUTE + 5);
+        assert_eq!(format_time(t, 365.0 * DAY as f64, 0), "2024");
+        assert_eq!(format_time(t, AVERAGE_MONTH, 0), "2024-03");
+        assert_eq!(format_time(t, DAY as f64, 0), "2024-03-15");
+        assert_eq!(format_time(t, HOUR as f64, 0), "14:30");
+        assert_eq!(format_time(t, 5.0, 0), "14:30:05");
+        assert_eq!(format_time(t + 0.25, 0.1, 0), "14:30:05.2");
+        assert_eq!(format_time(t, HOUR as f64, -3 * HOUR), "11:30");
+    }
+
+    #[test]
+    fn labels_across_unit_boundaries() {
+        // The last second of a minute, hour, day, month and year:
+        let t = timestamp(2023, 12, 31, DAY - 1);
+        assert_eq!(format_time(t, 1.0, 0), "23:59:59");
+        assert_eq!(format_time(t + 1.0, 1.0, 0), "00:00:00");
+        assert_eq!(format_time(t, DAY as f64, 0), "2023-12-31");
+        assert_eq!(format_time(t + 1.0, DAY as f64, 0), "2024-01-01");
+        assert_eq!(format_time(t + 1.0, AVERAGE_MONTH, 0), "2024-01");
+
+        // Leap day and month rollover:
+        let t = timestamp(2024, 2, 28, 0);
+        assert_eq!(format_time(t + DAY as f64, DAY as f64, 0), "2024-02-29");
+        assert_eq!(
+            format_time(t + 2.0 * DAY as f64, DAY as f64, 0),
+            "2024-03-01"
+        );
+        let t = timestamp(2023, 2, 28, 0);
+        assert_eq!(format_time(t + DAY as f64, DAY as f64, 0), "2023-03-01");
+
+        // The time zone can move a timestamp to another day:
+        let t = timestamp(2024, 1, 31, 23 * HOUR);
+        assert_eq!(format_time(t, DAY as f64, 2 * HOUR), "2024-02-01");
+    }
+
+    #[test]
+    fn civil_round_trip() {
+        for days in [-719_468, -1, 0, 59, 11_016, 19_782, 100_000] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+    }
+}
@@ -3,6 +3,45 @@ use std::ops::RangeInclusive;
 use super::PlotPoint;
 use crate::*;
 
+/// How the values along an axis are spread out over the screen.
+///
+/// See [`crate::Plot::x_axis_scale`] and [`crate::Plot::y_axis_scale`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum AxisScale {
+    /// Equal differences take up equal distances.
+    #[default]
+    Linear,
+
+    /// Equal ratios take up equal distances, so each decade (1, 10, 100, …) is equally wide.
+    ///
+    /// Only positive values can be shown on a logarithmic axis.
+    Log10,
+}
+
+impl AxisScale {
+    /// Map a value to the linear space in which the axis is laid out.
+    ///
+    /// For [`Self::Log10`] this is the logarithm of the value, so one unit is one decade.
+    /// Non-positive values are mapped far outside of any visible range.
+    #[inline]
+    pub fn to_linear(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => value.max(f64::MIN_POSITIVE).log10(),
+        }
+    }
+
+    /// The inverse of [`Self::to_linear`].
+    #[inline]
+    pub fn value_from_linear(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 => 10.0_f64.powf(value),
+        }
+    }
+}
+
 /// 2D bounding box of f64 precision.
 ///
 /// The range of data values we show.
@@ -151,6 +190,22 @@ impl PlotBounds {
         self.max[1] = other.max[1];
     }
 
+    /// Change the width, keeping the center.
+    #[inline]
+    pub fn set_width(&mut self, width: f64) {
+        let center = 0.5 * (self.min[0] + self.max[0]);
+        self.min[0] = center - 0.5 * width;
+        self.max[0] = center + 0.5 * width;
+    }
+
+    /// Change the height, keeping the center.
+    #[inline]
+    pub fn set_height(&mut self, height: f64) {
+        let center = 0.5 * (self.min[1] + self.max[1]);
+        self.min[1] = center - 0.5 * height;
+        self.max[1] = center + 0.5 * height;
+    }
+
     #[inline]
     pub fn merge(&mut self, other: &Self) {
         self.min[0] = self.min[0].min(other.min[0]);
@@ -220,6 +275,51 @@ impl PlotBounds {
         self.min[1] = -y_abs;
         self.max[1] = y_abs;
     }
+
+    /// Make sure the bounds can be shown on axes with the given scales.
+    ///
+    /// Logarithmic axes can only show positive values, so a non-positive minimum
+    /// is moved to three decades below the maximum.
+    pub(crate) fn clamp_to_scales(&mut self, scales: [AxisScale; 2]) {
+        for (axis, scale) in scales.into_iter().enumerate() {
+            if scale == AxisScale::Log10 {
+                if self.max[axis] <= 0.0 {
+                    self.min[axis] = 1.0;
+                    self.max[axis] = 10.0;
+                } else if self.min[axis] <= 0.0 {
+                    self.min[axis] = self.max[axis] * 1e-3;
+                }
+            }
+        }
+    }
+
+    /// The bounds in the linear space of the given axis scales, see [`AxisScale::to_linear`].
+    pub(crate) fn to_linear(self, scales: [AxisScale; 2]) -> Self {
+        Self {
+            min: [
+                scales[0].to_linear(self.min[0]),
+                scales[1].to_linear(self.min[1]),
+            ],
+            max: [
+                scales[0].to_linear(self.max[0]),
+                scales[1].to_linear(self.max[1]),
+            ],
+        }
+    }
+
+    /// The inverse of [`Self::to_linear`].
+    pub(crate) fn values_from_linear(self, scales: [AxisScale; 2]) -> Self {
+        Self {
+            min: [
+                scales[0].value_from_linear(self.min[0]),
+                scales[1].value_from_linear(self.min[1]),
+            ],
+            max: [
+                scales[0].value_from_linear(self.max[0]),
+                scales[1].value_from_linear(self.max[1]),
+            ],
+        }
+    }
 }
 
 /// Contains the screen rectangle and the plot bounds and provides methods to transform between them.
@@ -237,6 +337,14 @@ pub struct PlotTransform {
 
     /// Whether to always center the y-range of the bounds.
     y_centered: bool,
+
+    /// The scales of the x and y axes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    scales: [AxisScale; 2],
+
+    /// Whether the x values increase to the left, and the y values downwards.
+    #[cfg_attr(feature = "serde", serde(default))]
+    inverted_axis: Vec2b,
 }
 
 impl PlotTransform {
@@ -262,9 +370,70 @@ impl PlotTransform {
             bounds,
             x_centered,
             y_centered,
+            scales: Default::default(),
+            inverted_axis: Default::default(),
         }
     }
 
+    /// Let the x values increase to the left, and/or the y values downwards.
+    #[inline]
+    pub fn with_inverted_axis(mut self, inverted_axis: Vec2b) -> Self {
+        self.inverted_axis = inverted_axis;
+        self
+    }
+
+    /// Whether the x values increase to the left, and the y values downwards.
+    #[inline]
+    pub fn inverted_axis(&self) -> Vec2b {
+        self.inverted_axis
+    }
+
+    /// The left and right edges of the frame, in the order of increasing x values.
+    fn frame_range_x(&self) -> RangeInclusive<f64> {
+        let (left, right) = (self.frame.left() as f64, self.frame.right() as f64);
+        if self.inverted_axis.x {
+            right..=left
+        } else {
+            left..=right
+        }
+    }
+
+    /// The bottom and top edges of the frame, in the order of increasing y values.
+    fn frame_range_y(&self) -> RangeInclusive<f64> {
+        let (bottom, top) = (self.frame.bottom() as f64, self.frame.top() as f64);
+        if self.inverted_axis.y {
+            top..=bottom
+        } else {
+            bottom..=top // negated y axis!
+        }
+    }
+
+    /// Use the given scales for the x and y axes.
+    ///
+    /// The bounds are adjusted if they can't be shown with these scales,
+    /// e.g. if they include zero on a logarithmic axis.
+    #[inline]
+    pub fn with_scales(mut self, scales: [AxisScale; 2]) -> Self {
+        self.bounds.clamp_to_scales(scales);
+        self.scales = scales;
+        self
+    }
+
+    /// The scales of the x and y axes.
+    #[inline]
+    pub fn scales(&self) -> [AxisScale; 2] {
+        self.scales
+    }
+
+    /// The bounds in the linear space the axes are laid out in, see [`AxisScale::to_linear`].
+    fn linear_bounds(&self) -> PlotBounds {
+        self.bounds.to_linear(self.scales)
+    }
+
+    fn set_linear_bounds(&mut self, linear_bounds: PlotBounds) {
+        self.bounds = linear_bounds.values_from_linear(self.scales);
+    }
+
     /// ui-space rectangle.
     #[inline]
     pub fn frame(&self) -> &Rect {
@@ -291,34 +460,42 @@ impl PlotTransform {
         }
         delta_pos.x *= self.dvalue_dpos()[0] as f32;
         delta_pos.y *= self.dvalue_dpos()[1] as f32;
-        self.bounds.translate(delta_pos);
+        let mut linear_bounds = self.linear_bounds();
+        linear_bounds.translate(delta_pos);
+        self.set_linear_bounds(linear_bounds);
     }
 
     /// Zoom by a relative factor with the given screen position as center.
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
         let center = self.value_from_position(center);
+        let center = PlotPoint::new(
+            self.scales[0].to_linear(center.x),
+            self.scales[1].to_linear(center.y),
+        );
 
-        let mut new_bounds = self.bounds;
+        let mut new_bounds = self.linear_bounds();
         new_bounds.zoom(zoom_factor, center);
 
         if new_bounds.is_valid() {
-            self.bounds = new_bounds;
+            self.set_linear_bounds(new_bounds);
         }
     }
 
     pub fn position_from_point_x(&self, value: f64) -> f32 {
+        let scale = self.scales[0];
         remap(
-            value,
-            self.bounds.min[0]..=self.bounds.max[0],
-            (self.frame.left() as f64)..=(self.frame.right() as f64),
+            scale.to_linear(value),
+            scale.to_linear(self.bounds.min[0])..=scale.to_linear(self.bounds.max[0]),
+            self.frame_range_x(),
         ) as f32
     }
 
     pub fn position_from_point_y(&self, value: f64) -> f32 {
+        let scale = self.scales[1];
         remap(
-            value,
-            self.bounds.min[1]..=self.bounds.max[1],
-            (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
+            scale.to_linear(value),
+            scale.to_linear(self.bounds.min[1])..=scale.to_linear(self.bounds.max[1]),
+            self.frame_range_y(),
         ) as f32
     }
 
@@ -332,17 +509,21 @@ impl PlotTransform {
 
     /// Plot point from screen/ui position.
     pub fn value_from_position(&self, pos: Pos2) -> PlotPoint {
+        let linear_bounds = self.linear_bounds();
         let x = remap(
             pos.x as f64,
-            (self.frame.left() as f64)..=(self.frame.right() as f64),
-            self.bounds.min[0]..=self.bounds.max[0],
+            self.frame_range_x(),
+            linear_bounds.min[0]..=linear_bounds.max[0],
         );
         let y = remap(
             pos.y as f64,
-            (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
-            self.bounds.min[1]..=self.bounds.max[1],
+            self.frame_range_y(),
+            linear_bounds.min[1]..=linear_bounds.max[1],
         );
-        PlotPoint::new(x, y)
+        PlotPoint::new(
+            self.scales[0].value_from_linear(x),
+            self.scales[1].value_from_linear(y),
+        )
     }
 
     /// Transform a rectangle of plot values to a screen-coordinate rectangle.
@@ -360,13 +541,19 @@ impl PlotTransform {
     }
 
     /// delta position / delta value = how many ui points per step in the X axis in "plot space"
+    ///
+    /// On a logarithmic axis the step is measured in decades, see [`AxisScale::to_linear`].
     pub fn dpos_dvalue_x(&self) -> f64 {
-        self.frame.width() as f64 / self.bounds.width()
+        let range = self.frame_range_x();
+        (range.end() - range.start()) / self.linear_bounds().width()
     }
 
     /// delta position / delta value = how many ui points per step in the Y axis in "plot space"
+    ///
+    /// On a logarithmic axis the step is measured in decades, see [`AxisScale::to_linear`].
     pub fn dpos_dvalue_y(&self) -> f64 {
-        -self.frame.height() as f64 / self.bounds.height() // negated y axis!
+        let range = self.frame_range_y();
+        (range.end() - range.start()) / self.linear_bounds().height()
     }
 
     /// delta position / delta value = how many ui points per step in "plot space"
@@ -385,7 +572,8 @@ impl PlotTransform {
     fn aspect(&self) -> f64 {
         let rw = self.frame.width() as f64;
         let rh = self.frame.height() as f64;
-        (self.bounds.width() / rw) / (self.bounds.height() / rh)
+        let linear_bounds = self.linear_bounds();
+        (linear_bounds.width() / rw) / (linear_bounds.height() / rh)
     }
 
     /// Sets the aspect ratio by expanding the x- or y-axis.
@@ -400,13 +588,32 @@ impl PlotTransform {
             return;
         }
 
+        let mut linear_bounds = self.linear_bounds();
         if current_aspect < aspect {
-            self.bounds
-                .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
+            linear_bounds.expand_x((aspect / current_aspect - 1.0) * linear_bounds.width() * 0.5);
         } else {
-            self.bounds
-                .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
+            linear_bounds.expand_y((current_aspect / aspect - 1.0) * linear_bounds.height() * 0.5);
         }
+        self.set_linear_bounds(linear_bounds);
+    }
+
+    /// Sets the aspect ratio by shrinking the frame, keeping it centered where it was.
+    ///
+    /// This never changes the bounds, so the same data stays visible.
+    pub(crate) fn set_aspect_by_shrinking_frame(&mut self, aspect: f64) {
+        let linear_bounds = self.linear_bounds();
+        let width_per_height = (linear_bounds.width() / linear_bounds.height() / aspect) as f32;
+        if !width_per_height.is_finite() || width_per_height <= 0.0 {
+            return;
+        }
+
+        let available = self.frame;
+        let size = if available.width() > available.height() * width_per_height {
+            vec2(available.height() * width_per_height, available.height())
+        } else {
+            vec2(available.width(), available.width() / width_per_height)
+        };
+        self.frame = Rect::from_center_size(available.center(), size);
     }
 
     /// Sets the aspect ratio by changing either the X or Y axis (callers choice).
@@ -419,15 +626,79 @@ impl PlotTransform {
             return;
         }
 
+        let mut linear_bounds = self.linear_bounds();
         match axis {
             Axis::X => {
-                self.bounds
-                    .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
+                linear_bounds
+                    .expand_x((aspect / current_aspect - 1.0) * linear_bounds.width() * 0.5);
             }
             Axis::Y => {
-                self.bounds
-                    .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
+                linear_bounds
+                    .expand_y((current_aspect / aspect - 1.0) * linear_bounds.height() * 0.5);
             }
         }
+        self.set_linear_bounds(linear_bounds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() <= 1e-12 * b.abs(), "{a} != {b}");
+    }
+
+    #[test]
+    fn log_axis_round_trip() {
+        for value in [1e-300, 1e-5, 0.3, 1.0, 42.0, 1e9] {
+            let linear = AxisScale::Log10.to_linear(value);
+            assert_close(AxisScale::Log10.value_from_linear(linear), value);
+        }
+        assert_eq!(AxisScale::Log10.to_linear(1000.0), 3.0);
+        assert_eq!(AxisScale::Log10.value_from_linear(-2.0), 0.01);
+    }
+
+    #[test]
+    fn log_axis_non_positive() {
+        let smallest = AxisScale::Log10.to_linear(f64::MIN_POSITIVE);
+        for value in [0.0, -0.0, -1.0, f64::NEG_INFINITY] {
+            let linear = AxisScale::Log10.to_linear(value);
+            assert_eq!(linear, smallest);
+            assert!(linear < AxisScale::Log10.to_linear(1e-300));
+            assert!(AxisScale::Log10.value_from_linear(linear) > 0.0);
+        }
+    }
+
+    #[test]
+    fn linear_axis_is_identity() {
+        for value in [-1e9, -1.0, 0.0, 0.3, 1e9] {
+            assert_eq!(AxisScale::Linear.to_linear(value), value);
+            assert_eq!(AxisScale::Linear.value_from_linear(value), value);
+        }
+    }
+
+    #[test]
+    fn bounds_round_trip() {
+        let scales = [AxisScale::Linear, AxisScale::Log10];
+        let bounds = PlotBounds::from_min_max([-5.0, 0.01], [5.0, 100.0]);
+        let linear = bounds.to_linear(scales);
+        assert_eq!(linear.min(), [-5.0, -2.0]);
+        assert_eq!(linear.max(), [5.0, 2.0]);
+
+        let round_trip = linear.values_from_linear(scales);
+        for axis in 0..2 {
+            assert_close(round_trip.min()[axis], bounds.min()[axis]);
+            assert_close(round_trip.max()[axis], bounds.max()[axis]);
+        }
+    }
+
+    #[test]
+    fn clamp_non_positive_bounds_to_log_scale() {
+        let scales = [AxisScale::Log10, AxisScale::Log10];
+        let mut bounds = PlotBounds::from_min_max([-1.0, -10.0], [50.0, -1.0]);
+        bounds.clamp_to_scales(scales);
+        assert_eq!(bounds.min(), [0.05, 1.0]);
+        assert_eq!(bounds.max(), [50.0, 10.0]);
     }
 }
@@ -50,13 +50,13 @@ pub use self::{
     shadow::Shadow,
     shape::{
         CircleShape, EllipseShape, PaintCallback, PaintCallbackInfo, PathShape, RectShape,
-        Rounding, Shape, TextShape,
+        Rounding, Shape, TextShape, ViewportInPixels,
     },
     stats::PaintStats,
     stroke::Stroke,
     tessellator::{TessellationOptions, Tessellator},
     text::{FontFamily, FontId, Fonts, Galley},
-    texture_atlas::TextureAtlas,
+    texture_atlas::{PreparedDisc, TextureAtlas},
     texture_handle::TextureHandle,
     textures::TextureManager,
 };
@@ -107,6 +107,7 @@ impl Default for TextureId {
 ///
 /// Everything is using logical points.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ClippedShape {
     /// Clip / scissor rectangle.
     /// Only show the part of the [`Shape`] that falls within this.
@@ -120,6 +121,7 @@ pub struct ClippedShape {
 ///
 /// Everything is using logical points.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ClippedPrimitive {
     /// Clip / scissor rectangle.
     /// Only show the part of the [`Mesh`] that falls within this.
@@ -130,9 +132,14 @@ pub struct ClippedPrimitive {
 }
 
 /// A rendering primitive - either a [`Mesh`] or a [`PaintCallback`].
+///
+/// With the `serde` feature, [`Primitive::Callback`] fails to serialize.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Primitive {
     Mesh(Mesh),
+
+    #[cfg_attr(feature = "serde", serde(skip))]
     Callback(PaintCallback),
 }
 
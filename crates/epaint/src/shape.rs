@@ -17,8 +17,12 @@ pub use crate::{CubicBezierShape, QuadraticBezierShape};
 /// but storing them should also be fine with one exception:
 /// [`Shape::Text`] depends on the current `pixels_per_point` (dpi scale)
 /// and so must be recreated every time `pixels_per_point` changes.
+///
+/// With the `serde` feature, shapes can be serialized,
+/// except for [`Shape::Callback`] which fails to serialize.
 #[must_use = "Add a Shape to a Painter"]
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum Shape {
     /// Paint nothing. This can be useful as a placeholder.
     Noop,
@@ -60,6 +64,9 @@ pub enum Shape {
     CubicBezier(CubicBezierShape),
 
     /// Backend-specific painting.
+    ///
+    /// Can not be serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
     Callback(PaintCallback),
 }
 
@@ -1171,6 +1178,7 @@ pub struct PaintCallbackInfo {
 }
 
 /// Size of the viewport in whole, physical pixels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ViewportInPixels {
     /// Physical pixel offset for left side of the viewport.
     pub left_px: i32,
@@ -1252,7 +1260,21 @@ impl PaintCallbackInfo {
 
 /// If you want to paint some 3D shapes inside an egui region, you can use this.
 ///
-/// This is advanced usage, and is backend specific.
+/// This is advanced usage, and is backend specific:
+/// the payload is downcast by the rendering backend to the type it expects,
+/// e.g. `egui_glow::CallbackFn` or `egui_wgpu::Callback`.
+///
+/// To support several backends with the same shape, give it one payload per backend:
+///
+/// ```
+/// # use epaint::{PaintCallback, Rect};
+/// # struct GlowCallback;
+/// # struct WgpuCallback;
+/// # let rect = Rect::ZERO;
+/// let callback = PaintCallback::new(rect, GlowCallback).with_backend(WgpuCallback);
+/// assert!(callback.downcast_ref::<GlowCallback>().is_some());
+/// assert!(callback.downcast_ref::<WgpuCallback>().is_some());
+/// ```
 #[derive(Clone)]
 pub struct PaintCallback {
     /// Where to paint.
@@ -1279,6 +1301,49 @@ pub struct PaintCallback {
     pub callback: Arc<dyn Any + Send + Sync>,
 }
 
+/// The payloads of a [`PaintCallback`] that supports several backends.
+///
+/// See [`PaintCallback::with_backend`].
+struct BackendPayloads(Vec<Arc<dyn Any + Send + Sync>>);
+
+impl PaintCallback {
+    /// Paint in `rect` with a payload for one backend, e.g. `egui_glow::CallbackFn`.
+    pub fn new(rect: Rect, callback: impl Any + Send + Sync) -> Self {
+        Self {
+            rect,
+            callback: Arc::new(callback),
+        }
+    }
+
+    /// Add a payload for another backend.
+    ///
+    /// Each backend picks the payload of the type it expects, see [`Self::downcast_ref`].
+    #[inline]
+    pub fn with_backend(mut self, callback: impl Any + Send + Sync) -> Self {
+        let mut payloads = match self.callback.downcast_ref::<BackendPayloads>() {
+            Some(BackendPayloads(payloads)) => payloads.clone(),
+            None => vec![self.callback.clone()],
+        };
+        payloads.push(Arc::new(callback));
+        self.callback = Arc::new(BackendPayloads(payloads));
+        self
+    }
+
+    /// The payload of type `T`, if any.
+    ///
+    /// Rendering backends should use this rather than downcasting [`Self::callback`] directly,
+    /// so that callbacks with several backends (see [`Self::with_backend`]) work.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        if let Some(BackendPayloads(payloads)) = self.callback.downcast_ref::<BackendPayloads>() {
+            payloads
+                .iter()
+                .find_map(|payload| payload.downcast_ref::<T>())
+        } else {
+            self.callback.downcast_ref::<T>()
+        }
+    }
+}
+
 impl std::fmt::Debug for PaintCallback {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CustomShape")
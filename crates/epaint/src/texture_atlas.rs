@@ -40,6 +40,7 @@ struct PrerasterizedDisc {
 
 /// A pre-rasterized disc (filled circle), somewhere in the texture atlas.
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct PreparedDisc {
     /// The radius of this disc in texels.
     pub r: f32,